@@ -1,20 +1,44 @@
 // String length limits
 pub const MAX_TITLE_LENGTH: usize = 50;
 pub const MAX_DESCRIPTION_LENGTH: usize = 250;
-
-// Voting limits
-pub const MAX_SUPPORT_EPOCHS: u64 = 1; // Maximum 1 epochs for support phase
+pub const MAX_DISPLAY_NAME_LENGTH: usize = 64;
+pub const MAX_PROFILE_URL_LENGTH: usize = 128;
 
 pub const BASIS_POINTS_MAX: u64 = 10_000;
 
 // Anchor discriminator size
 pub const ANCHOR_DISCRIMINATOR: usize = 8;
 
+// Initial `GovernanceConfig` rules: thresholds, proposer stake, open proposal limit, bond
+// and phase lengths. Passed proposals can change them afterwards.
+
+// Share of decisive (for + against) stake that must vote `For` for a proposal to pass
+pub const PASS_THRESHOLD_BP: u64 = 6_667;
+pub const MAX_PASS_THRESHOLD_BP: u64 = 9_000;
+
+// Share of cluster stake that must vote, abstentions included, for a proposal to pass
+pub const QUORUM_BP: u64 = 1_000;
+pub const MAX_QUORUM_BP: u64 = 5_000;
+
 pub const MIN_PROPOSAL_STAKE_LAMPORTS: u64 = 100_000 * 1_000_000_000;
+pub const MAX_MIN_PROPOSAL_STAKE_LAMPORTS: u64 = 1_000_000 * 1_000_000_000;
+
+// Most non-finalized proposals a single vote account can have open at once
+pub const MAX_OPEN_PROPOSALS_PER_AUTHOR: u16 = 3;
 
-pub const CLUSTER_SUPPORT_MULTIPLIER: u128 = 100;
+// Bond locked by create_proposal, returned once the proposal reaches voting
+pub const PROPOSAL_BOND_LAMPORTS: u64 = 10 * 1_000_000_000;
+pub const MAX_PROPOSAL_BOND_LAMPORTS: u64 = 1_000 * 1_000_000_000;
+// Rent-exempt minimum of an empty account, so a bond forfeited into an empty treasury can fund it
+pub const MIN_PROPOSAL_BOND_LAMPORTS: u64 = 890_880;
 
-pub const CLUSTER_STAKE_MULTIPLIER: u128 = 5;
+// Share of cluster stake whose support activates voting
+pub const SUPPORT_THRESHOLD_BP: u64 = 500;
+// Highest support threshold of either class, so activation stays reachable
+pub const MAX_SUPPORT_THRESHOLD_BP: u64 = 3_000;
+
+// Number of epochs in which a proposal can gather support, starting the epoch after creation
+pub const SUPPORT_EPOCHS: u64 = 1;
 
 // Number of full epochs reserved for discussion between support and snapshot.
 // Example (creation at epoch 800):
@@ -25,3 +49,59 @@ pub const CLUSTER_STAKE_MULTIPLIER: u128 = 5;
 pub const DISCUSSION_EPOCHS: u64 = 3;
 pub const VOTING_EPOCHS: u64 = 3;
 pub const SNAPSHOT_EPOCH_EXTENSION: u64 = 1;
+
+// Emergency fast-track proposals need 15% of cluster stake in support, skip discussion and
+// vote for a single epoch. The schedule is fixed when voting activates; snapshot stake votes
+// can only land once the snapshot for the snapshot epoch is published.
+// Example (creation at epoch 800):
+// - Support: 801
+// - Snapshot: 802
+// - Voting: 803
+pub const EMERGENCY_SUPPORT_THRESHOLD_BP: u64 = 1_500;
+pub const EMERGENCY_SUPPORT_EPOCHS: u64 = 1;
+pub const EMERGENCY_DISCUSSION_EPOCHS: u64 = 0;
+pub const EMERGENCY_VOTING_EPOCHS: u64 = 1;
+
+// Longest support, discussion or voting period a parameter change can set
+pub const MAX_PHASE_EPOCHS: u64 = 10;
+// Maximum number of parameters a single proposal can change
+pub const MAX_PARAMETER_CHANGES: usize = 8;
+
+// Slots into the snapshot epoch of a fixed snapshot; a random snapshot stays this far
+// from either end of the epoch
+pub const SNAPSHOT_SLOT_OFFSET: u64 = 1000;
+
+// Proposal transaction limits
+pub const MAX_PROPOSAL_INSTRUCTIONS: usize = 8;
+pub const MAX_PROPOSAL_TRANSACTION_SIZE: usize = 8_192;
+// Longest hold-up an author may put between finalization and execution (~30 days)
+pub const MAX_EXECUTION_TIMELOCK_SECONDS: i64 = 30 * 24 * 60 * 60;
+
+// Anti-sniping limits: the window and each extension are bounded to one epoch
+pub const MAX_ANTI_SNIPING_WINDOW_SLOTS: u64 = 432_000;
+pub const MAX_ANTI_SNIPING_EXTENSION_EPOCHS: u64 = 1;
+pub const MAX_ANTI_SNIPING_EXTENSIONS: u8 = 3;
+
+// Longest reveal window a secret ballot proposal can have after voting ends
+pub const MAX_REVEAL_EPOCHS: u64 = 2;
+
+// Epochs after a snapshot epoch until every proposal voting with that snapshot has closed:
+// voting starts the next epoch and lasts at most the longest voting period, every
+// anti-sniping extension and the longest reveal window
+pub const SNAPSHOT_RETENTION_EPOCHS: u64 = 1
+    + MAX_PHASE_EPOCHS
+    + MAX_ANTI_SNIPING_EXTENSION_EPOCHS * MAX_ANTI_SNIPING_EXTENSIONS as u64
+    + MAX_REVEAL_EPOCHS;
+
+// Maximum number of times the author can flush a proposal's merkle root
+pub const MAX_MERKLE_ROOT_FLUSHES: usize = 3;
+
+// Prefix of the message a voting wallet signs for cast_vote_signed, so the signed bytes can
+// never be taken for a transaction message
+pub const SIGNED_VOTE_DOMAIN: &[u8] = b"svmgov signed vote";
+
+// Maximum number of stake accounts in a single cast_vote_override_batch
+pub const MAX_VOTE_OVERRIDE_BATCH_SIZE: usize = 8;
+
+// Number of proposals recorded in each proposal registry page
+pub const REGISTRY_PAGE_SIZE: usize = 32;
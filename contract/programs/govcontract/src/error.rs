@@ -92,4 +92,132 @@ pub enum GovernanceError {
     InvalidVoteOverrideCache,
     #[msg("Stake account owner mismatch")]
     StakeAccountOwnerMismatch,
+    #[msg("Proposal transaction must contain at least one instruction")]
+    EmptyProposalTransaction,
+    #[msg("Proposal transaction exceeds the maximum number of instructions or size")]
+    ProposalTransactionTooLarge,
+    #[msg("Execution timelock is out of range")]
+    InvalidExecutionTimelock,
+    #[msg("Only the governance authority can sign proposal instructions")]
+    InvalidProposalInstructionSigner,
+    #[msg("Proposal has not passed")]
+    ProposalNotPassed,
+    #[msg("Execution timelock has not elapsed")]
+    ExecutionTimelockNotElapsed,
+    #[msg("Proposal transaction has already been executed")]
+    ProposalTransactionAlreadyExecuted,
+    #[msg("Account required by a proposal instruction was not provided")]
+    MissingInstructionAccount,
+    #[msg("Invalid anti-sniping configuration")]
+    InvalidAntiSnipingConfig,
+    #[msg("Merkle root flush limit reached")]
+    MerkleRootFlushLimitReached,
+    #[msg("Cannot flush the merkle root after votes have been cast")]
+    CannotFlushAfterVotes,
+    #[msg("Vote override batch must contain between 1 and the maximum number of entries")]
+    InvalidVoteOverrideBatchSize,
+    #[msg("Vote override account is invalid or already initialized")]
+    InvalidVoteOverrideAccount,
+    #[msg("Proposal registry page is full")]
+    RegistryPageFull,
+    #[msg("Registry page does not hold this proposal")]
+    InvalidRegistryPage,
+    #[msg("Proposal bond cannot be settled until the proposal reaches voting or misses support")]
+    BondNotSettleable,
+    #[msg("Proposal bond has already been settled")]
+    BondAlreadySettled,
+    #[msg("Treasury transfer amount must be greater than zero")]
+    InvalidTreasuryTransferAmount,
+    #[msg("Treasury transfer has already been executed")]
+    TreasuryTransferAlreadyExecuted,
+    #[msg("Treasury balance is too low for this transfer")]
+    InsufficientTreasuryBalance,
+    #[msg("SPL treasury transfers need the mint, treasury token account and token program")]
+    MissingTreasuryTokenAccounts,
+    #[msg("Validator profile field is too long")]
+    ProfileFieldTooLong,
+    #[msg("Signals can only be sent between voting activation and the snapshot")]
+    NotInDiscussionPeriod,
+    #[msg("A validator cannot delegate its governance vote to itself")]
+    SelfDelegation,
+    #[msg("Delegation does not apply to this proposal or vote account")]
+    InvalidDelegation,
+    #[msg("A delegation for this specific proposal takes precedence")]
+    DelegationOverridden,
+    #[msg("The delegator has voted directly on this proposal")]
+    DelegatorVotedDirectly,
+    #[msg("Invalid secret ballot configuration")]
+    InvalidSecretBallotConfig,
+    #[msg("Secret ballot votes are cast with the placeholder split, then committed and revealed")]
+    SecretBallotPlaceholderRequired,
+    #[msg("Not available on secret ballot proposals")]
+    SecretBallotProposal,
+    #[msg("Proposal does not use secret ballots")]
+    NotSecretBallot,
+    #[msg("Ballots can only be revealed after voting ends and before the reveal window closes")]
+    NotInRevealPeriod,
+    #[msg("Revealed split and salt do not match the commitment")]
+    CommitmentMismatch,
+    #[msg("Ballot has already been revealed")]
+    BallotAlreadyRevealed,
+    #[msg("SlotHashes sysvar has no entries")]
+    SlotHashesUnavailable,
+    #[msg("Ballot box account is required for a fixed snapshot slot")]
+    BallotBoxRequired,
+    #[msg("Ballot box already exists")]
+    BallotBoxExists,
+    #[msg("Vote account has reached its limit of open proposals")]
+    TooManyOpenProposals,
+    #[msg("Proposal has already been superseded")]
+    ProposalAlreadySuperseded,
+    #[msg("Account is not the proposal this one supersedes")]
+    InvalidSupersededProposal,
+    #[msg("Superseded proposal account is required to finalize a superseding proposal")]
+    SupersededProposalRequired,
+    #[msg("Invalid governance parameter change")]
+    InvalidParameterChange,
+    #[msg("Parameter change has already been enacted")]
+    ParameterChangeAlreadyEnacted,
+    #[msg("Proposal has not been finalized")]
+    ProposalNotFinalized,
+    #[msg("Meta merkle proof does not belong to the proposal's snapshot")]
+    InvalidMetaMerkleProof,
+    #[msg("Snapshot accounts are required on proposals that use snapshot stake")]
+    SnapshotAccountsRequired,
+    #[msg("Not available on proposals that read epoch stake at vote time")]
+    EpochStakeProposal,
+    #[msg("Support was already measured in this epoch")]
+    SupportAlreadyMeasured,
+    #[msg("Previous instruction does not verify the voting wallet's signature of this vote")]
+    InvalidVoteSignature,
+    #[msg("Signed vote has expired")]
+    SignedVoteExpired,
+    #[msg("Signed vote is for a different proposal or vote account")]
+    SignedVoteMismatch,
+    #[msg("A proposal finalized later has already changed this parameter")]
+    StaleParameterChange,
+    #[msg("Stake proxy was registered by a previous owner of the stake account")]
+    StakeProxyAuthorityMismatch,
+    #[msg("Epoch stake votes can only be cast in the first voting epoch")]
+    EpochStakeVotingStarted,
+    #[msg("Proposals voting with this snapshot may still be open")]
+    SnapshotInUse,
+    #[msg("Override is not counted because its validator did not vote")]
+    OverrideNotCounted,
+    #[msg("Transfer would leave the treasury below rent exemption")]
+    TreasuryBelowRentExempt,
+    #[msg("Only the author of a proposal can supersede it")]
+    NotSupersededProposalAuthor,
+    #[msg("Proposal has been superseded")]
+    ProposalSuperseded,
+    #[msg("Proposal does not use a random snapshot slot")]
+    NotRandomSnapshot,
+    #[msg("Snapshot slot has already been drawn")]
+    SnapshotSlotAlreadyDrawn,
+    #[msg("Snapshot slot can only be drawn during the snapshot epoch")]
+    SnapshotDrawNotOpen,
+    #[msg("Snapshot slot has not been drawn yet")]
+    SnapshotSlotNotDrawn,
+    #[msg("Proposal instructions cannot invoke the governance program")]
+    ProposalInstructionInvokesGovernance,
 }
@@ -1,4 +1,61 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{
+    event::EVENT_IX_TAG_LE,
+    prelude::*,
+    solana_program::{instruction::Instruction, log::sol_log_data, program::invoke_signed},
+};
+
+use crate::state::{GovernanceParameter, SignalLean};
+
+/// Emits `event` to the program logs, as `emit!` does, and again as the data of a self-CPI
+/// signed by the event authority, as `emit_cpi!` does. RPC nodes may truncate logs but keep
+/// inner instructions, so indexers can rely on the latter. `emit_cpi!` needs a `Context` in
+/// scope, while the handlers here are methods on their accounts, so they pass the
+/// `#[event_cpi]` authority and its bump instead.
+pub fn emit_event<E: anchor_lang::Event>(
+    event_authority: &AccountInfo,
+    bump: u8,
+    event: E,
+) -> Result<()> {
+    let data = event.data();
+    sol_log_data(&[&data]);
+
+    let ix = Instruction::new_with_bytes(
+        crate::ID,
+        &[EVENT_IX_TAG_LE, &data].concat(),
+        vec![AccountMeta::new_readonly(*event_authority.key, true)],
+    );
+    invoke_signed(
+        &ix,
+        std::slice::from_ref(event_authority),
+        &[&[b"__event_authority", &[bump]]],
+    )?;
+    Ok(())
+}
+
+#[event]
+pub struct ProposalIndexInitialized {
+    pub proposal_index: Pubkey,
+    pub initializer: Pubkey,
+}
+
+#[event]
+pub struct GovernanceConfigInitialized {
+    pub governance_config: Pubkey,
+    pub initializer: Pubkey,
+    pub support_threshold_bp: u64,
+    pub emergency_support_threshold_bp: u64,
+    pub support_epochs: u64,
+    pub emergency_support_epochs: u64,
+    pub discussion_epochs: u64,
+    pub emergency_discussion_epochs: u64,
+    pub voting_epochs: u64,
+    pub emergency_voting_epochs: u64,
+    pub pass_threshold_bp: u64,
+    pub quorum_bp: u64,
+    pub min_proposal_stake_lamports: u64,
+    pub proposal_bond_lamports: u64,
+    pub max_open_proposals_per_author: u16,
+}
 
 #[event]
 pub struct ProposalCreated {
@@ -13,6 +70,7 @@ pub struct ProposalCreated {
 pub struct ProposalSupported {
     pub proposal_id: Pubkey,
     pub supporter: Pubkey,
+    pub support_lamports: u64,
     pub cluster_support_lamports: u64,
     pub voting_activated: bool,
     pub snapshot_slot: u64,
@@ -48,6 +106,20 @@ pub struct VoteOverrideCast {
     pub vote_timestamp: i64,
 }
 
+/// Running totals of the delegator overrides cached for a validator's vote, emitted
+/// whenever an override creates or changes them
+#[event]
+pub struct VoteOverrideCacheUpdated {
+    pub proposal_id: Pubkey,
+    pub validator: Pubkey,
+    pub vote_override_cache: Pubkey,
+    pub created: bool,
+    pub for_votes_lamports: u64,
+    pub against_votes_lamports: u64,
+    pub abstain_votes_lamports: u64,
+    pub total_stake: u64,
+}
+
 #[event]
 pub struct VoteModified {
     pub proposal_id: Pubkey,
@@ -92,13 +164,230 @@ pub struct ProposalFinalized {
     pub total_against_votes: u64,
     pub total_abstain_votes: u64,
     pub total_votes_count: u32,
+    pub passed: bool,
     pub finalization_timestamp: i64,
+    pub snapshot_slot: u64,
+    pub consensus_result: Pubkey,
+    pub start_epoch: u64,
+    pub end_epoch: u64,
+    pub pass_threshold_bp: u64,
+    pub quorum_bp: u64,
+    /// Stake that voted, including abstentions
+    pub participation_lamports: u64,
+    /// Cluster stake when voting was activated, which the quorum is measured against
+    pub cluster_stake_lamports: u64,
+}
+
+#[event]
+pub struct ProposalSuperseded {
+    pub proposal_id: Pubkey,
+    pub superseded_by: Pubkey,
+}
+
+#[event]
+pub struct SnapshotSlotDrawn {
+    pub proposal_id: Pubkey,
+    pub snapshot_slot: u64,
+    pub seed_slot: u64,
+    pub seed_hash: [u8; 32],
 }
 
 #[event]
 pub struct MerkleRootFlushed {
     pub proposal_id: Pubkey,
     pub author: Pubkey,
+    pub previous_snapshot_slot: u64,
     pub new_snapshot_slot: u64,
+    pub flush_count: u8,
     pub flush_timestamp: i64,
 }
+
+#[event]
+pub struct ProposalTransactionCreated {
+    pub proposal_id: Pubkey,
+    pub proposal_transaction: Pubkey,
+    pub author: Pubkey,
+    pub instruction_count: u8,
+    pub timelock_seconds: i64,
+}
+
+#[event]
+pub struct ProposalTransactionExecuted {
+    pub proposal_id: Pubkey,
+    pub proposal_transaction: Pubkey,
+    pub executor: Pubkey,
+    pub instruction_count: u8,
+    pub execution_timestamp: i64,
+}
+
+#[event]
+pub struct VotingPeriodExtended {
+    pub proposal_id: Pubkey,
+    pub voter: Pubkey,
+    pub new_end_epoch: u64,
+    pub voting_extensions: u8,
+    pub extension_timestamp: i64,
+}
+
+#[event]
+pub struct ProposalBondLocked {
+    pub proposal_id: Pubkey,
+    pub author: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ProposalBondSettled {
+    pub proposal_id: Pubkey,
+    pub author: Pubkey,
+    pub amount: u64,
+    pub returned: bool,
+    pub settlement_timestamp: i64,
+}
+
+#[event]
+pub struct ProposalCancelled {
+    pub proposal_id: Pubkey,
+    pub author: Pubkey,
+    pub bond_forfeited: u64,
+    pub cancellation_timestamp: i64,
+}
+
+#[event]
+pub struct TreasuryTransferCreated {
+    pub proposal_id: Pubkey,
+    pub treasury_transfer: Pubkey,
+    pub author: Pubkey,
+    pub destination: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub timelock_seconds: i64,
+}
+
+#[event]
+pub struct TreasuryTransferExecuted {
+    pub proposal_id: Pubkey,
+    pub treasury_transfer: Pubkey,
+    pub executor: Pubkey,
+    pub destination: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub execution_timestamp: i64,
+}
+
+#[event]
+pub struct ParameterChangeCreated {
+    pub proposal_id: Pubkey,
+    pub parameter_change: Pubkey,
+    pub author: Pubkey,
+    pub changes: Vec<GovernanceParameter>,
+}
+
+#[event]
+pub struct ParameterChangeEnacted {
+    pub proposal_id: Pubkey,
+    pub parameter_change: Pubkey,
+    pub executor: Pubkey,
+    pub changes: Vec<GovernanceParameter>,
+    pub enactment_timestamp: i64,
+}
+
+#[event]
+pub struct ProposalSignalled {
+    pub proposal_id: Pubkey,
+    pub vote_account: Pubkey,
+    pub validator: Pubkey,
+    pub lean: SignalLean,
+    pub stake: u64,
+    pub for_lamports: u64,
+    pub against_lamports: u64,
+    pub undecided_lamports: u64,
+}
+
+#[event]
+pub struct GovernanceDelegationSet {
+    pub delegator: Pubkey,
+    pub delegate: Pubkey,
+    /// `Pubkey::default()` for a standing delegation
+    pub proposal: Pubkey,
+}
+
+#[event]
+pub struct GovernanceDelegationRemoved {
+    pub delegator: Pubkey,
+    pub delegate: Pubkey,
+    pub proposal: Pubkey,
+}
+
+#[event]
+pub struct DelegatedVoteCast {
+    pub proposal_id: Pubkey,
+    pub delegator: Pubkey,
+    pub delegate: Pubkey,
+    pub for_votes_bp: u64,
+    pub against_votes_bp: u64,
+    pub abstain_votes_bp: u64,
+    pub for_votes_lamports: u64,
+    pub against_votes_lamports: u64,
+    pub abstain_votes_lamports: u64,
+    pub vote_timestamp: i64,
+}
+
+#[event]
+pub struct StakeProxyRegistered {
+    pub stake_account: Pubkey,
+    pub authority: Pubkey,
+    pub proxy: Pubkey,
+    pub registered_timestamp: i64,
+}
+
+#[event]
+pub struct StakeProxyRevoked {
+    pub stake_account: Pubkey,
+    pub authority: Pubkey,
+    pub proxy: Pubkey,
+    pub revoked_timestamp: i64,
+}
+
+#[event]
+pub struct ValidatorProfileUpdated {
+    pub vote_account: Pubkey,
+    pub validator: Pubkey,
+    pub display_name: String,
+    pub website: String,
+    pub governance_policy_url: String,
+}
+
+#[event]
+pub struct BallotCommitted {
+    pub proposal_id: Pubkey,
+    /// `Vote` or `VoteOverride` the commitment belongs to
+    pub vote_record: Pubkey,
+    pub voter: Pubkey,
+    pub commit_timestamp: i64,
+}
+
+#[event]
+pub struct BallotRevealed {
+    pub proposal_id: Pubkey,
+    pub vote_record: Pubkey,
+    pub revealer: Pubkey,
+    pub for_votes_bp: u64,
+    pub against_votes_bp: u64,
+    pub abstain_votes_bp: u64,
+    pub for_votes_lamports: u64,
+    pub against_votes_lamports: u64,
+    pub abstain_votes_lamports: u64,
+    pub reveal_timestamp: i64,
+}
+
+/// A snapshot proof account created for voting was closed after its proposal was finalized
+#[event]
+pub struct MetaMerkleProofClosed {
+    pub proposal_id: Pubkey,
+    pub meta_merkle_proof: Pubkey,
+    pub vote_account: Pubkey,
+    /// Account that created the proof and received its rent
+    pub payer: Pubkey,
+    pub refunded_lamports: u64,
+}
@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::GovernanceError,
+    events::{emit_event, ProposalBondSettled, ProposalCancelled},
+    state::{
+        BondStatus, Proposal, ProposalBond, ProposalOutcome, ProposalRegistryPage,
+        ValidatorGovernanceProfile,
+    },
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelProposal<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>, // Proposal author, receives the bond if nobody supported it
+    #[account(
+        mut,
+        constraint = proposal.load()?.author == signer.key() @ GovernanceError::Unauthorized,
+        constraint = !proposal.load()?.is_voting() @ GovernanceError::CannotModifyAfterStart,
+        constraint = !proposal.load()?.is_finalized() @ GovernanceError::ProposalFinalized,
+    )]
+    pub proposal: AccountLoader<'info, Proposal>,
+    // Pages are program-owned and only created at their own seeds, so `page` identifies them
+    #[account(
+        mut,
+        constraint = registry_page.page == ProposalRegistryPage::page_for(proposal.load()?.index)
+            @ GovernanceError::InvalidRegistryPage,
+    )]
+    pub registry_page: Account<'info, ProposalRegistryPage>,
+    #[account(
+        mut,
+        seeds = [b"bond", proposal.key().as_ref()],
+        bump = proposal_bond.bump,
+    )]
+    pub proposal_bond: Account<'info, ProposalBond>,
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump
+    )]
+    pub treasury: SystemAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"validator_profile", proposal.load()?.vote_account_pubkey.as_ref()],
+        bump = author_profile.bump,
+    )]
+    pub author_profile: Account<'info, ValidatorGovernanceProfile>,
+}
+
+impl<'info> CancelProposal<'info> {
+    pub fn cancel_proposal(&mut self, event_authority_bump: u8) -> Result<()> {
+        // A bond forfeited after the support window already closed the proposal's slot
+        require!(
+            self.proposal_bond.status == BondStatus::Locked,
+            GovernanceError::BondAlreadySettled
+        );
+
+        let clock = Clock::get()?;
+        let mut proposal = self.proposal.load_mut()?;
+        proposal.finalize(ProposalOutcome::Cancelled, clock.unix_timestamp);
+        self.registry_page
+            .set_outcome(proposal.index, ProposalOutcome::Cancelled);
+
+        // A proposal nobody supported yet is withdrawn with its bond; once it has support,
+        // withdrawing forfeits the bond, as if it had expired without reaching voting
+        let returned = proposal.cluster_support_lamports == 0;
+        let amount = self.proposal_bond.amount;
+        let recipient = if returned {
+            self.signer.to_account_info()
+        } else {
+            self.treasury.to_account_info()
+        };
+        self.proposal_bond.sub_lamports(amount)?;
+        recipient.add_lamports(amount)?;
+        self.proposal_bond.status = if returned {
+            BondStatus::Returned
+        } else {
+            BondStatus::Forfeited
+        };
+        self.author_profile.record_proposal_closed();
+
+        emit_event(
+            &self.event_authority,
+            event_authority_bump,
+            ProposalCancelled {
+                proposal_id: self.proposal.key(),
+                author: self.signer.key(),
+                bond_forfeited: if returned { 0 } else { amount },
+                cancellation_timestamp: clock.unix_timestamp,
+            },
+        )?;
+        emit_event(
+            &self.event_authority,
+            event_authority_bump,
+            ProposalBondSettled {
+                proposal_id: self.proposal.key(),
+                author: self.signer.key(),
+                amount,
+                returned,
+                settlement_timestamp: clock.unix_timestamp,
+            },
+        )?;
+
+        Ok(())
+    }
+}
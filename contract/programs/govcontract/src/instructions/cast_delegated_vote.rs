@@ -0,0 +1,225 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::vote::{program as vote_program, state::VoteState},
+};
+
+use crate::{
+    constants::*,
+    error::GovernanceError,
+    events::{emit_event, DelegatedVoteCast, VotingPeriodExtended},
+    stake_source::{SnapshotAccounts, ValidatorStake},
+    state::{GovernanceDelegation, Proposal, Vote, VoteOverrideCache},
+    tally::{self, VoteLamports},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CastDelegatedVote<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>, // Anyone, pays for the delegator's vote account
+    #[account(mut)]
+    pub proposal: AccountLoader<'info, Proposal>,
+    #[account(
+        constraint = delegation.delegator == spl_vote_account.key() @ GovernanceError::InvalidDelegation,
+        constraint = delegation.applies_to(&proposal.key()) @ GovernanceError::InvalidDelegation,
+    )]
+    pub delegation: Account<'info, GovernanceDelegation>,
+    /// CHECK: Delegation for this specific proposal, only needs to be empty when a standing delegation is used
+    #[account(
+        seeds = [b"delegation", spl_vote_account.key().as_ref(), proposal.key().as_ref()],
+        bump
+    )]
+    pub proposal_delegation: UncheckedAccount<'info>,
+    /// CHECK: Delegator's vote account, checked on owner and size and against its stake
+    #[account(
+        constraint = spl_vote_account.owner == &vote_program::ID @ ProgramError::InvalidAccountOwner,
+        constraint = spl_vote_account.data_len() == VoteState::size_of() @ GovernanceError::InvalidVoteAccountSize
+    )]
+    pub spl_vote_account: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = ANCHOR_DISCRIMINATOR + Vote::INIT_SPACE,
+        seeds = [b"vote", proposal.key().as_ref(), spl_vote_account.key().as_ref()],
+        bump
+    )]
+    pub vote: Account<'info, Vote>, // Delegator's vote, created on first use
+    #[account(
+        seeds = [b"vote", proposal.key().as_ref(), delegation.delegate.as_ref()],
+        bump = delegate_vote.bump
+    )]
+    pub delegate_vote: Account<'info, Vote>,
+    /// CHECK: Vote override cache account. Might not yet exist
+    #[account(
+        mut,
+        seeds = [b"vote_override_cache", proposal.key().as_ref(), vote.key().as_ref()],
+        bump
+    )]
+    pub vote_override_cache: UncheckedAccount<'info>,
+    /// CHECK: The snapshot program (gov-v1 or mock), omitted for epoch stake
+    // #[account(constraint = snapshot_program.key() == gov_v1::ID @ GovernanceError::InvalidSnapshotProgram)]
+    pub snapshot_program: Option<UncheckedAccount<'info>>,
+    /// CHECK: Consensus result account owned by snapshot program, omitted for epoch stake
+    pub consensus_result: Option<UncheckedAccount<'info>>,
+    /// CHECK: Delegator's meta merkle proof account owned by snapshot program, omitted for
+    /// epoch stake
+    pub meta_merkle_proof: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CastDelegatedVote<'info> {
+    /// Casts or refreshes the delegator's vote with the delegate's current split. The vote is
+    /// an ordinary `Vote`, so the delegator's own stake delegators override it as usual, and
+    /// `modify_vote` by the delegator takes it back. Delegation is pull-based: the delegate's
+    /// `modify_vote` cannot reach its delegators' votes, so each keeps the split it was cast
+    /// with until this instruction is called for it again.
+    pub fn cast_delegated_vote(&mut self, bumps: &CastDelegatedVoteBumps) -> Result<()> {
+        let proposal_key = self.proposal.key();
+        let mut proposal = self.proposal.load_mut()?;
+        require!(!proposal.is_finalized(), GovernanceError::ProposalFinalized);
+        // The delegate's split stays hidden on secret ballot proposals
+        require!(
+            !proposal.is_secret_ballot(),
+            GovernanceError::SecretBallotProposal
+        );
+
+        let clock = Clock::get()?;
+        let current_epoch = clock.epoch;
+        require!(
+            proposal.start_epoch <= current_epoch,
+            GovernanceError::VotingNotStarted
+        );
+        require!(
+            current_epoch < proposal.end_epoch,
+            GovernanceError::ProposalClosed
+        );
+
+        // A delegation for this proposal wins over a standing one
+        require!(
+            !self.delegation.is_standing() || self.proposal_delegation.data_is_empty(),
+            GovernanceError::DelegationOverridden
+        );
+
+        let delegate = self.delegation.delegate;
+        let for_votes_bp = self.delegate_vote.for_votes_bp;
+        let against_votes_bp = self.delegate_vote.against_votes_bp;
+        let abstain_votes_bp = self.delegate_vote.abstain_votes_bp;
+        let previous_standing = proposal.standing();
+
+        let is_new_vote = self.vote.proposal == Pubkey::default();
+        let tally = if is_new_vote {
+            // Read the delegator's stake from the proposal's stake source
+            let snapshot = SnapshotAccounts::new(
+                &self.snapshot_program,
+                &self.consensus_result,
+                &self.meta_merkle_proof,
+            );
+            let delegator_stake =
+                ValidatorStake::load(&proposal, snapshot, &self.spl_vote_account)?;
+            let voter_stake = delegator_stake.counted()?;
+            delegator_stake.verify()?;
+
+            // Delegators that overrode before this vote existed are applied like in cast_vote
+            let (cached_lamports, cached_stake) = if self.vote_override_cache.data_len() > 0
+                && self.vote_override_cache.owner == &crate::ID
+            {
+                let override_cache = VoteOverrideCache::load_from(&self.vote_override_cache)?;
+                (override_cache.vote_lamports(), override_cache.total_stake)
+            } else {
+                (VoteLamports::default(), 0)
+            };
+
+            let tally = tally::cast_vote(
+                proposal.vote_lamports(),
+                voter_stake,
+                cached_lamports,
+                cached_stake,
+                for_votes_bp,
+                against_votes_bp,
+                abstain_votes_bp,
+            )?;
+
+            self.vote.set_inner(Vote {
+                validator: delegator_stake.voting_wallet,
+                proposal: proposal_key,
+                for_votes_bp,
+                against_votes_bp,
+                abstain_votes_bp,
+                for_votes_lamports: tally.validator.for_votes,
+                against_votes_lamports: tally.validator.against_votes,
+                abstain_votes_lamports: tally.validator.abstain_votes,
+                stake: voter_stake,
+                override_lamports: cached_stake,
+                delegate,
+                vote_timestamp: clock.unix_timestamp,
+                bump: bumps.vote,
+            });
+            proposal.vote_count += 1;
+
+            tally
+        } else {
+            require!(
+                self.vote.is_delegated(),
+                GovernanceError::DelegatorVotedDirectly
+            );
+
+            // Follow the delegate's current split over the stake not overridden by delegators
+            let tally = tally::modify_vote(
+                proposal.vote_lamports(),
+                self.vote.vote_lamports(),
+                self.vote.stake,
+                self.vote.override_lamports,
+                for_votes_bp,
+                against_votes_bp,
+                abstain_votes_bp,
+            )?;
+
+            self.vote.for_votes_bp = for_votes_bp;
+            self.vote.against_votes_bp = against_votes_bp;
+            self.vote.abstain_votes_bp = abstain_votes_bp;
+            self.vote.set_vote_lamports(tally.validator);
+            self.vote.delegate = delegate;
+            self.vote.vote_timestamp = clock.unix_timestamp;
+
+            tally
+        };
+        proposal.set_vote_lamports(tally.totals);
+
+        emit_event(
+            &self.event_authority,
+            bumps.event_authority,
+            DelegatedVoteCast {
+                proposal_id: proposal_key,
+                delegator: self.spl_vote_account.key(),
+                delegate,
+                for_votes_bp,
+                against_votes_bp,
+                abstain_votes_bp,
+                for_votes_lamports: tally.validator.for_votes,
+                against_votes_lamports: tally.validator.against_votes,
+                abstain_votes_lamports: tally.validator.abstain_votes,
+                vote_timestamp: clock.unix_timestamp,
+            },
+        )?;
+
+        // Extend voting when this vote flipped the result close to the deadline
+        if let Some(new_end_epoch) =
+            proposal.extend_voting_if_flipped(previous_standing, clock.slot)?
+        {
+            emit_event(
+                &self.event_authority,
+                bumps.event_authority,
+                VotingPeriodExtended {
+                    proposal_id: proposal_key,
+                    voter: self.signer.key(),
+                    new_end_epoch,
+                    voting_extensions: proposal.voting_extensions,
+                    extension_timestamp: clock.unix_timestamp,
+                },
+            )?;
+        }
+
+        Ok(())
+    }
+}
@@ -4,21 +4,21 @@ use anchor_lang::{
 };
 
 use crate::{
-    calculate_vote_lamports,
     constants::*,
     error::GovernanceError,
-    events::VoteCast,
-    merkle_helpers::verify_merkle_proof_cpi,
-    state::{Proposal, Vote, VoteOverrideCache},
+    events::{emit_event, VoteCast, VotingPeriodExtended},
+    stake_source::{SnapshotAccounts, ValidatorStake},
+    state::{Proposal, ValidatorGovernanceProfile, Vote, VoteOverrideCache},
+    tally::{self, VoteLamports},
 };
-use gov_v1::{ConsensusResult, MetaMerkleProof};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct CastVote<'info> {
     #[account(mut)]
     pub signer: Signer<'info>, // Voter (validator)
     #[account(mut)]
-    pub proposal: Account<'info, Proposal>,
+    pub proposal: AccountLoader<'info, Proposal>,
     #[account(
         init,
         payer = signer,
@@ -33,6 +33,14 @@ pub struct CastVote<'info> {
         constraint = spl_vote_account.data_len() == VoteState::size_of() @ GovernanceError::InvalidVoteAccountSize
     )]
     pub spl_vote_account: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = ANCHOR_DISCRIMINATOR + ValidatorGovernanceProfile::INIT_SPACE,
+        seeds = [b"validator_profile", spl_vote_account.key().as_ref()],
+        bump
+    )]
+    pub validator_profile: Account<'info, ValidatorGovernanceProfile>,
     /// CHECK: Vote override cache account. Might not yet exist
     #[account(
         mut,
@@ -40,13 +48,13 @@ pub struct CastVote<'info> {
         bump
     )]
     pub vote_override_cache: UncheckedAccount<'info>,
-    /// CHECK: The snapshot program (gov-v1 or mock)
+    /// CHECK: The snapshot program (gov-v1 or mock), omitted for epoch stake
     // #[account(constraint = snapshot_program.key() == gov_v1::ID @ GovernanceError::InvalidSnapshotProgram)]
-    pub snapshot_program: UncheckedAccount<'info>,
-    /// CHECK: Consensus result account owned by snapshot program
-    pub consensus_result: UncheckedAccount<'info>,
-    /// CHECK: Meta merkle proof account owned by snapshot program
-    pub meta_merkle_proof: UncheckedAccount<'info>,
+    pub snapshot_program: Option<UncheckedAccount<'info>>,
+    /// CHECK: Consensus result account owned by snapshot program, omitted for epoch stake
+    pub consensus_result: Option<UncheckedAccount<'info>>,
+    /// CHECK: Meta merkle proof account owned by snapshot program, omitted for epoch stake
+    pub meta_merkle_proof: Option<UncheckedAccount<'info>>,
 
     pub system_program: Program<'info, System>,
 }
@@ -59,216 +67,163 @@ impl<'info> CastVote<'info> {
         abstain_votes_bp: u64,
         bumps: &CastVoteBumps,
     ) -> Result<()> {
-        // Check that the proposal is open for voting
-        require!(!self.proposal.finalized, GovernanceError::ProposalFinalized);
-
-        // Get the current epoch from the Clock sysvar
-        let clock = Clock::get()?;
-        let current_epoch = clock.epoch;
-        require!(
-            self.proposal.start_epoch <= current_epoch,
-            GovernanceError::VotingNotStarted
-        );
-        require!(
-            current_epoch < self.proposal.end_epoch,
-            GovernanceError::ProposalClosed
-        );
-
-        // Validate that the basis points sum to 10,000 (100%)
-        let total_bp = for_votes_bp
-            .checked_add(against_votes_bp)
-            .and_then(|sum| sum.checked_add(abstain_votes_bp))
-            .ok_or(GovernanceError::ArithmeticOverflow)?;
-        require!(
-            total_bp == BASIS_POINTS_MAX,
-            GovernanceError::InvalidVoteDistribution
-        );
-
-        // Validate snapshot program ownership
-        require!(
-            self.consensus_result.owner == self.snapshot_program.key,
-            GovernanceError::MustBeOwnedBySnapshotProgram
-        );
-        require!(
-            self.meta_merkle_proof.owner == self.snapshot_program.key,
-            GovernanceError::MustBeOwnedBySnapshotProgram
-        );
-
-        require!(
-            self.proposal.consensus_result.is_some(),
-            GovernanceError::ConsensusResultNotSet
-        );
-
-        // unwrap is safe because we checked that the consensus result is set in the previous require
-        require_keys_eq!(
-            self.proposal.consensus_result.unwrap(),
-            self.consensus_result.key(),
-            GovernanceError::InvalidConsensusResultPDA
-        );
-        let consensus_result_data = self.consensus_result.try_borrow_data()?;
-        let consensus_result = ConsensusResult::try_deserialize(&mut &consensus_result_data[..])?;
-
-        require!(
-            consensus_result
-                .ballot
-                .meta_merkle_root
-                .iter()
-                .any(|&x| x != 0),
-            GovernanceError::InvalidMerkleRoot
-        );
-        // Deserialize MetaMerkleProof for crosschecking
-        let meta_account_data = self.meta_merkle_proof.try_borrow_data()?;
-        let meta_merkle_proof = MetaMerkleProof::try_deserialize(&mut &meta_account_data[..])?;
-        let meta_merkle_leaf = meta_merkle_proof.meta_merkle_leaf;
-
-        // Crosscheck consensus result
-        require_eq!(
-            meta_merkle_proof.consensus_result,
-            self.consensus_result.key(),
-            GovernanceError::InvalidConsensusResultPDA
-        );
-
-        // Ensure leaf matches signer and has sufficient stake
-        require_eq!(
-            meta_merkle_leaf.voting_wallet,
-            self.signer.key(),
-            GovernanceError::InvalidVoteAccount
+        let signer = self.signer.key();
+        let snapshot = SnapshotAccounts::new(
+            &self.snapshot_program,
+            &self.consensus_result,
+            &self.meta_merkle_proof,
         );
-        require_gt!(
-            meta_merkle_leaf.active_stake,
-            0u64,
-            GovernanceError::NotEnoughStake
-        );
-
-        require_eq!(
-            meta_merkle_leaf.vote_account,
-            self.spl_vote_account.key(),
-            GovernanceError::InvalidVoteAccount
-        );
-
-        verify_merkle_proof_cpi(
-            &self.meta_merkle_proof.to_account_info(),
-            &self.consensus_result.to_account_info(),
-            &self.snapshot_program.to_account_info(),
-            None,
-            None,
-        )?;
-
-        // Calculate effective votes for each category based on actual lamports
-        let voter_stake = meta_merkle_leaf.active_stake;
-        let for_votes_lamports = calculate_vote_lamports!(voter_stake, for_votes_bp)?;
-        let against_votes_lamports = calculate_vote_lamports!(voter_stake, against_votes_bp)?;
-        let abstain_votes_lamports = calculate_vote_lamports!(voter_stake, abstain_votes_bp)?;
-
-        // Check if override cache PDA exists and has been initialized
-        // If it does, apply cached delegator votes
-        if self.vote_override_cache.data_len() > 0 && self.vote_override_cache.owner == &crate::ID {
-            let override_cache: VoteOverrideCache =
-                match anchor_lang::AccountDeserialize::try_deserialize(
-                    &mut self.vote_override_cache.data.borrow().as_ref(),
-                ) {
-                    Ok(cache) => cache,
-                    Err(_) => {
-                        // Account exists but is not a valid VoteOverrideCache - treat as non-existent
-                        return Err(GovernanceError::InvalidVoteOverrideCache.into());
-                    }
-                };
-
-            // Add cached votes
-            self.proposal.add_vote_lamports(
-                override_cache.for_votes_lamports,
-                override_cache.against_votes_lamports,
-                override_cache.abstain_votes_lamports,
-            )?;
-
-            let new_validator_stake = voter_stake
-                .checked_sub(override_cache.total_stake)
-                .ok_or(GovernanceError::ArithmeticOverflow)?;
-
-            // Calculate new validator votes for each category based on actual lamports
-            let for_votes_lamports_new =
-                calculate_vote_lamports!(new_validator_stake, for_votes_bp)?;
-            let against_votes_lamports_new =
-                calculate_vote_lamports!(new_validator_stake, against_votes_bp)?;
-            let abstain_votes_lamports_new =
-                calculate_vote_lamports!(new_validator_stake, abstain_votes_bp)?;
-
-            // Add validator's reduced votes to proposal
-            self.proposal.add_vote_lamports(
-                for_votes_lamports_new,
-                against_votes_lamports_new,
-                abstain_votes_lamports_new,
-            )?;
-
-            // Initialize the Vote PDA with all required fields
-            self.vote.set_inner(Vote {
-                validator: self.signer.key(),
-                proposal: self.proposal.key(),
-                for_votes_bp,
-                against_votes_bp,
-                abstain_votes_bp,
-                for_votes_lamports: for_votes_lamports_new,
-                against_votes_lamports: against_votes_lamports_new,
-                abstain_votes_lamports: abstain_votes_lamports_new,
-                override_lamports: override_cache.total_stake,
-                stake: voter_stake,
-                vote_timestamp: clock.unix_timestamp,
-                bump: bumps.vote,
-            });
+        cast_validator_vote(
+            &self.proposal,
+            &mut self.vote,
+            &self.spl_vote_account,
+            &mut self.validator_profile,
+            &self.vote_override_cache,
+            snapshot,
+            &self.event_authority,
+            for_votes_bp,
+            against_votes_bp,
+            abstain_votes_bp,
+            // Ensure the stake belongs to the signer
+            |voter| {
+                require_keys_eq!(*voter, signer, GovernanceError::InvalidVoteAccount);
+                Ok(())
+            },
+            bumps.vote,
+            bumps.validator_profile,
+            bumps.event_authority,
+        )
+    }
+}
 
-            // Emit the missing VoteCast event
-            emit!(VoteCast {
-                proposal_id: self.proposal.key(),
-                voter: self.signer.key(),
-                vote_account: self.spl_vote_account.key(),
-                for_votes_bp,
-                against_votes_bp,
-                abstain_votes_bp,
-                for_votes_lamports: for_votes_lamports_new,
-                against_votes_lamports: against_votes_lamports_new,
-                abstain_votes_lamports: abstain_votes_lamports_new,
-                vote_timestamp: clock.unix_timestamp,
-            });
+/// Casts a new vote for `spl_vote_account` with the stake of the proposal's stake source.
+/// The vote account's voting wallet is recorded as the voter once `authorize_voter` accepts
+/// it, which is how `cast_vote` and `cast_vote_signed` differ.
+pub(crate) fn cast_validator_vote<'info>(
+    proposal_loader: &AccountLoader<'info, Proposal>,
+    vote: &mut Account<'info, Vote>,
+    spl_vote_account: &AccountInfo<'info>,
+    validator_profile: &mut Account<'info, ValidatorGovernanceProfile>,
+    vote_override_cache: &AccountInfo<'info>,
+    snapshot: Option<SnapshotAccounts<'_, 'info>>,
+    event_authority: &AccountInfo<'info>,
+    for_votes_bp: u64,
+    against_votes_bp: u64,
+    abstain_votes_bp: u64,
+    authorize_voter: impl FnOnce(&Pubkey) -> Result<()>,
+    vote_bump: u8,
+    validator_profile_bump: u8,
+    event_authority_bump: u8,
+) -> Result<()> {
+    // Check that the proposal is open for voting
+    let proposal_key = proposal_loader.key();
+    let mut proposal = proposal_loader.load_mut()?;
+    require!(!proposal.is_finalized(), GovernanceError::ProposalFinalized);
+
+    // Get the current epoch from the Clock sysvar
+    let clock = Clock::get()?;
+    let current_epoch = clock.epoch;
+    require!(
+        proposal.start_epoch <= current_epoch,
+        GovernanceError::VotingNotStarted
+    );
+    require!(
+        current_epoch < proposal.end_epoch,
+        GovernanceError::ProposalClosed
+    );
+
+    // Validate that the basis points sum to 10,000 (100%), or match the secret ballot
+    // placeholder
+    proposal.validate_cast_split(for_votes_bp, against_votes_bp, abstain_votes_bp)?;
+
+    // Read the validator's stake from the proposal's stake source
+    let validator_stake = ValidatorStake::load(&proposal, snapshot, spl_vote_account)?;
+
+    // Ensure the voting wallet authorized the vote and the stake can be counted
+    let voter = validator_stake.voting_wallet;
+    authorize_voter(&voter)?;
+    let voter_stake = validator_stake.counted()?;
+
+    validator_stake.verify()?;
+
+    let previous_standing = proposal.standing();
+
+    // Check if override cache PDA exists and has been initialized
+    // If it does, apply cached delegator votes
+    let (cached_lamports, cached_stake) =
+        if vote_override_cache.data_len() > 0 && vote_override_cache.owner == &crate::ID {
+            let override_cache = VoteOverrideCache::load_from(vote_override_cache)?;
+            (override_cache.vote_lamports(), override_cache.total_stake)
         } else {
-            self.proposal.add_vote_lamports(
-                for_votes_lamports,
-                against_votes_lamports,
-                abstain_votes_lamports,
-            )?;
-
-            // Store the vote distribution in the Vote PDA
-            self.vote.set_inner(Vote {
-                validator: self.signer.key(),
-                proposal: self.proposal.key(),
-                for_votes_bp,
-                against_votes_bp,
-                abstain_votes_bp,
-                for_votes_lamports,
-                against_votes_lamports,
-                abstain_votes_lamports,
-                override_lamports: 0,
-                stake: voter_stake,
-                vote_timestamp: clock.unix_timestamp,
-                bump: bumps.vote,
-            });
-
-            // Emit vote cast event
-            emit!(VoteCast {
-                proposal_id: self.proposal.key(),
-                voter: self.signer.key(),
-                vote_account: self.spl_vote_account.key(),
-                for_votes_bp,
-                against_votes_bp,
-                abstain_votes_bp,
-                for_votes_lamports,
-                against_votes_lamports,
-                abstain_votes_lamports,
-                vote_timestamp: clock.unix_timestamp,
-            });
-        }
-
-        self.proposal.vote_count += 1;
-
-        Ok(())
+            (VoteLamports::default(), 0)
+        };
+
+    // Cached delegator stake is carved out of the validator's share
+    let tally = tally::cast_vote(
+        proposal.vote_lamports(),
+        voter_stake,
+        cached_lamports,
+        cached_stake,
+        for_votes_bp,
+        against_votes_bp,
+        abstain_votes_bp,
+    )?;
+    proposal.set_vote_lamports(tally.totals);
+
+    // Store the vote distribution in the Vote PDA
+    vote.set_inner(Vote {
+        validator: voter,
+        proposal: proposal_key,
+        for_votes_bp,
+        against_votes_bp,
+        abstain_votes_bp,
+        for_votes_lamports: tally.validator.for_votes,
+        against_votes_lamports: tally.validator.against_votes,
+        abstain_votes_lamports: tally.validator.abstain_votes,
+        override_lamports: cached_stake,
+        delegate: Pubkey::default(),
+        stake: voter_stake,
+        vote_timestamp: clock.unix_timestamp,
+        bump: vote_bump,
+    });
+
+    // Emit vote cast event
+    emit_event(
+        event_authority,
+        event_authority_bump,
+        VoteCast {
+            proposal_id: proposal_key,
+            voter,
+            vote_account: spl_vote_account.key(),
+            for_votes_bp,
+            against_votes_bp,
+            abstain_votes_bp,
+            for_votes_lamports: tally.validator.for_votes,
+            against_votes_lamports: tally.validator.against_votes,
+            abstain_votes_lamports: tally.validator.abstain_votes,
+            vote_timestamp: clock.unix_timestamp,
+        },
+    )?;
+
+    proposal.vote_count += 1;
+
+    validator_profile.init_if_new(spl_vote_account.key(), validator_profile_bump);
+    validator_profile.record_vote(current_epoch)?;
+
+    // Extend voting when this vote flipped the result close to the deadline
+    if let Some(new_end_epoch) = proposal.extend_voting_if_flipped(previous_standing, clock.slot)? {
+        emit_event(
+            event_authority,
+            event_authority_bump,
+            VotingPeriodExtended {
+                proposal_id: proposal_key,
+                voter,
+                new_end_epoch,
+                voting_extensions: proposal.voting_extensions,
+                extension_timestamp: clock.unix_timestamp,
+            },
+        )?;
     }
+
+    Ok(())
 }
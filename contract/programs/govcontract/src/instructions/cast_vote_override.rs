@@ -7,21 +7,22 @@ use anchor_lang::{
 };
 
 use crate::{
-    calculate_vote_lamports,
     constants::*,
     error::GovernanceError,
-    events::VoteOverrideCast,
-    merkle_helpers::verify_merkle_proof_cpi,
-    state::{Proposal, Vote, VoteOverride, VoteOverrideCache},
+    events::{emit_event, VoteOverrideCacheUpdated, VoteOverrideCast, VotingPeriodExtended},
+    stake_source::{SnapshotAccounts, ValidatorStake},
+    state::{Proposal, StakeProxy, Vote, VoteOverride, VoteOverrideCache},
+    tally::{self, VoteLamports},
 };
-use gov_v1::{ConsensusResult, MetaMerkleProof, StakeMerkleLeaf};
+use gov_v1::StakeMerkleLeaf;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct CastVoteOverride<'info> {
     #[account(mut)]
     pub signer: Signer<'info>, // Voter (staker/delegator)
     #[account(mut)]
-    pub proposal: Account<'info, Proposal>, // Proposal being voted on
+    pub proposal: AccountLoader<'info, Proposal>, // Proposal being voted on
     /// CHECK: Validator vote account. Might not yet exist
     #[account(
         mut,
@@ -47,23 +48,25 @@ pub struct CastVoteOverride<'info> {
     #[account(
         init_if_needed,
         payer = signer,
-        space = 8 + VoteOverrideCache::INIT_SPACE,
+        space = ANCHOR_DISCRIMINATOR + VoteOverrideCache::INIT_SPACE,
         seeds = [b"vote_override_cache", proposal.key().as_ref(), validator_vote.key().as_ref()],
         bump
     )]
-    pub vote_override_cache: Account<'info, VoteOverrideCache>,
+    pub vote_override_cache: AccountLoader<'info, VoteOverrideCache>,
     /// CHECK: stake account for override
     #[account(
         constraint = spl_stake_account.owner == &stake_program::ID @ ProgramError::InvalidAccountOwner,
     )]
     pub spl_stake_account: UncheckedAccount<'info>,
-    /// CHECK: The snapshot program (gov-v1 or mock)
+    /// Proxy registered for the stake account, required when the proxy signs
+    pub stake_proxy: Option<Account<'info, StakeProxy>>,
+    /// CHECK: The snapshot program (gov-v1 or mock), omitted for epoch stake
     // #[account(constraint = snapshot_program.key() == gov_v1::ID @ GovernanceError::InvalidSnapshotProgram)]
-    pub snapshot_program: UncheckedAccount<'info>,
-    /// CHECK: Consensus result account owned by snapshot program
-    pub consensus_result: UncheckedAccount<'info>,
-    /// CHECK: Meta merkle proof account owned by snapshot program
-    pub meta_merkle_proof: UncheckedAccount<'info>,
+    pub snapshot_program: Option<UncheckedAccount<'info>>,
+    /// CHECK: Consensus result account owned by snapshot program, omitted for epoch stake
+    pub consensus_result: Option<UncheckedAccount<'info>>,
+    /// CHECK: Meta merkle proof account owned by snapshot program, omitted for epoch stake
+    pub meta_merkle_proof: Option<UncheckedAccount<'info>>,
 
     pub system_program: Program<'info, System>,
 }
@@ -79,80 +82,41 @@ impl<'info> CastVoteOverride<'info> {
         bumps: &CastVoteOverrideBumps,
     ) -> Result<()> {
         // Check that the proposal is open for voting
-        require!(!self.proposal.finalized, GovernanceError::ProposalFinalized);
+        let proposal_key = self.proposal.key();
+        let mut proposal = self.proposal.load_mut()?;
+        require!(!proposal.is_finalized(), GovernanceError::ProposalFinalized);
 
         // Get the current epoch from the Clock sysvar
         let clock = Clock::get()?;
         let current_epoch = clock.epoch;
         require!(
-            self.proposal.start_epoch <= current_epoch,
+            proposal.start_epoch <= current_epoch,
             GovernanceError::VotingNotStarted
         );
         require!(
-            current_epoch < self.proposal.end_epoch,
+            current_epoch < proposal.end_epoch,
             GovernanceError::ProposalClosed
         );
 
-        // Validate that the basis points sum to 10,000 (100%)
-        let total_bp = for_votes_bp
-            .checked_add(against_votes_bp)
-            .and_then(|sum| sum.checked_add(abstain_votes_bp))
-            .ok_or(GovernanceError::ArithmeticOverflow)?;
-        require!(
-            total_bp == BASIS_POINTS_MAX,
-            GovernanceError::InvalidVoteDistribution
-        );
-
-        // Validate snapshot program ownership
-        require!(
-            self.consensus_result.owner == self.snapshot_program.key,
-            GovernanceError::MustBeOwnedBySnapshotProgram
-        );
-        require!(
-            self.meta_merkle_proof.owner == self.snapshot_program.key,
-            GovernanceError::MustBeOwnedBySnapshotProgram
-        );
-
-        require!(
-            self.proposal.consensus_result.is_some(),
-            GovernanceError::ConsensusResultNotSet
-        );
-
-        // unwrap is safe because we checked that the consensus result is set in the previous require
-        require_keys_eq!(
-            self.proposal.consensus_result.unwrap(),
-            self.consensus_result.key(),
-            GovernanceError::InvalidConsensusResultPDA
-        );
-        let consensus_result_data = self.consensus_result.try_borrow_data()?;
-        let consensus_result = ConsensusResult::try_deserialize(&mut &consensus_result_data[..])?;
-
-        require!(
-            consensus_result
-                .ballot
-                .meta_merkle_root
-                .iter()
-                .any(|&x| x != 0),
-            GovernanceError::InvalidMerkleRoot
-        );
-
-        // Deserialize MetaMerkleProof for crosschecking
-        let meta_account_data = self.meta_merkle_proof.try_borrow_data()?;
-        let meta_merkle_proof = MetaMerkleProof::try_deserialize(&mut &meta_account_data[..])?;
-
-        let meta_merkle_leaf = meta_merkle_proof.meta_merkle_leaf;
-
-        require_eq!(
-            meta_merkle_proof.consensus_result,
-            self.consensus_result.key(),
-            GovernanceError::InvalidConsensusResultPDA
-        );
+        // Validate that the basis points sum to 10,000 (100%), or match the secret ballot
+        // placeholder
+        proposal.validate_cast_split(for_votes_bp, against_votes_bp, abstain_votes_bp)?;
 
-        require_eq!(
-            stake_merkle_leaf.voting_wallet,
-            self.signer.key(),
-            GovernanceError::StakeAccountOwnerMismatch
+        // Read the validator's stake from the proposal's stake source
+        let snapshot = SnapshotAccounts::new(
+            &self.snapshot_program,
+            &self.consensus_result,
+            &self.meta_merkle_proof,
         );
+        let validator_stake = ValidatorStake::load(&proposal, snapshot, &self.spl_vote_account)?;
+
+        // The staker signs, or the proxy it registered for the stake account
+        let proxy = StakeProxy::authorize_override(
+            &self.signer.key(),
+            &stake_merkle_leaf.stake_account,
+            &stake_merkle_leaf.voting_wallet,
+            self.stake_proxy.as_deref(),
+        )?;
 
         require_gt!(
             stake_merkle_leaf.active_stake,
@@ -167,32 +131,24 @@ impl<'info> CastVoteOverride<'info> {
             GovernanceError::InvalidStakeAccount
         );
 
-        require_eq!(
-            meta_merkle_leaf.vote_account,
-            self.spl_vote_account.key(),
-            GovernanceError::InvalidVoteAccount
-        );
+        let stake_merkle_leaf =
+            validator_stake.verify_delegator(stake_merkle_proof, stake_merkle_leaf)?;
 
-        verify_merkle_proof_cpi(
-            &self.meta_merkle_proof.to_account_info(),
-            &self.consensus_result.to_account_info(),
-            &self.snapshot_program.to_account_info(),
-            Some(stake_merkle_proof),
-            Some(stake_merkle_leaf.clone()),
-        )?;
+        let previous_standing = proposal.standing();
 
         // Use verified stake amounts
         let delegator_stake = stake_merkle_leaf.active_stake;
-        let validator_stake = meta_merkle_leaf.active_stake;
 
         // Calculate delegator's vote lamports
-        let for_votes_lamports = calculate_vote_lamports!(delegator_stake, for_votes_bp)?;
-        let against_votes_lamports = calculate_vote_lamports!(delegator_stake, against_votes_bp)?;
-        let abstain_votes_lamports = calculate_vote_lamports!(delegator_stake, abstain_votes_bp)?;
-
-        // Check that validator vote exists
-        // If account does not exist, cache the delegator's vote in override PDA
+        let delegator_lamports = VoteLamports::from_stake(
+            delegator_stake,
+            for_votes_bp,
+            against_votes_bp,
+            abstain_votes_bp,
+        )?;
 
+        // If the validator already voted, move the delegator's share out of its vote now.
+        // Otherwise the override is only cached and applied when the validator votes.
         if self.validator_vote.data_len() > 0 && self.validator_vote.owner == &crate::ID {
             // Attempt to deserialize the validator vote account
             let mut validator_vote: Vote = match anchor_lang::AccountDeserialize::try_deserialize(
@@ -200,263 +156,130 @@ impl<'info> CastVoteOverride<'info> {
             ) {
                 Ok(vote) => vote,
                 Err(_) => {
-                    // Account exists but is not a valid Vote - treat as non-existent
-                    // Fall through to cache path below
+                    // Account exists but is not a valid Vote
                     return Err(GovernanceError::InvalidVoteAccount.into());
                 }
             };
 
-            // Subtract validator's vote
-            self.proposal.sub_vote_lamports(
-                validator_vote.for_votes_lamports,
-                validator_vote.against_votes_lamports,
-                validator_vote.abstain_votes_lamports,
-            )?;
-
-            // Add delegator's vote
-            self.proposal.add_vote_lamports(
-                for_votes_lamports,
-                against_votes_lamports,
-                abstain_votes_lamports,
-            )?;
-
-            // Calculate total overridden stake (current delegator + previously overridden delegators)
-            let total_overridden = delegator_stake
-                .checked_add(validator_vote.override_lamports)
-                .ok_or(GovernanceError::ArithmeticOverflow)?;
-            let new_validator_stake = validator_stake
-                .checked_sub(total_overridden)
+            // Replace the delegator's share of the validator's vote with the delegator's own vote
+            let overridden_stake = validator_vote
+                .override_lamports
+                .checked_add(delegator_stake)
                 .ok_or(GovernanceError::ArithmeticOverflow)?;
-
-            // Calculate new validator votes for each category based on actual lamports
-            let for_votes_lamports_new =
-                calculate_vote_lamports!(new_validator_stake, validator_vote.for_votes_bp)?;
-            let against_votes_lamports_new =
-                calculate_vote_lamports!(new_validator_stake, validator_vote.against_votes_bp)?;
-            let abstain_votes_lamports_new =
-                calculate_vote_lamports!(new_validator_stake, validator_vote.abstain_votes_bp)?;
-
-            // Add validator's new vote
-            self.proposal.add_vote_lamports(
-                for_votes_lamports_new,
-                against_votes_lamports_new,
-                abstain_votes_lamports_new,
+            let tally = tally::override_vote(
+                proposal.vote_lamports(),
+                validator_vote.vote_lamports(),
+                delegator_lamports,
+                validator_stake.active_stake,
+                overridden_stake,
+                validator_vote.for_votes_bp,
+                validator_vote.against_votes_bp,
+                validator_vote.abstain_votes_bp,
             )?;
+            proposal.set_vote_lamports(tally.totals);
 
             // Store ONLY validator's reduced votes (not including delegator override)
             // The delegator's votes are already added to proposal totals separately
-            validator_vote.for_votes_lamports = for_votes_lamports_new;
-            validator_vote.against_votes_lamports = against_votes_lamports_new;
-            validator_vote.abstain_votes_lamports = abstain_votes_lamports_new;
-            validator_vote.override_lamports = validator_vote
-                .override_lamports
-                .checked_add(delegator_stake)
-                .ok_or(GovernanceError::ArithmeticOverflow)?;
+            validator_vote.set_vote_lamports(tally.validator);
+            validator_vote.override_lamports = overridden_stake;
 
             // Serialize the updated validator vote back to the account
             anchor_lang::AccountSerialize::try_serialize(
                 &validator_vote,
                 &mut self.validator_vote.data.borrow_mut().as_mut(),
             )?;
+        }
 
-            // Store override
-            self.vote_override.set_inner(VoteOverride {
-                delegator: self.signer.key(),
-                stake_account: stake_merkle_leaf.stake_account,
-                validator: meta_merkle_leaf.vote_account,
-                proposal: self.proposal.key(),
-                vote_account_validator: self.validator_vote.key(),
-                for_votes_bp,
-                against_votes_bp,
-                abstain_votes_bp,
-                stake_amount: delegator_stake,
-                vote_override_timestamp: clock.unix_timestamp,
-                bump: bumps.vote_override,
-                for_votes_lamports,
-                against_votes_lamports,
-                abstain_votes_lamports,
-            });
-            if self.vote_override_cache.total_stake == 0 {
-                // First override for this validator - initialize cache
-                self.vote_override_cache.set_inner(VoteOverrideCache {
-                    validator: meta_merkle_leaf.vote_account,
-                    proposal: self.proposal.key(),
-                    vote_account_validator: self.validator_vote.key(),
-                    for_votes_bp,
-                    against_votes_bp,
-                    abstain_votes_bp,
-                    for_votes_lamports,
-                    against_votes_lamports,
-                    abstain_votes_lamports,
-                    total_stake: delegator_stake,
-                    bump: bumps.vote_override_cache,
-                });
-            } else {
-                // Subsequent override for this validator - update cache
-                require_eq!(
-                    self.vote_override_cache.proposal,
-                    self.proposal.key(),
-                    GovernanceError::InvalidVoteAccount
-                );
-                require_eq!(
-                    self.vote_override_cache.vote_account_validator,
-                    self.validator_vote.key(),
-                    GovernanceError::InvalidVoteAccount
-                );
-
-                self.vote_override_cache.for_votes_bp = self
-                    .vote_override_cache
-                    .for_votes_bp
-                    .checked_add(for_votes_bp)
-                    .ok_or(GovernanceError::ArithmeticOverflow)?;
-                self.vote_override_cache.against_votes_bp = self
-                    .vote_override_cache
-                    .against_votes_bp
-                    .checked_add(against_votes_bp)
-                    .ok_or(GovernanceError::ArithmeticOverflow)?;
-                self.vote_override_cache.abstain_votes_bp = self
-                    .vote_override_cache
-                    .abstain_votes_bp
-                    .checked_add(abstain_votes_bp)
-                    .ok_or(GovernanceError::ArithmeticOverflow)?;
-
-                self.vote_override_cache.for_votes_lamports = self
-                    .vote_override_cache
-                    .for_votes_lamports
-                    .checked_add(for_votes_lamports)
-                    .ok_or(GovernanceError::ArithmeticOverflow)?;
-                self.vote_override_cache.against_votes_lamports = self
-                    .vote_override_cache
-                    .against_votes_lamports
-                    .checked_add(against_votes_lamports)
-                    .ok_or(GovernanceError::ArithmeticOverflow)?;
-                self.vote_override_cache.abstain_votes_lamports = self
-                    .vote_override_cache
-                    .abstain_votes_lamports
-                    .checked_add(abstain_votes_lamports)
-                    .ok_or(GovernanceError::ArithmeticOverflow)?;
-
-                self.vote_override_cache.total_stake = self
-                    .vote_override_cache
-                    .total_stake
-                    .checked_add(delegator_stake)
-                    .ok_or(GovernanceError::ArithmeticOverflow)?;
-            }
-        } else {
-            // validator has no vote yet, so just store delegator's vote in override PDA
-            // Path 2a: nobody -> delegator (first delegator to override)
-            // Path 2b: delegator -> delegator (multiple delegators override same validator)
-
-            // With Anchor's init_if_needed, the account is automatically created if it doesn't exist
-            // or reused if it already exists. We just need to check if we're updating an existing cache.
-
-            // Check if this is the first delegator (cache is empty) or subsequent delegator
-            if self.vote_override_cache.total_stake == 0 {
-                // Path 2a: First delegator - initialize cache
-                self.vote_override_cache.set_inner(VoteOverrideCache {
-                    validator: meta_merkle_leaf.vote_account,
-                    proposal: self.proposal.key(),
-                    vote_account_validator: self.validator_vote.key(),
-                    for_votes_bp,
-                    against_votes_bp,
-                    abstain_votes_bp,
-                    for_votes_lamports,
-                    against_votes_lamports,
-                    abstain_votes_lamports,
-                    total_stake: delegator_stake,
-                    bump: bumps.vote_override_cache,
-                });
-            } else {
-                // Path 2b: Subsequent delegator - update cache
-                // Validate cache is for the same validator and proposal
-                require_eq!(
-                    self.vote_override_cache.proposal,
-                    self.proposal.key(),
-                    GovernanceError::InvalidVoteAccount
-                );
-                require_eq!(
-                    self.vote_override_cache.vote_account_validator,
-                    self.validator_vote.key(),
-                    GovernanceError::InvalidVoteAccount
-                );
-
-                // Update cache by adding delegator's vote
-                self.vote_override_cache.for_votes_bp = self
-                    .vote_override_cache
-                    .for_votes_bp
-                    .checked_add(for_votes_bp)
-                    .ok_or(GovernanceError::ArithmeticOverflow)?;
-                self.vote_override_cache.against_votes_bp = self
-                    .vote_override_cache
-                    .against_votes_bp
-                    .checked_add(against_votes_bp)
-                    .ok_or(GovernanceError::ArithmeticOverflow)?;
-                self.vote_override_cache.abstain_votes_bp = self
-                    .vote_override_cache
-                    .abstain_votes_bp
-                    .checked_add(abstain_votes_bp)
-                    .ok_or(GovernanceError::ArithmeticOverflow)?;
-
-                self.vote_override_cache.for_votes_lamports = self
-                    .vote_override_cache
-                    .for_votes_lamports
-                    .checked_add(for_votes_lamports)
-                    .ok_or(GovernanceError::ArithmeticOverflow)?;
-                self.vote_override_cache.against_votes_lamports = self
-                    .vote_override_cache
-                    .against_votes_lamports
-                    .checked_add(against_votes_lamports)
-                    .ok_or(GovernanceError::ArithmeticOverflow)?;
-                self.vote_override_cache.abstain_votes_lamports = self
-                    .vote_override_cache
-                    .abstain_votes_lamports
-                    .checked_add(abstain_votes_lamports)
-                    .ok_or(GovernanceError::ArithmeticOverflow)?;
+        // With Anchor's init_if_needed, the cache is created by the first override for this
+        // validator and reused by later ones
+        let mut vote_override_cache = VoteOverrideCache::load_or_init(&self.vote_override_cache)?;
+        let cache_created = vote_override_cache.total_stake == 0;
+        vote_override_cache.init_or_validate(
+            validator_stake.vote_account,
+            proposal_key,
+            self.validator_vote.key(),
+            bumps.vote_override_cache,
+        )?;
+        vote_override_cache.add_override(
+            for_votes_bp,
+            against_votes_bp,
+            abstain_votes_bp,
+            delegator_lamports,
+            delegator_stake,
+        )?;
 
-                self.vote_override_cache.total_stake = self
-                    .vote_override_cache
-                    .total_stake
-                    .checked_add(delegator_stake)
-                    .ok_or(GovernanceError::ArithmeticOverflow)?;
-            }
+        // Store override
+        self.vote_override.set_inner(VoteOverride {
+            delegator: stake_merkle_leaf.voting_wallet,
+            stake_account: stake_merkle_leaf.stake_account,
+            validator: validator_stake.vote_account,
+            proposal: proposal_key,
+            vote_account_validator: self.validator_vote.key(),
+            for_votes_bp,
+            against_votes_bp,
+            abstain_votes_bp,
+            stake_amount: delegator_stake,
+            proxy,
+            vote_override_timestamp: clock.unix_timestamp,
+            bump: bumps.vote_override,
+            for_votes_lamports: delegator_lamports.for_votes,
+            against_votes_lamports: delegator_lamports.against_votes,
+            abstain_votes_lamports: delegator_lamports.abstain_votes,
+        });
 
-            // Initialize the VoteOverride account with delegator's vote data
-            // This covers both cases: delegator -> delegator and nobody -> delegator
-            self.vote_override.set_inner(VoteOverride {
-                delegator: self.signer.key(),
+        // Emit vote override cast event
+        emit_event(
+            &self.event_authority,
+            bumps.event_authority,
+            VoteOverrideCast {
+                proposal_id: proposal_key,
+                delegator: stake_merkle_leaf.voting_wallet,
                 stake_account: stake_merkle_leaf.stake_account,
-                validator: meta_merkle_leaf.vote_account,
-                proposal: self.proposal.key(),
-                vote_account_validator: self.validator_vote.key(),
+                validator: validator_stake.vote_account,
                 for_votes_bp,
                 against_votes_bp,
                 abstain_votes_bp,
+                for_votes_lamports: delegator_lamports.for_votes,
+                against_votes_lamports: delegator_lamports.against_votes,
+                abstain_votes_lamports: delegator_lamports.abstain_votes,
                 stake_amount: delegator_stake,
-                vote_override_timestamp: clock.unix_timestamp,
-                bump: bumps.vote_override,
-                for_votes_lamports,
-                against_votes_lamports,
-                abstain_votes_lamports,
-            });
-        }
+                vote_timestamp: clock.unix_timestamp,
+            },
+        )?;
 
-        // Emit vote override cast event
-        emit!(VoteOverrideCast {
-            proposal_id: self.proposal.key(),
-            delegator: self.signer.key(),
-            stake_account: stake_merkle_leaf.stake_account,
-            validator: meta_merkle_leaf.vote_account,
-            for_votes_bp,
-            against_votes_bp,
-            abstain_votes_bp,
-            for_votes_lamports,
-            against_votes_lamports,
-            abstain_votes_lamports,
-            stake_amount: delegator_stake,
-            vote_timestamp: clock.unix_timestamp,
-        });
+        emit_event(
+            &self.event_authority,
+            bumps.event_authority,
+            VoteOverrideCacheUpdated {
+                proposal_id: proposal_key,
+                validator: vote_override_cache.validator,
+                vote_override_cache: self.vote_override_cache.key(),
+                created: cache_created,
+                for_votes_lamports: vote_override_cache.for_votes_lamports,
+                against_votes_lamports: vote_override_cache.against_votes_lamports,
+                abstain_votes_lamports: vote_override_cache.abstain_votes_lamports,
+                total_stake: vote_override_cache.total_stake,
+            },
+        )?;
 
-        self.proposal.vote_count += 1;
+        proposal.vote_count += 1;
+
+        // Extend voting when this vote flipped the result close to the deadline
+        if let Some(new_end_epoch) =
+            proposal.extend_voting_if_flipped(previous_standing, clock.slot)?
+        {
+            emit_event(
+                &self.event_authority,
+                bumps.event_authority,
+                VotingPeriodExtended {
+                    proposal_id: proposal_key,
+                    voter: self.signer.key(),
+                    new_end_epoch,
+                    voting_extensions: proposal.voting_extensions,
+                    extension_timestamp: clock.unix_timestamp,
+                },
+            )?;
+        }
 
         Ok(())
     }
@@ -0,0 +1,346 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        stake::program as stake_program,
+        vote::{program as vote_program, state::VoteState},
+    },
+};
+
+use crate::{
+    constants::*,
+    error::GovernanceError,
+    events::{emit_event, VoteOverrideCacheUpdated, VoteOverrideCast, VotingPeriodExtended},
+    stake_source::{SnapshotAccounts, ValidatorStake},
+    state::{Proposal, StakeProxy, Vote, VoteOverride, VoteOverrideCache},
+    tally::{self, VoteLamports},
+    utils::create_pda_account,
+};
+use gov_v1::StakeMerkleLeaf;
+
+/// A single stake account override within a batch
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct StakeOverrideEntry {
+    pub stake_merkle_proof: Vec<[u8; 32]>,
+    pub stake_merkle_leaf: StakeMerkleLeaf,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CastVoteOverrideBatch<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>, // Voter (staker/delegator)
+    #[account(mut)]
+    pub proposal: AccountLoader<'info, Proposal>, // Proposal being voted on
+    /// CHECK: Validator vote account. Might not yet exist
+    #[account(
+        mut,
+        seeds = [b"vote", proposal.key().as_ref(), spl_vote_account.key.as_ref()],
+        bump,
+    )]
+    pub validator_vote: UncheckedAccount<'info>, // Validator's existing vote (if any)
+    /// CHECK: Vote account is too big to deserialize, so we check on owner and size
+    #[account(
+        constraint = spl_vote_account.owner == &vote_program::ID @ ProgramError::InvalidAccountOwner,
+        constraint = spl_vote_account.data_len() == VoteState::size_of() @ GovernanceError::InvalidVoteAccountSize
+    )]
+    pub spl_vote_account: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = ANCHOR_DISCRIMINATOR + VoteOverrideCache::INIT_SPACE,
+        seeds = [b"vote_override_cache", proposal.key().as_ref(), validator_vote.key().as_ref()],
+        bump
+    )]
+    pub vote_override_cache: AccountLoader<'info, VoteOverrideCache>,
+    /// CHECK: The snapshot program (gov-v1 or mock), omitted for epoch stake
+    pub snapshot_program: Option<UncheckedAccount<'info>>,
+    /// CHECK: Consensus result account owned by snapshot program, omitted for epoch stake
+    pub consensus_result: Option<UncheckedAccount<'info>>,
+    /// CHECK: Meta merkle proof account owned by snapshot program, omitted for epoch stake
+    pub meta_merkle_proof: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CastVoteOverrideBatch<'info> {
+    /// Casts the same override for several stake accounts delegated to one validator.
+    /// For every entry, `remaining_accounts` holds the stake account followed by its
+    /// (uninitialized) `VoteOverride` PDA. When a proxy signs, each entry also carries the
+    /// stake account's `StakeProxy`, or the program ID in its place for entries without one.
+    pub fn cast_vote_override_batch(
+        &mut self,
+        for_votes_bp: u64,
+        against_votes_bp: u64,
+        abstain_votes_bp: u64,
+        entries: Vec<StakeOverrideEntry>,
+        remaining_accounts: &[AccountInfo<'info>],
+        bumps: &CastVoteOverrideBatchBumps,
+    ) -> Result<()> {
+        require!(
+            (1..=MAX_VOTE_OVERRIDE_BATCH_SIZE).contains(&entries.len()),
+            GovernanceError::InvalidVoteOverrideBatchSize
+        );
+        let stride = match remaining_accounts.len() {
+            len if len == entries.len() * 2 => 2,
+            len if len == entries.len() * 3 => 3,
+            _ => return err!(GovernanceError::NotEnoughAccounts),
+        };
+
+        // Check that the proposal is open for voting
+        let proposal_key = self.proposal.key();
+        let mut proposal = self.proposal.load_mut()?;
+        require!(!proposal.is_finalized(), GovernanceError::ProposalFinalized);
+
+        // Get the current epoch from the Clock sysvar
+        let clock = Clock::get()?;
+        let current_epoch = clock.epoch;
+        require!(
+            proposal.start_epoch <= current_epoch,
+            GovernanceError::VotingNotStarted
+        );
+        require!(
+            current_epoch < proposal.end_epoch,
+            GovernanceError::ProposalClosed
+        );
+
+        // Validate that the basis points sum to 10,000 (100%), or match the secret ballot
+        // placeholder
+        proposal.validate_cast_split(for_votes_bp, against_votes_bp, abstain_votes_bp)?;
+
+        // Read the validator's stake from the proposal's stake source
+        let snapshot = SnapshotAccounts::new(
+            &self.snapshot_program,
+            &self.consensus_result,
+            &self.meta_merkle_proof,
+        );
+        let validator_stake = ValidatorStake::load(&proposal, snapshot, &self.spl_vote_account)?;
+
+        let mut vote_override_cache = VoteOverrideCache::load_or_init(&self.vote_override_cache)?;
+        let cache_created = vote_override_cache.total_stake == 0;
+        vote_override_cache.init_or_validate(
+            validator_stake.vote_account,
+            proposal_key,
+            self.validator_vote.key(),
+            bumps.vote_override_cache,
+        )?;
+
+        let previous_standing = proposal.standing();
+        let validator_vote_key = self.validator_vote.key();
+
+        // Totals across the batch, applied to the proposal once all entries are verified
+        let mut batch_stake = 0u64;
+        let mut batch_lamports = VoteLamports::default();
+
+        for (entry, accounts) in entries
+            .into_iter()
+            .zip(remaining_accounts.chunks_exact(stride))
+        {
+            let spl_stake_account = &accounts[0];
+            let vote_override_info = &accounts[1];
+            let stake_proxy = match accounts.get(2) {
+                Some(info) if info.key() != crate::ID => {
+                    require_keys_eq!(
+                        *info.owner,
+                        crate::ID,
+                        ErrorCode::AccountOwnedByWrongProgram
+                    );
+                    Some(StakeProxy::try_deserialize(
+                        &mut &info.try_borrow_data()?[..],
+                    )?)
+                }
+                _ => None,
+            };
+            let stake_merkle_leaf = entry.stake_merkle_leaf;
+
+            require_keys_eq!(
+                *spl_stake_account.owner,
+                stake_program::ID,
+                GovernanceError::InvalidStakeAccount
+            );
+            let proxy = StakeProxy::authorize_override(
+                &self.signer.key(),
+                &stake_merkle_leaf.stake_account,
+                &stake_merkle_leaf.voting_wallet,
+                stake_proxy.as_ref(),
+            )?;
+            require_gt!(
+                stake_merkle_leaf.active_stake,
+                0u64,
+                GovernanceError::NotEnoughStake
+            );
+            // Ensure stake leaf contains the correct stake account
+            require_eq!(
+                stake_merkle_leaf.stake_account,
+                spl_stake_account.key(),
+                GovernanceError::InvalidStakeAccount
+            );
+
+            let stake_merkle_leaf =
+                validator_stake.verify_delegator(entry.stake_merkle_proof, stake_merkle_leaf)?;
+
+            // A stake account can only override once per proposal, so the PDA must not exist yet
+            let (vote_override_pda, vote_override_bump) = Pubkey::find_program_address(
+                &[
+                    b"vote_override",
+                    proposal_key.as_ref(),
+                    spl_stake_account.key.as_ref(),
+                    validator_vote_key.as_ref(),
+                ],
+                &crate::ID,
+            );
+            require!(
+                vote_override_info.key() == vote_override_pda
+                    && vote_override_info.owner == &System::id()
+                    && vote_override_info.data_is_empty(),
+                GovernanceError::InvalidVoteOverrideAccount
+            );
+
+            create_pda_account(
+                &self.signer.to_account_info(),
+                vote_override_info,
+                &self.system_program.to_account_info(),
+                ANCHOR_DISCRIMINATOR + VoteOverride::INIT_SPACE,
+                &[
+                    b"vote_override",
+                    proposal_key.as_ref(),
+                    spl_stake_account.key.as_ref(),
+                    validator_vote_key.as_ref(),
+                    &[vote_override_bump],
+                ],
+            )?;
+
+            // Calculate delegator's vote lamports
+            let delegator_stake = stake_merkle_leaf.active_stake;
+            let delegator_lamports = VoteLamports::from_stake(
+                delegator_stake,
+                for_votes_bp,
+                against_votes_bp,
+                abstain_votes_bp,
+            )?;
+
+            let vote_override = VoteOverride {
+                delegator: stake_merkle_leaf.voting_wallet,
+                stake_account: stake_merkle_leaf.stake_account,
+                validator: validator_stake.vote_account,
+                proposal: proposal_key,
+                vote_account_validator: validator_vote_key,
+                for_votes_bp,
+                against_votes_bp,
+                abstain_votes_bp,
+                stake_amount: delegator_stake,
+                proxy,
+                vote_override_timestamp: clock.unix_timestamp,
+                bump: vote_override_bump,
+                for_votes_lamports: delegator_lamports.for_votes,
+                against_votes_lamports: delegator_lamports.against_votes,
+                abstain_votes_lamports: delegator_lamports.abstain_votes,
+            };
+            anchor_lang::AccountSerialize::try_serialize(
+                &vote_override,
+                &mut vote_override_info.data.borrow_mut().as_mut(),
+            )?;
+
+            vote_override_cache.add_override(
+                for_votes_bp,
+                against_votes_bp,
+                abstain_votes_bp,
+                delegator_lamports,
+                delegator_stake,
+            )?;
+
+            batch_stake = batch_stake
+                .checked_add(delegator_stake)
+                .ok_or(GovernanceError::ArithmeticOverflow)?;
+            batch_lamports = batch_lamports.checked_add(delegator_lamports)?;
+
+            emit_event(
+                &self.event_authority,
+                bumps.event_authority,
+                VoteOverrideCast {
+                    proposal_id: proposal_key,
+                    delegator: stake_merkle_leaf.voting_wallet,
+                    stake_account: stake_merkle_leaf.stake_account,
+                    validator: validator_stake.vote_account,
+                    for_votes_bp,
+                    against_votes_bp,
+                    abstain_votes_bp,
+                    for_votes_lamports: delegator_lamports.for_votes,
+                    against_votes_lamports: delegator_lamports.against_votes,
+                    abstain_votes_lamports: delegator_lamports.abstain_votes,
+                    stake_amount: delegator_stake,
+                    vote_timestamp: clock.unix_timestamp,
+                },
+            )?;
+
+            proposal.vote_count += 1;
+        }
+
+        emit_event(
+            &self.event_authority,
+            bumps.event_authority,
+            VoteOverrideCacheUpdated {
+                proposal_id: proposal_key,
+                validator: vote_override_cache.validator,
+                vote_override_cache: self.vote_override_cache.key(),
+                created: cache_created,
+                for_votes_lamports: vote_override_cache.for_votes_lamports,
+                against_votes_lamports: vote_override_cache.against_votes_lamports,
+                abstain_votes_lamports: vote_override_cache.abstain_votes_lamports,
+                total_stake: vote_override_cache.total_stake,
+            },
+        )?;
+
+        // If the validator already voted, replace its votes for the overridden stake once.
+        // Otherwise the cache is applied when the validator votes.
+        if self.validator_vote.data_len() > 0 && self.validator_vote.owner == &crate::ID {
+            let mut validator_vote: Vote = anchor_lang::AccountDeserialize::try_deserialize(
+                &mut self.validator_vote.data.borrow().as_ref(),
+            )
+            .map_err(|_| GovernanceError::InvalidVoteAccount)?;
+
+            // Calculate total overridden stake (this batch + previously overridden delegators)
+            let overridden_stake = batch_stake
+                .checked_add(validator_vote.override_lamports)
+                .ok_or(GovernanceError::ArithmeticOverflow)?;
+            let tally = tally::override_vote(
+                proposal.vote_lamports(),
+                validator_vote.vote_lamports(),
+                batch_lamports,
+                validator_stake.active_stake,
+                overridden_stake,
+                validator_vote.for_votes_bp,
+                validator_vote.against_votes_bp,
+                validator_vote.abstain_votes_bp,
+            )?;
+            proposal.set_vote_lamports(tally.totals);
+
+            validator_vote.set_vote_lamports(tally.validator);
+            validator_vote.override_lamports = overridden_stake;
+
+            // Serialize the updated validator vote back to the account
+            anchor_lang::AccountSerialize::try_serialize(
+                &validator_vote,
+                &mut self.validator_vote.data.borrow_mut().as_mut(),
+            )?;
+        }
+
+        // Extend voting when this batch flipped the result close to the deadline
+        if let Some(new_end_epoch) =
+            proposal.extend_voting_if_flipped(previous_standing, clock.slot)?
+        {
+            emit_event(
+                &self.event_authority,
+                bumps.event_authority,
+                VotingPeriodExtended {
+                    proposal_id: proposal_key,
+                    voter: self.signer.key(),
+                    new_end_epoch,
+                    voting_extensions: proposal.voting_extensions,
+                    extension_timestamp: clock.unix_timestamp,
+                },
+            )?;
+        }
+
+        Ok(())
+    }
+}
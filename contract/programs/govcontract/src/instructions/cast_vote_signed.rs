@@ -0,0 +1,115 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        sysvar::instructions as instructions_sysvar,
+        vote::{program as vote_program, state::VoteState},
+    },
+};
+
+use crate::{
+    constants::*,
+    error::GovernanceError,
+    instructions::cast_vote::cast_validator_vote,
+    stake_source::SnapshotAccounts,
+    state::{Proposal, SignedVote, ValidatorGovernanceProfile, Vote},
+    utils::verify_ed25519_signature,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CastVoteSigned<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>, // Relayer, pays for the vote account
+    #[account(mut)]
+    pub proposal: AccountLoader<'info, Proposal>,
+    #[account(
+        init,
+        payer = payer,
+        space = ANCHOR_DISCRIMINATOR + Vote::INIT_SPACE,
+        seeds = [b"vote", proposal.key().as_ref(), spl_vote_account.key().as_ref()],
+        bump
+    )]
+    pub vote: Account<'info, Vote>, // New vote account
+    /// CHECK: Vote account is too big to deserialize, so we check on owner and size; its voting wallet must have signed the vote
+    #[account(
+        constraint = spl_vote_account.owner == &vote_program::ID @ ProgramError::InvalidAccountOwner,
+        constraint = spl_vote_account.data_len() == VoteState::size_of() @ GovernanceError::InvalidVoteAccountSize
+    )]
+    pub spl_vote_account: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ANCHOR_DISCRIMINATOR + ValidatorGovernanceProfile::INIT_SPACE,
+        seeds = [b"validator_profile", spl_vote_account.key().as_ref()],
+        bump
+    )]
+    pub validator_profile: Account<'info, ValidatorGovernanceProfile>,
+    /// CHECK: Vote override cache account. Might not yet exist
+    #[account(
+        mut,
+        seeds = [b"vote_override_cache", proposal.key().as_ref(), vote.key().as_ref()],
+        bump
+    )]
+    pub vote_override_cache: UncheckedAccount<'info>,
+    /// CHECK: The snapshot program (gov-v1 or mock), omitted for epoch stake
+    // #[account(constraint = snapshot_program.key() == gov_v1::ID @ GovernanceError::InvalidSnapshotProgram)]
+    pub snapshot_program: Option<UncheckedAccount<'info>>,
+    /// CHECK: Consensus result account owned by snapshot program, omitted for epoch stake
+    pub consensus_result: Option<UncheckedAccount<'info>>,
+    /// CHECK: Meta merkle proof account owned by snapshot program, omitted for epoch stake
+    pub meta_merkle_proof: Option<UncheckedAccount<'info>>,
+    /// CHECK: Instructions sysvar, read for the Ed25519 instruction before this one
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CastVoteSigned<'info> {
+    /// Casts a vote on behalf of the voting wallet that signed it. Each vote account votes
+    /// once per proposal, so a signed vote can only be relayed once.
+    pub fn cast_vote_signed(
+        &mut self,
+        signed_vote: SignedVote,
+        bumps: &CastVoteSignedBumps,
+    ) -> Result<()> {
+        require!(
+            signed_vote.proposal == self.proposal.key()
+                && signed_vote.vote_account == self.spl_vote_account.key(),
+            GovernanceError::SignedVoteMismatch
+        );
+        // A secret ballot would also need its commitment signed
+        require!(
+            !self.proposal.load()?.is_secret_ballot(),
+            GovernanceError::SecretBallotProposal
+        );
+        require!(
+            Clock::get()?.unix_timestamp <= signed_vote.expiry,
+            GovernanceError::SignedVoteExpired
+        );
+
+        let message = signed_vote.message();
+        let snapshot = SnapshotAccounts::new(
+            &self.snapshot_program,
+            &self.consensus_result,
+            &self.meta_merkle_proof,
+        );
+        cast_validator_vote(
+            &self.proposal,
+            &mut self.vote,
+            &self.spl_vote_account,
+            &mut self.validator_profile,
+            &self.vote_override_cache,
+            snapshot,
+            &self.event_authority,
+            signed_vote.for_votes_bp,
+            signed_vote.against_votes_bp,
+            signed_vote.abstain_votes_bp,
+            // The vote must be signed by the vote account's voting wallet
+            |voter| verify_ed25519_signature(&self.instructions, voter, &message),
+            bumps.vote,
+            bumps.validator_profile,
+            bumps.event_authority,
+        )
+    }
+}
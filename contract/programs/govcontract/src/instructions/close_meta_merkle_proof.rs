@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::GovernanceError,
+    events::{emit_event, MetaMerkleProofClosed},
+    merkle_helpers::close_meta_merkle_proof_cpi,
+    state::Proposal,
+};
+use gov_v1::MetaMerkleProof;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseMetaMerkleProof<'info> {
+    pub signer: Signer<'info>, // Anyone can clean up proofs once no proposal can vote with them
+    #[account(
+        constraint = proposal.load()?.is_finalized() @ GovernanceError::ProposalNotFinalized,
+    )]
+    pub proposal: AccountLoader<'info, Proposal>,
+    /// CHECK: Meta merkle proof account owned by snapshot program
+    #[account(
+        mut,
+        constraint = meta_merkle_proof.owner == snapshot_program.key @ GovernanceError::MustBeOwnedBySnapshotProgram,
+    )]
+    pub meta_merkle_proof: UncheckedAccount<'info>,
+    /// CHECK: Account that created the proof and receives its rent, checked by the snapshot program
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+    /// CHECK: The snapshot program
+    #[account(address = gov_v1::ID @ GovernanceError::InvalidSnapshotProgram)]
+    pub snapshot_program: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CloseMetaMerkleProof<'info> {
+    pub fn close_meta_merkle_proof(&mut self, event_authority_bump: u8) -> Result<()> {
+        let proposal = self.proposal.load()?;
+        let consensus_result = proposal.consensus_result;
+        // Other proposals can share the snapshot and still be voting or revealing, so the
+        // proofs are kept until none of them can be open anymore
+        require!(
+            Clock::get()?.epoch >= proposal.snapshot_release_epoch(),
+            GovernanceError::SnapshotInUse
+        );
+        drop(proposal);
+
+        let meta_merkle_proof = {
+            let data = self.meta_merkle_proof.try_borrow_data()?;
+            MetaMerkleProof::try_deserialize(&mut &data[..])?
+        };
+        // Only proofs of the finalized proposal's own snapshot are closed through it
+        require_keys_eq!(
+            meta_merkle_proof.consensus_result,
+            consensus_result,
+            GovernanceError::InvalidMetaMerkleProof
+        );
+
+        let refunded_lamports = self.meta_merkle_proof.lamports();
+        close_meta_merkle_proof_cpi(
+            &self.meta_merkle_proof.to_account_info(),
+            &self.payer.to_account_info(),
+            &self.system_program.to_account_info(),
+            &self.snapshot_program.to_account_info(),
+        )?;
+
+        emit_event(
+            &self.event_authority,
+            event_authority_bump,
+            MetaMerkleProofClosed {
+                proposal_id: self.proposal.key(),
+                meta_merkle_proof: self.meta_merkle_proof.key(),
+                vote_account: meta_merkle_proof.meta_merkle_leaf.vote_account,
+                payer: meta_merkle_proof.payer,
+                refunded_lamports,
+            },
+        )
+    }
+}
@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    error::GovernanceError,
+    events::{emit_event, BallotCommitted},
+    state::{Ballot, Proposal, StakeProxy, Vote, VoteOverride},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CommitVote<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>, // Validator that cast the vote
+    pub proposal: AccountLoader<'info, Proposal>,
+    #[account(
+        constraint = vote.proposal == proposal.key() @ GovernanceError::InvalidVoteAccount,
+        constraint = vote.validator == signer.key() @ GovernanceError::InvalidVoteAccount,
+    )]
+    pub vote: Account<'info, Vote>,
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = ANCHOR_DISCRIMINATOR + Ballot::INIT_SPACE,
+        seeds = [b"ballot", vote.key().as_ref()],
+        bump
+    )]
+    pub ballot: Account<'info, Ballot>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CommitVoteOverride<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>, // Staker, or its registered proxy
+    pub proposal: AccountLoader<'info, Proposal>,
+    #[account(
+        constraint = vote_override.proposal == proposal.key() @ GovernanceError::InvalidVoteOverrideAccount,
+    )]
+    pub vote_override: Account<'info, VoteOverride>,
+    /// Proxy registered for the stake account, required when the proxy signs
+    pub stake_proxy: Option<Account<'info, StakeProxy>>,
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = ANCHOR_DISCRIMINATOR + Ballot::INIT_SPACE,
+        seeds = [b"ballot", vote_override.key().as_ref()],
+        bump
+    )]
+    pub ballot: Account<'info, Ballot>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Records the commitment for a vote record while voting is open. Committing again during
+/// voting replaces the commitment. Returns the event for the caller to emit.
+fn commit_ballot(
+    proposal: &AccountLoader<Proposal>,
+    vote_record: Pubkey,
+    ballot: &mut Account<Ballot>,
+    voter: Pubkey,
+    commitment: [u8; 32],
+    bump: u8,
+) -> Result<BallotCommitted> {
+    let proposal_key = proposal.key();
+    let proposal = proposal.load()?;
+    require!(
+        proposal.is_secret_ballot(),
+        GovernanceError::NotSecretBallot
+    );
+    require!(!proposal.is_finalized(), GovernanceError::ProposalFinalized);
+    let clock = Clock::get()?;
+    require!(
+        clock.epoch < proposal.end_epoch,
+        GovernanceError::ProposalClosed
+    );
+
+    ballot.set_inner(Ballot {
+        proposal: proposal_key,
+        vote_record,
+        commitment,
+        commit_timestamp: clock.unix_timestamp,
+        revealed: false,
+        bump,
+    });
+
+    Ok(BallotCommitted {
+        proposal_id: proposal_key,
+        vote_record,
+        voter,
+        commit_timestamp: clock.unix_timestamp,
+    })
+}
+
+impl<'info> CommitVote<'info> {
+    /// Commits the split a validator will reveal for its placeholder vote
+    pub fn commit_vote(&mut self, commitment: [u8; 32], bumps: &CommitVoteBumps) -> Result<()> {
+        let event = commit_ballot(
+            &self.proposal,
+            self.vote.key(),
+            &mut self.ballot,
+            self.signer.key(),
+            commitment,
+            bumps.ballot,
+        )?;
+        emit_event(&self.event_authority, bumps.event_authority, event)
+    }
+}
+
+impl<'info> CommitVoteOverride<'info> {
+    /// Commits the split a staker will reveal for its placeholder override
+    pub fn commit_vote_override(
+        &mut self,
+        commitment: [u8; 32],
+        bumps: &CommitVoteOverrideBumps,
+    ) -> Result<()> {
+        // The proxy must still be registered by the staker that cast the override
+        StakeProxy::authorize_override(
+            &self.signer.key(),
+            &self.vote_override.stake_account,
+            &self.vote_override.delegator,
+            self.stake_proxy.as_deref(),
+        )?;
+        let event = commit_ballot(
+            &self.proposal,
+            self.vote_override.key(),
+            &mut self.ballot,
+            self.signer.key(),
+            commitment,
+            bumps.ballot,
+        )?;
+        emit_event(&self.event_authority, bumps.event_authority, event)
+    }
+}
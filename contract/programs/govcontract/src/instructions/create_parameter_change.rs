@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    error::GovernanceError,
+    events::{emit_event, ParameterChangeCreated},
+    state::{GovernanceParameter, ParameterChange, Proposal},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreateParameterChange<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>, // Proposal author
+    #[account(
+        constraint = proposal.load()?.author == signer.key() @ GovernanceError::Unauthorized,
+        constraint = !proposal.load()?.is_voting() @ GovernanceError::CannotModifyAfterStart,
+        constraint = !proposal.load()?.is_finalized() @ GovernanceError::ProposalFinalized,
+    )]
+    pub proposal: AccountLoader<'info, Proposal>,
+    #[account(
+        init,
+        payer = signer,
+        space = ANCHOR_DISCRIMINATOR + ParameterChange::INIT_SPACE,
+        seeds = [b"parameter_change", proposal.key().as_ref()],
+        bump
+    )]
+    pub parameter_change: Account<'info, ParameterChange>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateParameterChange<'info> {
+    pub fn create_parameter_change(
+        &mut self,
+        changes: Vec<GovernanceParameter>,
+        bumps: &CreateParameterChangeBumps,
+    ) -> Result<()> {
+        require!(
+            !changes.is_empty() && changes.len() <= MAX_PARAMETER_CHANGES,
+            GovernanceError::InvalidParameterChange
+        );
+        for change in changes.iter() {
+            change.validate()?;
+        }
+
+        emit_event(
+            &self.event_authority,
+            bumps.event_authority,
+            ParameterChangeCreated {
+                proposal_id: self.proposal.key(),
+                parameter_change: self.parameter_change.key(),
+                author: self.signer.key(),
+                changes: changes.clone(),
+            },
+        )?;
+
+        self.parameter_change.set_inner(ParameterChange {
+            proposal: self.proposal.key(),
+            changes,
+            enacted_timestamp: 0,
+            bump: bumps.parameter_change,
+        });
+
+        Ok(())
+    }
+}
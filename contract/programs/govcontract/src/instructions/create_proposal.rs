@@ -6,17 +6,23 @@ use anchor_lang::{
         epoch_stake::{get_epoch_stake_for_vote_account, get_epoch_total_stake},
         vote::{program as vote_program, state::VoteState},
     },
+    system_program::{transfer, Transfer},
 };
 
 use crate::{
     constants::*,
     error::GovernanceError,
-    events::ProposalCreated,
+    events::{emit_event, ProposalBondLocked, ProposalCreated},
     stake_weight_bp,
-    state::{Proposal, ProposalIndex},
+    state::{
+        AntiSnipingConfig, BondStatus, GovernanceConfig, Proposal, ProposalBond, ProposalClass,
+        ProposalContent, ProposalIndex, ProposalRegistryPage, SecretBallotConfig, StakeSource,
+        ValidatorGovernanceProfile,
+    },
     utils::is_valid_github_link,
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(seed: u64)]
 pub struct CreateProposal<'info> {
@@ -29,19 +35,63 @@ pub struct CreateProposal<'info> {
         bump,
         space = ANCHOR_DISCRIMINATOR + Proposal::INIT_SPACE,
     )]
-    pub proposal: Account<'info, Proposal>,
+    pub proposal: AccountLoader<'info, Proposal>,
+    #[account(
+        init,
+        payer = signer,
+        seeds = [b"proposal_content", proposal.key().as_ref()],
+        bump,
+        space = ANCHOR_DISCRIMINATOR + ProposalContent::INIT_SPACE,
+    )]
+    pub proposal_content: Account<'info, ProposalContent>,
+    #[account(
+        init,
+        payer = signer,
+        seeds = [b"bond", proposal.key().as_ref()],
+        bump,
+        space = ANCHOR_DISCRIMINATOR + ProposalBond::INIT_SPACE,
+    )]
+    pub proposal_bond: Account<'info, ProposalBond>,
     #[account(
         mut,
         seeds = [b"index"],
         bump = proposal_index.bump
     )]
     pub proposal_index: Account<'info, ProposalIndex>,
+    #[account(
+        init_if_needed,
+        payer = signer,
+        seeds = [
+            b"registry",
+            ProposalRegistryPage::page_for(proposal_index.current_index + 1).to_le_bytes().as_ref()
+        ],
+        bump,
+        space = ANCHOR_DISCRIMINATOR + ProposalRegistryPage::INIT_SPACE,
+    )]
+    pub registry_page: Account<'info, ProposalRegistryPage>,
     /// CHECK: Vote account is too big to deserialize, so we check on owner and size, then compare node_pubkey with signer
     #[account(
         constraint = spl_vote_account.owner == &vote_program::ID @ ProgramError::InvalidAccountOwner,
         constraint = spl_vote_account.data_len() == VoteState::size_of() @ GovernanceError::InvalidVoteAccountSize
     )]
     pub spl_vote_account: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = ANCHOR_DISCRIMINATOR + ValidatorGovernanceProfile::INIT_SPACE,
+        seeds = [b"validator_profile", spl_vote_account.key().as_ref()],
+        bump
+    )]
+    pub validator_profile: Account<'info, ValidatorGovernanceProfile>,
+    #[account(
+        seeds = [b"governance_config"],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+    /// Earlier proposal this one supersedes, if any
+    pub supersedes: Option<AccountLoader<'info, Proposal>>,
+    /// Earlier proposal this one depends on, if any
+    pub depends_on: Option<AccountLoader<'info, Proposal>>,
 
     pub system_program: Program<'info, System>,
 }
@@ -52,6 +102,11 @@ impl<'info> CreateProposal<'info> {
         seed: u64,
         title: String,
         description: String,
+        anti_sniping: Option<AntiSnipingConfig>,
+        secret_ballot: Option<SecretBallotConfig>,
+        random_snapshot: bool,
+        class: ProposalClass,
+        stake_source: StakeSource,
         bumps: &CreateProposalBumps,
     ) -> Result<()> {
         // Validate proposal inputs
@@ -70,6 +125,36 @@ impl<'info> CreateProposal<'info> {
             is_valid_github_link(&description),
             GovernanceError::DescriptionInvalid
         );
+        if let Some(config) = anti_sniping.as_ref() {
+            config.validate()?;
+        }
+        // Epoch stake has no snapshot slot to draw
+        require!(
+            !(random_snapshot && stake_source == StakeSource::EpochStake),
+            GovernanceError::EpochStakeProposal
+        );
+        if let Some(config) = secret_ballot.as_ref() {
+            config.validate()?;
+            // Placeholder votes never flip the result, so there is nothing to extend for
+            require!(
+                anti_sniping.is_none(),
+                GovernanceError::InvalidSecretBallotConfig
+            );
+        }
+        // Linked proposals are checked to exist by their loaders. Only the author of a
+        // proposal can revise it
+        if let Some(supersedes) = self.supersedes.as_ref() {
+            let supersedes = supersedes.load()?;
+            require_keys_eq!(
+                supersedes.author,
+                self.signer.key(),
+                GovernanceError::NotSupersededProposalAuthor
+            );
+            require!(
+                !supersedes.is_superseded(),
+                GovernanceError::ProposalAlreadySuperseded
+            );
+        }
 
         let clock = Clock::get()?;
 
@@ -93,35 +178,97 @@ impl<'info> CreateProposal<'info> {
         let proposer_stake_weight_bp = stake_weight_bp!(proposer_stake, cluster_stake)?;
 
         require!(
-            proposer_stake >= MIN_PROPOSAL_STAKE_LAMPORTS,
+            proposer_stake >= self.governance_config.min_proposal_stake_lamports,
             GovernanceError::NotEnoughStake
         );
 
         // Initialize proposal account
-        self.proposal.set_inner(Proposal {
-            author: self.signer.key(),
-            title,
-            description,
-            creation_epoch: clock.epoch,
-            start_epoch: 0,
-            end_epoch: 0,
-            proposer_stake_weight_bp,
-            proposal_bump: bumps.proposal,
-            creation_timestamp: clock.unix_timestamp,
-            index: self.proposal_index.current_index + 1,
-            proposal_seed: seed,
-            vote_account_pubkey: self.spl_vote_account.key(),
-            ..Proposal::default()
-        });
-        self.proposal_index.current_index += 1;
+        let index = self.proposal_index.current_index + 1;
+        let mut proposal = self.proposal.load_init()?;
+        proposal.author = self.signer.key();
+        proposal.creation_epoch = clock.epoch;
+        proposal.proposer_stake_weight_bp = proposer_stake_weight_bp;
+        proposal.proposal_bump = bumps.proposal;
+        proposal.creation_timestamp = clock.unix_timestamp;
+        proposal.index = index;
+        proposal.proposal_seed = seed;
+        proposal.vote_account_pubkey = self.spl_vote_account.key();
+        proposal.set_anti_sniping(anti_sniping.unwrap_or_default());
+        if let Some(config) = secret_ballot {
+            proposal.set_secret_ballot(config);
+        }
+        proposal.random_snapshot = random_snapshot as u8;
+        proposal.class = class as u8;
+        proposal.stake_source = stake_source as u8;
+        proposal.set_rules(&self.governance_config);
+        if let Some(supersedes) = self.supersedes.as_ref() {
+            proposal.supersedes = supersedes.key();
+        }
+        if let Some(depends_on) = self.depends_on.as_ref() {
+            proposal.depends_on = depends_on.key();
+        }
+        self.proposal_index.current_index = index;
+
+        // Record the proposal in the registry, starting a new page when needed
+        if self.registry_page.entries.is_empty() {
+            self.registry_page.page = ProposalRegistryPage::page_for(index);
+            self.registry_page.bump = bumps.registry_page;
+        }
+        self.registry_page.append(index, self.proposal.key())?;
+
+        self.validator_profile
+            .init_if_new(self.spl_vote_account.key(), bumps.validator_profile);
+        self.validator_profile
+            .record_proposal_authored(self.governance_config.max_open_proposals_per_author)?;
 
         // Emit proposal created event
-        emit!(ProposalCreated {
-            proposal_id: self.proposal.key(),
+        emit_event(
+            &self.event_authority,
+            bumps.event_authority,
+            ProposalCreated {
+                proposal_id: self.proposal.key(),
+                author: self.signer.key(),
+                title: title.clone(),
+                description: description.clone(),
+                creation_timestamp: clock.unix_timestamp,
+            },
+        )?;
+
+        // Lock the author's bond on top of the bond account's rent
+        let bond_lamports = self.governance_config.proposal_bond_lamports;
+        transfer(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                Transfer {
+                    from: self.signer.to_account_info(),
+                    to: self.proposal_bond.to_account_info(),
+                },
+            ),
+            bond_lamports,
+        )?;
+        self.proposal_bond.set_inner(ProposalBond {
+            proposal: self.proposal.key(),
             author: self.signer.key(),
-            title: self.proposal.title.clone(),
-            description: self.proposal.description.clone(),
-            creation_timestamp: self.proposal.creation_timestamp,
+            amount: bond_lamports,
+            status: BondStatus::Locked,
+            bump: bumps.proposal_bond,
+        });
+
+        emit_event(
+            &self.event_authority,
+            bumps.event_authority,
+            ProposalBondLocked {
+                proposal_id: self.proposal.key(),
+                author: self.signer.key(),
+                amount: bond_lamports,
+            },
+        )?;
+
+        self.proposal_content.set_inner(ProposalContent {
+            proposal: self.proposal.key(),
+            title,
+            description,
+            bump: bumps.proposal_content,
         });
 
         Ok(())
@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    error::GovernanceError,
+    events::{emit_event, ProposalTransactionCreated},
+    state::{Proposal, ProposalInstruction, ProposalTransaction},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(instructions: Vec<ProposalInstruction>)]
+pub struct CreateProposalTransaction<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>, // Proposal author
+    #[account(
+        constraint = proposal.load()?.author == signer.key() @ GovernanceError::Unauthorized,
+        constraint = !proposal.load()?.is_voting() @ GovernanceError::CannotModifyAfterStart,
+        constraint = !proposal.load()?.is_finalized() @ GovernanceError::ProposalFinalized,
+    )]
+    pub proposal: AccountLoader<'info, Proposal>,
+    #[account(
+        init,
+        payer = signer,
+        space = ANCHOR_DISCRIMINATOR + ProposalTransaction::space(&instructions),
+        seeds = [b"proposal_transaction", proposal.key().as_ref()],
+        bump
+    )]
+    pub proposal_transaction: Account<'info, ProposalTransaction>,
+    /// CHECK: PDA that signs proposal instructions on execution, only its address is used here
+    #[account(seeds = [b"governance"], bump)]
+    pub governance_authority: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateProposalTransaction<'info> {
+    pub fn create_proposal_transaction(
+        &mut self,
+        instructions: Vec<ProposalInstruction>,
+        timelock_seconds: i64,
+        bumps: &CreateProposalTransactionBumps,
+    ) -> Result<()> {
+        require!(
+            !instructions.is_empty(),
+            GovernanceError::EmptyProposalTransaction
+        );
+        require!(
+            instructions.len() <= MAX_PROPOSAL_INSTRUCTIONS
+                && ProposalTransaction::space(&instructions) <= MAX_PROPOSAL_TRANSACTION_SIZE,
+            GovernanceError::ProposalTransactionTooLarge
+        );
+        require!(
+            (0..=MAX_EXECUTION_TIMELOCK_SECONDS).contains(&timelock_seconds),
+            GovernanceError::InvalidExecutionTimelock
+        );
+
+        // Execution is permissionless, so the governance PDA is the only signer we can provide
+        let governance_authority = self.governance_authority.key();
+        require!(
+            instructions
+                .iter()
+                .flat_map(|ix| ix.accounts.iter())
+                .all(|meta| !meta.is_signer || meta.pubkey == governance_authority),
+            GovernanceError::InvalidProposalInstructionSigner
+        );
+
+        // The governance PDA signs for every passed proposal, so a stored instruction calling
+        // back into this program could act on other proposals or re-enter execution
+        require!(
+            instructions.iter().all(|ix| ix.program_id != crate::ID),
+            GovernanceError::ProposalInstructionInvokesGovernance
+        );
+
+        let instruction_count = instructions.len() as u8;
+
+        self.proposal_transaction.set_inner(ProposalTransaction {
+            proposal: self.proposal.key(),
+            timelock_seconds,
+            executed_timestamp: 0,
+            instructions,
+            bump: bumps.proposal_transaction,
+        });
+
+        emit_event(
+            &self.event_authority,
+            bumps.event_authority,
+            ProposalTransactionCreated {
+                proposal_id: self.proposal.key(),
+                proposal_transaction: self.proposal_transaction.key(),
+                author: self.signer.key(),
+                instruction_count,
+                timelock_seconds,
+            },
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    error::GovernanceError,
+    events::{emit_event, TreasuryTransferCreated},
+    state::{Proposal, TreasuryTransfer},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreateTreasuryTransfer<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>, // Proposal author
+    #[account(
+        constraint = proposal.load()?.author == signer.key() @ GovernanceError::Unauthorized,
+        constraint = !proposal.load()?.is_voting() @ GovernanceError::CannotModifyAfterStart,
+        constraint = !proposal.load()?.is_finalized() @ GovernanceError::ProposalFinalized,
+    )]
+    pub proposal: AccountLoader<'info, Proposal>,
+    #[account(
+        init,
+        payer = signer,
+        space = ANCHOR_DISCRIMINATOR + TreasuryTransfer::INIT_SPACE,
+        seeds = [b"treasury_transfer", proposal.key().as_ref()],
+        bump
+    )]
+    pub treasury_transfer: Account<'info, TreasuryTransfer>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateTreasuryTransfer<'info> {
+    pub fn create_treasury_transfer(
+        &mut self,
+        destination: Pubkey,
+        mint: Option<Pubkey>,
+        amount: u64,
+        timelock_seconds: i64,
+        bumps: &CreateTreasuryTransferBumps,
+    ) -> Result<()> {
+        require!(amount > 0, GovernanceError::InvalidTreasuryTransferAmount);
+        require!(
+            (0..=MAX_EXECUTION_TIMELOCK_SECONDS).contains(&timelock_seconds),
+            GovernanceError::InvalidExecutionTimelock
+        );
+
+        let mint = mint.unwrap_or_default();
+        self.treasury_transfer.set_inner(TreasuryTransfer {
+            proposal: self.proposal.key(),
+            destination,
+            mint,
+            amount,
+            timelock_seconds,
+            executed_timestamp: 0,
+            bump: bumps.treasury_transfer,
+        });
+
+        emit_event(
+            &self.event_authority,
+            bumps.event_authority,
+            TreasuryTransferCreated {
+                proposal_id: self.proposal.key(),
+                treasury_transfer: self.treasury_transfer.key(),
+                author: self.signer.key(),
+                destination,
+                mint,
+                amount,
+                timelock_seconds,
+            },
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+use anchor_lang::{prelude::*, solana_program::sysvar::slot_hashes};
+
+use crate::{
+    error::GovernanceError,
+    events::{emit_event, SnapshotSlotDrawn},
+    state::Proposal,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DrawSnapshotSlot<'info> {
+    pub signer: Signer<'info>, // Anyone can draw once the snapshot epoch has started
+    #[account(
+        mut,
+        constraint = proposal.load()?.is_voting() @ GovernanceError::VotingNotStarted,
+        constraint = !proposal.load()?.is_finalized() @ GovernanceError::ProposalFinalized,
+        constraint = proposal.load()?.is_random_snapshot() @ GovernanceError::NotRandomSnapshot,
+        constraint = proposal.load()?.snapshot_slot == 0 @ GovernanceError::SnapshotSlotAlreadyDrawn,
+    )]
+    pub proposal: AccountLoader<'info, Proposal>,
+    /// CHECK: Ballot program account, which the consensus result address is derived from
+    #[account(
+        constraint = ballot_program.key == &gov_v1::ID @ ProgramError::InvalidAccountOwner,
+    )]
+    pub ballot_program: UncheckedAccount<'info>,
+    /// CHECK: SlotHashes sysvar, the random snapshot slot is drawn from its latest entry
+    #[account(address = slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
+}
+
+impl<'info> DrawSnapshotSlot<'info> {
+    /// Draws a random snapshot slot during the snapshot epoch. Drawing it at activation
+    /// would make it public for the whole discussion period, no less predictable than the
+    /// fixed slot. `init_ballot_box` creates the ballot box for it afterwards.
+    pub fn draw_snapshot_slot(&mut self, event_authority_bump: u8) -> Result<()> {
+        let clock = Clock::get()?;
+        let mut proposal = self.proposal.load_mut()?;
+
+        let snapshot_slot =
+            proposal.draw_snapshot_slot(&self.proposal.key(), clock.slot, &self.slot_hashes)?;
+        proposal.set_snapshot_slot(snapshot_slot, self.ballot_program.key);
+
+        emit_event(
+            &self.event_authority,
+            event_authority_bump,
+            SnapshotSlotDrawn {
+                proposal_id: self.proposal.key(),
+                snapshot_slot,
+                seed_slot: proposal.snapshot_seed_slot,
+                seed_hash: proposal.snapshot_seed_hash,
+            },
+        )?;
+
+        Ok(())
+    }
+}
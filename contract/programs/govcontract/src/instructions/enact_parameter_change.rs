@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::GovernanceError,
+    events::{emit_event, ParameterChangeEnacted},
+    state::{GovernanceConfig, ParameterChange, Proposal, ProposalOutcome},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct EnactParameterChange<'info> {
+    pub signer: Signer<'info>, // Anyone can enact once the proposal has passed
+    #[account(
+        constraint = proposal.load()?.is_finalized() @ GovernanceError::ProposalNotFinalized,
+        constraint = proposal.load()?.outcome() == ProposalOutcome::Passed @ GovernanceError::ProposalNotPassed,
+        constraint = !proposal.load()?.is_superseded() @ GovernanceError::ProposalSuperseded,
+    )]
+    pub proposal: AccountLoader<'info, Proposal>,
+    #[account(
+        mut,
+        seeds = [b"parameter_change", proposal.key().as_ref()],
+        bump = parameter_change.bump,
+        has_one = proposal,
+    )]
+    pub parameter_change: Account<'info, ParameterChange>,
+    #[account(
+        mut,
+        seeds = [b"governance_config"],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+}
+
+impl<'info> EnactParameterChange<'info> {
+    pub fn enact_parameter_change(&mut self, event_authority_bump: u8) -> Result<()> {
+        require!(
+            !self.parameter_change.is_enacted(),
+            GovernanceError::ParameterChangeAlreadyEnacted
+        );
+
+        let finalization_timestamp = self.proposal.load()?.finalization_timestamp;
+        for change in self.parameter_change.changes.iter() {
+            self.governance_config
+                .apply(*change, finalization_timestamp)?;
+        }
+        self.governance_config.validate()?;
+
+        let clock = Clock::get()?;
+        self.parameter_change.enacted_timestamp = clock.unix_timestamp;
+
+        emit_event(
+            &self.event_authority,
+            event_authority_bump,
+            ParameterChangeEnacted {
+                proposal_id: self.proposal.key(),
+                parameter_change: self.parameter_change.key(),
+                executor: self.signer.key(),
+                changes: self.parameter_change.changes.clone(),
+                enactment_timestamp: clock.unix_timestamp,
+            },
+        )?;
+
+        Ok(())
+    }
+}
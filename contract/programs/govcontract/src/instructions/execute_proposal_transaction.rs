@@ -0,0 +1,122 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        instruction::{AccountMeta, Instruction},
+        program::invoke_signed,
+    },
+};
+
+use crate::{
+    error::GovernanceError,
+    events::{emit_event, ProposalTransactionExecuted},
+    state::{Proposal, ProposalOutcome, ProposalTransaction},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExecuteProposalTransaction<'info> {
+    pub signer: Signer<'info>, // Anyone can execute once the timelock has elapsed
+    #[account(
+        constraint = proposal.load()?.is_finalized() @ GovernanceError::ProposalNotFinalized,
+        constraint = proposal.load()?.outcome() == ProposalOutcome::Passed @ GovernanceError::ProposalNotPassed,
+        constraint = !proposal.load()?.is_superseded() @ GovernanceError::ProposalSuperseded,
+    )]
+    pub proposal: AccountLoader<'info, Proposal>,
+    #[account(
+        mut,
+        seeds = [b"proposal_transaction", proposal.key().as_ref()],
+        bump = proposal_transaction.bump,
+        has_one = proposal,
+    )]
+    pub proposal_transaction: Account<'info, ProposalTransaction>,
+    /// CHECK: PDA signing the proposal instructions, holds no data. It is one global signer
+    /// shared by every passed proposal, so stored instructions can move any lamports, tokens
+    /// or authorities (such as upgrade or mint authorities) it holds, whichever proposal
+    /// placed them there. They cannot invoke this program.
+    #[account(mut, seeds = [b"governance"], bump)]
+    pub governance_authority: UncheckedAccount<'info>,
+}
+
+impl<'info> ExecuteProposalTransaction<'info> {
+    /// Invokes every stored instruction in order. Accounts referenced by the
+    /// instructions, including the invoked programs, are passed as remaining accounts.
+    pub fn execute_proposal_transaction(
+        &mut self,
+        remaining_accounts: &[AccountInfo<'info>],
+        governance_authority_bump: u8,
+        event_authority_bump: u8,
+    ) -> Result<()> {
+        require!(
+            !self.proposal_transaction.is_executed(),
+            GovernanceError::ProposalTransactionAlreadyExecuted
+        );
+
+        let clock = Clock::get()?;
+        let executable_at = self
+            .proposal
+            .load()?
+            .finalization_timestamp
+            .checked_add(self.proposal_transaction.timelock_seconds)
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
+        require!(
+            clock.unix_timestamp >= executable_at,
+            GovernanceError::ExecutionTimelockNotElapsed
+        );
+
+        // Mark as executed and persist it before invoking, so the transaction can never run
+        // twice even if an invoked program reads the account
+        self.proposal_transaction.executed_timestamp = clock.unix_timestamp;
+        self.proposal_transaction.exit(&crate::ID)?;
+
+        let governance_authority = self.governance_authority.to_account_info();
+        let signer_seeds: &[&[u8]] = &[b"governance".as_ref(), &[governance_authority_bump]];
+
+        let find_account = |key: &Pubkey| -> Result<AccountInfo<'info>> {
+            if key == governance_authority.key {
+                return Ok(governance_authority.clone());
+            }
+            remaining_accounts
+                .iter()
+                .find(|account| account.key == key)
+                .cloned()
+                .ok_or_else(|| GovernanceError::MissingInstructionAccount.into())
+        };
+
+        for proposal_instruction in self.proposal_transaction.instructions.iter() {
+            let mut account_infos = Vec::with_capacity(proposal_instruction.accounts.len() + 1);
+            let mut account_metas = Vec::with_capacity(proposal_instruction.accounts.len());
+
+            for meta in proposal_instruction.accounts.iter() {
+                account_infos.push(find_account(&meta.pubkey)?);
+                account_metas.push(AccountMeta {
+                    pubkey: meta.pubkey,
+                    is_signer: meta.is_signer,
+                    is_writable: meta.is_writable,
+                });
+            }
+            account_infos.push(find_account(&proposal_instruction.program_id)?);
+
+            let instruction = Instruction {
+                program_id: proposal_instruction.program_id,
+                accounts: account_metas,
+                data: proposal_instruction.data.clone(),
+            };
+
+            invoke_signed(&instruction, &account_infos, &[signer_seeds])?;
+        }
+
+        emit_event(
+            &self.event_authority,
+            event_authority_bump,
+            ProposalTransactionExecuted {
+                proposal_id: self.proposal.key(),
+                proposal_transaction: self.proposal_transaction.key(),
+                executor: self.signer.key(),
+                instruction_count: self.proposal_transaction.instructions.len() as u8,
+                execution_timestamp: clock.unix_timestamp,
+            },
+        )?;
+
+        Ok(())
+    }
+}
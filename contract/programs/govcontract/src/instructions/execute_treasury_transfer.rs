@@ -0,0 +1,151 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::{
+    error::GovernanceError,
+    events::{emit_event, TreasuryTransferExecuted},
+    state::{Proposal, ProposalOutcome, TreasuryTransfer},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExecuteTreasuryTransfer<'info> {
+    pub signer: Signer<'info>, // Anyone can execute once the timelock has elapsed
+    #[account(
+        constraint = proposal.load()?.is_finalized() @ GovernanceError::ProposalNotFinalized,
+        constraint = proposal.load()?.outcome() == ProposalOutcome::Passed @ GovernanceError::ProposalNotPassed,
+        constraint = !proposal.load()?.is_superseded() @ GovernanceError::ProposalSuperseded,
+    )]
+    pub proposal: AccountLoader<'info, Proposal>,
+    #[account(
+        mut,
+        seeds = [b"treasury_transfer", proposal.key().as_ref()],
+        bump = treasury_transfer.bump,
+        has_one = proposal,
+        has_one = destination,
+    )]
+    pub treasury_transfer: Account<'info, TreasuryTransfer>,
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump
+    )]
+    pub treasury: SystemAccount<'info>,
+    /// CHECK: SOL recipient or recipient token account, checked against the transfer
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+    // SPL transfers only
+    #[account(address = treasury_transfer.mint @ GovernanceError::MissingTreasuryTokenAccounts)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = treasury,
+        token::token_program = token_program,
+    )]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ExecuteTreasuryTransfer<'info> {
+    pub fn execute_treasury_transfer(
+        &mut self,
+        treasury_bump: u8,
+        event_authority_bump: u8,
+    ) -> Result<()> {
+        require!(
+            !self.treasury_transfer.is_executed(),
+            GovernanceError::TreasuryTransferAlreadyExecuted
+        );
+
+        let clock = Clock::get()?;
+        let executable_at = self
+            .proposal
+            .load()?
+            .finalization_timestamp
+            .checked_add(self.treasury_transfer.timelock_seconds)
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
+        require!(
+            clock.unix_timestamp >= executable_at,
+            GovernanceError::ExecutionTimelockNotElapsed
+        );
+
+        // Mark as executed before transferring so the transfer can never run twice
+        self.treasury_transfer.executed_timestamp = clock.unix_timestamp;
+
+        let amount = self.treasury_transfer.amount;
+        let signer_seeds: &[&[u8]] = &[b"treasury".as_ref(), &[treasury_bump]];
+
+        if self.treasury_transfer.is_sol() {
+            require!(
+                self.treasury.lamports() >= amount,
+                GovernanceError::InsufficientTreasuryBalance
+            );
+            // The treasury may be emptied but never left below rent exemption
+            let remaining = self.treasury.lamports() - amount;
+            require!(
+                remaining == 0 || remaining >= Rent::get()?.minimum_balance(0),
+                GovernanceError::TreasuryBelowRentExempt
+            );
+            transfer(
+                CpiContext::new_with_signer(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.treasury.to_account_info(),
+                        to: self.destination.to_account_info(),
+                    },
+                    &[signer_seeds],
+                ),
+                amount,
+            )?;
+        } else {
+            let (Some(mint), Some(treasury_token_account), Some(token_program)) = (
+                self.mint.as_ref(),
+                self.treasury_token_account.as_ref(),
+                self.token_program.as_ref(),
+            ) else {
+                return err!(GovernanceError::MissingTreasuryTokenAccounts);
+            };
+            require!(
+                treasury_token_account.amount >= amount,
+                GovernanceError::InsufficientTreasuryBalance
+            );
+            transfer_checked(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    TransferChecked {
+                        from: treasury_token_account.to_account_info(),
+                        mint: mint.to_account_info(),
+                        to: self.destination.to_account_info(),
+                        authority: self.treasury.to_account_info(),
+                    },
+                    &[signer_seeds],
+                ),
+                amount,
+                mint.decimals,
+            )?;
+        }
+
+        emit_event(
+            &self.event_authority,
+            event_authority_bump,
+            TreasuryTransferExecuted {
+                proposal_id: self.proposal.key(),
+                treasury_transfer: self.treasury_transfer.key(),
+                executor: self.signer.key(),
+                destination: self.treasury_transfer.destination,
+                mint: self.treasury_transfer.mint,
+                amount,
+                execution_timestamp: clock.unix_timestamp,
+            },
+        )?;
+
+        Ok(())
+    }
+}
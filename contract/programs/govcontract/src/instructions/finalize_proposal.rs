@@ -1,40 +1,114 @@
 use anchor_lang::prelude::*;
 
-use crate::{error::GovernanceError, events::ProposalFinalized, state::Proposal};
+use crate::{
+    error::GovernanceError,
+    events::{emit_event, ProposalFinalized, ProposalSuperseded},
+    state::{Proposal, ProposalOutcome, ProposalRegistryPage, ValidatorGovernanceProfile},
+};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct FinalizeProposal<'info> {
     pub signer: Signer<'info>, // Anyone can finalize after voting period ends
     #[account(
         mut,
-        constraint = !proposal.finalized @ GovernanceError::ProposalFinalized,
+        constraint = !proposal.load()?.is_finalized() @ GovernanceError::ProposalFinalized,
     )]
-    pub proposal: Account<'info, Proposal>,
+    pub proposal: AccountLoader<'info, Proposal>,
+    // Pages are program-owned and only created at their own seeds, so `page` identifies them
+    #[account(
+        mut,
+        constraint = registry_page.page == ProposalRegistryPage::page_for(proposal.load()?.index)
+            @ GovernanceError::InvalidRegistryPage,
+    )]
+    pub registry_page: Account<'info, ProposalRegistryPage>,
+    #[account(
+        mut,
+        seeds = [b"validator_profile", proposal.load()?.vote_account_pubkey.as_ref()],
+        bump = author_profile.bump,
+    )]
+    pub author_profile: Account<'info, ValidatorGovernanceProfile>,
+    /// Proposal this one supersedes, marked as superseded if this one passes
+    #[account(
+        mut,
+        constraint = superseded_proposal.key() == proposal.load()?.supersedes
+            @ GovernanceError::InvalidSupersededProposal,
+    )]
+    pub superseded_proposal: Option<AccountLoader<'info, Proposal>>,
 }
 
 impl<'info> FinalizeProposal<'info> {
-    pub fn finalize_proposal(&mut self) -> Result<()> {
+    pub fn finalize_proposal(&mut self, event_authority_bump: u8) -> Result<()> {
+        let mut proposal = self.proposal.load_mut()?;
         require!(
-            self.proposal.voting == true && self.proposal.finalized == false,
+            proposal.is_voting() && !proposal.is_finalized(),
             GovernanceError::ProposalNotInVotingPhase
         );
         let clock = Clock::get()?;
+        // Secret ballots are finalized once their reveal window has closed
         require!(
-            clock.epoch >= self.proposal.end_epoch,
+            clock.epoch >= proposal.reveal_end_epoch()?,
             GovernanceError::VotingPeriodNotEnded
         );
 
-        emit!(ProposalFinalized {
-            proposal_id: self.proposal.key(),
-            finalizer: self.signer.key(),
-            total_for_votes: self.proposal.for_votes_lamports,
-            total_against_votes: self.proposal.against_votes_lamports,
-            total_abstain_votes: self.proposal.abstain_votes_lamports,
-            total_votes_count: self.proposal.vote_count,
-            finalization_timestamp: clock.unix_timestamp,
-        });
-
-        self.proposal.finalized = true;
+        // A superseded proposal cannot pass, and neither can a revision of a proposal that
+        // another passed revision has already superseded
+        let mut passed = proposal.is_passing() && !proposal.is_superseded();
+        let mut superseded_proposal = None;
+        if let (true, Some(supersedes)) = (passed, proposal.supersedes()) {
+            let account = self
+                .superseded_proposal
+                .as_ref()
+                .ok_or(GovernanceError::SupersededProposalRequired)?;
+            passed = !account.load()?.is_superseded();
+            superseded_proposal = passed.then_some((supersedes, account));
+        }
+
+        emit_event(
+            &self.event_authority,
+            event_authority_bump,
+            ProposalFinalized {
+                proposal_id: self.proposal.key(),
+                finalizer: self.signer.key(),
+                total_for_votes: proposal.for_votes_lamports,
+                total_against_votes: proposal.against_votes_lamports,
+                total_abstain_votes: proposal.abstain_votes_lamports,
+                total_votes_count: proposal.vote_count,
+                passed,
+                finalization_timestamp: clock.unix_timestamp,
+                snapshot_slot: proposal.snapshot_slot,
+                consensus_result: proposal.consensus_result,
+                start_epoch: proposal.start_epoch,
+                end_epoch: proposal.end_epoch,
+                pass_threshold_bp: proposal.pass_threshold_bp,
+                quorum_bp: proposal.quorum_bp,
+                participation_lamports: proposal.vote_lamports().total()?,
+                cluster_stake_lamports: proposal.cluster_stake_lamports,
+            },
+        )?;
+
+        let outcome = if passed {
+            ProposalOutcome::Passed
+        } else {
+            ProposalOutcome::Rejected
+        };
+        proposal.finalize(outcome, clock.unix_timestamp);
+        self.registry_page.set_outcome(proposal.index, outcome);
+        self.author_profile.record_proposal_closed();
+
+        // A passed revision replaces the proposal it supersedes
+        if let Some((supersedes, superseded_proposal)) = superseded_proposal {
+            superseded_proposal.load_mut()?.superseded_by = self.proposal.key();
+
+            emit_event(
+                &self.event_authority,
+                event_authority_bump,
+                ProposalSuperseded {
+                    proposal_id: supersedes,
+                    superseded_by: self.proposal.key(),
+                },
+            )?;
+        }
 
         Ok(())
     }
@@ -1,27 +1,28 @@
-use anchor_lang::{prelude::*, solana_program::vote};
+use anchor_lang::prelude::*;
 
 use crate::{
-    constants::*, error::GovernanceError, events::MerkleRootFlushed, state::Proposal,
-    utils::get_epoch_slot_range,
+    constants::*,
+    error::GovernanceError,
+    events::{emit_event, MerkleRootFlushed},
+    state::{Proposal, StakeSource},
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct FlushMerkleRoot<'info> {
     #[account(mut)]
     pub signer: Signer<'info>, // Proposal author
     #[account(
         mut,
-        constraint = proposal.author == signer.key() @ GovernanceError::Unauthorized,
-        constraint = !proposal.finalized @ GovernanceError::ProposalFinalized,
+        constraint = proposal.load()?.author == signer.key() @ GovernanceError::Unauthorized,
+        constraint = !proposal.load()?.is_finalized() @ GovernanceError::ProposalFinalized,
+        constraint = proposal.load()?.vote_count == 0 @ GovernanceError::CannotFlushAfterVotes,
     )]
-    pub proposal: Account<'info, Proposal>,
-    /// CHECK: Vote account is too big to deserialize, so we check on owner and size
-    #[account(
-        constraint = spl_vote_account.owner == &vote::program::ID @ ProgramError::InvalidAccountOwner,
-    )]
-    pub spl_vote_account: UncheckedAccount<'info>,
-    /// CHECK: Ballot box account - may or may not exist, checked with data_is_empty()
-    pub ballot_box: UncheckedAccount<'info>,
+    pub proposal: AccountLoader<'info, Proposal>,
+    /// CHECK: Ballot box account - may or may not exist, checked with data_is_empty().
+    /// Omitted for a random snapshot, which keeps its slot; `init_ballot_box` creates its
+    /// ballot box if it is missing.
+    pub ballot_box: Option<UncheckedAccount<'info>>,
     /// CHECK: Ballot program account
     #[account(
         constraint = ballot_program.key == &gov_v1::ID @ ProgramError::InvalidAccountOwner,
@@ -39,49 +40,82 @@ pub struct FlushMerkleRoot<'info> {
 }
 
 impl<'info> FlushMerkleRoot<'info> {
-    pub fn flush_merkle_root(&mut self) -> Result<()> {
+    pub fn flush_merkle_root(&mut self, event_authority_bump: u8) -> Result<()> {
         let clock = Clock::get()?;
+        let mut proposal = self.proposal.load_mut()?;
 
+        // Epoch stake proposals have no snapshot to flush
+        require!(
+            proposal.stake_source() == StakeSource::Snapshot,
+            GovernanceError::EpochStakeProposal
+        );
         // Clear the consensus_result
         require!(
-            self.proposal.snapshot_slot > 0,
+            proposal.snapshot_slot > 0,
             GovernanceError::InvalidSnapshotSlot
         );
         require!(
-            self.proposal.consensus_result.is_some(),
+            proposal.consensus_result().is_some(),
             GovernanceError::ConsensusResultNotSet
         );
+        let flush_count = proposal.flush_count as usize;
+        require!(
+            flush_count < MAX_MERKLE_ROOT_FLUSHES,
+            GovernanceError::MerkleRootFlushLimitReached
+        );
 
-        // Recalculate snapshot_slot based on current epoch
-        // Using the same logic as in support_proposal
-        let target_epoch = clock.epoch + SNAPSHOT_EPOCH_EXTENSION;
-        let (start_slot, _) = get_epoch_slot_range(target_epoch);
-        // 1000 slots into snapshot
-        let snapshot_slot = start_slot + 1000;
-        self.proposal.snapshot_slot = snapshot_slot;
-        // start voting 1 epoch after snapshot
-        self.proposal.start_epoch = target_epoch + 1;
-        self.proposal.end_epoch = target_epoch + 1 + VOTING_EPOCHS;
+        // Record the replaced snapshot so every flush stays auditable on-chain
+        let previous_snapshot_slot = proposal.snapshot_slot;
+        proposal.prior_snapshot_slots[flush_count] = previous_snapshot_slot;
+        proposal.flush_count += 1;
 
-        // Calculate new consensus_result PDA based on new snapshot_slot
-        let (consensus_result_pda, _) = Pubkey::find_program_address(
-            &[b"ConsensusResult", &snapshot_slot.to_le_bytes()],
-            &self.ballot_program.key,
-        );
+        if proposal.is_random_snapshot() {
+            // Drawing again would let the author re-roll the slot until one suits them, so a
+            // random snapshot keeps its slot and schedule and only re-points to the consensus
+            // result published for that slot
+            proposal.set_snapshot_slot(previous_snapshot_slot, self.ballot_program.key);
+        } else {
+            // Recalculate snapshot_slot based on current epoch
+            // Using the same logic as in support_proposal
+            let target_epoch = clock.epoch + SNAPSHOT_EPOCH_EXTENSION;
+            // start voting 1 epoch after snapshot
+            proposal.start_epoch = target_epoch + 1;
+            proposal.end_epoch = target_epoch + 1 + proposal.voting_epochs;
+            proposal.set_snapshot_slot(
+                Proposal::fixed_snapshot_slot(target_epoch),
+                self.ballot_program.key,
+            );
+        }
+        let snapshot_slot = proposal.snapshot_slot;
 
-        self.proposal.consensus_result = Some(consensus_result_pda);
+        let flush_count = proposal.flush_count;
+        let proposal_seed = proposal.proposal_seed;
+        let vote_account_key = proposal.vote_account_pubkey;
+        let proposal_bump = proposal.proposal_bump;
+        let random_snapshot = proposal.is_random_snapshot();
+        // The proposal signs the ballot box CPI, so its data must not stay borrowed
+        drop(proposal);
 
-        // Initialize ballot box if it doesn't exist
-        if self.ballot_box.data_is_empty() {
+        // Initialize ballot box if it doesn't exist; a random snapshot's ballot box is
+        // created by `init_ballot_box`
+        let ballot_box = if random_snapshot {
+            None
+        } else {
+            let ballot_box = self
+                .ballot_box
+                .as_ref()
+                .ok_or(GovernanceError::BallotBoxRequired)?;
+            ballot_box.data_is_empty().then_some(ballot_box)
+        };
+        if let Some(ballot_box) = ballot_box {
             // Create seed components with sufficient lifetime
-            let proposal_seed_val = self.proposal.proposal_seed.to_le_bytes();
-            let vote_account_key = self.proposal.vote_account_pubkey.key();
+            let proposal_seed_val = proposal_seed.to_le_bytes();
 
             let seeds: &[&[u8]] = &[
                 b"proposal".as_ref(),
                 &proposal_seed_val,
                 vote_account_key.as_ref(),
-                &[self.proposal.proposal_bump],
+                &[proposal_bump],
             ];
             let signer = &[&seeds[..]];
             // Initialize the ballot box via CPI
@@ -90,27 +124,28 @@ impl<'info> FlushMerkleRoot<'info> {
                 gov_v1::cpi::accounts::InitBallotBox {
                     payer: self.signer.to_account_info(),
                     proposal: self.proposal.to_account_info(),
-                    ballot_box: self.ballot_box.to_account_info(),
+                    ballot_box: ballot_box.to_account_info(),
                     program_config: self.program_config.to_account_info(),
                     system_program: self.system_program.to_account_info(),
                 },
                 signer,
             );
 
-            gov_v1::cpi::init_ballot_box(
-                cpi_ctx,
-                snapshot_slot,
-                self.proposal.proposal_seed,
-                self.spl_vote_account.key(),
-            )?;
+            gov_v1::cpi::init_ballot_box(cpi_ctx, snapshot_slot, proposal_seed, vote_account_key)?;
         }
 
-        emit!(MerkleRootFlushed {
-            proposal_id: self.proposal.key(),
-            author: self.signer.key(),
-            new_snapshot_slot: self.proposal.snapshot_slot,
-            flush_timestamp: clock.unix_timestamp,
-        });
+        emit_event(
+            &self.event_authority,
+            event_authority_bump,
+            MerkleRootFlushed {
+                proposal_id: self.proposal.key(),
+                author: self.signer.key(),
+                previous_snapshot_slot,
+                new_snapshot_slot: snapshot_slot,
+                flush_count,
+                flush_timestamp: clock.unix_timestamp,
+            },
+        )?;
 
         Ok(())
     }
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::GovernanceError,
+    state::{Proposal, StakeSource},
+};
+
+#[derive(Accounts)]
+pub struct InitBallotBox<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>, // Anyone can pay for the ballot box
+    #[account(
+        constraint = proposal.load()?.is_voting() @ GovernanceError::VotingNotStarted,
+        constraint = !proposal.load()?.is_finalized() @ GovernanceError::ProposalFinalized,
+        constraint = proposal.load()?.stake_source() == StakeSource::Snapshot @ GovernanceError::EpochStakeProposal,
+        constraint = proposal.load()?.snapshot_slot != 0 @ GovernanceError::SnapshotSlotNotDrawn,
+    )]
+    pub proposal: AccountLoader<'info, Proposal>,
+    /// CHECK: Ballot box account, its address is checked by the ballot program against the
+    /// proposal's snapshot slot
+    #[account(mut)]
+    pub ballot_box: UncheckedAccount<'info>,
+    /// CHECK: Ballot program account
+    #[account(
+        constraint = ballot_program.key == &gov_v1::ID @ ProgramError::InvalidAccountOwner,
+    )]
+    pub ballot_program: UncheckedAccount<'info>,
+    /// CHECK: Program config account
+    #[account(
+        seeds = [b"ProgramConfig"],
+        bump,
+        seeds::program = ballot_program.key(),
+        constraint = program_config.owner == &gov_v1::ID @ ProgramError::InvalidAccountOwner,
+    )]
+    pub program_config: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitBallotBox<'info> {
+    /// Creates the ballot box for the proposal's current snapshot slot. Random snapshot
+    /// slots are only known once `draw_snapshot_slot` has drawn them, so their ballot box is
+    /// created here instead of when voting is activated or the merkle root flushed.
+    pub fn init_ballot_box(&mut self) -> Result<()> {
+        require!(
+            self.ballot_box.data_is_empty(),
+            GovernanceError::BallotBoxExists
+        );
+
+        let proposal = self.proposal.load()?;
+        let snapshot_slot = proposal.snapshot_slot;
+        let proposal_seed = proposal.proposal_seed;
+        let vote_account_key = proposal.vote_account_pubkey;
+        let proposal_bump = proposal.proposal_bump;
+        // The proposal signs the ballot box CPI, so its data must not stay borrowed
+        drop(proposal);
+
+        let proposal_seed_val = proposal_seed.to_le_bytes();
+        let seeds: &[&[u8]] = &[
+            b"proposal".as_ref(),
+            &proposal_seed_val,
+            vote_account_key.as_ref(),
+            &[proposal_bump],
+        ];
+        let signer_seeds = &[seeds];
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.ballot_program.to_account_info(),
+            gov_v1::cpi::accounts::InitBallotBox {
+                payer: self.signer.to_account_info(),
+                proposal: self.proposal.to_account_info(),
+                ballot_box: self.ballot_box.to_account_info(),
+                program_config: self.program_config.to_account_info(),
+                system_program: self.system_program.to_account_info(),
+            },
+            signer_seeds,
+        );
+        gov_v1::cpi::init_ballot_box(cpi_ctx, snapshot_slot, proposal_seed, vote_account_key)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    events::{emit_event, GovernanceConfigInitialized},
+    state::GovernanceConfig,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializeGovernanceConfig<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(
+        init,
+        payer = signer,
+        space = ANCHOR_DISCRIMINATOR + GovernanceConfig::INIT_SPACE,
+        seeds = [b"governance_config"],
+        bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializeGovernanceConfig<'info> {
+    /// Creates the config with the default rules; from then on only passed proposals
+    /// change it
+    pub fn initialize_governance_config(
+        &mut self,
+        bumps: &InitializeGovernanceConfigBumps,
+    ) -> Result<()> {
+        let config = GovernanceConfig::new(bumps.governance_config);
+        emit_event(
+            &self.event_authority,
+            bumps.event_authority,
+            GovernanceConfigInitialized {
+                governance_config: self.governance_config.key(),
+                initializer: self.signer.key(),
+                support_threshold_bp: config.support_threshold_bp,
+                emergency_support_threshold_bp: config.emergency_support_threshold_bp,
+                support_epochs: config.support_epochs,
+                emergency_support_epochs: config.emergency_support_epochs,
+                discussion_epochs: config.discussion_epochs,
+                emergency_discussion_epochs: config.emergency_discussion_epochs,
+                voting_epochs: config.voting_epochs,
+                emergency_voting_epochs: config.emergency_voting_epochs,
+                pass_threshold_bp: config.pass_threshold_bp,
+                quorum_bp: config.quorum_bp,
+                min_proposal_stake_lamports: config.min_proposal_stake_lamports,
+                proposal_bond_lamports: config.proposal_bond_lamports,
+                max_open_proposals_per_author: config.max_open_proposals_per_author,
+            },
+        )?;
+        self.governance_config.set_inner(config);
+        Ok(())
+    }
+}
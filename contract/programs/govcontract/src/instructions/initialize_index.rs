@@ -1,7 +1,12 @@
 use anchor_lang::prelude::*;
 
-use crate::{constants::*, state::ProposalIndex};
+use crate::{
+    constants::*,
+    events::{emit_event, ProposalIndexInitialized},
+    state::ProposalIndex,
+};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct InitializedIndex<'info> {
     #[account(mut)]
@@ -23,6 +28,13 @@ impl<'info> InitializedIndex<'info> {
             current_index: 0,
             bump: bumps.proposal_index,
         });
-        Ok(())
+        emit_event(
+            &self.event_authority,
+            bumps.event_authority,
+            ProposalIndexInitialized {
+                proposal_index: self.proposal_index.key(),
+                initializer: self.signer.key(),
+            },
+        )
     }
 }
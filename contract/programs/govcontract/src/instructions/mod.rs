@@ -1,19 +1,65 @@
+pub mod cancel_proposal;
+pub mod cast_delegated_vote;
 pub mod cast_vote;
 pub mod cast_vote_override;
+pub mod cast_vote_override_batch;
+pub mod cast_vote_signed;
+pub mod close_meta_merkle_proof;
+pub mod commit_vote;
+pub mod create_parameter_change;
 pub mod create_proposal;
+pub mod create_proposal_transaction;
+pub mod create_treasury_transfer;
+pub mod draw_snapshot_slot;
+pub mod enact_parameter_change;
+pub mod execute_proposal_transaction;
+pub mod execute_treasury_transfer;
 pub mod finalize_proposal;
 pub mod flush_merkle_root;
+pub mod init_ballot_box;
+pub mod initialize_governance_config;
 pub mod initialize_index;
 pub mod modify_vote;
 pub mod modify_vote_override;
+pub mod register_stake_proxy;
+pub mod remove_governance_delegation;
+pub mod reveal_vote;
+pub mod revoke_stake_proxy;
+pub mod set_governance_delegation;
+pub mod settle_proposal_bond;
+pub mod signal;
 pub mod support_proposal;
+pub mod update_validator_profile;
 
+pub use cancel_proposal::*;
+pub use cast_delegated_vote::*;
 pub use cast_vote::*;
 pub use cast_vote_override::*;
+pub use cast_vote_override_batch::*;
+pub use cast_vote_signed::*;
+pub use close_meta_merkle_proof::*;
+pub use commit_vote::*;
+pub use create_parameter_change::*;
 pub use create_proposal::*;
+pub use create_proposal_transaction::*;
+pub use create_treasury_transfer::*;
+pub use draw_snapshot_slot::*;
+pub use enact_parameter_change::*;
+pub use execute_proposal_transaction::*;
+pub use execute_treasury_transfer::*;
 pub use finalize_proposal::*;
 pub use flush_merkle_root::*;
+pub use init_ballot_box::*;
+pub use initialize_governance_config::*;
 pub use initialize_index::*;
 pub use modify_vote::*;
 pub use modify_vote_override::*;
+pub use register_stake_proxy::*;
+pub use remove_governance_delegation::*;
+pub use reveal_vote::*;
+pub use revoke_stake_proxy::*;
+pub use set_governance_delegation::*;
+pub use settle_proposal_bond::*;
+pub use signal::*;
 pub use support_proposal::*;
+pub use update_validator_profile::*;
@@ -4,20 +4,19 @@ use anchor_lang::{
 };
 
 use crate::{
-    calculate_vote_lamports,
-    constants::*,
     error::GovernanceError,
-    events::VoteModified,
-    merkle_helpers::verify_merkle_proof_cpi,
+    events::{emit_event, VoteModified, VotingPeriodExtended},
+    stake_source::{SnapshotAccounts, ValidatorStake},
     state::{Proposal, Vote},
+    tally,
 };
-use gov_v1::{ConsensusResult, MetaMerkleProof};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct ModifyVote<'info> {
     pub signer: Signer<'info>, // Voter (validator)
     #[account(mut)]
-    pub proposal: Account<'info, Proposal>, // Proposal being modified
+    pub proposal: AccountLoader<'info, Proposal>, // Proposal being modified
     #[account(
         mut,
         seeds = [b"vote", proposal.key().as_ref(), spl_vote_account.key().as_ref()],
@@ -30,13 +29,13 @@ pub struct ModifyVote<'info> {
         constraint = spl_vote_account.data_len() == VoteState::size_of() @ GovernanceError::InvalidVoteAccountSize
     )]
     pub spl_vote_account: UncheckedAccount<'info>,
-    /// CHECK: The snapshot program (gov-v1 or mock)
+    /// CHECK: The snapshot program (gov-v1 or mock), omitted for epoch stake
     // #[account(constraint = snapshot_program.key() == gov_v1::ID @ GovernanceError::InvalidSnapshotProgram)]
-    pub snapshot_program: UncheckedAccount<'info>,
-    /// CHECK: Consensus result account owned by snapshot program
-    pub consensus_result: UncheckedAccount<'info>,
-    /// CHECK: Meta merkle proof account owned by snapshot program
-    pub meta_merkle_proof: UncheckedAccount<'info>,
+    pub snapshot_program: Option<UncheckedAccount<'info>>,
+    /// CHECK: Consensus result account owned by snapshot program, omitted for epoch stake
+    pub consensus_result: Option<UncheckedAccount<'info>>,
+    /// CHECK: Meta merkle proof account owned by snapshot program, omitted for epoch stake
+    pub meta_merkle_proof: Option<UncheckedAccount<'info>>,
     pub system_program: Program<'info, System>, // For account operations
 }
 
@@ -46,151 +45,114 @@ impl<'info> ModifyVote<'info> {
         for_votes_bp: u64,
         against_votes_bp: u64,
         abstain_votes_bp: u64,
+        event_authority_bump: u8,
     ) -> Result<()> {
         // Check that the proposal is open for voting
-        require!(!self.proposal.finalized, GovernanceError::ProposalFinalized);
+        let proposal_key = self.proposal.key();
+        let mut proposal = self.proposal.load_mut()?;
+        require!(!proposal.is_finalized(), GovernanceError::ProposalFinalized);
+        // Secret ballot votes only change through their reveal
+        require!(
+            !proposal.is_secret_ballot(),
+            GovernanceError::SecretBallotProposal
+        );
 
         // Get the current epoch from the Clock sysvar
         let clock = Clock::get()?;
         let current_epoch = clock.epoch;
         require!(
-            self.proposal.start_epoch <= current_epoch,
+            proposal.start_epoch <= current_epoch,
             GovernanceError::VotingNotStarted
         );
         require!(
-            current_epoch < self.proposal.end_epoch,
+            current_epoch < proposal.end_epoch,
             GovernanceError::ProposalClosed
         );
 
         // Validate that the basis points sum to 10,000 (100%)
-        let total_bp = for_votes_bp
-            .checked_add(against_votes_bp)
-            .and_then(|sum| sum.checked_add(abstain_votes_bp))
-            .ok_or(GovernanceError::ArithmeticOverflow)?;
-        require!(
-            total_bp == BASIS_POINTS_MAX,
-            GovernanceError::InvalidVoteDistribution
-        );
-
-        // Validate snapshot program ownership
-        require!(
-            self.consensus_result.owner == self.snapshot_program.key,
-            GovernanceError::MustBeOwnedBySnapshotProgram
-        );
-        require!(
-            self.meta_merkle_proof.owner == self.snapshot_program.key,
-            GovernanceError::MustBeOwnedBySnapshotProgram
-        );
+        tally::validate_distribution(for_votes_bp, against_votes_bp, abstain_votes_bp)?;
 
-        require!(
-            self.proposal.consensus_result.is_some(),
-            GovernanceError::ConsensusResultNotSet
+        // Read the validator's stake from the proposal's stake source
+        let snapshot = SnapshotAccounts::new(
+            &self.snapshot_program,
+            &self.consensus_result,
+            &self.meta_merkle_proof,
         );
+        let validator_stake = ValidatorStake::load(&proposal, snapshot, &self.spl_vote_account)?;
 
-        // unwrap is safe because we checked that the consensus result is set in the previous require
-        require_keys_eq!(
-            self.proposal.consensus_result.unwrap(),
-            self.consensus_result.key(),
-            GovernanceError::InvalidConsensusResultPDA
-        );
-        let consensus_result_data = self.consensus_result.try_borrow_data()?;
-        let consensus_result = ConsensusResult::try_deserialize(&mut &consensus_result_data[..])?;
-
-        require!(
-            consensus_result
-                .ballot
-                .meta_merkle_root
-                .iter()
-                .any(|&x| x != 0),
-            GovernanceError::InvalidMerkleRoot
-        );
-
-        // Deserialize MetaMerkleProof for crosschecking
-        let meta_account_data = self.meta_merkle_proof.try_borrow_data()?;
-        let meta_merkle_proof = MetaMerkleProof::try_deserialize(&mut &meta_account_data[..])?;
-        let meta_merkle_leaf = meta_merkle_proof.meta_merkle_leaf;
-
-        // Crosscheck consensus result
+        // Ensure the stake belongs to the signer and is not empty
         require_eq!(
-            meta_merkle_proof.consensus_result,
-            self.consensus_result.key(),
-            GovernanceError::InvalidConsensusResultPDA
-        );
-
-        // Ensure leaf matches signer and has sufficient stake
-        require_eq!(
-            meta_merkle_leaf.voting_wallet,
+            validator_stake.voting_wallet,
             self.signer.key(),
             GovernanceError::InvalidVoteAccount
         );
-        // Ensure the proof's vote_account matches the provided SPL vote account
-        require_eq!(
-            meta_merkle_leaf.vote_account,
-            self.spl_vote_account.key(),
-            GovernanceError::InvalidVoteAccount
-        );
         require_gt!(
-            meta_merkle_leaf.active_stake,
+            validator_stake.active_stake,
             0u64,
             GovernanceError::NotEnoughStake
         );
 
-        verify_merkle_proof_cpi(
-            &self.meta_merkle_proof.to_account_info(),
-            &self.consensus_result.to_account_info(),
-            &self.snapshot_program.to_account_info(),
-            None,
-            None,
-        )?;
-
-        // Subtract old lamports from proposal totals
-        self.proposal.sub_vote_lamports(
-            self.vote.for_votes_lamports,
-            self.vote.against_votes_lamports,
-            self.vote.abstain_votes_lamports,
-        )?;
-
-        // Calculate new effective votes for each category based on actual lamports
-        let full_validator_stake = meta_merkle_leaf.active_stake;
-        let voter_stake = full_validator_stake
-            .checked_sub(self.vote.override_lamports)
-            .ok_or(GovernanceError::ArithmeticOverflow)?;
+        validator_stake.verify()?;
 
-        let for_votes_lamports = calculate_vote_lamports!(voter_stake, for_votes_bp)?;
-        let against_votes_lamports = calculate_vote_lamports!(voter_stake, against_votes_bp)?;
-        let abstain_votes_lamports = calculate_vote_lamports!(voter_stake, abstain_votes_bp)?;
+        let previous_standing = proposal.standing();
 
-        // Add new lamports to proposal totals
-        self.proposal.add_vote_lamports(
-            for_votes_lamports,
-            against_votes_lamports,
-            abstain_votes_lamports,
+        // Recompute the validator's votes over the stake its delegators have not overridden
+        let tally = tally::modify_vote(
+            proposal.vote_lamports(),
+            self.vote.vote_lamports(),
+            validator_stake.recounted(validator_stake.active_stake, self.vote.stake),
+            self.vote.override_lamports,
+            for_votes_bp,
+            against_votes_bp,
+            abstain_votes_bp,
+        )?;
+        proposal.set_vote_lamports(tally.totals);
+
+        emit_event(
+            &self.event_authority,
+            event_authority_bump,
+            VoteModified {
+                proposal_id: proposal_key,
+                voter: self.signer.key(),
+                vote_account: self.spl_vote_account.key(),
+                old_for_votes_bp: self.vote.for_votes_bp,
+                old_against_votes_bp: self.vote.against_votes_bp,
+                old_abstain_votes_bp: self.vote.abstain_votes_bp,
+                new_for_votes_bp: for_votes_bp,
+                new_against_votes_bp: against_votes_bp,
+                new_abstain_votes_bp: abstain_votes_bp,
+                for_votes_lamports: tally.validator.for_votes,
+                against_votes_lamports: tally.validator.against_votes,
+                abstain_votes_lamports: tally.validator.abstain_votes,
+                modification_timestamp: clock.unix_timestamp,
+            },
         )?;
 
-        emit!(VoteModified {
-            proposal_id: self.proposal.key(),
-            voter: self.signer.key(),
-            vote_account: self.spl_vote_account.key(),
-            old_for_votes_bp: self.vote.for_votes_bp,
-            old_against_votes_bp: self.vote.against_votes_bp,
-            old_abstain_votes_bp: self.vote.abstain_votes_bp,
-            new_for_votes_bp: for_votes_bp,
-            new_against_votes_bp: against_votes_bp,
-            new_abstain_votes_bp: abstain_votes_bp,
-            for_votes_lamports,
-            against_votes_lamports,
-            abstain_votes_lamports,
-            modification_timestamp: clock.unix_timestamp,
-        });
+        // Extend voting when this vote flipped the result close to the deadline
+        if let Some(new_end_epoch) =
+            proposal.extend_voting_if_flipped(previous_standing, clock.slot)?
+        {
+            emit_event(
+                &self.event_authority,
+                event_authority_bump,
+                VotingPeriodExtended {
+                    proposal_id: proposal_key,
+                    voter: self.signer.key(),
+                    new_end_epoch,
+                    voting_extensions: proposal.voting_extensions,
+                    extension_timestamp: clock.unix_timestamp,
+                },
+            )?;
+        }
 
         // Update the vote account with new distribution and lamports
         self.vote.for_votes_bp = for_votes_bp;
         self.vote.against_votes_bp = against_votes_bp;
         self.vote.abstain_votes_bp = abstain_votes_bp;
-        self.vote.for_votes_lamports = for_votes_lamports;
-        self.vote.against_votes_lamports = against_votes_lamports;
-        self.vote.abstain_votes_lamports = abstain_votes_lamports;
+        self.vote.set_vote_lamports(tally.validator);
         self.vote.vote_timestamp = clock.unix_timestamp;
+        // Voting directly stops following a delegate
+        self.vote.delegate = Pubkey::default();
 
         Ok(())
     }
@@ -5,22 +5,23 @@ use anchor_lang::{
         vote::{program as vote_program, state::VoteState},
     },
 };
-use gov_v1::{ConsensusResult, MetaMerkleProof, StakeMerkleLeaf};
+use gov_v1::StakeMerkleLeaf;
 
 use crate::{
-    calculate_vote_lamports,
     constants::*,
     error::GovernanceError,
-    events::VoteOverrideModified,
-    merkle_helpers::verify_merkle_proof_cpi,
-    state::{Proposal, Vote, VoteOverride, VoteOverrideCache},
+    events::{emit_event, VoteOverrideCacheUpdated, VoteOverrideModified, VotingPeriodExtended},
+    stake_source::{SnapshotAccounts, ValidatorStake},
+    state::{Proposal, StakeProxy, Vote, VoteOverride, VoteOverrideCache},
+    tally::{self, VoteLamports},
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct ModifyVoteOverride<'info> {
     pub signer: Signer<'info>, // Voter (staker/delegator)
     #[account(mut)]
-    pub proposal: Account<'info, Proposal>, // Proposal being voted on
+    pub proposal: AccountLoader<'info, Proposal>, // Proposal being voted on
     /// CHECK: Validator vote account. Must exist for modification
     #[account(
         mut,
@@ -52,13 +53,15 @@ pub struct ModifyVoteOverride<'info> {
         constraint = spl_stake_account.owner == &stake_program::ID @ ProgramError::InvalidAccountOwner,
     )]
     pub spl_stake_account: UncheckedAccount<'info>,
-    /// CHECK: The snapshot program (gov-v1 or mock)
+    /// Proxy registered for the stake account, required when the proxy signs
+    pub stake_proxy: Option<Account<'info, StakeProxy>>,
+    /// CHECK: The snapshot program (gov-v1 or mock), omitted for epoch stake
     // #[account(constraint = snapshot_program.key() == gov_v1::ID @ GovernanceError::InvalidSnapshotProgram)]
-    pub snapshot_program: UncheckedAccount<'info>,
-    /// CHECK: Consensus result account owned by snapshot program
-    pub consensus_result: UncheckedAccount<'info>,
-    /// CHECK: Meta merkle proof account owned by snapshot program
-    pub meta_merkle_proof: UncheckedAccount<'info>,
+    pub snapshot_program: Option<UncheckedAccount<'info>>,
+    /// CHECK: Consensus result account owned by snapshot program, omitted for epoch stake
+    pub consensus_result: Option<UncheckedAccount<'info>>,
+    /// CHECK: Meta merkle proof account owned by snapshot program, omitted for epoch stake
+    pub meta_merkle_proof: Option<UncheckedAccount<'info>>,
 
     pub system_program: Program<'info, System>,
 }
@@ -74,72 +77,45 @@ impl<'info> ModifyVoteOverride<'info> {
         bumps: &ModifyVoteOverrideBumps,
     ) -> Result<()> {
         // Check that the proposal is open for voting
-        require!(!self.proposal.finalized, GovernanceError::ProposalFinalized);
+        let proposal_key = self.proposal.key();
+        let mut proposal = self.proposal.load_mut()?;
+        require!(!proposal.is_finalized(), GovernanceError::ProposalFinalized);
+        // Secret ballot votes only change through their reveal
+        require!(
+            !proposal.is_secret_ballot(),
+            GovernanceError::SecretBallotProposal
+        );
 
         // Get the current epoch from the Clock sysvar
         let clock = Clock::get()?;
         let current_epoch = clock.epoch;
         require!(
-            self.proposal.start_epoch <= current_epoch,
+            proposal.start_epoch <= current_epoch,
             GovernanceError::VotingNotStarted
         );
         require!(
-            current_epoch < self.proposal.end_epoch,
+            current_epoch < proposal.end_epoch,
             GovernanceError::ProposalClosed
         );
 
         // Validate that the basis points sum to 10,000 (100%)
-        let total_bp = for_votes_bp
-            .checked_add(against_votes_bp)
-            .and_then(|sum| sum.checked_add(abstain_votes_bp))
-            .ok_or(GovernanceError::ArithmeticOverflow)?;
-        require!(
-            total_bp == BASIS_POINTS_MAX,
-            GovernanceError::InvalidVoteDistribution
-        );
-
-        // Validate snapshot program ownership
-        require!(
-            self.consensus_result.owner == self.snapshot_program.key,
-            GovernanceError::MustBeOwnedBySnapshotProgram
-        );
-        require!(
-            self.meta_merkle_proof.owner == self.snapshot_program.key,
-            GovernanceError::MustBeOwnedBySnapshotProgram
-        );
-        let consensus_result_data = self.consensus_result.try_borrow_data()?;
-        let consensus_result = ConsensusResult::try_deserialize(&mut &consensus_result_data[..])?;
+        tally::validate_distribution(for_votes_bp, against_votes_bp, abstain_votes_bp)?;
 
-        require_keys_eq!(
-            self.proposal.consensus_result.unwrap(),
-            self.consensus_result.key(),
-            GovernanceError::InvalidConsensusResultPDA
-        );
-        require!(
-            consensus_result
-                .ballot
-                .meta_merkle_root
-                .iter()
-                .any(|&x| x != 0),
-            GovernanceError::InvalidMerkleRoot
-        );
-        // Deserialize MetaMerkleProof for crosschecking
-        let meta_account_data = self.meta_merkle_proof.try_borrow_data()?;
-        let meta_merkle_proof = MetaMerkleProof::try_deserialize(&mut &meta_account_data[..])?;
-
-        let meta_merkle_leaf = meta_merkle_proof.meta_merkle_leaf;
-
-        require_eq!(
-            meta_merkle_proof.consensus_result,
-            self.consensus_result.key(),
-            GovernanceError::InvalidConsensusResultPDA
-        );
-
-        require_eq!(
-            stake_merkle_leaf.voting_wallet,
-            self.signer.key(),
-            GovernanceError::StakeAccountOwnerMismatch
+        // Read the validator's stake from the proposal's stake source
+        let snapshot = SnapshotAccounts::new(
+            &self.snapshot_program,
+            &self.consensus_result,
+            &self.meta_merkle_proof,
         );
+        let validator_stake = ValidatorStake::load(&proposal, snapshot, &self.spl_vote_account)?;
+
+        // The staker signs, or the proxy it registered for the stake account
+        let proxy = StakeProxy::authorize_override(
+            &self.signer.key(),
+            &stake_merkle_leaf.stake_account,
+            &stake_merkle_leaf.voting_wallet,
+            self.stake_proxy.as_deref(),
+        )?;
 
         require_gt!(
             stake_merkle_leaf.active_stake,
@@ -154,12 +130,6 @@ impl<'info> ModifyVoteOverride<'info> {
             GovernanceError::InvalidStakeAccount
         );
 
-        require_eq!(
-            meta_merkle_leaf.vote_account,
-            self.spl_vote_account.key(),
-            GovernanceError::InvalidVoteAccount
-        );
-
         // Verify that the override account belongs to this delegator and stake account
         require_eq!(
             self.vote_override.stake_account,
@@ -167,118 +137,115 @@ impl<'info> ModifyVoteOverride<'info> {
             GovernanceError::InvalidStakeAccount
         );
 
-        verify_merkle_proof_cpi(
-            &self.meta_merkle_proof.to_account_info(),
-            &self.consensus_result.to_account_info(),
-            &self.snapshot_program.to_account_info(),
-            Some(stake_merkle_proof),
-            Some(stake_merkle_leaf.clone()),
-        )?;
+        let stake_merkle_leaf =
+            validator_stake.verify_delegator(stake_merkle_proof, stake_merkle_leaf)?;
+
+        let previous_standing = proposal.standing();
 
         // Use verified stake amounts
         let delegator_stake = stake_merkle_leaf.active_stake;
-        let validator_stake = meta_merkle_leaf.active_stake;
 
         // Store old values for event emission
         let old_for_votes_bp = self.vote_override.for_votes_bp;
         let old_against_votes_bp = self.vote_override.against_votes_bp;
         let old_abstain_votes_bp = self.vote_override.abstain_votes_bp;
-        let old_for_votes_lamports = self.vote_override.for_votes_lamports;
-        let old_against_votes_lamports = self.vote_override.against_votes_lamports;
-        let old_abstain_votes_lamports = self.vote_override.abstain_votes_lamports;
+        let old_lamports = self.vote_override.vote_lamports();
 
         // Calculate delegator's new vote lamports
-        let for_votes_lamports = calculate_vote_lamports!(delegator_stake, for_votes_bp)?;
-        let against_votes_lamports = calculate_vote_lamports!(delegator_stake, against_votes_bp)?;
-        let abstain_votes_lamports = calculate_vote_lamports!(delegator_stake, abstain_votes_bp)?;
+        let delegator_lamports = VoteLamports::from_stake(
+            delegator_stake,
+            for_votes_bp,
+            against_votes_bp,
+            abstain_votes_bp,
+        )?;
 
         // Update the override account with new values
         self.vote_override.for_votes_bp = for_votes_bp;
         self.vote_override.against_votes_bp = against_votes_bp;
         self.vote_override.abstain_votes_bp = abstain_votes_bp;
-        self.vote_override.for_votes_lamports = for_votes_lamports;
-        self.vote_override.against_votes_lamports = against_votes_lamports;
-        self.vote_override.abstain_votes_lamports = abstain_votes_lamports;
+        self.vote_override.set_vote_lamports(delegator_lamports);
+        self.vote_override.proxy = proxy;
         self.vote_override.vote_override_timestamp = clock.unix_timestamp;
 
+        // Emit vote override modified event
+        emit_event(
+            &self.event_authority,
+            bumps.event_authority,
+            VoteOverrideModified {
+                proposal_id: proposal_key,
+                delegator: stake_merkle_leaf.voting_wallet,
+                stake_account: stake_merkle_leaf.stake_account,
+                validator: validator_stake.vote_account,
+                old_for_votes_bp,
+                old_against_votes_bp,
+                old_abstain_votes_bp,
+                new_for_votes_bp: for_votes_bp,
+                new_against_votes_bp: against_votes_bp,
+                new_abstain_votes_bp: abstain_votes_bp,
+                for_votes_lamports: delegator_lamports.for_votes,
+                against_votes_lamports: delegator_lamports.against_votes,
+                abstain_votes_lamports: delegator_lamports.abstain_votes,
+                stake_amount: delegator_stake,
+                modification_timestamp: clock.unix_timestamp,
+            },
+        )?;
+
         if self.validator_vote.owner == &crate::ID
             && self.validator_vote.data_len() == (ANCHOR_DISCRIMINATOR + Vote::INIT_SPACE)
         {
-            // Subtract old delegator's vote from proposal totals
-            self.proposal.sub_vote_lamports(
-                old_for_votes_lamports,
-                old_against_votes_lamports,
-                old_abstain_votes_lamports,
-            )?;
-
-            // Add new delegator's vote to proposal totals
-            self.proposal.add_vote_lamports(
-                for_votes_lamports,
-                against_votes_lamports,
-                abstain_votes_lamports,
-            )?;
+            // Swap the delegator's old vote for the new one in the proposal totals
+            let totals =
+                tally::replace_votes(proposal.vote_lamports(), old_lamports, delegator_lamports)?;
+            proposal.set_vote_lamports(totals);
         } else {
             require!(
                 self.vote_override_cache.owner == &crate::ID,
                 GovernanceError::InvalidVoteAccount
             );
             // Update vote override cache if it exists
-            // Use try_deserialize to properly handle discriminator
-            let vote_override_cache_result: Result<VoteOverrideCache> =
-                anchor_lang::AccountDeserialize::try_deserialize(
-                    &mut self.vote_override_cache.data.borrow_mut().as_ref(),
-                );
-            if let Ok(mut vote_override_cache) = vote_override_cache_result {
+            if let Ok(mut vote_override_cache) =
+                VoteOverrideCache::load_mut_from(&self.vote_override_cache)
+            {
                 // Update cache by subtracting old values and adding new ones
-                vote_override_cache.for_votes_lamports = vote_override_cache
-                    .for_votes_lamports
-                    .checked_sub(old_for_votes_lamports)
-                    .and_then(|val| val.checked_add(for_votes_lamports))
-                    .ok_or(GovernanceError::ArithmeticOverflow)?;
-
-                vote_override_cache.against_votes_lamports = vote_override_cache
-                    .against_votes_lamports
-                    .checked_sub(old_against_votes_lamports)
-                    .and_then(|val| val.checked_add(against_votes_lamports))
-                    .ok_or(GovernanceError::ArithmeticOverflow)?;
-
-                vote_override_cache.abstain_votes_lamports = vote_override_cache
-                    .abstain_votes_lamports
-                    .checked_sub(old_abstain_votes_lamports)
-                    .and_then(|val| val.checked_add(abstain_votes_lamports))
-                    .ok_or(GovernanceError::ArithmeticOverflow)?;
-
-                // Serialize the updated cache back to the account data
-                let mut cache_data = self.vote_override_cache.data.borrow_mut();
-                anchor_lang::AccountSerialize::try_serialize(
-                    &vote_override_cache,
-                    &mut cache_data.as_mut(),
-                )
-                .map_err(|e| {
-                    msg!("Error serializing VoteOverrideCache: {}", e);
-                    GovernanceError::ArithmeticOverflow
-                })?;
+                let cached = tally::replace_votes(
+                    vote_override_cache.vote_lamports(),
+                    old_lamports,
+                    delegator_lamports,
+                )?;
+                vote_override_cache.set_vote_lamports(cached);
+                emit_event(
+                    &self.event_authority,
+                    bumps.event_authority,
+                    VoteOverrideCacheUpdated {
+                        proposal_id: proposal_key,
+                        validator: vote_override_cache.validator,
+                        vote_override_cache: self.vote_override_cache.key(),
+                        created: false,
+                        for_votes_lamports: vote_override_cache.for_votes_lamports,
+                        against_votes_lamports: vote_override_cache.against_votes_lamports,
+                        abstain_votes_lamports: vote_override_cache.abstain_votes_lamports,
+                        total_stake: vote_override_cache.total_stake,
+                    },
+                )?;
             }
         }
 
-        // Emit vote override modified event
-        emit!(VoteOverrideModified {
-            proposal_id: self.proposal.key(),
-            delegator: self.signer.key(),
-            stake_account: stake_merkle_leaf.stake_account,
-            validator: meta_merkle_leaf.vote_account,
-            old_for_votes_bp,
-            old_against_votes_bp,
-            old_abstain_votes_bp,
-            new_for_votes_bp: for_votes_bp,
-            new_against_votes_bp: against_votes_bp,
-            new_abstain_votes_bp: abstain_votes_bp,
-            for_votes_lamports,
-            against_votes_lamports,
-            abstain_votes_lamports,
-            stake_amount: delegator_stake,
-            modification_timestamp: clock.unix_timestamp,
-        });
+        // Extend voting when this vote flipped the result close to the deadline
+        if let Some(new_end_epoch) =
+            proposal.extend_voting_if_flipped(previous_standing, clock.slot)?
+        {
+            emit_event(
+                &self.event_authority,
+                bumps.event_authority,
+                VotingPeriodExtended {
+                    proposal_id: proposal_key,
+                    voter: self.signer.key(),
+                    new_end_epoch,
+                    voting_extensions: proposal.voting_extensions,
+                    extension_timestamp: clock.unix_timestamp,
+                },
+            )?;
+        }
 
         Ok(())
     }
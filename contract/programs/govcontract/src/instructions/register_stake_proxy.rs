@@ -0,0 +1,68 @@
+use anchor_lang::{prelude::*, solana_program::stake::program as stake_program};
+
+use crate::{
+    constants::*,
+    error::GovernanceError,
+    events::{emit_event, StakeProxyRegistered},
+    state::StakeProxy,
+    utils::stake_withdrawer,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RegisterStakeProxy<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>, // Stake account withdraw authority
+    /// CHECK: stake account whose overrides the proxy may cast, withdrawer checked in the handler
+    #[account(
+        constraint = spl_stake_account.owner == &stake_program::ID @ ProgramError::InvalidAccountOwner,
+    )]
+    pub spl_stake_account: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = ANCHOR_DISCRIMINATOR + StakeProxy::INIT_SPACE,
+        seeds = [b"stake_proxy", spl_stake_account.key().as_ref()],
+        bump
+    )]
+    pub stake_proxy: Account<'info, StakeProxy>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RegisterStakeProxy<'info> {
+    /// Lets `proxy` cast and modify vote overrides for the stake account. Registering again
+    /// replaces the proxy.
+    pub fn register_stake_proxy(
+        &mut self,
+        proxy: Pubkey,
+        bumps: &RegisterStakeProxyBumps,
+    ) -> Result<()> {
+        require_keys_eq!(
+            stake_withdrawer(&self.spl_stake_account)?,
+            self.signer.key(),
+            GovernanceError::StakeAccountOwnerMismatch
+        );
+
+        let clock = Clock::get()?;
+        self.stake_proxy.set_inner(StakeProxy {
+            stake_account: self.spl_stake_account.key(),
+            authority: self.signer.key(),
+            proxy,
+            registered_timestamp: clock.unix_timestamp,
+            bump: bumps.stake_proxy,
+        });
+
+        emit_event(
+            &self.event_authority,
+            bumps.event_authority,
+            StakeProxyRegistered {
+                stake_account: self.spl_stake_account.key(),
+                authority: self.signer.key(),
+                proxy,
+                registered_timestamp: clock.unix_timestamp,
+            },
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,66 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::vote::{program as vote_program, state::VoteState},
+};
+
+use crate::{
+    error::GovernanceError,
+    events::{emit_event, GovernanceDelegationRemoved},
+    state::GovernanceDelegation,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(proposal: Option<Pubkey>)]
+pub struct RemoveGovernanceDelegation<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>, // Delegating validator identity
+    /// CHECK: Vote account is too big to deserialize, so we check on owner and size, then compare node_pubkey with signer
+    #[account(
+        constraint = spl_vote_account.owner == &vote_program::ID @ ProgramError::InvalidAccountOwner,
+        constraint = spl_vote_account.data_len() == VoteState::size_of() @ GovernanceError::InvalidVoteAccountSize
+    )]
+    pub spl_vote_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = signer,
+        seeds = [
+            b"delegation",
+            spl_vote_account.key().as_ref(),
+            proposal.unwrap_or_default().as_ref()
+        ],
+        bump = delegation.bump
+    )]
+    pub delegation: Account<'info, GovernanceDelegation>,
+}
+
+impl<'info> RemoveGovernanceDelegation<'info> {
+    /// Closes the delegation. Delegated votes already cast stay with the delegate's split
+    /// until the validator votes directly with `modify_vote`.
+    pub fn remove_governance_delegation(&mut self, event_authority_bump: u8) -> Result<()> {
+        let vote_account_data = self.spl_vote_account.data.borrow();
+        let vote_account = match VoteState::deserialize(&vote_account_data) {
+            Ok(vote_account) => vote_account,
+            Err(_) => return Err(GovernanceError::InvalidVoteAccount.into()),
+        };
+
+        // Ensuring signer is the same as the vote account node_pubkey
+        require_keys_eq!(
+            vote_account.node_pubkey,
+            self.signer.key(),
+            GovernanceError::InvalidVoteAccount
+        );
+
+        emit_event(
+            &self.event_authority,
+            event_authority_bump,
+            GovernanceDelegationRemoved {
+                delegator: self.delegation.delegator,
+                delegate: self.delegation.delegate,
+                proposal: self.delegation.proposal,
+            },
+        )?;
+
+        Ok(())
+    }
+}
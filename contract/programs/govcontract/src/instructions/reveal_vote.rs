@@ -0,0 +1,183 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    error::GovernanceError,
+    events::{emit_event, BallotRevealed},
+    state::{Ballot, Proposal, Vote, VoteOverride},
+    tally::{self, VoteLamports},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RevealVote<'info> {
+    pub signer: Signer<'info>, // Anyone holding the split and salt can reveal
+    #[account(mut)]
+    pub proposal: AccountLoader<'info, Proposal>,
+    #[account(
+        mut,
+        constraint = vote.proposal == proposal.key() @ GovernanceError::InvalidVoteAccount,
+    )]
+    pub vote: Account<'info, Vote>,
+    #[account(
+        mut,
+        seeds = [b"ballot", vote.key().as_ref()],
+        bump = ballot.bump
+    )]
+    pub ballot: Account<'info, Ballot>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RevealVoteOverride<'info> {
+    pub signer: Signer<'info>, // Anyone holding the split and salt can reveal
+    #[account(mut)]
+    pub proposal: AccountLoader<'info, Proposal>,
+    #[account(
+        mut,
+        constraint = vote_override.proposal == proposal.key() @ GovernanceError::InvalidVoteOverrideAccount,
+    )]
+    pub vote_override: Account<'info, VoteOverride>,
+    /// CHECK: Validator vote the override belongs to. Might not exist if the validator never voted
+    #[account(address = vote_override.vote_account_validator @ GovernanceError::InvalidVoteAccount)]
+    pub validator_vote: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"ballot", vote_override.key().as_ref()],
+        bump = ballot.bump
+    )]
+    pub ballot: Account<'info, Ballot>,
+}
+
+/// Reveals are accepted from the end of voting until the reveal window closes
+fn require_reveal_period(proposal: &Proposal, epoch: u64) -> Result<()> {
+    require!(
+        proposal.is_secret_ballot(),
+        GovernanceError::NotSecretBallot
+    );
+    require!(!proposal.is_finalized(), GovernanceError::ProposalFinalized);
+    require!(
+        proposal.end_epoch <= epoch && epoch < proposal.reveal_end_epoch()?,
+        GovernanceError::NotInRevealPeriod
+    );
+    Ok(())
+}
+
+impl<'info> RevealVote<'info> {
+    /// Replaces a validator's placeholder vote with the committed split
+    pub fn reveal_vote(
+        &mut self,
+        for_votes_bp: u64,
+        against_votes_bp: u64,
+        abstain_votes_bp: u64,
+        salt: [u8; 32],
+        event_authority_bump: u8,
+    ) -> Result<()> {
+        let proposal_key = self.proposal.key();
+        let mut proposal = self.proposal.load_mut()?;
+        let clock = Clock::get()?;
+        require_reveal_period(&proposal, clock.epoch)?;
+        tally::validate_distribution(for_votes_bp, against_votes_bp, abstain_votes_bp)?;
+        self.ballot
+            .reveal(for_votes_bp, against_votes_bp, abstain_votes_bp, &salt)?;
+
+        // Recompute the validator's votes over the stake its delegators have not overridden
+        let tally = tally::modify_vote(
+            proposal.vote_lamports(),
+            self.vote.vote_lamports(),
+            self.vote.stake,
+            self.vote.override_lamports,
+            for_votes_bp,
+            against_votes_bp,
+            abstain_votes_bp,
+        )?;
+        proposal.set_vote_lamports(tally.totals);
+
+        self.vote.for_votes_bp = for_votes_bp;
+        self.vote.against_votes_bp = against_votes_bp;
+        self.vote.abstain_votes_bp = abstain_votes_bp;
+        self.vote.set_vote_lamports(tally.validator);
+
+        emit_event(
+            &self.event_authority,
+            event_authority_bump,
+            BallotRevealed {
+                proposal_id: proposal_key,
+                vote_record: self.vote.key(),
+                revealer: self.signer.key(),
+                for_votes_bp,
+                against_votes_bp,
+                abstain_votes_bp,
+                for_votes_lamports: tally.validator.for_votes,
+                against_votes_lamports: tally.validator.against_votes,
+                abstain_votes_lamports: tally.validator.abstain_votes,
+                reveal_timestamp: clock.unix_timestamp,
+            },
+        )?;
+
+        Ok(())
+    }
+}
+
+impl<'info> RevealVoteOverride<'info> {
+    /// Replaces a staker's placeholder override with the committed split
+    pub fn reveal_vote_override(
+        &mut self,
+        for_votes_bp: u64,
+        against_votes_bp: u64,
+        abstain_votes_bp: u64,
+        salt: [u8; 32],
+        event_authority_bump: u8,
+    ) -> Result<()> {
+        let proposal_key = self.proposal.key();
+        let mut proposal = self.proposal.load_mut()?;
+        let clock = Clock::get()?;
+        require_reveal_period(&proposal, clock.epoch)?;
+        // Overrides only enter the totals when their validator votes. Reveals come after
+        // voting, so an override still cached for a validator that never voted is not counted.
+        require!(
+            self.validator_vote.owner == &crate::ID
+                && self.validator_vote.data_len() == (ANCHOR_DISCRIMINATOR + Vote::INIT_SPACE),
+            GovernanceError::OverrideNotCounted
+        );
+        tally::validate_distribution(for_votes_bp, against_votes_bp, abstain_votes_bp)?;
+        self.ballot
+            .reveal(for_votes_bp, against_votes_bp, abstain_votes_bp, &salt)?;
+
+        let old_lamports = self.vote_override.vote_lamports();
+        let delegator_lamports = VoteLamports::from_stake(
+            self.vote_override.stake_amount,
+            for_votes_bp,
+            against_votes_bp,
+            abstain_votes_bp,
+        )?;
+
+        self.vote_override.for_votes_bp = for_votes_bp;
+        self.vote_override.against_votes_bp = against_votes_bp;
+        self.vote_override.abstain_votes_bp = abstain_votes_bp;
+        self.vote_override.set_vote_lamports(delegator_lamports);
+
+        emit_event(
+            &self.event_authority,
+            event_authority_bump,
+            BallotRevealed {
+                proposal_id: proposal_key,
+                vote_record: self.vote_override.key(),
+                revealer: self.signer.key(),
+                for_votes_bp,
+                against_votes_bp,
+                abstain_votes_bp,
+                for_votes_lamports: delegator_lamports.for_votes,
+                against_votes_lamports: delegator_lamports.against_votes,
+                abstain_votes_lamports: delegator_lamports.abstain_votes,
+                reveal_timestamp: clock.unix_timestamp,
+            },
+        )?;
+
+        let totals =
+            tally::replace_votes(proposal.vote_lamports(), old_lamports, delegator_lamports)?;
+        proposal.set_vote_lamports(totals);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,51 @@
+use anchor_lang::{prelude::*, solana_program::stake::program as stake_program};
+
+use crate::{
+    error::GovernanceError,
+    events::{emit_event, StakeProxyRevoked},
+    state::StakeProxy,
+    utils::stake_withdrawer,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RevokeStakeProxy<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>, // Stake account withdraw authority
+    /// CHECK: stake account whose proxy is revoked, withdrawer checked in the handler
+    #[account(
+        constraint = spl_stake_account.owner == &stake_program::ID @ ProgramError::InvalidAccountOwner,
+    )]
+    pub spl_stake_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = signer,
+        seeds = [b"stake_proxy", spl_stake_account.key().as_ref()],
+        bump = stake_proxy.bump
+    )]
+    pub stake_proxy: Account<'info, StakeProxy>,
+}
+
+impl<'info> RevokeStakeProxy<'info> {
+    /// Closes the registration. Overrides the proxy already cast stay in place.
+    pub fn revoke_stake_proxy(&mut self, event_authority_bump: u8) -> Result<()> {
+        require_keys_eq!(
+            stake_withdrawer(&self.spl_stake_account)?,
+            self.signer.key(),
+            GovernanceError::StakeAccountOwnerMismatch
+        );
+
+        emit_event(
+            &self.event_authority,
+            event_authority_bump,
+            StakeProxyRevoked {
+                stake_account: self.spl_stake_account.key(),
+                authority: self.signer.key(),
+                proxy: self.stake_proxy.proxy,
+                revoked_timestamp: Clock::get()?.unix_timestamp,
+            },
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::vote::{program as vote_program, state::VoteState},
+};
+
+use crate::{
+    constants::*,
+    error::GovernanceError,
+    events::{emit_event, GovernanceDelegationSet},
+    state::GovernanceDelegation,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(delegate: Pubkey, proposal: Option<Pubkey>)]
+pub struct SetGovernanceDelegation<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>, // Delegating validator identity
+    /// CHECK: Vote account is too big to deserialize, so we check on owner and size, then compare node_pubkey with signer
+    #[account(
+        constraint = spl_vote_account.owner == &vote_program::ID @ ProgramError::InvalidAccountOwner,
+        constraint = spl_vote_account.data_len() == VoteState::size_of() @ GovernanceError::InvalidVoteAccountSize
+    )]
+    pub spl_vote_account: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = ANCHOR_DISCRIMINATOR + GovernanceDelegation::INIT_SPACE,
+        seeds = [
+            b"delegation",
+            spl_vote_account.key().as_ref(),
+            proposal.unwrap_or_default().as_ref()
+        ],
+        bump
+    )]
+    pub delegation: Account<'info, GovernanceDelegation>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetGovernanceDelegation<'info> {
+    /// Delegates the vote account's governance vote to `delegate`, for one proposal or, when
+    /// `proposal` is `None`, for every proposal. Setting it again changes the delegate.
+    pub fn set_governance_delegation(
+        &mut self,
+        delegate: Pubkey,
+        proposal: Option<Pubkey>,
+        bumps: &SetGovernanceDelegationBumps,
+    ) -> Result<()> {
+        let vote_account_data = self.spl_vote_account.data.borrow();
+        let vote_account = match VoteState::deserialize(&vote_account_data) {
+            Ok(vote_account) => vote_account,
+            Err(_) => return Err(GovernanceError::InvalidVoteAccount.into()),
+        };
+
+        // Ensuring signer is the same as the vote account node_pubkey
+        require_keys_eq!(
+            vote_account.node_pubkey,
+            self.signer.key(),
+            GovernanceError::InvalidVoteAccount
+        );
+        require_keys_neq!(
+            delegate,
+            self.spl_vote_account.key(),
+            GovernanceError::SelfDelegation
+        );
+
+        self.delegation.set_inner(GovernanceDelegation {
+            delegator: self.spl_vote_account.key(),
+            delegate,
+            proposal: proposal.unwrap_or_default(),
+            bump: bumps.delegation,
+        });
+
+        emit_event(
+            &self.event_authority,
+            bumps.event_authority,
+            GovernanceDelegationSet {
+                delegator: self.delegation.delegator,
+                delegate,
+                proposal: self.delegation.proposal,
+            },
+        )?;
+
+        Ok(())
+    }
+}
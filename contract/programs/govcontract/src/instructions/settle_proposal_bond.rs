@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::GovernanceError,
+    events::{emit_event, ProposalBondSettled},
+    state::{BondStatus, Proposal, ProposalBond, ValidatorGovernanceProfile},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SettleProposalBond<'info> {
+    pub signer: Signer<'info>, // Anyone can settle once the bond's outcome is known
+    pub proposal: AccountLoader<'info, Proposal>,
+    #[account(
+        mut,
+        seeds = [b"bond", proposal.key().as_ref()],
+        bump = proposal_bond.bump,
+        has_one = author @ GovernanceError::Unauthorized,
+    )]
+    pub proposal_bond: Account<'info, ProposalBond>,
+    /// CHECK: Proposal author receiving a returned bond, checked against the bond account
+    #[account(mut)]
+    pub author: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump
+    )]
+    pub treasury: SystemAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"validator_profile", proposal.load()?.vote_account_pubkey.as_ref()],
+        bump = author_profile.bump,
+    )]
+    pub author_profile: Account<'info, ValidatorGovernanceProfile>,
+}
+
+impl<'info> SettleProposalBond<'info> {
+    pub fn settle_proposal_bond(&mut self, event_authority_bump: u8) -> Result<()> {
+        require!(
+            self.proposal_bond.status == BondStatus::Locked,
+            GovernanceError::BondAlreadySettled
+        );
+
+        let clock = Clock::get()?;
+        let status = ProposalBond::settlement(&*self.proposal.load()?, clock.epoch)
+            .ok_or(GovernanceError::BondNotSettleable)?;
+
+        let amount = self.proposal_bond.amount;
+        let returned = status == BondStatus::Returned;
+        let recipient = if returned {
+            self.author.to_account_info()
+        } else {
+            self.treasury.to_account_info()
+        };
+        self.proposal_bond.sub_lamports(amount)?;
+        recipient.add_lamports(amount)?;
+        self.proposal_bond.status = status;
+        // A forfeited bond means the proposal expired without reaching voting
+        if !returned {
+            self.author_profile.record_proposal_closed();
+        }
+
+        emit_event(
+            &self.event_authority,
+            event_authority_bump,
+            ProposalBondSettled {
+                proposal_id: self.proposal.key(),
+                author: self.author.key(),
+                amount,
+                returned,
+                settlement_timestamp: clock.unix_timestamp,
+            },
+        )?;
+
+        Ok(())
+    }
+}
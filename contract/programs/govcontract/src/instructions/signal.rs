@@ -0,0 +1,122 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        epoch_stake::get_epoch_stake_for_vote_account,
+        vote::{program as vote_program, state::VoteState},
+    },
+};
+
+use crate::{
+    constants::*,
+    error::GovernanceError,
+    events::{emit_event, ProposalSignalled},
+    state::{Proposal, Signal, SignalLean, SignalTally},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SignalProposal<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>, // Validator identity
+    pub proposal: AccountLoader<'info, Proposal>,
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = ANCHOR_DISCRIMINATOR + Signal::INIT_SPACE,
+        seeds = [b"signal", proposal.key().as_ref(), spl_vote_account.key().as_ref()],
+        bump
+    )]
+    pub signal: Account<'info, Signal>,
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = ANCHOR_DISCRIMINATOR + SignalTally::INIT_SPACE,
+        seeds = [b"signal_tally", proposal.key().as_ref()],
+        bump
+    )]
+    pub signal_tally: Account<'info, SignalTally>,
+    /// CHECK: Vote account is too big to deserialize, so we check on owner and size, then compare node_pubkey with signer
+    #[account(
+        constraint = spl_vote_account.owner == &vote_program::ID @ ProgramError::InvalidAccountOwner,
+        constraint = spl_vote_account.data_len() == VoteState::size_of() @ GovernanceError::InvalidVoteAccountSize
+    )]
+    pub spl_vote_account: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SignalProposal<'info> {
+    /// Records a non-binding, stake-weighted lean during the discussion phase. Signals are
+    /// tallied in `SignalTally` and never count towards the proposal's outcome.
+    pub fn signal(&mut self, lean: SignalLean, bumps: &SignalProposalBumps) -> Result<()> {
+        let clock = Clock::get()?;
+        {
+            let proposal = self.proposal.load()?;
+            require!(
+                proposal.is_voting()
+                    && !proposal.is_finalized()
+                    && proposal.is_before_snapshot(clock.slot),
+                GovernanceError::NotInDiscussionPeriod
+            );
+        }
+
+        let vote_account_data = self.spl_vote_account.data.borrow();
+        let vote_account = match VoteState::deserialize(&vote_account_data) {
+            Ok(vote_account) => vote_account,
+            Err(_) => return Err(GovernanceError::InvalidVoteAccount.into()),
+        };
+
+        // Ensuring signer is the same as the vote account node_pubkey
+        require_keys_eq!(
+            vote_account.node_pubkey,
+            self.signer.key(),
+            GovernanceError::InvalidVoteAccount
+        );
+
+        let stake = get_epoch_stake_for_vote_account(self.spl_vote_account.key);
+        require_gt!(stake, 0u64, GovernanceError::NotEnoughStake);
+
+        let tally = &mut self.signal_tally;
+        if tally.proposal == Pubkey::default() {
+            tally.proposal = self.proposal.key();
+            tally.bump = bumps.signal_tally;
+        }
+
+        // A repeat signal replaces the previous one, weighted by the current epoch stake
+        let signal = &mut self.signal;
+        if signal.proposal == Pubkey::default() {
+            tally.signal_count = tally
+                .signal_count
+                .checked_add(1)
+                .ok_or(GovernanceError::ArithmeticOverflow)?;
+        } else {
+            tally.remove(signal.lean, signal.stake)?;
+        }
+        tally.add(lean, stake)?;
+
+        signal.set_inner(Signal {
+            proposal: self.proposal.key(),
+            vote_account: self.spl_vote_account.key(),
+            lean,
+            stake,
+            signal_epoch: clock.epoch,
+            bump: bumps.signal,
+        });
+
+        emit_event(
+            &self.event_authority,
+            bumps.event_authority,
+            ProposalSignalled {
+                proposal_id: self.proposal.key(),
+                vote_account: self.spl_vote_account.key(),
+                validator: self.signer.key(),
+                lean,
+                stake,
+                for_lamports: tally.for_lamports,
+                against_lamports: tally.against_lamports,
+                undecided_lamports: tally.undecided_lamports,
+            },
+        )?;
+
+        Ok(())
+    }
+}
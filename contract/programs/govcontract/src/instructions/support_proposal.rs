@@ -9,35 +9,46 @@ use anchor_lang::{
 use crate::{
     constants::*,
     error::GovernanceError,
-    events::ProposalSupported,
-    state::{Proposal, Support},
-    utils::get_epoch_slot_range,
+    events::{emit_event, ProposalSupported},
+    state::{Proposal, StakeSource, Support, ValidatorGovernanceProfile},
+    utils::vote_account_identity,
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct SupportProposal<'info> {
     #[account(mut)]
     pub signer: Signer<'info>, // Proposal supporter (validator)
     #[account(mut)]
-    pub proposal: Account<'info, Proposal>,
+    pub proposal: AccountLoader<'info, Proposal>,
     #[account(
-        init,
+        init_if_needed,
         payer = signer,
         space = ANCHOR_DISCRIMINATOR + Support::INIT_SPACE,
         seeds = [b"support", proposal.key().as_ref(), spl_vote_account.key().as_ref()],
         bump
     )]
-    pub support: Account<'info, Support>, // New support account
-    /// CHECK: Vote account is too big to deserialize, so we check on owner and size, then compare node_pubkey with signer
+    pub support: Account<'info, Support>, // New support account, or one to re-measure
+    /// CHECK: Vote account is too big to deserialize, so we check on owner and size, then compare its identity with signer
     #[account(
         constraint = spl_vote_account.owner == &vote_program::ID @ ProgramError::InvalidAccountOwner,
         constraint = spl_vote_account.data_len() == VoteState::size_of() @ GovernanceError::InvalidVoteAccountSize
     )]
     pub spl_vote_account: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = ANCHOR_DISCRIMINATOR + ValidatorGovernanceProfile::INIT_SPACE,
+        seeds = [b"validator_profile", spl_vote_account.key().as_ref()],
+        bump
+    )]
+    pub validator_profile: Account<'info, ValidatorGovernanceProfile>,
 
-    /// CHECK: Ballot box account - may or may not exist, checked with data_is_empty()
+    /// CHECK: Ballot box account - may or may not exist, checked with data_is_empty().
+    /// Omitted for a random snapshot, whose slot is only known once drawn; `init_ballot_box`
+    /// creates it afterwards. Also omitted for epoch stake, which needs no snapshot.
     #[account(mut)]
-    pub ballot_box: UncheckedAccount<'info>,
+    pub ballot_box: Option<UncheckedAccount<'info>>,
 
     /// CHECK: Ballot program account
     #[account(
@@ -45,114 +56,154 @@ pub struct SupportProposal<'info> {
     )]
     pub ballot_program: UncheckedAccount<'info>,
 
-    /// CHECK: Program config account
+    /// CHECK: Program config account, omitted for epoch stake on clusters without gov-v1
     #[account(
         seeds = [b"ProgramConfig"],
         bump,
         seeds::program = ballot_program.key(),
         constraint = program_config.owner == &gov_v1::ID @ ProgramError::InvalidAccountOwner,
     )]
-    pub program_config: UncheckedAccount<'info>,
+    pub program_config: Option<UncheckedAccount<'info>>,
 
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> SupportProposal<'info> {
     pub fn support_proposal(&mut self, bumps: &SupportProposalBumps) -> Result<()> {
+        // Only the validator can support, or re-measure support, with its vote account
+        require_keys_eq!(
+            vote_account_identity(&self.spl_vote_account)?,
+            self.signer.key(),
+            GovernanceError::InvalidVoteAccount
+        );
+
         let clock = Clock::get()?;
+        let mut proposal = self.proposal.load_mut()?;
 
         // Ensure proposal is eligible for support
         require!(
-            self.proposal.voting == false && self.proposal.finalized == false,
+            !proposal.is_voting() && !proposal.is_finalized(),
             GovernanceError::ProposalClosed
         );
 
         require!(
-            clock.epoch == self.proposal.creation_epoch + MAX_SUPPORT_EPOCHS,
+            proposal.is_in_support_period(clock.epoch),
             GovernanceError::NotInSupportPeriod
         );
 
-        // assuming this returns in lamports
+        // Support counts the supporter's stake in the epoch it is given. Supporting again in
+        // a later epoch of the window re-measures it, so a stake change is only reflected
+        // once the vote account supports again.
         let supporter_stake = get_epoch_stake_for_vote_account(self.spl_vote_account.key);
 
-        // Add the supporter's stake (from verified leaf) to the proposal's cluster support
-        self.proposal.add_cluster_support(supporter_stake)?;
-
-        // Initialize the support account
-        self.support.set_inner(Support {
-            proposal: self.proposal.key(),
-            validator: self.signer.key(),
-            bump: bumps.support,
-        });
+        self.validator_profile
+            .init_if_new(self.spl_vote_account.key(), bumps.validator_profile);
+        if self.support.proposal == Pubkey::default() {
+            proposal.add_cluster_support(supporter_stake)?;
+            self.support.set_inner(Support {
+                proposal: self.proposal.key(),
+                validator: self.signer.key(),
+                stake: supporter_stake,
+                epoch: clock.epoch,
+                bump: bumps.support,
+            });
+            self.validator_profile.record_support()?;
+        } else {
+            require!(
+                self.support.epoch < clock.epoch,
+                GovernanceError::SupportAlreadyMeasured
+            );
+            proposal.remeasure_cluster_support(self.support.stake, supporter_stake)?;
+            self.support.stake = supporter_stake;
+            self.support.epoch = clock.epoch;
+        }
 
+        // Thresholds and timings were fixed when the proposal was created. Activation is
+        // checked against the cluster stake of the current epoch on every support.
         let cluster_stake = get_epoch_total_stake();
-        let support_scaled =
-            (self.proposal.cluster_support_lamports as u128) * CLUSTER_SUPPORT_MULTIPLIER;
-        let cluster_scaled = (cluster_stake as u128) * CLUSTER_STAKE_MULTIPLIER;
-        self.proposal.voting = if support_scaled >= cluster_scaled {
-            let (start_slot, _) =
-                get_epoch_slot_range(clock.epoch + DISCUSSION_EPOCHS + SNAPSHOT_EPOCH_EXTENSION);
-            let snapshot_slot = start_slot + 1000;
+        let voting_activated = proposal.has_enough_support(cluster_stake);
+        if voting_activated {
+            let snapshot_epoch =
+                clock.epoch + proposal.discussion_epochs + SNAPSHOT_EPOCH_EXTENSION;
             // start voting 1 epoch after snapshot
             // checking in any vote or others is start_epoch <= current_epoch < end_epoch
-            self.proposal.start_epoch =
-                clock.epoch + DISCUSSION_EPOCHS + SNAPSHOT_EPOCH_EXTENSION + 1;
-            self.proposal.end_epoch =
-                clock.epoch + DISCUSSION_EPOCHS + SNAPSHOT_EPOCH_EXTENSION + 1 + VOTING_EPOCHS;
-            self.proposal.snapshot_slot = snapshot_slot; // 1000 slots into snapshot
-
-            let (consensus_result_pda, _) = Pubkey::find_program_address(
-                &[b"ConsensusResult", &snapshot_slot.to_le_bytes()],
-                &self.ballot_program.key,
-            );
-
-            self.proposal.consensus_result = Some(consensus_result_pda);
-
-            if self.ballot_box.data_is_empty() {
-                // Create seed components with sufficient lifetime
-                let proposal_seed_val = self.proposal.proposal_seed.to_le_bytes();
-                let vote_account_key = self.proposal.vote_account_pubkey.key();
-
-                let seeds: &[&[u8]] = &[
-                    b"proposal".as_ref(),
-                    &proposal_seed_val,
-                    vote_account_key.as_ref(),
-                    &[self.proposal.proposal_bump],
-                ];
-                let signer_seeds = &[&seeds[..]];
-
-                let cpi_ctx = CpiContext::new_with_signer(
-                    self.ballot_program.to_account_info(),
-                    gov_v1::cpi::accounts::InitBallotBox {
-                        payer: self.signer.to_account_info(),
-                        proposal: self.proposal.to_account_info(),
-                        ballot_box: self.ballot_box.to_account_info(),
-                        program_config: self.program_config.to_account_info(),
-                        system_program: self.system_program.to_account_info(),
-                    },
-                    signer_seeds,
+            proposal.start_epoch = snapshot_epoch + 1;
+            proposal.end_epoch = snapshot_epoch + 1 + proposal.voting_epochs;
+            proposal.cluster_stake_lamports = cluster_stake;
+
+            // A random snapshot slot is only drawn once the snapshot epoch has started, by
+            // `draw_snapshot_slot`, so it is not known during discussion
+            if !proposal.is_random_snapshot() {
+                proposal.set_snapshot_slot(
+                    Proposal::fixed_snapshot_slot(snapshot_epoch),
+                    self.ballot_program.key,
                 );
-                gov_v1::cpi::init_ballot_box(
-                    cpi_ctx,
-                    snapshot_slot,
-                    self.proposal.proposal_seed,
-                    self.proposal.vote_account_pubkey,
-                )?;
             }
-
-            true
+            proposal.voting = 1;
+        }
+
+        let cluster_support_lamports = proposal.cluster_support_lamports;
+        let snapshot_slot = proposal.snapshot_slot;
+        let proposal_seed = proposal.proposal_seed;
+        let vote_account_key = proposal.vote_account_pubkey;
+        let proposal_bump = proposal.proposal_bump;
+        let random_snapshot = proposal.is_random_snapshot();
+        let snapshot_stake = proposal.stake_source() == StakeSource::Snapshot;
+        // The proposal signs the ballot box CPI, so its data must not stay borrowed
+        drop(proposal);
+
+        // A random snapshot's ballot box is created by `init_ballot_box`
+        let ballot_box = if voting_activated && !random_snapshot && snapshot_stake {
+            let ballot_box = self
+                .ballot_box
+                .as_ref()
+                .ok_or(GovernanceError::BallotBoxRequired)?;
+            ballot_box.data_is_empty().then_some(ballot_box)
         } else {
-            false
+            None
         };
-
-        self.proposal.reload()?;
-        emit!(ProposalSupported {
-            proposal_id: self.proposal.key(),
-            supporter: self.signer.key(),
-            cluster_support_lamports: self.proposal.cluster_support_lamports,
-            voting_activated: self.proposal.voting,
-            snapshot_slot: self.proposal.snapshot_slot,
-        });
+        if let Some(ballot_box) = ballot_box {
+            let program_config = self
+                .program_config
+                .as_ref()
+                .ok_or(GovernanceError::SnapshotAccountsRequired)?;
+            // Create seed components with sufficient lifetime
+            let proposal_seed_val = proposal_seed.to_le_bytes();
+
+            let seeds: &[&[u8]] = &[
+                b"proposal".as_ref(),
+                &proposal_seed_val,
+                vote_account_key.as_ref(),
+                &[proposal_bump],
+            ];
+            let signer_seeds = &[&seeds[..]];
+
+            let cpi_ctx = CpiContext::new_with_signer(
+                self.ballot_program.to_account_info(),
+                gov_v1::cpi::accounts::InitBallotBox {
+                    payer: self.signer.to_account_info(),
+                    proposal: self.proposal.to_account_info(),
+                    ballot_box: ballot_box.to_account_info(),
+                    program_config: program_config.to_account_info(),
+                    system_program: self.system_program.to_account_info(),
+                },
+                signer_seeds,
+            );
+            gov_v1::cpi::init_ballot_box(cpi_ctx, snapshot_slot, proposal_seed, vote_account_key)?;
+        }
+
+        emit_event(
+            &self.event_authority,
+            bumps.event_authority,
+            ProposalSupported {
+                proposal_id: self.proposal.key(),
+                supporter: self.signer.key(),
+                support_lamports: supporter_stake,
+                cluster_support_lamports,
+                voting_activated,
+                snapshot_slot,
+            },
+        )?;
 
         Ok(())
     }
@@ -0,0 +1,96 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::vote::{program as vote_program, state::VoteState},
+};
+
+use crate::{
+    constants::*,
+    error::GovernanceError,
+    events::{emit_event, ValidatorProfileUpdated},
+    state::ValidatorGovernanceProfile,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateValidatorProfile<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>, // Validator identity
+    /// CHECK: Vote account is too big to deserialize, so we check on owner and size, then compare node_pubkey with signer
+    #[account(
+        constraint = spl_vote_account.owner == &vote_program::ID @ ProgramError::InvalidAccountOwner,
+        constraint = spl_vote_account.data_len() == VoteState::size_of() @ GovernanceError::InvalidVoteAccountSize
+    )]
+    pub spl_vote_account: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = ANCHOR_DISCRIMINATOR + ValidatorGovernanceProfile::INIT_SPACE,
+        seeds = [b"validator_profile", spl_vote_account.key().as_ref()],
+        bump
+    )]
+    pub validator_profile: Account<'info, ValidatorGovernanceProfile>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> UpdateValidatorProfile<'info> {
+    /// Sets the given metadata fields, leaving `None` fields unchanged. An empty string clears a field.
+    pub fn update_validator_profile(
+        &mut self,
+        display_name: Option<String>,
+        website: Option<String>,
+        governance_policy_url: Option<String>,
+        bumps: &UpdateValidatorProfileBumps,
+    ) -> Result<()> {
+        let vote_account_data = self.spl_vote_account.data.borrow();
+        let vote_account = match VoteState::deserialize(&vote_account_data) {
+            Ok(vote_account) => vote_account,
+            Err(_) => return Err(GovernanceError::InvalidVoteAccount.into()),
+        };
+
+        // Ensuring signer is the same as the vote account node_pubkey
+        require_keys_eq!(
+            vote_account.node_pubkey,
+            self.signer.key(),
+            GovernanceError::InvalidVoteAccount
+        );
+
+        let profile = &mut self.validator_profile;
+        profile.init_if_new(self.spl_vote_account.key(), bumps.validator_profile);
+
+        if let Some(display_name) = display_name {
+            require!(
+                display_name.len() <= MAX_DISPLAY_NAME_LENGTH,
+                GovernanceError::ProfileFieldTooLong
+            );
+            profile.display_name = display_name;
+        }
+        if let Some(website) = website {
+            require!(
+                website.len() <= MAX_PROFILE_URL_LENGTH,
+                GovernanceError::ProfileFieldTooLong
+            );
+            profile.website = website;
+        }
+        if let Some(governance_policy_url) = governance_policy_url {
+            require!(
+                governance_policy_url.len() <= MAX_PROFILE_URL_LENGTH,
+                GovernanceError::ProfileFieldTooLong
+            );
+            profile.governance_policy_url = governance_policy_url;
+        }
+
+        emit_event(
+            &self.event_authority,
+            bumps.event_authority,
+            ValidatorProfileUpdated {
+                vote_account: profile.vote_account,
+                validator: self.signer.key(),
+                display_name: profile.display_name.clone(),
+                website: profile.website.clone(),
+                governance_policy_url: profile.governance_policy_url.clone(),
+            },
+        )?;
+
+        Ok(())
+    }
+}
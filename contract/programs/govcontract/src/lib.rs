@@ -1,15 +1,21 @@
 #![allow(unexpected_cfgs, unused_variables, clippy::too_many_arguments)]
-mod constants;
-mod error;
+pub mod constants;
+pub mod error;
 mod events;
 mod instructions;
 mod merkle_helpers;
-mod state;
+mod stake_source;
+pub mod state;
+pub mod tally;
 mod utils;
 use anchor_lang::prelude::*;
 use instructions::*;
 
 use gov_v1::StakeMerkleLeaf;
+use state::{
+    AntiSnipingConfig, GovernanceParameter, ProposalClass, ProposalInstruction, SecretBallotConfig,
+    SignalLean, SignedVote, StakeSource,
+};
 
 declare_id!("6MX2RaV2vfTGv6c7zCmRAod2E6MdAgR6be2Vb3NsMxPW");
 
@@ -22,14 +28,33 @@ pub mod govcontract {
         Ok(())
     }
 
+    pub fn initialize_governance_config(ctx: Context<InitializeGovernanceConfig>) -> Result<()> {
+        ctx.accounts.initialize_governance_config(&ctx.bumps)?;
+        Ok(())
+    }
+
     pub fn create_proposal(
         ctx: Context<CreateProposal>,
         seed: u64,
         title: String,
         description: String,
+        anti_sniping: Option<AntiSnipingConfig>,
+        secret_ballot: Option<SecretBallotConfig>,
+        random_snapshot: bool,
+        class: ProposalClass,
+        stake_source: StakeSource,
     ) -> Result<()> {
-        ctx.accounts
-            .create_proposal(seed, title, description, &ctx.bumps)?;
+        ctx.accounts.create_proposal(
+            seed,
+            title,
+            description,
+            anti_sniping,
+            secret_ballot,
+            random_snapshot,
+            class,
+            stake_source,
+            &ctx.bumps,
+        )?;
         Ok(())
     }
 
@@ -38,6 +63,26 @@ pub mod govcontract {
         Ok(())
     }
 
+    pub fn signal(ctx: Context<SignalProposal>, lean: SignalLean) -> Result<()> {
+        ctx.accounts.signal(lean, &ctx.bumps)?;
+        Ok(())
+    }
+
+    pub fn update_validator_profile(
+        ctx: Context<UpdateValidatorProfile>,
+        display_name: Option<String>,
+        website: Option<String>,
+        governance_policy_url: Option<String>,
+    ) -> Result<()> {
+        ctx.accounts.update_validator_profile(
+            display_name,
+            website,
+            governance_policy_url,
+            &ctx.bumps,
+        )?;
+        Ok(())
+    }
+
     pub fn cast_vote(
         ctx: Context<CastVote>,
         for_votes_bp: u64,
@@ -49,14 +94,69 @@ pub mod govcontract {
         Ok(())
     }
 
+    pub fn cast_vote_signed(ctx: Context<CastVoteSigned>, signed_vote: SignedVote) -> Result<()> {
+        ctx.accounts.cast_vote_signed(signed_vote, &ctx.bumps)?;
+        Ok(())
+    }
+
+    pub fn commit_vote(ctx: Context<CommitVote>, commitment: [u8; 32]) -> Result<()> {
+        ctx.accounts.commit_vote(commitment, &ctx.bumps)?;
+        Ok(())
+    }
+
+    pub fn reveal_vote(
+        ctx: Context<RevealVote>,
+        for_votes_bp: u64,
+        against_votes_bp: u64,
+        abstain_votes_bp: u64,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        ctx.accounts.reveal_vote(
+            for_votes_bp,
+            against_votes_bp,
+            abstain_votes_bp,
+            salt,
+            ctx.bumps.event_authority,
+        )?;
+        Ok(())
+    }
+
+    pub fn set_governance_delegation(
+        ctx: Context<SetGovernanceDelegation>,
+        delegate: Pubkey,
+        proposal: Option<Pubkey>,
+    ) -> Result<()> {
+        ctx.accounts
+            .set_governance_delegation(delegate, proposal, &ctx.bumps)?;
+        Ok(())
+    }
+
+    pub fn remove_governance_delegation(
+        ctx: Context<RemoveGovernanceDelegation>,
+        proposal: Option<Pubkey>,
+    ) -> Result<()> {
+        ctx.accounts
+            .remove_governance_delegation(ctx.bumps.event_authority)?;
+        Ok(())
+    }
+
+    pub fn cast_delegated_vote(ctx: Context<CastDelegatedVote>) -> Result<()> {
+        ctx.accounts.cast_delegated_vote(&ctx.bumps)?;
+        Ok(())
+    }
+
     pub fn modify_vote(
         ctx: Context<ModifyVote>,
         for_votes_bp: u64,
         against_votes_bp: u64,
         abstain_votes_bp: u64,
     ) -> Result<()> {
-        ctx.accounts
-            .modify_vote(for_votes_bp, against_votes_bp, abstain_votes_bp)?;
+        ctx.accounts.modify_vote(
+            for_votes_bp,
+            against_votes_bp,
+            abstain_votes_bp,
+            ctx.bumps.event_authority,
+        )?;
         Ok(())
     }
 
@@ -79,6 +179,24 @@ pub mod govcontract {
         Ok(())
     }
 
+    pub fn cast_vote_override_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CastVoteOverrideBatch<'info>>,
+        for_votes_bp: u64,
+        against_votes_bp: u64,
+        abstain_votes_bp: u64,
+        entries: Vec<StakeOverrideEntry>,
+    ) -> Result<()> {
+        ctx.accounts.cast_vote_override_batch(
+            for_votes_bp,
+            against_votes_bp,
+            abstain_votes_bp,
+            entries,
+            ctx.remaining_accounts,
+            &ctx.bumps,
+        )?;
+        Ok(())
+    }
+
     pub fn modify_vote_override(
         ctx: Context<ModifyVoteOverride>,
         for_votes_bp: u64,
@@ -98,14 +216,134 @@ pub mod govcontract {
         Ok(())
     }
 
+    pub fn commit_vote_override(
+        ctx: Context<CommitVoteOverride>,
+        commitment: [u8; 32],
+    ) -> Result<()> {
+        ctx.accounts.commit_vote_override(commitment, &ctx.bumps)?;
+        Ok(())
+    }
+
+    pub fn reveal_vote_override(
+        ctx: Context<RevealVoteOverride>,
+        for_votes_bp: u64,
+        against_votes_bp: u64,
+        abstain_votes_bp: u64,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        ctx.accounts.reveal_vote_override(
+            for_votes_bp,
+            against_votes_bp,
+            abstain_votes_bp,
+            salt,
+            ctx.bumps.event_authority,
+        )?;
+        Ok(())
+    }
+
+    pub fn register_stake_proxy(ctx: Context<RegisterStakeProxy>, proxy: Pubkey) -> Result<()> {
+        ctx.accounts.register_stake_proxy(proxy, &ctx.bumps)?;
+        Ok(())
+    }
+
+    pub fn revoke_stake_proxy(ctx: Context<RevokeStakeProxy>) -> Result<()> {
+        ctx.accounts.revoke_stake_proxy(ctx.bumps.event_authority)?;
+        Ok(())
+    }
+
     pub fn finalize_proposal(ctx: Context<FinalizeProposal>) -> Result<()> {
-        ctx.accounts.finalize_proposal()?;
+        ctx.accounts.finalize_proposal(ctx.bumps.event_authority)?;
+
+        Ok(())
+    }
+
+    pub fn settle_proposal_bond(ctx: Context<SettleProposalBond>) -> Result<()> {
+        ctx.accounts
+            .settle_proposal_bond(ctx.bumps.event_authority)?;
+        Ok(())
+    }
 
+    pub fn cancel_proposal(ctx: Context<CancelProposal>) -> Result<()> {
+        ctx.accounts.cancel_proposal(ctx.bumps.event_authority)?;
         Ok(())
     }
 
     pub fn flush_merkle_root(ctx: Context<FlushMerkleRoot>) -> Result<()> {
-        ctx.accounts.flush_merkle_root()?;
+        ctx.accounts.flush_merkle_root(ctx.bumps.event_authority)?;
+        Ok(())
+    }
+
+    pub fn close_meta_merkle_proof(ctx: Context<CloseMetaMerkleProof>) -> Result<()> {
+        ctx.accounts
+            .close_meta_merkle_proof(ctx.bumps.event_authority)?;
+        Ok(())
+    }
+
+    pub fn draw_snapshot_slot(ctx: Context<DrawSnapshotSlot>) -> Result<()> {
+        ctx.accounts.draw_snapshot_slot(ctx.bumps.event_authority)?;
+        Ok(())
+    }
+
+    pub fn init_ballot_box(ctx: Context<InitBallotBox>) -> Result<()> {
+        ctx.accounts.init_ballot_box()?;
+        Ok(())
+    }
+
+    pub fn create_proposal_transaction(
+        ctx: Context<CreateProposalTransaction>,
+        instructions: Vec<ProposalInstruction>,
+        timelock_seconds: i64,
+    ) -> Result<()> {
+        ctx.accounts
+            .create_proposal_transaction(instructions, timelock_seconds, &ctx.bumps)?;
+        Ok(())
+    }
+
+    pub fn execute_proposal_transaction<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteProposalTransaction<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.execute_proposal_transaction(
+            ctx.remaining_accounts,
+            ctx.bumps.governance_authority,
+            ctx.bumps.event_authority,
+        )?;
+        Ok(())
+    }
+
+    pub fn create_treasury_transfer(
+        ctx: Context<CreateTreasuryTransfer>,
+        destination: Pubkey,
+        mint: Option<Pubkey>,
+        amount: u64,
+        timelock_seconds: i64,
+    ) -> Result<()> {
+        ctx.accounts.create_treasury_transfer(
+            destination,
+            mint,
+            amount,
+            timelock_seconds,
+            &ctx.bumps,
+        )?;
+        Ok(())
+    }
+
+    pub fn execute_treasury_transfer(ctx: Context<ExecuteTreasuryTransfer>) -> Result<()> {
+        ctx.accounts
+            .execute_treasury_transfer(ctx.bumps.treasury, ctx.bumps.event_authority)?;
+        Ok(())
+    }
+
+    pub fn create_parameter_change(
+        ctx: Context<CreateParameterChange>,
+        changes: Vec<GovernanceParameter>,
+    ) -> Result<()> {
+        ctx.accounts.create_parameter_change(changes, &ctx.bumps)?;
+        Ok(())
+    }
+
+    pub fn enact_parameter_change(ctx: Context<EnactParameterChange>) -> Result<()> {
+        ctx.accounts
+            .enact_parameter_change(ctx.bumps.event_authority)?;
         Ok(())
     }
 }
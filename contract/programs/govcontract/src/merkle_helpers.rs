@@ -1,7 +1,10 @@
 use anchor_lang::prelude::*;
 
 use gov_v1::{
-    cpi::{accounts::VerifyMerkleProof, verify_merkle_proof},
+    cpi::{
+        accounts::{CloseMetaMerkleProof, VerifyMerkleProof},
+        close_meta_merkle_proof, verify_merkle_proof,
+    },
     StakeMerkleLeaf,
 };
 
@@ -26,3 +29,22 @@ pub fn verify_merkle_proof_cpi<'info>(
 
     Ok(())
 }
+
+/// Closes a meta merkle proof account in the snapshot program, refunding its rent to the
+/// payer that created it
+pub fn close_meta_merkle_proof_cpi<'info>(
+    meta_merkle_proof_account: &AccountInfo<'info>,
+    payer_account: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    gov_v1_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let cpi_accounts = CloseMetaMerkleProof {
+        payer: payer_account.clone(),
+        meta_merkle_proof: meta_merkle_proof_account.clone(),
+        system_program: system_program.clone(),
+    };
+
+    let cpi_ctx = CpiContext::new(gov_v1_program.clone(), cpi_accounts);
+
+    close_meta_merkle_proof(cpi_ctx)
+}
@@ -0,0 +1,179 @@
+use anchor_lang::{prelude::*, solana_program::epoch_stake::get_epoch_stake_for_vote_account};
+
+use gov_v1::{ConsensusResult, MetaMerkleProof, StakeMerkleLeaf};
+
+use crate::{
+    error::GovernanceError,
+    merkle_helpers::verify_merkle_proof_cpi,
+    state::{Proposal, StakeSource},
+    utils::vote_account_identity,
+};
+
+/// Snapshot program accounts passed to the vote instructions, only used by proposals with
+/// `StakeSource::Snapshot`
+#[derive(Clone, Copy)]
+pub struct SnapshotAccounts<'a, 'info> {
+    pub snapshot_program: &'a AccountInfo<'info>,
+    pub consensus_result: &'a AccountInfo<'info>,
+    pub meta_merkle_proof: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> SnapshotAccounts<'a, 'info> {
+    /// `None` unless all three accounts were passed
+    pub fn new(
+        snapshot_program: &'a Option<UncheckedAccount<'info>>,
+        consensus_result: &'a Option<UncheckedAccount<'info>>,
+        meta_merkle_proof: &'a Option<UncheckedAccount<'info>>,
+    ) -> Option<Self> {
+        Some(Self {
+            snapshot_program: snapshot_program.as_deref()?,
+            consensus_result: consensus_result.as_deref()?,
+            meta_merkle_proof: meta_merkle_proof.as_deref()?,
+        })
+    }
+}
+
+/// Stake of a vote account on a proposal, read from the proposal's stake source
+pub struct ValidatorStake<'a, 'info> {
+    pub vote_account: Pubkey,
+    /// Wallet that votes for the vote account, its identity for epoch stake
+    pub voting_wallet: Pubkey,
+    pub active_stake: u64,
+    /// `None` for epoch stake
+    snapshot: Option<SnapshotAccounts<'a, 'info>>,
+    /// Whether the stake is the stake of the epoch before voting, see `counted`
+    measured_before_voting: bool,
+}
+
+impl<'a, 'info> ValidatorStake<'a, 'info> {
+    /// Reads the vote account's stake. On snapshot proposals the meta merkle proof is checked
+    /// against the proposal's consensus result, but only verified by `verify` or
+    /// `verify_delegator`.
+    pub fn load(
+        proposal: &Proposal,
+        snapshot: Option<SnapshotAccounts<'a, 'info>>,
+        spl_vote_account: &AccountInfo<'info>,
+    ) -> Result<Self> {
+        if proposal.stake_source() == StakeSource::EpochStake {
+            // The runtime reports the stake of the previous epoch
+            return Ok(Self {
+                vote_account: spl_vote_account.key(),
+                voting_wallet: vote_account_identity(spl_vote_account)?,
+                active_stake: get_epoch_stake_for_vote_account(spl_vote_account.key),
+                snapshot: None,
+                measured_before_voting: Clock::get()?.epoch == proposal.start_epoch,
+            });
+        }
+
+        let snapshot = snapshot.ok_or(GovernanceError::SnapshotAccountsRequired)?;
+        // Validate snapshot program ownership
+        require!(
+            snapshot.consensus_result.owner == snapshot.snapshot_program.key,
+            GovernanceError::MustBeOwnedBySnapshotProgram
+        );
+        require!(
+            snapshot.meta_merkle_proof.owner == snapshot.snapshot_program.key,
+            GovernanceError::MustBeOwnedBySnapshotProgram
+        );
+
+        let proposal_consensus_result = proposal
+            .consensus_result()
+            .ok_or(GovernanceError::ConsensusResultNotSet)?;
+        require_keys_eq!(
+            proposal_consensus_result,
+            snapshot.consensus_result.key(),
+            GovernanceError::InvalidConsensusResultPDA
+        );
+        let consensus_result_data = snapshot.consensus_result.try_borrow_data()?;
+        let consensus_result = ConsensusResult::try_deserialize(&mut &consensus_result_data[..])?;
+        require!(
+            consensus_result
+                .ballot
+                .meta_merkle_root
+                .iter()
+                .any(|&x| x != 0),
+            GovernanceError::InvalidMerkleRoot
+        );
+
+        // Deserialize MetaMerkleProof for crosschecking
+        let meta_account_data = snapshot.meta_merkle_proof.try_borrow_data()?;
+        let meta_merkle_proof = MetaMerkleProof::try_deserialize(&mut &meta_account_data[..])?;
+        let meta_merkle_leaf = meta_merkle_proof.meta_merkle_leaf;
+        require_eq!(
+            meta_merkle_proof.consensus_result,
+            snapshot.consensus_result.key(),
+            GovernanceError::InvalidConsensusResultPDA
+        );
+        // Ensure the proof's vote_account matches the provided SPL vote account
+        require_eq!(
+            meta_merkle_leaf.vote_account,
+            spl_vote_account.key(),
+            GovernanceError::InvalidVoteAccount
+        );
+
+        Ok(Self {
+            vote_account: meta_merkle_leaf.vote_account,
+            voting_wallet: meta_merkle_leaf.voting_wallet,
+            active_stake: meta_merkle_leaf.active_stake,
+            snapshot: Some(snapshot),
+            measured_before_voting: true,
+        })
+    }
+
+    /// Verifies the validator's own stake. Epoch stake was read from the runtime, so only
+    /// snapshot proofs need verifying.
+    pub fn verify(&self) -> Result<()> {
+        match self.snapshot {
+            Some(snapshot) => verify_merkle_proof_cpi(
+                snapshot.meta_merkle_proof,
+                snapshot.consensus_result,
+                snapshot.snapshot_program,
+                None,
+                None,
+            ),
+            None => Ok(()),
+        }
+    }
+
+    /// Stake to count a new vote with. Epoch stake is only the stake of the epoch before
+    /// voting during the first voting epoch. Read later, stake redelegated during voting would
+    /// count through both vote accounts, so new epoch stake votes are cast in the first voting
+    /// epoch and keep their recorded stake afterwards.
+    pub fn counted(&self) -> Result<u64> {
+        require!(
+            self.measured_before_voting,
+            GovernanceError::EpochStakeVotingStarted
+        );
+        require_gt!(self.active_stake, 0, GovernanceError::NotEnoughStake);
+        Ok(self.active_stake)
+    }
+
+    /// Verifies a delegator's stake leaf against the validator's meta merkle proof. Without
+    /// a snapshot, stake split off an account that already overrode cannot be told apart from
+    /// other stake delegated to the vote account, so epoch stake proposals have no overrides.
+    pub fn verify_delegator(
+        &self,
+        stake_merkle_proof: Vec<[u8; 32]>,
+        stake_merkle_leaf: StakeMerkleLeaf,
+    ) -> Result<StakeMerkleLeaf> {
+        let snapshot = self.snapshot.ok_or(GovernanceError::EpochStakeProposal)?;
+        verify_merkle_proof_cpi(
+            snapshot.meta_merkle_proof,
+            snapshot.consensus_result,
+            snapshot.snapshot_program,
+            Some(stake_merkle_proof),
+            Some(stake_merkle_leaf.clone()),
+        )?;
+        Ok(stake_merkle_leaf)
+    }
+
+    /// Stake to count for a vote that was already recorded with `recorded` stake. Snapshot
+    /// stake is proven again on every change. Epoch stake moves between epochs, so it stays
+    /// at what was recorded and totals move by the amount the vote was counted with.
+    pub fn recounted(&self, proven: u64, recorded: u64) -> u64 {
+        match self.snapshot {
+            Some(_) => proven,
+            None => recorded,
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use anchor_lang::{prelude::*, solana_program::hash::hashv};
+
+use crate::error::GovernanceError;
+
+/// Commitment to the split of a secret ballot vote, kept next to the `Vote` or
+/// `VoteOverride` that carries its placeholder
+#[account]
+#[derive(InitSpace)]
+pub struct Ballot {
+    pub proposal: Pubkey,
+    /// `Vote` or `VoteOverride` the commitment belongs to
+    pub vote_record: Pubkey,
+    pub commitment: [u8; 32],
+    pub commit_timestamp: i64,
+    pub revealed: bool,
+    pub bump: u8,
+}
+
+impl Ballot {
+    /// `sha256(for_bp || against_bp || abstain_bp || salt || vote_record)`, with the basis
+    /// points as little-endian `u64`s. Binding the vote record keeps a commitment from
+    /// being copied onto another vote.
+    pub fn commitment(
+        vote_record: &Pubkey,
+        for_votes_bp: u64,
+        against_votes_bp: u64,
+        abstain_votes_bp: u64,
+        salt: &[u8; 32],
+    ) -> [u8; 32] {
+        hashv(&[
+            &for_votes_bp.to_le_bytes(),
+            &against_votes_bp.to_le_bytes(),
+            &abstain_votes_bp.to_le_bytes(),
+            salt,
+            vote_record.as_ref(),
+        ])
+        .to_bytes()
+    }
+
+    /// Checks a reveal against the commitment and marks the ballot revealed
+    pub fn reveal(
+        &mut self,
+        for_votes_bp: u64,
+        against_votes_bp: u64,
+        abstain_votes_bp: u64,
+        salt: &[u8; 32],
+    ) -> Result<()> {
+        require!(!self.revealed, GovernanceError::BallotAlreadyRevealed);
+        require!(
+            Self::commitment(
+                &self.vote_record,
+                for_votes_bp,
+                against_votes_bp,
+                abstain_votes_bp,
+                salt,
+            ) == self.commitment,
+            GovernanceError::CommitmentMismatch
+        );
+        self.revealed = true;
+        Ok(())
+    }
+}
@@ -0,0 +1,143 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    error::GovernanceError,
+    state::{GovernanceParameter, ProposalClass, GOVERNANCE_PARAMETER_COUNT},
+};
+
+/// Governance rules that passed proposals change through `enact_parameter_change`.
+/// Proposals copy the rules that apply to them at creation, so a change never affects
+/// proposals already in flight.
+#[account]
+#[derive(InitSpace)]
+pub struct GovernanceConfig {
+    /// Share of cluster stake whose support activates voting on a standard proposal
+    pub support_threshold_bp: u64,
+    pub emergency_support_threshold_bp: u64,
+    /// Epochs in which a proposal can gather support, starting the epoch after creation
+    pub support_epochs: u64,
+    pub emergency_support_epochs: u64,
+    /// Full epochs between the support epoch and the snapshot epoch
+    pub discussion_epochs: u64,
+    pub emergency_discussion_epochs: u64,
+    pub voting_epochs: u64,
+    pub emergency_voting_epochs: u64,
+    /// Share of decisive (for + against) stake that must vote `For` for a proposal to pass
+    pub pass_threshold_bp: u64,
+    /// Share of cluster stake that must vote, abstentions included, for a proposal to pass
+    pub quorum_bp: u64,
+    pub min_proposal_stake_lamports: u64,
+    pub proposal_bond_lamports: u64,
+    pub max_open_proposals_per_author: u16,
+    /// Finalization timestamp of the proposal that last changed each rule, indexed by
+    /// `GovernanceParameter::index`, so an older change can't revert a newer one
+    pub parameter_finalized_at: [i64; GOVERNANCE_PARAMETER_COUNT],
+    pub bump: u8,
+}
+
+impl GovernanceConfig {
+    /// The rules the program shipped with
+    pub fn new(bump: u8) -> Self {
+        Self {
+            support_threshold_bp: SUPPORT_THRESHOLD_BP,
+            emergency_support_threshold_bp: EMERGENCY_SUPPORT_THRESHOLD_BP,
+            support_epochs: SUPPORT_EPOCHS,
+            emergency_support_epochs: EMERGENCY_SUPPORT_EPOCHS,
+            discussion_epochs: DISCUSSION_EPOCHS,
+            emergency_discussion_epochs: EMERGENCY_DISCUSSION_EPOCHS,
+            voting_epochs: VOTING_EPOCHS,
+            emergency_voting_epochs: EMERGENCY_VOTING_EPOCHS,
+            pass_threshold_bp: PASS_THRESHOLD_BP,
+            quorum_bp: QUORUM_BP,
+            min_proposal_stake_lamports: MIN_PROPOSAL_STAKE_LAMPORTS,
+            proposal_bond_lamports: PROPOSAL_BOND_LAMPORTS,
+            max_open_proposals_per_author: MAX_OPEN_PROPOSALS_PER_AUTHOR,
+            parameter_finalized_at: [0; GOVERNANCE_PARAMETER_COUNT],
+            bump,
+        }
+    }
+
+    pub fn support_threshold_bp(&self, class: ProposalClass) -> u64 {
+        match class {
+            ProposalClass::Standard => self.support_threshold_bp,
+            ProposalClass::Emergency => self.emergency_support_threshold_bp,
+        }
+    }
+
+    pub fn support_epochs(&self, class: ProposalClass) -> u64 {
+        match class {
+            ProposalClass::Standard => self.support_epochs,
+            ProposalClass::Emergency => self.emergency_support_epochs,
+        }
+    }
+
+    pub fn discussion_epochs(&self, class: ProposalClass) -> u64 {
+        match class {
+            ProposalClass::Standard => self.discussion_epochs,
+            ProposalClass::Emergency => self.emergency_discussion_epochs,
+        }
+    }
+
+    pub fn voting_epochs(&self, class: ProposalClass) -> u64 {
+        match class {
+            ProposalClass::Standard => self.voting_epochs,
+            ProposalClass::Emergency => self.emergency_voting_epochs,
+        }
+    }
+
+    /// Sets the rule unless a proposal finalized after `finalization_timestamp` already
+    /// changed it
+    pub fn apply(
+        &mut self,
+        change: GovernanceParameter,
+        finalization_timestamp: i64,
+    ) -> Result<()> {
+        let finalized_at = &mut self.parameter_finalized_at[change.index()];
+        require!(
+            finalization_timestamp >= *finalized_at,
+            GovernanceError::StaleParameterChange
+        );
+        *finalized_at = finalization_timestamp;
+
+        match change {
+            GovernanceParameter::SupportThresholdBp(value) => self.support_threshold_bp = value,
+            GovernanceParameter::EmergencySupportThresholdBp(value) => {
+                self.emergency_support_threshold_bp = value
+            }
+            GovernanceParameter::DiscussionEpochs(value) => self.discussion_epochs = value,
+            GovernanceParameter::EmergencyDiscussionEpochs(value) => {
+                self.emergency_discussion_epochs = value
+            }
+            GovernanceParameter::VotingEpochs(value) => self.voting_epochs = value,
+            GovernanceParameter::EmergencyVotingEpochs(value) => {
+                self.emergency_voting_epochs = value
+            }
+            GovernanceParameter::PassThresholdBp(value) => self.pass_threshold_bp = value,
+            GovernanceParameter::MinProposalStakeLamports(value) => {
+                self.min_proposal_stake_lamports = value
+            }
+            GovernanceParameter::ProposalBondLamports(value) => self.proposal_bond_lamports = value,
+            GovernanceParameter::MaxOpenProposalsPerAuthor(value) => {
+                self.max_open_proposals_per_author = value
+            }
+            GovernanceParameter::SupportEpochs(value) => self.support_epochs = value,
+            GovernanceParameter::EmergencySupportEpochs(value) => {
+                self.emergency_support_epochs = value
+            }
+            GovernanceParameter::QuorumBp(value) => self.quorum_bp = value,
+        }
+        Ok(())
+    }
+
+    /// Checks the rules against each other once changes are applied. Each value is
+    /// already bounded by `GovernanceParameter::validate`.
+    pub fn validate(&self) -> Result<()> {
+        // The fast track must never be easier to activate than the regular path
+        require!(
+            self.emergency_support_threshold_bp >= self.support_threshold_bp,
+            GovernanceError::InvalidParameterChange
+        );
+        Ok(())
+    }
+}
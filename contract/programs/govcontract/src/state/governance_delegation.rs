@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+/// A validator following another validator's vote. `proposal` is `Pubkey::default()` for a
+/// standing delegation covering every proposal; a delegation for a specific proposal takes
+/// precedence over it.
+#[account]
+#[derive(InitSpace)]
+pub struct GovernanceDelegation {
+    /// Vote account whose voting power is delegated
+    pub delegator: Pubkey,
+    /// Vote account whose split the delegator follows
+    pub delegate: Pubkey,
+    pub proposal: Pubkey,
+    pub bump: u8,
+}
+
+impl GovernanceDelegation {
+    pub fn is_standing(&self) -> bool {
+        self.proposal == Pubkey::default()
+    }
+
+    pub fn applies_to(&self, proposal: &Pubkey) -> bool {
+        self.is_standing() || self.proposal == *proposal
+    }
+}
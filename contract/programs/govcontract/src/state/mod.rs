@@ -1,13 +1,39 @@
+pub mod ballot;
+pub mod governance_config;
+pub mod governance_delegation;
+pub mod parameter_change;
 pub mod proposal;
+pub mod proposal_bond;
+pub mod proposal_content;
 pub mod proposal_index;
+pub mod proposal_registry;
+pub mod proposal_transaction;
+pub mod signal;
+pub mod signed_vote;
+pub mod stake_proxy;
 pub mod support;
+pub mod treasury_transfer;
+pub mod validator_governance_profile;
 pub mod vote;
 pub mod vote_override;
 pub mod vote_override_cache;
 
+pub use ballot::*;
+pub use governance_config::*;
+pub use governance_delegation::*;
+pub use parameter_change::*;
 pub use proposal::*;
+pub use proposal_bond::*;
+pub use proposal_content::*;
 pub use proposal_index::*;
+pub use proposal_registry::*;
+pub use proposal_transaction::*;
+pub use signal::*;
+pub use signed_vote::*;
+pub use stake_proxy::*;
 pub use support::*;
+pub use treasury_transfer::*;
+pub use validator_governance_profile::*;
 pub use vote::*;
 pub use vote_override::*;
 pub use vote_override_cache::*;
@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, error::GovernanceError};
+
+/// Number of `GovernanceParameter` variants
+pub const GOVERNANCE_PARAMETER_COUNT: usize = 13;
+
+/// A single `GovernanceConfig` rule and the value a proposal sets it to
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum GovernanceParameter {
+    SupportThresholdBp(u64),
+    EmergencySupportThresholdBp(u64),
+    DiscussionEpochs(u64),
+    EmergencyDiscussionEpochs(u64),
+    VotingEpochs(u64),
+    EmergencyVotingEpochs(u64),
+    PassThresholdBp(u64),
+    MinProposalStakeLamports(u64),
+    ProposalBondLamports(u64),
+    MaxOpenProposalsPerAuthor(u16),
+    SupportEpochs(u64),
+    EmergencySupportEpochs(u64),
+    QuorumBp(u64),
+}
+
+impl GovernanceParameter {
+    /// Position of the rule in `GovernanceConfig::parameter_finalized_at`
+    pub fn index(&self) -> usize {
+        match self {
+            GovernanceParameter::SupportThresholdBp(_) => 0,
+            GovernanceParameter::EmergencySupportThresholdBp(_) => 1,
+            GovernanceParameter::DiscussionEpochs(_) => 2,
+            GovernanceParameter::EmergencyDiscussionEpochs(_) => 3,
+            GovernanceParameter::VotingEpochs(_) => 4,
+            GovernanceParameter::EmergencyVotingEpochs(_) => 5,
+            GovernanceParameter::PassThresholdBp(_) => 6,
+            GovernanceParameter::MinProposalStakeLamports(_) => 7,
+            GovernanceParameter::ProposalBondLamports(_) => 8,
+            GovernanceParameter::MaxOpenProposalsPerAuthor(_) => 9,
+            GovernanceParameter::SupportEpochs(_) => 10,
+            GovernanceParameter::EmergencySupportEpochs(_) => 11,
+            GovernanceParameter::QuorumBp(_) => 12,
+        }
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        let valid = match *self {
+            GovernanceParameter::SupportThresholdBp(value)
+            | GovernanceParameter::EmergencySupportThresholdBp(value) => {
+                (1..=MAX_SUPPORT_THRESHOLD_BP).contains(&value)
+            }
+            GovernanceParameter::DiscussionEpochs(value)
+            | GovernanceParameter::EmergencyDiscussionEpochs(value) => value <= MAX_PHASE_EPOCHS,
+            GovernanceParameter::SupportEpochs(value)
+            | GovernanceParameter::EmergencySupportEpochs(value)
+            | GovernanceParameter::VotingEpochs(value)
+            | GovernanceParameter::EmergencyVotingEpochs(value) => {
+                (1..=MAX_PHASE_EPOCHS).contains(&value)
+            }
+            // Passing always takes a strict majority of the decisive stake, but never unanimity
+            GovernanceParameter::PassThresholdBp(value) => {
+                (BASIS_POINTS_MAX / 2 + 1..=MAX_PASS_THRESHOLD_BP).contains(&value)
+            }
+            GovernanceParameter::QuorumBp(value) => (1..=MAX_QUORUM_BP).contains(&value),
+            GovernanceParameter::MinProposalStakeLamports(value) => {
+                value <= MAX_MIN_PROPOSAL_STAKE_LAMPORTS
+            }
+            GovernanceParameter::ProposalBondLamports(value) => {
+                (MIN_PROPOSAL_BOND_LAMPORTS..=MAX_PROPOSAL_BOND_LAMPORTS).contains(&value)
+            }
+            GovernanceParameter::MaxOpenProposalsPerAuthor(value) => value > 0,
+        };
+        require!(valid, GovernanceError::InvalidParameterChange);
+        Ok(())
+    }
+}
+
+/// Parameter changes attached to a proposal, applied to the `GovernanceConfig` by
+/// `enact_parameter_change` once the proposal passes
+#[account]
+#[derive(InitSpace)]
+pub struct ParameterChange {
+    pub proposal: Pubkey,
+    #[max_len(MAX_PARAMETER_CHANGES)]
+    pub changes: Vec<GovernanceParameter>,
+    /// Unix timestamp of enactment, 0 while pending
+    pub enacted_timestamp: i64,
+    pub bump: u8,
+}
+
+impl ParameterChange {
+    pub fn is_enacted(&self) -> bool {
+        self.enacted_timestamp != 0
+    }
+}
@@ -1,115 +1,523 @@
-use crate::{constants::*, error::GovernanceError};
-use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    error::GovernanceError,
+    state::GovernanceConfig,
+    tally::{self, VoteLamports},
+    utils::{get_epoch_slot_range, get_slot_epoch, latest_slot_hash},
+};
+use anchor_lang::{prelude::*, solana_program::hash::hashv};
 
-#[account]
+/// Result recorded by `finalize_proposal`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum ProposalOutcome {
+    Undecided,
+    Passed,
+    Rejected,
+    /// Withdrawn by its author before voting started
+    Cancelled,
+}
+
+impl From<u8> for ProposalOutcome {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => ProposalOutcome::Passed,
+            2 => ProposalOutcome::Rejected,
+            3 => ProposalOutcome::Cancelled,
+            _ => ProposalOutcome::Undecided,
+        }
+    }
+}
+
+/// Path a proposal takes from support to voting, each with its own thresholds and timings
+/// in the `GovernanceConfig`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum ProposalClass {
+    /// 5% support, three discussion epochs and three voting epochs by default
+    Standard,
+    /// Fast track for urgent changes such as a feature-gate rollback: 15% support, no
+    /// discussion and a single voting epoch by default
+    Emergency,
+}
+
+impl From<u8> for ProposalClass {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => ProposalClass::Emergency,
+            _ => ProposalClass::Standard,
+        }
+    }
+}
+
+/// Where a proposal reads validator and delegator stake from when votes are cast
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum StakeSource {
+    /// gov-v1 snapshot at a fixed slot, proven with meta merkle proofs
+    Snapshot,
+    /// Native epoch stake of the vote account, read at vote time. For clusters without
+    /// gov-v1 snapshots; stake can change between epochs while voting is open.
+    EpochStake,
+}
+
+impl From<u8> for StakeSource {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => StakeSource::EpochStake,
+            _ => StakeSource::Snapshot,
+        }
+    }
+}
+
+/// Anti-sniping rule: a vote that flips the result, changing the leading option or whether
+/// the proposal passes, within `window_slots` of the end of voting pushes `end_epoch` back
+/// by `extension_epochs`, at most `max_extensions` times. All zeroes disables the rule.
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace,
+)]
+pub struct AntiSnipingConfig {
+    pub window_slots: u64,
+    pub extension_epochs: u64,
+    pub max_extensions: u8,
+}
+
+impl AntiSnipingConfig {
+    pub fn is_enabled(&self) -> bool {
+        self.max_extensions > 0
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            (1..=MAX_ANTI_SNIPING_WINDOW_SLOTS).contains(&self.window_slots)
+                && (1..=MAX_ANTI_SNIPING_EXTENSION_EPOCHS).contains(&self.extension_epochs)
+                && (1..=MAX_ANTI_SNIPING_EXTENSIONS).contains(&self.max_extensions),
+            GovernanceError::InvalidAntiSnipingConfig
+        );
+        Ok(())
+    }
+}
+
+/// One of the three options a vote splits its stake between
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VoteChoice {
+    For,
+    Against,
+    Abstain,
+}
+
+/// Where the tally stands, compared before and after a vote by the anti-sniping rule
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TallyStanding {
+    /// Option holding the most stake, `None` while no option leads outright
+    pub leading: Option<VoteChoice>,
+    pub passing: bool,
+}
+
+/// What a secret ballot that was never revealed counts as
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum UnrevealedBallots {
+    /// The voter's stake is tallied as `Abstain`
+    Abstain,
+    /// The voter's stake is left out of the tally
+    Exclude,
+}
+
+impl From<u8> for UnrevealedBallots {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => UnrevealedBallots::Exclude,
+            _ => UnrevealedBallots::Abstain,
+        }
+    }
+}
+
+/// Commit-reveal voting: votes are cast with a placeholder split and committed to a hash of
+/// the real one, which is revealed during the `reveal_epochs` after voting ends. Until then
+/// the placeholder stands in for the vote, as `unrevealed` configures.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct SecretBallotConfig {
+    pub reveal_epochs: u64,
+    pub unrevealed: UnrevealedBallots,
+}
+
+impl SecretBallotConfig {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            (1..=MAX_REVEAL_EPOCHS).contains(&self.reveal_epochs),
+            GovernanceError::InvalidSecretBallotConfig
+        );
+        Ok(())
+    }
+
+    /// Split tallied for a vote until it is revealed
+    pub fn placeholder_split(&self) -> [u64; 3] {
+        match self.unrevealed {
+            UnrevealedBallots::Abstain => [0, 0, BASIS_POINTS_MAX],
+            UnrevealedBallots::Exclude => [0, 0, 0],
+        }
+    }
+}
+
+/// Hot voting state, read and written by every vote instruction. Kept in a fixed
+/// zero-copy layout so votes don't pay for (de)serializing it; the title and description
+/// live in `ProposalContent`.
+#[account(zero_copy)]
 #[derive(InitSpace)]
 pub struct Proposal {
     /// The public key of the validator who created this proposal
     pub author: Pubkey,
-    #[max_len(MAX_TITLE_LENGTH)]
-    pub title: String,
-    #[max_len(MAX_DESCRIPTION_LENGTH)]
-    pub description: String,
+    // Seeds for CPI
+    pub vote_account_pubkey: Pubkey,
+    /// Consensus result PDA of the snapshot program, `Pubkey::default()` until voting starts
+    pub consensus_result: Pubkey,
+    /// Earlier proposal this one replaces, `Pubkey::default()` if none
+    pub supersedes: Pubkey,
+    /// Earlier proposal this one builds on, `Pubkey::default()` if none
+    pub depends_on: Pubkey,
+    /// Passed proposal that replaced this one, `Pubkey::default()` until then
+    pub superseded_by: Pubkey,
+    /// Slot hash a random snapshot slot was drawn from
+    pub snapshot_seed_hash: [u8; 32],
     pub creation_epoch: u64,
     pub start_epoch: u64,
     pub end_epoch: u64,
     pub proposer_stake_weight_bp: u64,
     pub cluster_support_lamports: u64,
+    /// Cluster stake in the epoch voting was activated, which the quorum is measured against
+    pub cluster_stake_lamports: u64,
     /// Total lamports voted in favor of this proposal
     pub for_votes_lamports: u64,
     /// Total lamports voted against this proposal
     pub against_votes_lamports: u64,
     /// Total lamports that abstained from voting on this proposal
     pub abstain_votes_lamports: u64,
-    pub voting: bool,
-    pub finalized: bool,
-    pub proposal_bump: u8,
+    /// Unix timestamp of finalization, used as the start of any execution timelock
+    pub finalization_timestamp: i64,
     pub creation_timestamp: i64,
-    pub vote_count: u32,
-    pub index: u32,
-    pub consensus_result: Option<Pubkey>,
-    /// Slot number when the validator stake snapshot was taken
+    /// Slot number when the validator stake snapshot was taken, 0 until a random one is drawn
     pub snapshot_slot: u64,
-    // Seeds for CPI
     pub proposal_seed: u64,
-    pub vote_account_pubkey: Pubkey,
+    pub anti_sniping_window_slots: u64,
+    pub anti_sniping_extension_epochs: u64,
+    /// Epochs after `end_epoch` for revealing secret ballots, 0 for an open ballot
+    pub reveal_epochs: u64,
+    /// Slot of `snapshot_seed_hash`, 0 for a fixed snapshot slot
+    pub snapshot_seed_slot: u64,
+    // Rules copied from the `GovernanceConfig` at creation
+    pub support_threshold_bp: u64,
+    pub pass_threshold_bp: u64,
+    pub quorum_bp: u64,
+    pub support_epochs: u64,
+    pub discussion_epochs: u64,
+    pub voting_epochs: u64,
+    /// Snapshot slots replaced by each flush, in order
+    pub prior_snapshot_slots: [u64; MAX_MERKLE_ROOT_FLUSHES],
+    pub vote_count: u32,
+    pub index: u32,
+    pub proposal_bump: u8,
+    /// 1 once enough cluster support activated voting
+    pub voting: u8,
+    /// 1 once `finalize_proposal` recorded the outcome
+    pub finalized: u8,
+    /// `ProposalOutcome` as a `u8`
+    pub outcome: u8,
+    pub anti_sniping_max_extensions: u8,
+    /// Number of times `end_epoch` has been extended by the anti-sniping rule
+    pub voting_extensions: u8,
+    /// Number of times the merkle root has been flushed
+    pub flush_count: u8,
+    /// `UnrevealedBallots` as a `u8`, only meaningful for secret ballots
+    pub unrevealed_ballots: u8,
+    /// 1 when the snapshot slot is drawn from SlotHashes instead of fixed
+    pub random_snapshot: u8,
+    /// `ProposalClass` as a `u8`
+    pub class: u8,
+    /// `StakeSource` as a `u8`
+    pub stake_source: u8,
+    pub padding: [u8; 5],
 }
 
-impl Default for Proposal {
-    fn default() -> Self {
-        Self {
-            author: Pubkey::default(),
-            title: "".to_string(),
-            description: "".to_string(),
-            creation_epoch: 0,
-            start_epoch: 0,
-            end_epoch: 0,
-            proposer_stake_weight_bp: 0,
-            cluster_support_lamports: 0,
-            for_votes_lamports: 0,
-            against_votes_lamports: 0,
-            abstain_votes_lamports: 0,
-            voting: false,
-            finalized: false,
-            proposal_bump: 0,
-            creation_timestamp: 0,
-            vote_count: 0,
-            index: 0,
-            snapshot_slot: 0,
-            consensus_result: None,
-            proposal_seed: 0,
-            vote_account_pubkey: Pubkey::default(),
+impl Proposal {
+    pub fn is_voting(&self) -> bool {
+        self.voting != 0
+    }
+
+    pub fn is_finalized(&self) -> bool {
+        self.finalized != 0
+    }
+
+    pub fn outcome(&self) -> ProposalOutcome {
+        ProposalOutcome::from(self.outcome)
+    }
+
+    pub fn finalize(&mut self, outcome: ProposalOutcome, timestamp: i64) {
+        self.finalized = 1;
+        self.outcome = outcome as u8;
+        self.finalization_timestamp = timestamp;
+    }
+
+    pub fn class(&self) -> ProposalClass {
+        ProposalClass::from(self.class)
+    }
+
+    pub fn stake_source(&self) -> StakeSource {
+        StakeSource::from(self.stake_source)
+    }
+
+    pub fn consensus_result(&self) -> Option<Pubkey> {
+        (self.consensus_result != Pubkey::default()).then_some(self.consensus_result)
+    }
+
+    /// First epoch in which no proposal can still vote with this proposal's snapshot. Every
+    /// proposal on the same snapshot slot starts voting the epoch after the snapshot epoch,
+    /// so `SNAPSHOT_RETENTION_EPOCHS` later all of them have closed, whoever created them.
+    pub fn snapshot_release_epoch(&self) -> u64 {
+        get_slot_epoch(self.snapshot_slot) + SNAPSHOT_RETENTION_EPOCHS
+    }
+
+    pub fn supersedes(&self) -> Option<Pubkey> {
+        (self.supersedes != Pubkey::default()).then_some(self.supersedes)
+    }
+
+    pub fn depends_on(&self) -> Option<Pubkey> {
+        (self.depends_on != Pubkey::default()).then_some(self.depends_on)
+    }
+
+    pub fn is_superseded(&self) -> bool {
+        self.superseded_by != Pubkey::default()
+    }
+
+    pub fn anti_sniping(&self) -> AntiSnipingConfig {
+        AntiSnipingConfig {
+            window_slots: self.anti_sniping_window_slots,
+            extension_epochs: self.anti_sniping_extension_epochs,
+            max_extensions: self.anti_sniping_max_extensions,
         }
     }
-}
 
-impl Proposal {
-    pub fn add_vote_lamports(
-        &mut self,
-        for_votes: u64,
-        against_votes: u64,
-        abstain_votes: u64,
+    pub fn set_anti_sniping(&mut self, config: AntiSnipingConfig) {
+        self.anti_sniping_window_slots = config.window_slots;
+        self.anti_sniping_extension_epochs = config.extension_epochs;
+        self.anti_sniping_max_extensions = config.max_extensions;
+    }
+
+    pub fn is_secret_ballot(&self) -> bool {
+        self.reveal_epochs > 0
+    }
+
+    pub fn secret_ballot(&self) -> Option<SecretBallotConfig> {
+        self.is_secret_ballot().then(|| SecretBallotConfig {
+            reveal_epochs: self.reveal_epochs,
+            unrevealed: UnrevealedBallots::from(self.unrevealed_ballots),
+        })
+    }
+
+    pub fn set_secret_ballot(&mut self, config: SecretBallotConfig) {
+        self.reveal_epochs = config.reveal_epochs;
+        self.unrevealed_ballots = config.unrevealed as u8;
+    }
+
+    /// First epoch after the reveal window, which is `end_epoch` for an open ballot
+    pub fn reveal_end_epoch(&self) -> Result<u64> {
+        Ok(self
+            .end_epoch
+            .checked_add(self.reveal_epochs)
+            .ok_or(GovernanceError::ArithmeticOverflow)?)
+    }
+
+    /// Checks the split of a vote cast during voting: a full distribution for an open
+    /// ballot, the placeholder for a secret one
+    pub fn validate_cast_split(
+        &self,
+        for_votes_bp: u64,
+        against_votes_bp: u64,
+        abstain_votes_bp: u64,
     ) -> Result<()> {
-        self.for_votes_lamports = self
-            .for_votes_lamports
-            .checked_add(for_votes)
-            .ok_or(GovernanceError::ArithmeticOverflow)?;
+        match self.secret_ballot() {
+            Some(config) => {
+                require!(
+                    [for_votes_bp, against_votes_bp, abstain_votes_bp]
+                        == config.placeholder_split(),
+                    GovernanceError::SecretBallotPlaceholderRequired
+                );
+                Ok(())
+            }
+            None => tally::validate_distribution(for_votes_bp, against_votes_bp, abstain_votes_bp),
+        }
+    }
 
-        self.against_votes_lamports = self
-            .against_votes_lamports
-            .checked_add(against_votes)
-            .ok_or(GovernanceError::ArithmeticOverflow)?;
+    pub fn vote_lamports(&self) -> VoteLamports {
+        VoteLamports {
+            for_votes: self.for_votes_lamports,
+            against_votes: self.against_votes_lamports,
+            abstain_votes: self.abstain_votes_lamports,
+        }
+    }
 
-        self.abstain_votes_lamports = self
-            .abstain_votes_lamports
-            .checked_add(abstain_votes)
-            .ok_or(GovernanceError::ArithmeticOverflow)?;
+    pub fn set_vote_lamports(&mut self, lamports: VoteLamports) {
+        self.for_votes_lamports = lamports.for_votes;
+        self.against_votes_lamports = lamports.against_votes;
+        self.abstain_votes_lamports = lamports.abstain_votes;
+    }
 
-        Ok(())
+    /// A proposal passes when the stake that voted, abstentions included, reaches
+    /// `quorum_bp` of the cluster stake and `For` holds at least `pass_threshold_bp` of the
+    /// decisive (`For` + `Against`) stake. Abstentions do not count either way in the latter.
+    pub fn is_passing(&self) -> bool {
+        let for_votes = self.for_votes_lamports as u128;
+        let decisive = for_votes + self.against_votes_lamports as u128;
+        let participation = decisive + self.abstain_votes_lamports as u128;
+
+        participation * BASIS_POINTS_MAX as u128
+            >= self.cluster_stake_lamports as u128 * self.quorum_bp as u128
+            && decisive > 0
+            && for_votes * BASIS_POINTS_MAX as u128 >= decisive * self.pass_threshold_bp as u128
+    }
+
+    /// Option holding strictly more stake than each of the others, `None` on a tie or
+    /// before any votes
+    pub fn leading_option(&self) -> Option<VoteChoice> {
+        let options = [
+            (VoteChoice::For, self.for_votes_lamports),
+            (VoteChoice::Against, self.against_votes_lamports),
+            (VoteChoice::Abstain, self.abstain_votes_lamports),
+        ];
+        let (leader, most) = options.into_iter().max_by_key(|&(_, lamports)| lamports)?;
+        let tied = options
+            .iter()
+            .any(|&(option, lamports)| option != leader && lamports == most);
+        (!tied).then_some(leader)
+    }
+
+    pub fn standing(&self) -> TallyStanding {
+        TallyStanding {
+            leading: self.leading_option(),
+            passing: self.is_passing(),
+        }
+    }
+
+    /// Copies the rules of the proposal's class from the current config
+    pub fn set_rules(&mut self, config: &GovernanceConfig) {
+        let class = self.class();
+        self.support_threshold_bp = config.support_threshold_bp(class);
+        self.pass_threshold_bp = config.pass_threshold_bp;
+        self.quorum_bp = config.quorum_bp;
+        self.support_epochs = config.support_epochs(class);
+        self.discussion_epochs = config.discussion_epochs(class);
+        self.voting_epochs = config.voting_epochs(class);
     }
 
-    pub fn sub_vote_lamports(
+    /// Last epoch in which the proposal can gather support
+    pub fn support_end_epoch(&self) -> u64 {
+        self.creation_epoch.saturating_add(self.support_epochs)
+    }
+
+    /// Support opens the epoch after creation and lasts `support_epochs` epochs
+    pub fn is_in_support_period(&self, epoch: u64) -> bool {
+        epoch > self.creation_epoch && epoch <= self.support_end_epoch()
+    }
+
+    /// Whether `cluster_support_lamports` reaches the support threshold of `cluster_stake`
+    pub fn has_enough_support(&self, cluster_stake: u64) -> bool {
+        self.cluster_support_lamports as u128 * BASIS_POINTS_MAX as u128
+            >= cluster_stake as u128 * self.support_threshold_bp as u128
+    }
+
+    /// Applies the anti-sniping rule after a tally change. The result flipped when another
+    /// option took the lead or the proposal started or stopped passing compared to
+    /// `previous`. Returns the new `end_epoch` when the change flipped the result inside the
+    /// window and voting was extended.
+    pub fn extend_voting_if_flipped(
         &mut self,
-        for_votes: u64,
-        against_votes: u64,
-        abstain_votes: u64,
-    ) -> Result<()> {
-        self.for_votes_lamports = self
-            .for_votes_lamports
-            .checked_sub(for_votes)
-            .ok_or(GovernanceError::ArithmeticOverflow)?;
+        previous: TallyStanding,
+        current_slot: u64,
+    ) -> Result<Option<u64>> {
+        let config = self.anti_sniping();
+        if !config.is_enabled()
+            || self.voting_extensions >= config.max_extensions
+            || self.standing() == previous
+        {
+            return Ok(None);
+        }
 
-        self.against_votes_lamports = self
-            .against_votes_lamports
-            .checked_sub(against_votes)
-            .ok_or(GovernanceError::ArithmeticOverflow)?;
+        // Voting closes at the first slot of `end_epoch`
+        let (voting_end_slot, _) = get_epoch_slot_range(self.end_epoch);
+        if current_slot.saturating_add(config.window_slots) < voting_end_slot {
+            return Ok(None);
+        }
 
-        self.abstain_votes_lamports = self
-            .abstain_votes_lamports
-            .checked_sub(abstain_votes)
+        self.end_epoch = self
+            .end_epoch
+            .checked_add(config.extension_epochs)
             .ok_or(GovernanceError::ArithmeticOverflow)?;
+        self.voting_extensions += 1;
 
-        Ok(())
+        Ok(Some(self.end_epoch))
+    }
+
+    pub fn is_random_snapshot(&self) -> bool {
+        self.random_snapshot != 0
+    }
+
+    /// Epoch the snapshot is taken in, the one before voting starts
+    pub fn snapshot_epoch(&self) -> u64 {
+        self.start_epoch.saturating_sub(1)
+    }
+
+    /// Whether `slot` comes before the snapshot. A random snapshot slot is only drawn during
+    /// the snapshot epoch, so for those the discussion ends when that epoch starts.
+    pub fn is_before_snapshot(&self, slot: u64) -> bool {
+        if self.is_random_snapshot() {
+            let (snapshot_epoch_start, _) = get_epoch_slot_range(self.snapshot_epoch());
+            slot < snapshot_epoch_start
+        } else {
+            slot < self.snapshot_slot
+        }
+    }
+
+    /// Fixed snapshot slot of `snapshot_epoch`, `SNAPSHOT_SLOT_OFFSET` slots into the epoch
+    pub fn fixed_snapshot_slot(snapshot_epoch: u64) -> u64 {
+        let (start_slot, _) = get_epoch_slot_range(snapshot_epoch);
+        start_slot + SNAPSHOT_SLOT_OFFSET
+    }
+
+    /// Sets the snapshot slot and, for snapshot stake, the consensus result the ballot
+    /// program publishes for it
+    pub fn set_snapshot_slot(&mut self, snapshot_slot: u64, ballot_program: &Pubkey) {
+        self.snapshot_slot = snapshot_slot;
+        if self.stake_source() == StakeSource::Snapshot {
+            let (consensus_result, _) = Pubkey::find_program_address(
+                &[b"ConsensusResult", &snapshot_slot.to_le_bytes()],
+                ballot_program,
+            );
+            self.consensus_result = consensus_result;
+        }
+    }
+
+    /// Draws a random snapshot slot from the latest entry of the SlotHashes sysvar, which
+    /// is recorded as the derivation input. It is drawn during the snapshot epoch, among the
+    /// slots at least `SNAPSHOT_SLOT_OFFSET` after `current_slot` and before the epoch's last
+    /// `SNAPSHOT_SLOT_OFFSET`, so the slot cannot be known before the epoch starts.
+    pub fn draw_snapshot_slot(
+        &mut self,
+        proposal: &Pubkey,
+        current_slot: u64,
+        slot_hashes: &AccountInfo,
+    ) -> Result<u64> {
+        let (start_slot, end_slot) = get_epoch_slot_range(self.snapshot_epoch());
+        let first_slot = start_slot.max(current_slot) + SNAPSHOT_SLOT_OFFSET;
+        let last_slot = end_slot - SNAPSHOT_SLOT_OFFSET;
+        require!(
+            current_slot >= start_slot && first_slot <= last_slot,
+            GovernanceError::SnapshotDrawNotOpen
+        );
+
+        let (seed_slot, seed_hash) = latest_slot_hash(slot_hashes)?;
+        self.snapshot_seed_slot = seed_slot;
+        self.snapshot_seed_hash = seed_hash;
+
+        let draw = hashv(&[&seed_hash, proposal.as_ref()]).to_bytes();
+        let range = last_slot + 1 - first_slot;
+        let offset = u64::from_le_bytes(draw[..8].try_into().unwrap()) % range;
+        Ok(first_slot + offset)
     }
 
     pub fn add_cluster_support(&mut self, support_lamports: u64) -> Result<()> {
@@ -120,4 +528,15 @@ impl Proposal {
 
         Ok(())
     }
+
+    /// Replaces a supporter's previously counted stake with a new measurement
+    pub fn remeasure_cluster_support(&mut self, previous: u64, current: u64) -> Result<()> {
+        self.cluster_support_lamports = self
+            .cluster_support_lamports
+            .checked_sub(previous)
+            .and_then(|lamports| lamports.checked_add(current))
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
+
+        Ok(())
+    }
 }
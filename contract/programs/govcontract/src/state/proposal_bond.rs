@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::state::Proposal;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum BondStatus {
+    Locked,
+    Returned,
+    Forfeited,
+}
+
+/// SOL locked by the author in `create_proposal`. The bond lamports sit on top of this
+/// account's rent and leave it once `settle_proposal_bond` decides where they go.
+#[account]
+#[derive(InitSpace)]
+pub struct ProposalBond {
+    pub proposal: Pubkey,
+    pub author: Pubkey,
+    pub amount: u64,
+    pub status: BondStatus,
+    pub bump: u8,
+}
+
+impl ProposalBond {
+    /// Where the bond goes given the proposal's state, or `None` while it must stay locked.
+    /// Reaching voting returns it, which also covers every proposal that later passes;
+    /// missing support by the end of the support period forfeits it.
+    pub fn settlement(proposal: &Proposal, epoch: u64) -> Option<BondStatus> {
+        if proposal.is_voting() {
+            Some(BondStatus::Returned)
+        } else if epoch > proposal.support_end_epoch() {
+            Some(BondStatus::Forfeited)
+        } else {
+            None
+        }
+    }
+}
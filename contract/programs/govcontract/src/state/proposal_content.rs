@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+
+/// Title and description of a proposal, written once by `create_proposal` and never
+/// loaded by the vote instructions
+#[account]
+#[derive(InitSpace)]
+pub struct ProposalContent {
+    pub proposal: Pubkey,
+    #[max_len(MAX_TITLE_LENGTH)]
+    pub title: String,
+    #[max_len(MAX_DESCRIPTION_LENGTH)]
+    pub description: String,
+    pub bump: u8,
+}
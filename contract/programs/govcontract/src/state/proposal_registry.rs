@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::REGISTRY_PAGE_SIZE, error::GovernanceError, state::ProposalOutcome};
+
+/// A registered proposal and where it stands
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct RegistryEntry {
+    pub index: u32,
+    pub proposal: Pubkey,
+    /// `Undecided` until the proposal is finalized
+    pub outcome: ProposalOutcome,
+}
+
+/// One page of the proposal registry, holding the proposals whose index falls in
+/// `[page * REGISTRY_PAGE_SIZE + 1, (page + 1) * REGISTRY_PAGE_SIZE]`.
+/// Clients list proposals by reading `ProposalIndex` and the pages up to its current index.
+#[account]
+#[derive(InitSpace)]
+pub struct ProposalRegistryPage {
+    pub page: u32,
+    #[max_len(REGISTRY_PAGE_SIZE)]
+    pub entries: Vec<RegistryEntry>,
+    pub bump: u8,
+}
+
+impl ProposalRegistryPage {
+    /// Page that holds the proposal with the given (1-based) index
+    pub fn page_for(index: u32) -> u32 {
+        index.saturating_sub(1) / REGISTRY_PAGE_SIZE as u32
+    }
+
+    pub fn append(&mut self, index: u32, proposal: Pubkey) -> Result<()> {
+        require!(
+            self.entries.len() < REGISTRY_PAGE_SIZE,
+            GovernanceError::RegistryPageFull
+        );
+        self.entries.push(RegistryEntry {
+            index,
+            proposal,
+            outcome: ProposalOutcome::Undecided,
+        });
+        Ok(())
+    }
+
+    /// Records a proposal's outcome. Proposals created before the registry existed
+    /// have no entry and are left alone.
+    pub fn set_outcome(&mut self, index: u32, outcome: ProposalOutcome) {
+        if let Some(entry) = self.entries.iter_mut().find(|entry| entry.index == index) {
+            entry.outcome = outcome;
+        }
+    }
+}
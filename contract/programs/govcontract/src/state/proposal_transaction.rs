@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
+pub struct ProposalAccountMeta {
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+/// A serialized instruction invoked by the governance authority once the proposal passes
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ProposalInstruction {
+    pub program_id: Pubkey,
+    pub accounts: Vec<ProposalAccountMeta>,
+    pub data: Vec<u8>,
+}
+
+impl ProposalInstruction {
+    pub fn space(&self) -> usize {
+        32 + 4 + self.accounts.len() * ProposalAccountMeta::INIT_SPACE + 4 + self.data.len()
+    }
+}
+
+#[account]
+pub struct ProposalTransaction {
+    pub proposal: Pubkey,
+    /// Seconds that must pass after finalization before the transaction can be executed
+    pub timelock_seconds: i64,
+    /// Unix timestamp of execution, 0 while pending
+    pub executed_timestamp: i64,
+    pub instructions: Vec<ProposalInstruction>,
+    pub bump: u8,
+}
+
+impl ProposalTransaction {
+    pub fn space(instructions: &[ProposalInstruction]) -> usize {
+        32 + 8 + 8 + 4 + instructions.iter().map(|ix| ix.space()).sum::<usize>() + 1
+    }
+
+    pub fn is_executed(&self) -> bool {
+        self.executed_timestamp != 0
+    }
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::error::GovernanceError;
+
+/// Non-binding lean recorded with `signal`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum SignalLean {
+    For,
+    Against,
+    Undecided,
+}
+
+/// A validator's temperature-check signal on a proposal. Signalling again replaces it.
+#[account]
+#[derive(InitSpace)]
+pub struct Signal {
+    pub proposal: Pubkey,
+    pub vote_account: Pubkey,
+    pub lean: SignalLean,
+    /// Epoch stake of the vote account when it last signalled
+    pub stake: u64,
+    pub signal_epoch: u64,
+    pub bump: u8,
+}
+
+/// Stake-weighted signals on a proposal, kept apart from the binding vote tally
+#[account]
+#[derive(InitSpace)]
+pub struct SignalTally {
+    pub proposal: Pubkey,
+    pub for_lamports: u64,
+    pub against_lamports: u64,
+    pub undecided_lamports: u64,
+    pub signal_count: u32,
+    pub bump: u8,
+}
+
+impl SignalTally {
+    fn lamports_mut(&mut self, lean: SignalLean) -> &mut u64 {
+        match lean {
+            SignalLean::For => &mut self.for_lamports,
+            SignalLean::Against => &mut self.against_lamports,
+            SignalLean::Undecided => &mut self.undecided_lamports,
+        }
+    }
+
+    pub fn add(&mut self, lean: SignalLean, stake: u64) -> Result<()> {
+        let lamports = self.lamports_mut(lean);
+        *lamports = lamports
+            .checked_add(stake)
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    pub fn remove(&mut self, lean: SignalLean, stake: u64) -> Result<()> {
+        let lamports = self.lamports_mut(lean);
+        *lamports = lamports
+            .checked_sub(stake)
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
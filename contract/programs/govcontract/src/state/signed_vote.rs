@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::SIGNED_VOTE_DOMAIN;
+
+/// Vote signed off-chain by the vote account's voting wallet. The wallet signs
+/// `SignedVote::message`, and a relayer submits it with an Ed25519 program instruction
+/// verifying that signature right before `cast_vote_signed`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SignedVote {
+    pub proposal: Pubkey,
+    pub vote_account: Pubkey,
+    pub for_votes_bp: u64,
+    pub against_votes_bp: u64,
+    pub abstain_votes_bp: u64,
+    /// Chosen by the signer so otherwise identical votes sign different messages
+    pub nonce: u64,
+    /// Unix timestamp after which the vote can no longer be relayed
+    pub expiry: i64,
+}
+
+impl SignedVote {
+    /// `SIGNED_VOTE_DOMAIN || program ID || proposal || vote account || for_bp || against_bp ||
+    /// abstain_bp || nonce || expiry`, integers as little-endian
+    pub fn message(&self) -> Vec<u8> {
+        [
+            SIGNED_VOTE_DOMAIN,
+            crate::ID.as_ref(),
+            self.proposal.as_ref(),
+            self.vote_account.as_ref(),
+            &self.for_votes_bp.to_le_bytes(),
+            &self.against_votes_bp.to_le_bytes(),
+            &self.abstain_votes_bp.to_le_bytes(),
+            &self.nonce.to_le_bytes(),
+            &self.expiry.to_le_bytes(),
+        ]
+        .concat()
+    }
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::error::GovernanceError;
+
+/// Wallet allowed to cast and modify vote overrides for a stake account, registered by the
+/// stake account's withdraw authority
+#[account]
+#[derive(InitSpace)]
+pub struct StakeProxy {
+    pub stake_account: Pubkey,
+    /// Withdraw authority that registered the proxy
+    pub authority: Pubkey,
+    pub proxy: Pubkey,
+    pub registered_timestamp: i64,
+    pub bump: u8,
+}
+
+impl StakeProxy {
+    /// Checks that `signer` may override for `stake_account`: either its voting wallet, or the
+    /// proxy that wallet registered for the stake account. Returns the proxy that signed,
+    /// `Pubkey::default()` when the staker signed.
+    pub fn authorize_override(
+        signer: &Pubkey,
+        stake_account: &Pubkey,
+        voting_wallet: &Pubkey,
+        stake_proxy: Option<&StakeProxy>,
+    ) -> Result<Pubkey> {
+        if voting_wallet == signer {
+            return Ok(Pubkey::default());
+        }
+
+        match stake_proxy {
+            Some(stake_proxy)
+                if stake_proxy.stake_account == *stake_account && stake_proxy.proxy == *signer =>
+            {
+                // A proxy registered by a former owner of the stake account no longer applies
+                require_keys_eq!(
+                    stake_proxy.authority,
+                    *voting_wallet,
+                    GovernanceError::StakeProxyAuthorityMismatch
+                );
+                Ok(*signer)
+            }
+            _ => err!(GovernanceError::StakeAccountOwnerMismatch),
+        }
+    }
+}
@@ -5,5 +5,9 @@ use anchor_lang::prelude::*;
 pub struct Support {
     pub proposal: Pubkey,
     pub validator: Pubkey,
+    /// Stake counted towards the proposal's support, measured in `epoch`
+    pub stake: u64,
+    /// Epoch of the latest measurement
+    pub epoch: u64,
     pub bump: u8,
 }
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+/// Treasury withdrawal attached to a proposal, paid out once the proposal passes.
+/// Only `execute_treasury_transfer` can move funds out of the treasury PDA.
+#[account]
+#[derive(InitSpace)]
+pub struct TreasuryTransfer {
+    pub proposal: Pubkey,
+    /// SOL recipient, or the recipient token account for SPL transfers
+    pub destination: Pubkey,
+    /// Token mint, `Pubkey::default()` for SOL
+    pub mint: Pubkey,
+    /// Lamports, or base units of `mint`
+    pub amount: u64,
+    /// Seconds that must pass after finalization before the transfer can be executed
+    pub timelock_seconds: i64,
+    /// Unix timestamp of execution, 0 while pending
+    pub executed_timestamp: i64,
+    pub bump: u8,
+}
+
+impl TreasuryTransfer {
+    pub fn is_sol(&self) -> bool {
+        self.mint == Pubkey::default()
+    }
+
+    pub fn is_executed(&self) -> bool {
+        self.executed_timestamp != 0
+    }
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, error::GovernanceError};
+
+/// Per vote account governance profile: self-reported metadata and participation counters
+/// kept up to date by `create_proposal`, `support_proposal` and `cast_vote`, plus the
+/// number of its proposals still open. Metadata fields are empty until the validator sets them.
+#[account]
+#[derive(InitSpace)]
+pub struct ValidatorGovernanceProfile {
+    pub vote_account: Pubkey,
+    #[max_len(MAX_DISPLAY_NAME_LENGTH)]
+    pub display_name: String,
+    #[max_len(MAX_PROFILE_URL_LENGTH)]
+    pub website: String,
+    #[max_len(MAX_PROFILE_URL_LENGTH)]
+    pub governance_policy_url: String,
+    pub proposals_authored: u32,
+    pub proposals_supported: u32,
+    pub proposals_voted: u32,
+    /// Authored proposals not yet finalized or expired, capped by the governance config
+    pub open_proposals: u16,
+    /// Epoch of the latest `cast_vote`, 0 if the validator never voted
+    pub last_vote_epoch: u64,
+    pub bump: u8,
+}
+
+impl ValidatorGovernanceProfile {
+    /// Fills in a profile just created by `init_if_needed`
+    pub fn init_if_new(&mut self, vote_account: Pubkey, bump: u8) {
+        if self.vote_account == Pubkey::default() {
+            self.vote_account = vote_account;
+            self.bump = bump;
+        }
+    }
+
+    pub fn record_proposal_authored(&mut self, max_open_proposals: u16) -> Result<()> {
+        require!(
+            self.open_proposals < max_open_proposals,
+            GovernanceError::TooManyOpenProposals
+        );
+        self.open_proposals += 1;
+        self.proposals_authored = self
+            .proposals_authored
+            .checked_add(1)
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Frees the slot of a proposal that was finalized or expired
+    pub fn record_proposal_closed(&mut self) {
+        self.open_proposals = self.open_proposals.saturating_sub(1);
+    }
+
+    pub fn record_support(&mut self) -> Result<()> {
+        self.proposals_supported = self
+            .proposals_supported
+            .checked_add(1)
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    pub fn record_vote(&mut self, epoch: u64) -> Result<()> {
+        self.proposals_voted = self
+            .proposals_voted
+            .checked_add(1)
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
+        self.last_vote_epoch = epoch;
+        Ok(())
+    }
+}
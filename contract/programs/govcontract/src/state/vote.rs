@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::tally::VoteLamports;
+
 #[account]
 #[derive(InitSpace)]
 pub struct Vote {
@@ -13,6 +15,29 @@ pub struct Vote {
     pub abstain_votes_lamports: u64,
     pub stake: u64,
     pub override_lamports: u64,
+    /// Vote account whose split this vote follows through a `GovernanceDelegation`,
+    /// `Pubkey::default()` once the validator votes directly
+    pub delegate: Pubkey,
     pub vote_timestamp: i64,
     pub bump: u8,
 }
+
+impl Vote {
+    pub fn is_delegated(&self) -> bool {
+        self.delegate != Pubkey::default()
+    }
+
+    pub fn vote_lamports(&self) -> VoteLamports {
+        VoteLamports {
+            for_votes: self.for_votes_lamports,
+            against_votes: self.against_votes_lamports,
+            abstain_votes: self.abstain_votes_lamports,
+        }
+    }
+
+    pub fn set_vote_lamports(&mut self, lamports: VoteLamports) {
+        self.for_votes_lamports = lamports.for_votes;
+        self.against_votes_lamports = lamports.against_votes;
+        self.abstain_votes_lamports = lamports.abstain_votes;
+    }
+}
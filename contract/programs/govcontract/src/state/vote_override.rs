@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::tally::VoteLamports;
+
 #[account]
 #[derive(InitSpace)]
 pub struct VoteOverride {
@@ -15,6 +17,24 @@ pub struct VoteOverride {
     pub against_votes_lamports: u64,
     pub abstain_votes_lamports: u64,
     pub stake_amount: u64,
+    /// Registered proxy that cast the override, `Pubkey::default()` if the staker did
+    pub proxy: Pubkey,
     pub vote_override_timestamp: i64,
     pub bump: u8,
 }
+
+impl VoteOverride {
+    pub fn vote_lamports(&self) -> VoteLamports {
+        VoteLamports {
+            for_votes: self.for_votes_lamports,
+            against_votes: self.against_votes_lamports,
+            abstain_votes: self.abstain_votes_lamports,
+        }
+    }
+
+    pub fn set_vote_lamports(&mut self, lamports: VoteLamports) {
+        self.for_votes_lamports = lamports.for_votes;
+        self.against_votes_lamports = lamports.against_votes;
+        self.abstain_votes_lamports = lamports.abstain_votes;
+    }
+}
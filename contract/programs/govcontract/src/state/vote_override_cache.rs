@@ -1,6 +1,12 @@
+use std::cell::{Ref, RefMut};
+
 use anchor_lang::prelude::*;
 
-#[account]
+use crate::{constants::ANCHOR_DISCRIMINATOR, error::GovernanceError, tally::VoteLamports};
+
+/// Delegator overrides made before their validator voted, applied by `cast_vote`.
+/// Zero-copy so the override instructions only touch the fields they update.
+#[account(zero_copy)]
 #[derive(InitSpace)]
 pub struct VoteOverrideCache {
     pub validator: Pubkey,
@@ -14,4 +20,113 @@ pub struct VoteOverrideCache {
     pub abstain_votes_lamports: u64,
     pub total_stake: u64,
     pub bump: u8,
+    pub padding: [u8; 7],
+}
+
+impl VoteOverrideCache {
+    /// Loads a cache created with `init_if_needed`. A new account only gets its
+    /// discriminator when the instruction exits, so it is loaded for initialization.
+    pub fn load_or_init<'a>(
+        loader: &'a AccountLoader<'_, VoteOverrideCache>,
+    ) -> Result<RefMut<'a, VoteOverrideCache>> {
+        loader.load_mut().or_else(|_| loader.load_init())
+    }
+
+    /// Loads a cache passed as an unchecked account, which only exists once a delegator overrode
+    pub fn load_from<'a>(info: &'a AccountInfo) -> Result<Ref<'a, VoteOverrideCache>> {
+        let data = info.try_borrow_data()?;
+        Self::check(info, &data)?;
+        Ok(Ref::map(data, |data| {
+            bytemuck::from_bytes(&data[ANCHOR_DISCRIMINATOR..])
+        }))
+    }
+
+    /// Mutable counterpart of `load_from`
+    pub fn load_mut_from<'a>(info: &'a AccountInfo) -> Result<RefMut<'a, VoteOverrideCache>> {
+        let data = info.try_borrow_mut_data()?;
+        Self::check(info, &data)?;
+        Ok(RefMut::map(data, |data| {
+            bytemuck::from_bytes_mut(&mut data[ANCHOR_DISCRIMINATOR..])
+        }))
+    }
+
+    fn check(info: &AccountInfo, data: &[u8]) -> Result<()> {
+        require!(
+            info.owner == &crate::ID
+                && data.len() == ANCHOR_DISCRIMINATOR + VoteOverrideCache::INIT_SPACE
+                && data[..ANCHOR_DISCRIMINATOR] == *VoteOverrideCache::DISCRIMINATOR,
+            GovernanceError::InvalidVoteOverrideCache
+        );
+        Ok(())
+    }
+
+    /// Fills in a new cache, or checks that an existing one belongs to this validator's vote
+    pub fn init_or_validate(
+        &mut self,
+        validator: Pubkey,
+        proposal: Pubkey,
+        vote_account_validator: Pubkey,
+        bump: u8,
+    ) -> Result<()> {
+        if self.total_stake == 0 {
+            self.validator = validator;
+            self.proposal = proposal;
+            self.vote_account_validator = vote_account_validator;
+            self.bump = bump;
+        } else {
+            require_keys_eq!(self.proposal, proposal, GovernanceError::InvalidVoteAccount);
+            require_keys_eq!(
+                self.vote_account_validator,
+                vote_account_validator,
+                GovernanceError::InvalidVoteAccount
+            );
+        }
+        Ok(())
+    }
+
+    pub fn vote_lamports(&self) -> VoteLamports {
+        VoteLamports {
+            for_votes: self.for_votes_lamports,
+            against_votes: self.against_votes_lamports,
+            abstain_votes: self.abstain_votes_lamports,
+        }
+    }
+
+    pub fn set_vote_lamports(&mut self, lamports: VoteLamports) {
+        self.for_votes_lamports = lamports.for_votes;
+        self.against_votes_lamports = lamports.against_votes;
+        self.abstain_votes_lamports = lamports.abstain_votes;
+    }
+
+    /// Accumulates a delegator's override into the cache
+    pub fn add_override(
+        &mut self,
+        for_votes_bp: u64,
+        against_votes_bp: u64,
+        abstain_votes_bp: u64,
+        lamports: VoteLamports,
+        stake: u64,
+    ) -> Result<()> {
+        self.for_votes_bp = self
+            .for_votes_bp
+            .checked_add(for_votes_bp)
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
+        self.against_votes_bp = self
+            .against_votes_bp
+            .checked_add(against_votes_bp)
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
+        self.abstain_votes_bp = self
+            .abstain_votes_bp
+            .checked_add(abstain_votes_bp)
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
+
+        self.set_vote_lamports(self.vote_lamports().checked_add(lamports)?);
+
+        self.total_stake = self
+            .total_stake
+            .checked_add(stake)
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
+
+        Ok(())
+    }
 }
@@ -0,0 +1,189 @@
+//! Vote tally arithmetic shared by the vote instructions.
+//!
+//! These functions only take and return numbers, so the invariants between proposal totals,
+//! validator votes and delegator overrides can be tested without any accounts.
+
+use anchor_lang::prelude::*;
+
+use crate::{calculate_vote_lamports, constants::BASIS_POINTS_MAX, error::GovernanceError};
+
+/// Lamports split across the three vote choices
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct VoteLamports {
+    pub for_votes: u64,
+    pub against_votes: u64,
+    pub abstain_votes: u64,
+}
+
+impl VoteLamports {
+    /// Splits `stake` by a basis point distribution, rounding each choice down
+    pub fn from_stake(
+        stake: u64,
+        for_votes_bp: u64,
+        against_votes_bp: u64,
+        abstain_votes_bp: u64,
+    ) -> Result<Self> {
+        Ok(Self {
+            for_votes: calculate_vote_lamports!(stake, for_votes_bp)?,
+            against_votes: calculate_vote_lamports!(stake, against_votes_bp)?,
+            abstain_votes: calculate_vote_lamports!(stake, abstain_votes_bp)?,
+        })
+    }
+
+    pub fn checked_add(self, other: Self) -> Result<Self> {
+        Ok(Self {
+            for_votes: self
+                .for_votes
+                .checked_add(other.for_votes)
+                .ok_or(GovernanceError::ArithmeticOverflow)?,
+            against_votes: self
+                .against_votes
+                .checked_add(other.against_votes)
+                .ok_or(GovernanceError::ArithmeticOverflow)?,
+            abstain_votes: self
+                .abstain_votes
+                .checked_add(other.abstain_votes)
+                .ok_or(GovernanceError::ArithmeticOverflow)?,
+        })
+    }
+
+    /// Lamports across all three choices
+    pub fn total(&self) -> Result<u64> {
+        self.for_votes
+            .checked_add(self.against_votes)
+            .and_then(|sum| sum.checked_add(self.abstain_votes))
+            .ok_or(GovernanceError::ArithmeticOverflow.into())
+    }
+
+    pub fn checked_sub(self, other: Self) -> Result<Self> {
+        Ok(Self {
+            for_votes: self
+                .for_votes
+                .checked_sub(other.for_votes)
+                .ok_or(GovernanceError::ArithmeticOverflow)?,
+            against_votes: self
+                .against_votes
+                .checked_sub(other.against_votes)
+                .ok_or(GovernanceError::ArithmeticOverflow)?,
+            abstain_votes: self
+                .abstain_votes
+                .checked_sub(other.abstain_votes)
+                .ok_or(GovernanceError::ArithmeticOverflow)?,
+        })
+    }
+}
+
+/// Checks that a basis point distribution adds up to 100%
+pub fn validate_distribution(
+    for_votes_bp: u64,
+    against_votes_bp: u64,
+    abstain_votes_bp: u64,
+) -> Result<()> {
+    let total_bp = for_votes_bp
+        .checked_add(against_votes_bp)
+        .and_then(|sum| sum.checked_add(abstain_votes_bp))
+        .ok_or(GovernanceError::ArithmeticOverflow)?;
+    require!(
+        total_bp == BASIS_POINTS_MAX,
+        GovernanceError::InvalidVoteDistribution
+    );
+    Ok(())
+}
+
+/// Proposal totals and the validator's own vote lamports after a tally change
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ValidatorTally {
+    pub totals: VoteLamports,
+    pub validator: VoteLamports,
+}
+
+/// First vote of a validator. Delegators that overrode before the validator voted are
+/// cached; their lamports are added and their stake is carved out of the validator's share.
+pub fn cast_vote(
+    totals: VoteLamports,
+    validator_stake: u64,
+    cached: VoteLamports,
+    cached_stake: u64,
+    for_votes_bp: u64,
+    against_votes_bp: u64,
+    abstain_votes_bp: u64,
+) -> Result<ValidatorTally> {
+    let validator = VoteLamports::from_stake(
+        remaining_stake(validator_stake, cached_stake)?,
+        for_votes_bp,
+        against_votes_bp,
+        abstain_votes_bp,
+    )?;
+
+    Ok(ValidatorTally {
+        totals: totals.checked_add(cached)?.checked_add(validator)?,
+        validator,
+    })
+}
+
+/// New distribution for a validator's existing vote, over the stake not overridden by delegators
+pub fn modify_vote(
+    totals: VoteLamports,
+    previous: VoteLamports,
+    validator_stake: u64,
+    overridden_stake: u64,
+    for_votes_bp: u64,
+    against_votes_bp: u64,
+    abstain_votes_bp: u64,
+) -> Result<ValidatorTally> {
+    let validator = VoteLamports::from_stake(
+        remaining_stake(validator_stake, overridden_stake)?,
+        for_votes_bp,
+        against_votes_bp,
+        abstain_votes_bp,
+    )?;
+
+    Ok(ValidatorTally {
+        totals: replace_votes(totals, previous, validator)?,
+        validator,
+    })
+}
+
+/// Delegators overriding a validator that already voted. Their lamports are added and the
+/// validator's vote is recomputed with its current distribution over the remaining stake.
+/// `overridden_stake` includes the stake of the delegators being applied.
+pub fn override_vote(
+    totals: VoteLamports,
+    previous: VoteLamports,
+    delegators: VoteLamports,
+    validator_stake: u64,
+    overridden_stake: u64,
+    for_votes_bp: u64,
+    against_votes_bp: u64,
+    abstain_votes_bp: u64,
+) -> Result<ValidatorTally> {
+    let validator = VoteLamports::from_stake(
+        remaining_stake(validator_stake, overridden_stake)?,
+        for_votes_bp,
+        against_votes_bp,
+        abstain_votes_bp,
+    )?;
+
+    Ok(ValidatorTally {
+        totals: totals
+            .checked_sub(previous)?
+            .checked_add(delegators)?
+            .checked_add(validator)?,
+        validator,
+    })
+}
+
+/// Swaps `previous` for `current` in a running tally, either proposal totals or an override cache
+pub fn replace_votes(
+    totals: VoteLamports,
+    previous: VoteLamports,
+    current: VoteLamports,
+) -> Result<VoteLamports> {
+    totals.checked_sub(previous)?.checked_add(current)
+}
+
+fn remaining_stake(validator_stake: u64, overridden_stake: u64) -> Result<u64> {
+    validator_stake
+        .checked_sub(overridden_stake)
+        .ok_or(GovernanceError::ArithmeticOverflow.into())
+}
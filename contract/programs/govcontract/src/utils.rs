@@ -1,3 +1,15 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        ed25519_program,
+        stake::state::StakeStateV2,
+        sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+    },
+    system_program::{self, Allocate, Assign, Transfer},
+};
+
+use crate::error::GovernanceError;
+
 /// Calculates the validator's stake weight in basis points (1 bp = 0.01%) relative to the cluster stake.
 ///
 /// This macro uses integer arithmetic to compute the stake weight by multiplying the validator's stake
@@ -157,3 +169,139 @@ pub fn get_epoch_slot_range(epoch: u64) -> (u64, u64) {
 
     (start_slot, end_slot)
 }
+
+/// Epoch containing `slot`, with the 432,000 slot epochs of `get_epoch_slot_range`
+pub fn get_slot_epoch(slot: u64) -> u64 {
+    const SLOTS_PER_EPOCH: u64 = 432_000;
+
+    slot / SLOTS_PER_EPOCH
+}
+
+/// Newest `(slot, hash)` entry of the SlotHashes sysvar. The sysvar is too large to
+/// deserialize, so only the entry count and the first entry are read.
+pub fn latest_slot_hash(slot_hashes: &AccountInfo) -> Result<(u64, [u8; 32])> {
+    let data = slot_hashes.try_borrow_data()?;
+    // Bincode layout: u64 entry count, then (u64 slot, [u8; 32] hash) entries, newest first
+    let entry = data
+        .get(..48)
+        .filter(|header| u64::from_le_bytes(header[..8].try_into().unwrap()) > 0)
+        .ok_or(GovernanceError::SlotHashesUnavailable)?;
+    let slot = u64::from_le_bytes(entry[8..16].try_into().unwrap());
+    let hash = entry[16..48].try_into().unwrap();
+    Ok((slot, hash))
+}
+
+/// Withdraw authority of an initialized or delegated stake account
+pub fn stake_withdrawer(stake_account: &AccountInfo) -> Result<Pubkey> {
+    let data = stake_account.try_borrow_data()?;
+    match StakeStateV2::deserialize(&mut &data[..]) {
+        Ok(StakeStateV2::Initialized(meta)) | Ok(StakeStateV2::Stake(meta, _, _)) => {
+            Ok(meta.authorized.withdrawer)
+        }
+        _ => err!(GovernanceError::InvalidStakeState),
+    }
+}
+
+/// Validator identity (`node_pubkey`) of a vote account. The vote state is too large to
+/// deserialize, so only the version tag and the identity after it are read.
+pub fn vote_account_identity(vote_account: &AccountInfo) -> Result<Pubkey> {
+    let data = vote_account.try_borrow_data()?;
+    let identity = data.get(4..36).ok_or(GovernanceError::InvalidVoteAccount)?;
+    Ok(Pubkey::try_from(identity).unwrap())
+}
+
+/// Checks that the instruction before the current one is an Ed25519 program instruction
+/// verifying `signer`'s signature of `message`. The runtime rejects the transaction if that
+/// signature is invalid, so only what was verified needs comparing here.
+pub fn verify_ed25519_signature(
+    instructions_sysvar: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> Result<()> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    let index = current_index
+        .checked_sub(1)
+        .ok_or(GovernanceError::InvalidVoteSignature)?;
+    let instruction = load_instruction_at_checked(index as usize, instructions_sysvar)?;
+    require_keys_eq!(
+        instruction.program_id,
+        ed25519_program::ID,
+        GovernanceError::InvalidVoteSignature
+    );
+
+    // Layout: u8 signature count, u8 padding, then per signature the u16 offsets of the
+    // signature, public key and message, each followed by the index of the instruction
+    // holding it (u16::MAX for the Ed25519 instruction itself), with the message size
+    // after the message offset
+    let data = &instruction.data;
+    require!(
+        data.len() >= 16 && data[0] == 1,
+        GovernanceError::InvalidVoteSignature
+    );
+    let read_u16 = |at: usize| u16::from_le_bytes([data[at], data[at + 1]]);
+    require!(
+        read_u16(4) == u16::MAX && read_u16(8) == u16::MAX && read_u16(14) == u16::MAX,
+        GovernanceError::InvalidVoteSignature
+    );
+    let public_key_offset = read_u16(6) as usize;
+    let message_offset = read_u16(10) as usize;
+    let message_size = read_u16(12) as usize;
+    require!(
+        data.get(public_key_offset..public_key_offset + 32) == Some(signer.as_ref())
+            && data.get(message_offset..message_offset + message_size) == Some(message),
+        GovernanceError::InvalidVoteSignature
+    );
+    Ok(())
+}
+
+/// Creates a PDA owned by this program, even if lamports were sent to the address beforehand.
+///
+/// `create_account` fails when the address already holds lamports, which would let anyone
+/// block the PDA by prefunding it. Instead the rent shortfall is topped up and the account
+/// is allocated and assigned.
+pub fn create_pda_account<'info>(
+    payer: &AccountInfo<'info>,
+    new_account: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let required_lamports = Rent::get()?.minimum_balance(space);
+    let current_lamports = new_account.lamports();
+    if current_lamports < required_lamports {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: new_account.clone(),
+                },
+            ),
+            required_lamports - current_lamports,
+        )?;
+    }
+
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Allocate {
+                account_to_allocate: new_account.clone(),
+            },
+            &[signer_seeds],
+        ),
+        space as u64,
+    )?;
+
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Assign {
+                account_to_assign: new_account.clone(),
+            },
+            &[signer_seeds],
+        ),
+        &crate::ID,
+    )?;
+
+    Ok(())
+}
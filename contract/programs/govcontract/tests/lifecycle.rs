@@ -0,0 +1,3876 @@
+//! End-to-end tests running govcontract against `mock-gov-v1` under solana-program-test.
+//!
+//! Both programs run as native processors, so `cargo test` works offline without building
+//! SBF binaries or starting a validator. `compute_unit_budgets` is the exception: compute
+//! units are only metered for SBF programs, so it runs under `cargo test-sbf -- --ignored`.
+
+use anchor_lang::{
+    error::{ErrorCode, ERROR_CODE_OFFSET},
+    prelude::{AccountInfo, Pubkey, Rent},
+    solana_program::{
+        ed25519_program,
+        entrypoint::ProgramResult,
+        slot_hashes::SlotHashes,
+        stake::{
+            program as stake_program,
+            state::{Authorized, Delegation, Lockup, Meta, Stake, StakeFlags, StakeStateV2},
+        },
+        sysvar::{instructions as instructions_sysvar, slot_hashes},
+        vote::{
+            program as vote_program,
+            state::{VoteInit, VoteState, VoteStateVersions},
+        },
+    },
+    system_program, AccountDeserialize, AccountSerialize, InstructionData, ToAccountMetas,
+};
+use govcontract::{
+    constants::{
+        DISCUSSION_EPOCHS, EMERGENCY_VOTING_EPOCHS, MAX_MIN_PROPOSAL_STAKE_LAMPORTS,
+        MAX_OPEN_PROPOSALS_PER_AUTHOR, MAX_PASS_THRESHOLD_BP, MAX_PROPOSAL_BOND_LAMPORTS,
+        MAX_QUORUM_BP, MAX_SUPPORT_THRESHOLD_BP, MIN_PROPOSAL_BOND_LAMPORTS,
+        PROPOSAL_BOND_LAMPORTS, QUORUM_BP, SNAPSHOT_EPOCH_EXTENSION, SNAPSHOT_SLOT_OFFSET,
+        VOTING_EPOCHS,
+    },
+    error::GovernanceError,
+    state::{
+        AntiSnipingConfig, Ballot, BondStatus, GovernanceConfig, GovernanceParameter,
+        ParameterChange, Proposal, ProposalAccountMeta, ProposalBond, ProposalClass,
+        ProposalContent, ProposalInstruction, ProposalOutcome, ProposalRegistryPage,
+        SecretBallotConfig, Signal, SignalLean, SignalTally, SignedVote, StakeSource, Support,
+        TreasuryTransfer, UnrevealedBallots, ValidatorGovernanceProfile, Vote, VoteChoice,
+        VoteOverride,
+    },
+};
+use mock_gov_v1::{merkle, MetaMerkleLeaf, MockGovError, StakeMerkleLeaf};
+use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::Account,
+    clock::Clock,
+    hash::hashv,
+    instruction::{AccountMeta, Instruction, InstructionError},
+    native_token::LAMPORTS_PER_SOL,
+    signature::{Keypair, Signer},
+    transaction::{Transaction, TransactionError},
+};
+
+// Matches the fixed epoch length used by `utils::get_epoch_slot_range`
+const SLOTS_PER_EPOCH: u64 = 432_000;
+const PROPOSAL_SEED: u64 = 1;
+const PROPOSAL_TITLE: &str = "Raise the compute unit limit";
+const PROPOSAL_DESCRIPTION: &str =
+    "https://github.com/solana-foundation/solana-improvement-documents/pull/1";
+
+/// Compute unit ceilings per instruction, checked by `compute_unit_budgets` against the SBF
+/// builds in CI. Lower a ceiling when an optimization lands so the gain can't silently regress.
+/// These have not been calibrated against an SBF run yet: set each from the usage the test
+/// prints, plus headroom.
+const COMPUTE_UNIT_BUDGETS: &[(&str, u64)] = &[
+    ("create_proposal", 72_000),
+    ("support_proposal", 66_000),
+    ("cast_vote", 66_000),
+    ("modify_vote", 51_000),
+    ("cast_vote_override", 82_000),
+    ("modify_vote_override", 72_000),
+    ("finalize_proposal", 16_000),
+];
+
+fn process_govcontract(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // Anchor's entry ties the slice and account lifetimes together, so hand it a leaked copy
+    let accounts = Box::leak(Box::new(accounts.to_vec()));
+    govcontract::entry(program_id, accounts, data)
+}
+
+fn process_mock_gov_v1(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let accounts = Box::leak(Box::new(accounts.to_vec()));
+    mock_gov_v1::entry(program_id, accounts, data)
+}
+
+struct Delegator {
+    staker: Keypair,
+    stake_account: Pubkey,
+    stake: u64,
+}
+
+struct Validator {
+    identity: Keypair,
+    vote_account: Pubkey,
+    own_stake_account: Pubkey,
+    own_stake: u64,
+    delegators: Vec<Delegator>,
+}
+
+impl Validator {
+    fn new(own_stake_sol: u64, delegator_stakes_sol: &[u64]) -> Self {
+        Self {
+            identity: Keypair::new(),
+            vote_account: Pubkey::new_unique(),
+            own_stake_account: Pubkey::new_unique(),
+            own_stake: own_stake_sol * LAMPORTS_PER_SOL,
+            delegators: delegator_stakes_sol
+                .iter()
+                .map(|stake_sol| Delegator {
+                    staker: Keypair::new(),
+                    stake_account: Pubkey::new_unique(),
+                    stake: stake_sol * LAMPORTS_PER_SOL,
+                })
+                .collect(),
+        }
+    }
+
+    fn active_stake(&self) -> u64 {
+        self.own_stake + self.delegators.iter().map(|d| d.stake).sum::<u64>()
+    }
+
+    fn stake_leaves(&self) -> Vec<StakeMerkleLeaf> {
+        let own = StakeMerkleLeaf {
+            voting_wallet: self.identity.pubkey(),
+            stake_account: self.own_stake_account,
+            active_stake: self.own_stake,
+        };
+        std::iter::once(own)
+            .chain(self.delegators.iter().map(|d| StakeMerkleLeaf {
+                voting_wallet: d.staker.pubkey(),
+                stake_account: d.stake_account,
+                active_stake: d.stake,
+            }))
+            .collect()
+    }
+
+    fn add_genesis_accounts(&self, program_test: &mut ProgramTest) {
+        program_test.add_account(self.identity.pubkey(), funded_account());
+        program_test.add_account(self.vote_account, vote_account(&self.identity.pubkey()));
+        program_test.add_account(
+            self.own_stake_account,
+            stake_account(&self.vote_account, &self.identity.pubkey(), self.own_stake),
+        );
+        for delegator in self.delegators.iter() {
+            program_test.add_account(delegator.staker.pubkey(), funded_account());
+            program_test.add_account(
+                delegator.stake_account,
+                stake_account(
+                    &self.vote_account,
+                    &delegator.staker.pubkey(),
+                    delegator.stake,
+                ),
+            );
+        }
+    }
+}
+
+fn funded_account() -> Account {
+    Account::new(100 * LAMPORTS_PER_SOL, 0, &system_program::ID)
+}
+
+fn vote_account(identity: &Pubkey) -> Account {
+    let vote_init = VoteInit {
+        node_pubkey: *identity,
+        authorized_voter: *identity,
+        authorized_withdrawer: *identity,
+        commission: 0,
+    };
+    let vote_state = VoteState::new(&vote_init, &Clock::default());
+    let mut account = Account::new(LAMPORTS_PER_SOL, VoteState::size_of(), &vote_program::ID);
+    VoteState::serialize(
+        &VoteStateVersions::new_current(vote_state),
+        &mut account.data,
+    )
+    .unwrap();
+    account
+}
+
+/// Stake active since genesis, so it counts toward the epoch stakes right away
+fn stake_account(vote_account: &Pubkey, staker: &Pubkey, stake: u64) -> Account {
+    let rent_exempt_reserve = Rent::default().minimum_balance(StakeStateV2::size_of());
+    let state = StakeStateV2::Stake(
+        Meta {
+            rent_exempt_reserve,
+            authorized: Authorized::auto(staker),
+            lockup: Lockup::default(),
+        },
+        Stake {
+            delegation: Delegation::new(vote_account, stake, u64::MAX),
+            credits_observed: 0,
+        },
+        StakeFlags::empty(),
+    );
+    Account::new_data_with_space(
+        rent_exempt_reserve + stake,
+        &state,
+        StakeStateV2::size_of(),
+        &stake_program::ID,
+    )
+    .unwrap()
+}
+
+/// Published consensus result, with every stake leaf and its proof. Epoch stake proposals
+/// have no consensus result and their leaves no proofs.
+struct Snapshot {
+    consensus_result: Option<Pubkey>,
+    stake_proofs: Vec<(StakeMerkleLeaf, Vec<[u8; 32]>)>,
+}
+
+impl Snapshot {
+    /// Stake leaves for an epoch stake proposal, checked against the stake accounts
+    fn epoch_stake(validators: &[&Validator]) -> Self {
+        Snapshot {
+            consensus_result: None,
+            stake_proofs: validators
+                .iter()
+                .flat_map(|validator| validator.stake_leaves())
+                .map(|leaf| (leaf, Vec::new()))
+                .collect(),
+        }
+    }
+
+    fn stake_proof(&self, stake_account: &Pubkey) -> (gov_v1::StakeMerkleLeaf, Vec<[u8; 32]>) {
+        let (leaf, proof) = self
+            .stake_proofs
+            .iter()
+            .find(|(leaf, _)| leaf.stake_account == *stake_account)
+            .expect("stake account is in the snapshot");
+        let leaf = gov_v1::StakeMerkleLeaf {
+            voting_wallet: leaf.voting_wallet,
+            stake_account: leaf.stake_account,
+            active_stake: leaf.active_stake,
+        };
+        (leaf, proof.clone())
+    }
+
+    fn snapshot_program(&self) -> Option<Pubkey> {
+        self.consensus_result.map(|_| mock_gov_v1::ID)
+    }
+
+    fn meta_merkle_proof(&self, vote_account: &Pubkey) -> Option<Pubkey> {
+        self.consensus_result
+            .map(|consensus_result| meta_merkle_proof_pda(&consensus_result, vote_account))
+    }
+}
+
+fn index_pda() -> Pubkey {
+    Pubkey::find_program_address(&[b"index"], &govcontract::ID).0
+}
+
+fn proposal_pda(seed: u64, vote_account: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"proposal", &seed.to_le_bytes(), vote_account.as_ref()],
+        &govcontract::ID,
+    )
+    .0
+}
+
+fn proposal_content_pda(proposal: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"proposal_content", proposal.as_ref()], &govcontract::ID).0
+}
+
+fn proposal_bond_pda(proposal: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"bond", proposal.as_ref()], &govcontract::ID).0
+}
+
+fn treasury_pda() -> Pubkey {
+    Pubkey::find_program_address(&[b"treasury"], &govcontract::ID).0
+}
+
+fn validator_profile_pda(vote_account: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"validator_profile", vote_account.as_ref()],
+        &govcontract::ID,
+    )
+    .0
+}
+
+fn event_authority_pda() -> Pubkey {
+    Pubkey::find_program_address(&[b"__event_authority"], &govcontract::ID).0
+}
+
+fn governance_config_pda() -> Pubkey {
+    Pubkey::find_program_address(&[b"governance_config"], &govcontract::ID).0
+}
+
+fn parameter_change_pda(proposal: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"parameter_change", proposal.as_ref()], &govcontract::ID).0
+}
+
+fn treasury_transfer_pda(proposal: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"treasury_transfer", proposal.as_ref()], &govcontract::ID).0
+}
+
+fn proposal_transaction_pda(proposal: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"proposal_transaction", proposal.as_ref()],
+        &govcontract::ID,
+    )
+    .0
+}
+
+fn governance_authority_pda() -> Pubkey {
+    Pubkey::find_program_address(&[b"governance"], &govcontract::ID).0
+}
+
+/// System program transfer signed by the governance PDA, bincode encoded by hand
+fn governance_transfer(recipient: Pubkey, lamports: u64) -> ProposalInstruction {
+    let mut data = 2u32.to_le_bytes().to_vec();
+    data.extend_from_slice(&lamports.to_le_bytes());
+    ProposalInstruction {
+        program_id: system_program::ID,
+        accounts: vec![
+            ProposalAccountMeta {
+                pubkey: governance_authority_pda(),
+                is_signer: true,
+                is_writable: true,
+            },
+            ProposalAccountMeta {
+                pubkey: recipient,
+                is_signer: false,
+                is_writable: true,
+            },
+        ],
+        data,
+    }
+}
+
+fn registry_page_pda(page: u32) -> Pubkey {
+    Pubkey::find_program_address(&[b"registry", &page.to_le_bytes()], &govcontract::ID).0
+}
+
+fn support_pda(proposal: &Pubkey, vote_account: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"support", proposal.as_ref(), vote_account.as_ref()],
+        &govcontract::ID,
+    )
+    .0
+}
+
+fn delegation_pda(vote_account: &Pubkey, proposal: Option<Pubkey>) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"delegation",
+            vote_account.as_ref(),
+            proposal.unwrap_or_default().as_ref(),
+        ],
+        &govcontract::ID,
+    )
+    .0
+}
+
+fn ballot_pda(vote_record: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"ballot", vote_record.as_ref()], &govcontract::ID).0
+}
+
+fn stake_proxy_pda(stake_account: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"stake_proxy", stake_account.as_ref()], &govcontract::ID).0
+}
+
+fn signal_pda(proposal: &Pubkey, vote_account: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"signal", proposal.as_ref(), vote_account.as_ref()],
+        &govcontract::ID,
+    )
+    .0
+}
+
+fn signal_tally_pda(proposal: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"signal_tally", proposal.as_ref()], &govcontract::ID).0
+}
+
+fn vote_pda(proposal: &Pubkey, vote_account: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"vote", proposal.as_ref(), vote_account.as_ref()],
+        &govcontract::ID,
+    )
+    .0
+}
+
+fn vote_override_cache_pda(proposal: &Pubkey, validator_vote: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"vote_override_cache",
+            proposal.as_ref(),
+            validator_vote.as_ref(),
+        ],
+        &govcontract::ID,
+    )
+    .0
+}
+
+fn vote_override_pda(proposal: &Pubkey, stake_account: &Pubkey, validator_vote: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"vote_override",
+            proposal.as_ref(),
+            stake_account.as_ref(),
+            validator_vote.as_ref(),
+        ],
+        &govcontract::ID,
+    )
+    .0
+}
+
+fn program_config_pda() -> Pubkey {
+    Pubkey::find_program_address(&[b"ProgramConfig"], &mock_gov_v1::ID).0
+}
+
+fn ballot_box_pda(snapshot_slot: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"BallotBox", &snapshot_slot.to_le_bytes()],
+        &mock_gov_v1::ID,
+    )
+    .0
+}
+
+fn consensus_result_pda(snapshot_slot: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"ConsensusResult", &snapshot_slot.to_le_bytes()],
+        &mock_gov_v1::ID,
+    )
+    .0
+}
+
+fn meta_merkle_proof_pda(consensus_result: &Pubkey, vote_account: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"MetaMerkleProof",
+            consensus_result.as_ref(),
+            vote_account.as_ref(),
+        ],
+        &mock_gov_v1::ID,
+    )
+    .0
+}
+
+fn governance_error(error: GovernanceError) -> u32 {
+    error as u32 + ERROR_CODE_OFFSET
+}
+
+fn mock_gov_error(error: MockGovError) -> u32 {
+    error as u32 + ERROR_CODE_OFFSET
+}
+
+fn assert_custom_error(result: Result<(), BanksClientError>, expected: u32) {
+    match result.expect_err("transaction should fail").unwrap() {
+        TransactionError::InstructionError(_, InstructionError::Custom(code)) => {
+            assert_eq!(code, expected)
+        }
+        other => panic!("expected custom error {expected}, got {other:?}"),
+    }
+}
+
+/// Ed25519 program instruction checking `signer`'s signature of `message`, with the public
+/// key, signature and message all stored in the instruction's own data
+fn ed25519_instruction(signer: &Keypair, message: &[u8]) -> Instruction {
+    const HEADER_LEN: u16 = 16;
+    let public_key_offset = HEADER_LEN;
+    let signature_offset = public_key_offset + 32;
+    let message_offset = signature_offset + 64;
+    let mut data = vec![1, 0];
+    for field in [
+        signature_offset,
+        u16::MAX,
+        public_key_offset,
+        u16::MAX,
+        message_offset,
+        message.len() as u16,
+        u16::MAX,
+    ] {
+        data.extend_from_slice(&field.to_le_bytes());
+    }
+    data.extend_from_slice(signer.pubkey().as_ref());
+    data.extend_from_slice(signer.sign_message(message).as_ref());
+    data.extend_from_slice(message);
+    Instruction {
+        program_id: ed25519_program::ID,
+        accounts: vec![],
+        data,
+    }
+}
+
+/// Creation arguments beyond the seed, defaulting to a plain standard proposal
+#[derive(Clone, Copy)]
+struct ProposalOptions {
+    anti_sniping: Option<AntiSnipingConfig>,
+    secret_ballot: Option<SecretBallotConfig>,
+    random_snapshot: bool,
+    class: ProposalClass,
+    stake_source: StakeSource,
+    supersedes: Option<Pubkey>,
+    depends_on: Option<Pubkey>,
+}
+
+impl Default for ProposalOptions {
+    fn default() -> Self {
+        Self {
+            anti_sniping: None,
+            secret_ballot: None,
+            random_snapshot: false,
+            class: ProposalClass::Standard,
+            stake_source: StakeSource::Snapshot,
+            supersedes: None,
+            depends_on: None,
+        }
+    }
+}
+
+struct TestEnv {
+    context: ProgramTestContext,
+    /// Compute units consumed by the last successful transaction
+    compute_units: u64,
+}
+
+impl TestEnv {
+    async fn start(validators: &[&Validator]) -> Self {
+        Self::start_with(validators, false).await
+    }
+
+    /// Starts the test validator, loading the SBF builds of both programs when `prefer_bpf` is set
+    async fn start_with(validators: &[&Validator], prefer_bpf: bool) -> Self {
+        let mut program_test = ProgramTest::new(
+            "govcontract",
+            govcontract::ID,
+            processor!(process_govcontract),
+        );
+        program_test.add_program(
+            "mock_gov_v1",
+            mock_gov_v1::ID,
+            processor!(process_mock_gov_v1),
+        );
+        program_test.prefer_bpf(prefer_bpf);
+        for validator in validators {
+            validator.add_genesis_accounts(&mut program_test);
+        }
+
+        let mut env = Self {
+            context: program_test.start_with_context().await,
+            compute_units: 0,
+        };
+
+        // Cross each epoch boundary one at a time so the epoch stakes of every epoch
+        // used by create and support include the genesis stake accounts
+        env.warp_to_epoch(1).await;
+        env.warp_to_epoch(2).await;
+
+        let payer = env.context.payer.pubkey();
+        env.send(
+            Instruction {
+                program_id: govcontract::ID,
+                accounts: govcontract::accounts::InitializedIndex {
+                    signer: payer,
+                    proposal_index: index_pda(),
+                    system_program: system_program::ID,
+                    event_authority: event_authority_pda(),
+                    program: govcontract::ID,
+                }
+                .to_account_metas(None),
+                data: govcontract::instruction::InitializeIndex {}.data(),
+            },
+            &[],
+        )
+        .await
+        .unwrap();
+        env.send(
+            Instruction {
+                program_id: govcontract::ID,
+                accounts: govcontract::accounts::InitializeGovernanceConfig {
+                    signer: payer,
+                    governance_config: governance_config_pda(),
+                    system_program: system_program::ID,
+                    event_authority: event_authority_pda(),
+                    program: govcontract::ID,
+                }
+                .to_account_metas(None),
+                data: govcontract::instruction::InitializeGovernanceConfig {}.data(),
+            },
+            &[],
+        )
+        .await
+        .unwrap();
+        env.send(
+            Instruction {
+                program_id: mock_gov_v1::ID,
+                accounts: mock_gov_v1::accounts::InitProgramConfig {
+                    payer,
+                    authority: payer,
+                    program_config: program_config_pda(),
+                    system_program: system_program::ID,
+                }
+                .to_account_metas(None),
+                data: mock_gov_v1::instruction::InitProgramConfig {}.data(),
+            },
+            &[],
+        )
+        .await
+        .unwrap();
+
+        env
+    }
+
+    async fn warp_to_epoch(&mut self, epoch: u64) {
+        let slot = self
+            .context
+            .genesis_config()
+            .epoch_schedule
+            .get_first_slot_in_epoch(epoch);
+        self.context.warp_to_slot(slot).unwrap();
+    }
+
+    async fn current_epoch(&mut self) -> u64 {
+        self.context
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await
+            .unwrap()
+            .epoch
+    }
+
+    async fn send(
+        &mut self,
+        instruction: Instruction,
+        signers: &[&Keypair],
+    ) -> Result<(), BanksClientError> {
+        self.send_all(&[instruction], signers).await
+    }
+
+    async fn send_all(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.context.banks_client.get_latest_blockhash().await?;
+        let mut all_signers = vec![&self.context.payer];
+        all_signers.extend_from_slice(signers);
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.context.payer.pubkey()),
+            &all_signers,
+            blockhash,
+        );
+        let result = self
+            .context
+            .banks_client
+            .process_transaction_with_metadata(transaction)
+            .await?;
+        result.result.map_err(BanksClientError::TransactionError)?;
+        if let Some(metadata) = result.metadata {
+            self.compute_units = metadata.compute_units_consumed;
+        }
+        Ok(())
+    }
+
+    fn assert_within_budget(&self, instruction: &str) {
+        let (_, budget) = COMPUTE_UNIT_BUDGETS
+            .iter()
+            .find(|(name, _)| *name == instruction)
+            .expect("instruction has a compute unit budget");
+        println!(
+            "{instruction}: {} compute units, budget {budget}",
+            self.compute_units
+        );
+        assert!(
+            self.compute_units <= *budget,
+            "{instruction} used {} compute units, budget is {budget}",
+            self.compute_units
+        );
+    }
+
+    async fn account<T: AccountDeserialize>(&mut self, address: Pubkey) -> T {
+        let account = self
+            .context
+            .banks_client
+            .get_account(address)
+            .await
+            .unwrap()
+            .expect("account exists");
+        T::try_deserialize(&mut account.data.as_slice()).unwrap()
+    }
+
+    /// Rewrites the governance config in place, standing in for an enacted parameter change
+    async fn set_governance_config(&mut self, update: impl FnOnce(&mut GovernanceConfig)) {
+        let address = governance_config_pda();
+        let mut account = self
+            .context
+            .banks_client
+            .get_account(address)
+            .await
+            .unwrap()
+            .expect("account exists");
+        let mut config = GovernanceConfig::try_deserialize(&mut account.data.as_slice()).unwrap();
+        update(&mut config);
+        account.data.clear();
+        config.try_serialize(&mut account.data).unwrap();
+        self.context.set_account(&address, &account.into());
+    }
+
+    async fn balance(&mut self, address: Pubkey) -> u64 {
+        self.context
+            .banks_client
+            .get_balance(address)
+            .await
+            .unwrap()
+    }
+
+    async fn proposal(&mut self, address: Pubkey) -> Proposal {
+        self.account(address).await
+    }
+
+    async fn create_proposal(&mut self, author: &Validator) -> Pubkey {
+        self.create_proposal_with(author, None, false).await
+    }
+
+    async fn create_proposal_with(
+        &mut self,
+        author: &Validator,
+        secret_ballot: Option<SecretBallotConfig>,
+        random_snapshot: bool,
+    ) -> Pubkey {
+        let options = ProposalOptions {
+            secret_ballot,
+            random_snapshot,
+            ..Default::default()
+        };
+        self.create_proposal_seeded(author, PROPOSAL_SEED, options)
+            .await
+            .unwrap()
+    }
+
+    async fn create_emergency_proposal(&mut self, author: &Validator) -> Pubkey {
+        let options = ProposalOptions {
+            class: ProposalClass::Emergency,
+            ..Default::default()
+        };
+        self.create_proposal_seeded(author, PROPOSAL_SEED, options)
+            .await
+            .unwrap()
+    }
+
+    async fn create_proposal_seeded(
+        &mut self,
+        author: &Validator,
+        seed: u64,
+        options: ProposalOptions,
+    ) -> Result<Pubkey, BanksClientError> {
+        let proposal = proposal_pda(seed, &author.vote_account);
+        self.send(
+            Instruction {
+                program_id: govcontract::ID,
+                accounts: govcontract::accounts::CreateProposal {
+                    signer: author.identity.pubkey(),
+                    proposal,
+                    proposal_content: proposal_content_pda(&proposal),
+                    proposal_bond: proposal_bond_pda(&proposal),
+                    proposal_index: index_pda(),
+                    registry_page: registry_page_pda(0),
+                    spl_vote_account: author.vote_account,
+                    validator_profile: validator_profile_pda(&author.vote_account),
+                    governance_config: governance_config_pda(),
+                    supersedes: options.supersedes,
+                    depends_on: options.depends_on,
+                    system_program: system_program::ID,
+                    event_authority: event_authority_pda(),
+                    program: govcontract::ID,
+                }
+                .to_account_metas(None),
+                data: govcontract::instruction::CreateProposal {
+                    seed,
+                    title: PROPOSAL_TITLE.to_string(),
+                    description: PROPOSAL_DESCRIPTION.to_string(),
+                    anti_sniping: options.anti_sniping,
+                    secret_ballot: options.secret_ballot,
+                    random_snapshot: options.random_snapshot,
+                    class: options.class,
+                    stake_source: options.stake_source,
+                }
+                .data(),
+            },
+            &[&author.identity],
+        )
+        .await?;
+        Ok(proposal)
+    }
+
+    async fn support_proposal(
+        &mut self,
+        proposal: Pubkey,
+        supporter: &Validator,
+    ) -> Result<(), BanksClientError> {
+        self.support_proposal_as(proposal, supporter, &supporter.identity)
+            .await
+    }
+
+    /// Supports with `supporter`'s vote account, signed by `signer`
+    async fn support_proposal_as(
+        &mut self,
+        proposal: Pubkey,
+        supporter: &Validator,
+        signer: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        let epoch = self.current_epoch().await;
+        let current = self.proposal(proposal).await;
+        // Same derivation support_proposal uses for a fixed snapshot slot; a random one is
+        // only known afterwards, so its ballot box is left to `init_ballot_box`. Epoch stake
+        // needs none.
+        let snapshot_stake = current.stake_source() == StakeSource::Snapshot;
+        let ballot_box = (!current.is_random_snapshot() && snapshot_stake).then(|| {
+            ballot_box_pda(
+                (epoch + current.discussion_epochs + SNAPSHOT_EPOCH_EXTENSION) * SLOTS_PER_EPOCH
+                    + SNAPSHOT_SLOT_OFFSET,
+            )
+        });
+        self.send(
+            Instruction {
+                program_id: govcontract::ID,
+                accounts: govcontract::accounts::SupportProposal {
+                    signer: signer.pubkey(),
+                    proposal,
+                    support: support_pda(&proposal, &supporter.vote_account),
+                    spl_vote_account: supporter.vote_account,
+                    validator_profile: validator_profile_pda(&supporter.vote_account),
+                    ballot_box,
+                    ballot_program: mock_gov_v1::ID,
+                    program_config: Some(program_config_pda()),
+                    system_program: system_program::ID,
+                    event_authority: event_authority_pda(),
+                    program: govcontract::ID,
+                }
+                .to_account_metas(None),
+                data: govcontract::instruction::SupportProposal {}.data(),
+            },
+            &[signer],
+        )
+        .await
+    }
+
+    async fn draw_snapshot_slot(&mut self, proposal: Pubkey) -> Result<(), BanksClientError> {
+        let payer = self.context.payer.pubkey();
+        self.send(
+            Instruction {
+                program_id: govcontract::ID,
+                accounts: govcontract::accounts::DrawSnapshotSlot {
+                    signer: payer,
+                    proposal,
+                    ballot_program: mock_gov_v1::ID,
+                    slot_hashes: slot_hashes::ID,
+                    event_authority: event_authority_pda(),
+                    program: govcontract::ID,
+                }
+                .to_account_metas(None),
+                data: govcontract::instruction::DrawSnapshotSlot {}.data(),
+            },
+            &[],
+        )
+        .await
+    }
+
+    async fn flush_merkle_root(
+        &mut self,
+        proposal: Pubkey,
+        author: &Validator,
+    ) -> Result<(), BanksClientError> {
+        let epoch = self.current_epoch().await;
+        let current = self.proposal(proposal).await;
+        // A fixed snapshot moves to the same slot support_proposal would pick in this epoch; a
+        // random one keeps its slot and ballot box
+        let ballot_box = (!current.is_random_snapshot()).then(|| {
+            ballot_box_pda(
+                (epoch + SNAPSHOT_EPOCH_EXTENSION) * SLOTS_PER_EPOCH + SNAPSHOT_SLOT_OFFSET,
+            )
+        });
+        self.send(
+            Instruction {
+                program_id: govcontract::ID,
+                accounts: govcontract::accounts::FlushMerkleRoot {
+                    signer: author.identity.pubkey(),
+                    proposal,
+                    ballot_box,
+                    ballot_program: mock_gov_v1::ID,
+                    program_config: program_config_pda(),
+                    system_program: system_program::ID,
+                    event_authority: event_authority_pda(),
+                    program: govcontract::ID,
+                }
+                .to_account_metas(None),
+                data: govcontract::instruction::FlushMerkleRoot {}.data(),
+            },
+            &[&author.identity],
+        )
+        .await
+    }
+
+    async fn init_ballot_box(&mut self, proposal: Pubkey) -> Result<(), BanksClientError> {
+        let snapshot_slot = self.proposal(proposal).await.snapshot_slot;
+        let payer = self.context.payer.pubkey();
+        self.send(
+            Instruction {
+                program_id: govcontract::ID,
+                accounts: govcontract::accounts::InitBallotBox {
+                    signer: payer,
+                    proposal,
+                    ballot_box: ballot_box_pda(snapshot_slot),
+                    ballot_program: mock_gov_v1::ID,
+                    program_config: program_config_pda(),
+                    system_program: system_program::ID,
+                }
+                .to_account_metas(None),
+                data: govcontract::instruction::InitBallotBox {}.data(),
+            },
+            &[],
+        )
+        .await
+    }
+
+    async fn signal(
+        &mut self,
+        proposal: Pubkey,
+        validator: &Validator,
+        lean: SignalLean,
+    ) -> Result<(), BanksClientError> {
+        self.send(
+            Instruction {
+                program_id: govcontract::ID,
+                accounts: govcontract::accounts::SignalProposal {
+                    signer: validator.identity.pubkey(),
+                    proposal,
+                    signal: signal_pda(&proposal, &validator.vote_account),
+                    signal_tally: signal_tally_pda(&proposal),
+                    spl_vote_account: validator.vote_account,
+                    system_program: system_program::ID,
+                    event_authority: event_authority_pda(),
+                    program: govcontract::ID,
+                }
+                .to_account_metas(None),
+                data: govcontract::instruction::Signal { lean }.data(),
+            },
+            &[&validator.identity],
+        )
+        .await
+    }
+
+    async fn create_consensus_result(&mut self, snapshot_slot: u64, meta_merkle_root: [u8; 32]) {
+        let payer = self.context.payer.pubkey();
+        self.send(
+            Instruction {
+                program_id: mock_gov_v1::ID,
+                accounts: mock_gov_v1::accounts::CreateConsensusResult {
+                    payer,
+                    consensus_result: consensus_result_pda(snapshot_slot),
+                    system_program: system_program::ID,
+                }
+                .to_account_metas(None),
+                data: mock_gov_v1::instruction::CreateConsensusResult {
+                    snapshot_slot,
+                    meta_merkle_root,
+                    snapshot_hash: [7; 32],
+                    tie_breaker_consensus: false,
+                }
+                .data(),
+            },
+            &[],
+        )
+        .await
+        .unwrap();
+    }
+
+    async fn init_meta_merkle_proof(
+        &mut self,
+        consensus_result: Pubkey,
+        meta_merkle_leaf: MetaMerkleLeaf,
+        meta_merkle_proof: Vec<[u8; 32]>,
+    ) {
+        let payer = self.context.payer.pubkey();
+        self.send(
+            Instruction {
+                program_id: mock_gov_v1::ID,
+                accounts: mock_gov_v1::accounts::InitMetaMerkleProof {
+                    payer,
+                    merkle_proof: meta_merkle_proof_pda(
+                        &consensus_result,
+                        &meta_merkle_leaf.vote_account,
+                    ),
+                    consensus_result,
+                    system_program: system_program::ID,
+                }
+                .to_account_metas(None),
+                data: mock_gov_v1::instruction::InitMetaMerkleProof {
+                    meta_merkle_leaf,
+                    meta_merkle_proof,
+                    // Closable once govcontract allows it, as svmgov creates them
+                    close_timestamp: 1,
+                }
+                .data(),
+            },
+            &[],
+        )
+        .await
+        .unwrap();
+    }
+
+    /// Builds the stake and meta merkle trees for the proposal's snapshot slot and publishes them
+    async fn publish_snapshot(&mut self, proposal: Pubkey, validators: &[&Validator]) -> Snapshot {
+        let snapshot_slot = self.proposal(proposal).await.snapshot_slot;
+
+        let mut stake_proofs = Vec::new();
+        let mut meta_leaves = Vec::new();
+        for validator in validators {
+            let stake_leaves = validator.stake_leaves();
+            let stake_hashes = stake_leaves
+                .iter()
+                .map(merkle::leaf_hash)
+                .collect::<Vec<_>>();
+            let (stake_merkle_root, proofs) = merkle::build(&stake_hashes);
+            stake_proofs.extend(stake_leaves.into_iter().zip(proofs));
+            meta_leaves.push(MetaMerkleLeaf {
+                voting_wallet: validator.identity.pubkey(),
+                vote_account: validator.vote_account,
+                stake_merkle_root,
+                active_stake: validator.active_stake(),
+            });
+        }
+
+        let meta_hashes = meta_leaves
+            .iter()
+            .map(merkle::leaf_hash)
+            .collect::<Vec<_>>();
+        let (meta_merkle_root, meta_proofs) = merkle::build(&meta_hashes);
+        self.create_consensus_result(snapshot_slot, meta_merkle_root)
+            .await;
+
+        let consensus_result = consensus_result_pda(snapshot_slot);
+        for (leaf, proof) in meta_leaves.into_iter().zip(meta_proofs) {
+            self.init_meta_merkle_proof(consensus_result, leaf, proof)
+                .await;
+        }
+
+        Snapshot {
+            consensus_result: Some(consensus_result),
+            stake_proofs,
+        }
+    }
+
+    /// Creates a proposal at the current epoch and activates voting by supporting it next epoch
+    async fn open_proposal(&mut self, author: &Validator) -> Pubkey {
+        self.open_proposal_with(author, None).await
+    }
+
+    async fn open_proposal_with(
+        &mut self,
+        author: &Validator,
+        secret_ballot: Option<SecretBallotConfig>,
+    ) -> Pubkey {
+        let proposal = self
+            .create_proposal_with(author, secret_ballot, false)
+            .await;
+        let creation_epoch = self.current_epoch().await;
+        self.warp_to_epoch(creation_epoch + 1).await;
+        self.support_proposal(proposal, author).await.unwrap();
+        proposal
+    }
+
+    async fn cast_vote(
+        &mut self,
+        proposal: Pubkey,
+        snapshot: &Snapshot,
+        validator: &Validator,
+        bp: [u64; 3],
+    ) -> Result<(), BanksClientError> {
+        let vote = vote_pda(&proposal, &validator.vote_account);
+        self.send(
+            Instruction {
+                program_id: govcontract::ID,
+                accounts: govcontract::accounts::CastVote {
+                    signer: validator.identity.pubkey(),
+                    proposal,
+                    vote,
+                    spl_vote_account: validator.vote_account,
+                    validator_profile: validator_profile_pda(&validator.vote_account),
+                    vote_override_cache: vote_override_cache_pda(&proposal, &vote),
+                    snapshot_program: snapshot.snapshot_program(),
+                    consensus_result: snapshot.consensus_result,
+                    meta_merkle_proof: snapshot.meta_merkle_proof(&validator.vote_account),
+                    system_program: system_program::ID,
+                    event_authority: event_authority_pda(),
+                    program: govcontract::ID,
+                }
+                .to_account_metas(None),
+                data: govcontract::instruction::CastVote {
+                    for_votes_bp: bp[0],
+                    against_votes_bp: bp[1],
+                    abstain_votes_bp: bp[2],
+                }
+                .data(),
+            },
+            &[&validator.identity],
+        )
+        .await
+    }
+
+    /// Relays `vote` against `proposal` from the test payer. `signature` names the wallet and
+    /// vote the preceding Ed25519 instruction verifies; `None` leaves that instruction out.
+    async fn cast_vote_signed(
+        &mut self,
+        proposal: Pubkey,
+        snapshot: &Snapshot,
+        vote: &SignedVote,
+        signature: Option<(&Keypair, &SignedVote)>,
+    ) -> Result<(), BanksClientError> {
+        let vote_record = vote_pda(&proposal, &vote.vote_account);
+        let mut instructions: Vec<Instruction> = signature
+            .map(|(signer, signed)| ed25519_instruction(signer, &signed.message()))
+            .into_iter()
+            .collect();
+        instructions.push(Instruction {
+            program_id: govcontract::ID,
+            accounts: govcontract::accounts::CastVoteSigned {
+                payer: self.context.payer.pubkey(),
+                proposal,
+                vote: vote_record,
+                spl_vote_account: vote.vote_account,
+                validator_profile: validator_profile_pda(&vote.vote_account),
+                vote_override_cache: vote_override_cache_pda(&proposal, &vote_record),
+                snapshot_program: snapshot.snapshot_program(),
+                consensus_result: snapshot.consensus_result,
+                meta_merkle_proof: snapshot.meta_merkle_proof(&vote.vote_account),
+                instructions: instructions_sysvar::ID,
+                system_program: system_program::ID,
+                event_authority: event_authority_pda(),
+                program: govcontract::ID,
+            }
+            .to_account_metas(None),
+            data: govcontract::instruction::CastVoteSigned {
+                signed_vote: vote.clone(),
+            }
+            .data(),
+        });
+        self.send_all(&instructions, &[]).await
+    }
+
+    async fn register_stake_proxy(
+        &mut self,
+        delegator: &Delegator,
+        proxy: &Pubkey,
+    ) -> Result<(), BanksClientError> {
+        self.register_stake_proxy_as(delegator, &delegator.staker, proxy)
+            .await
+    }
+
+    /// Registers the proxy signed by `signer`, which must be the stake account's withdraw
+    /// authority on chain
+    async fn register_stake_proxy_as(
+        &mut self,
+        delegator: &Delegator,
+        signer: &Keypair,
+        proxy: &Pubkey,
+    ) -> Result<(), BanksClientError> {
+        self.send(
+            Instruction {
+                program_id: govcontract::ID,
+                accounts: govcontract::accounts::RegisterStakeProxy {
+                    signer: signer.pubkey(),
+                    spl_stake_account: delegator.stake_account,
+                    stake_proxy: stake_proxy_pda(&delegator.stake_account),
+                    system_program: system_program::ID,
+                    event_authority: event_authority_pda(),
+                    program: govcontract::ID,
+                }
+                .to_account_metas(None),
+                data: govcontract::instruction::RegisterStakeProxy { proxy: *proxy }.data(),
+            },
+            &[signer],
+        )
+        .await
+    }
+
+    async fn revoke_stake_proxy(&mut self, delegator: &Delegator) -> Result<(), BanksClientError> {
+        self.send(
+            Instruction {
+                program_id: govcontract::ID,
+                accounts: govcontract::accounts::RevokeStakeProxy {
+                    signer: delegator.staker.pubkey(),
+                    spl_stake_account: delegator.stake_account,
+                    stake_proxy: stake_proxy_pda(&delegator.stake_account),
+                    event_authority: event_authority_pda(),
+                    program: govcontract::ID,
+                }
+                .to_account_metas(None),
+                data: govcontract::instruction::RevokeStakeProxy {}.data(),
+            },
+            &[&delegator.staker],
+        )
+        .await
+    }
+
+    async fn set_governance_delegation(
+        &mut self,
+        delegator: &Validator,
+        delegate: &Validator,
+        proposal: Option<Pubkey>,
+    ) -> Result<(), BanksClientError> {
+        self.send(
+            Instruction {
+                program_id: govcontract::ID,
+                accounts: govcontract::accounts::SetGovernanceDelegation {
+                    signer: delegator.identity.pubkey(),
+                    spl_vote_account: delegator.vote_account,
+                    delegation: delegation_pda(&delegator.vote_account, proposal),
+                    system_program: system_program::ID,
+                    event_authority: event_authority_pda(),
+                    program: govcontract::ID,
+                }
+                .to_account_metas(None),
+                data: govcontract::instruction::SetGovernanceDelegation {
+                    delegate: delegate.vote_account,
+                    proposal,
+                }
+                .data(),
+            },
+            &[&delegator.identity],
+        )
+        .await
+    }
+
+    /// Cranks the delegator's vote using the delegation for `delegation_proposal`
+    async fn cast_delegated_vote(
+        &mut self,
+        proposal: Pubkey,
+        snapshot: &Snapshot,
+        delegator: &Validator,
+        delegate: &Validator,
+        delegation_proposal: Option<Pubkey>,
+    ) -> Result<(), BanksClientError> {
+        let vote = vote_pda(&proposal, &delegator.vote_account);
+        self.send(
+            Instruction {
+                program_id: govcontract::ID,
+                accounts: govcontract::accounts::CastDelegatedVote {
+                    signer: self.context.payer.pubkey(),
+                    proposal,
+                    delegation: delegation_pda(&delegator.vote_account, delegation_proposal),
+                    proposal_delegation: delegation_pda(&delegator.vote_account, Some(proposal)),
+                    spl_vote_account: delegator.vote_account,
+                    vote,
+                    delegate_vote: vote_pda(&proposal, &delegate.vote_account),
+                    vote_override_cache: vote_override_cache_pda(&proposal, &vote),
+                    snapshot_program: snapshot.snapshot_program(),
+                    consensus_result: snapshot.consensus_result,
+                    meta_merkle_proof: snapshot.meta_merkle_proof(&delegator.vote_account),
+                    system_program: system_program::ID,
+                    event_authority: event_authority_pda(),
+                    program: govcontract::ID,
+                }
+                .to_account_metas(None),
+                data: govcontract::instruction::CastDelegatedVote {}.data(),
+            },
+            &[],
+        )
+        .await
+    }
+
+    async fn modify_vote(
+        &mut self,
+        proposal: Pubkey,
+        snapshot: &Snapshot,
+        validator: &Validator,
+        bp: [u64; 3],
+    ) -> Result<(), BanksClientError> {
+        self.send(
+            Instruction {
+                program_id: govcontract::ID,
+                accounts: govcontract::accounts::ModifyVote {
+                    signer: validator.identity.pubkey(),
+                    proposal,
+                    vote: vote_pda(&proposal, &validator.vote_account),
+                    spl_vote_account: validator.vote_account,
+                    snapshot_program: snapshot.snapshot_program(),
+                    consensus_result: snapshot.consensus_result,
+                    meta_merkle_proof: snapshot.meta_merkle_proof(&validator.vote_account),
+                    system_program: system_program::ID,
+                    event_authority: event_authority_pda(),
+                    program: govcontract::ID,
+                }
+                .to_account_metas(None),
+                data: govcontract::instruction::ModifyVote {
+                    for_votes_bp: bp[0],
+                    against_votes_bp: bp[1],
+                    abstain_votes_bp: bp[2],
+                }
+                .data(),
+            },
+            &[&validator.identity],
+        )
+        .await
+    }
+
+    async fn cast_vote_override(
+        &mut self,
+        proposal: Pubkey,
+        snapshot: &Snapshot,
+        validator: &Validator,
+        delegator: &Delegator,
+        bp: [u64; 3],
+    ) -> Result<(), BanksClientError> {
+        self.cast_vote_override_as(
+            proposal,
+            snapshot,
+            validator,
+            delegator,
+            &delegator.staker,
+            bp,
+        )
+        .await
+    }
+
+    /// Signs the override with `signer`, passing the stake account's proxy registration when
+    /// `signer` is not the staker
+    async fn cast_vote_override_as(
+        &mut self,
+        proposal: Pubkey,
+        snapshot: &Snapshot,
+        validator: &Validator,
+        delegator: &Delegator,
+        signer: &Keypair,
+        bp: [u64; 3],
+    ) -> Result<(), BanksClientError> {
+        let stake_proxy = (signer.pubkey() != delegator.staker.pubkey())
+            .then(|| stake_proxy_pda(&delegator.stake_account));
+        let validator_vote = vote_pda(&proposal, &validator.vote_account);
+        let (stake_merkle_leaf, stake_merkle_proof) =
+            snapshot.stake_proof(&delegator.stake_account);
+        self.send(
+            Instruction {
+                program_id: govcontract::ID,
+                accounts: govcontract::accounts::CastVoteOverride {
+                    signer: signer.pubkey(),
+                    proposal,
+                    validator_vote,
+                    spl_vote_account: validator.vote_account,
+                    vote_override: vote_override_pda(
+                        &proposal,
+                        &delegator.stake_account,
+                        &validator_vote,
+                    ),
+                    vote_override_cache: vote_override_cache_pda(&proposal, &validator_vote),
+                    spl_stake_account: delegator.stake_account,
+                    stake_proxy,
+                    snapshot_program: snapshot.snapshot_program(),
+                    consensus_result: snapshot.consensus_result,
+                    meta_merkle_proof: snapshot.meta_merkle_proof(&validator.vote_account),
+                    system_program: system_program::ID,
+                    event_authority: event_authority_pda(),
+                    program: govcontract::ID,
+                }
+                .to_account_metas(None),
+                data: govcontract::instruction::CastVoteOverride {
+                    for_votes_bp: bp[0],
+                    against_votes_bp: bp[1],
+                    abstain_votes_bp: bp[2],
+                    stake_merkle_proof,
+                    stake_merkle_leaf,
+                }
+                .data(),
+            },
+            &[signer],
+        )
+        .await
+    }
+
+    async fn modify_vote_override(
+        &mut self,
+        proposal: Pubkey,
+        snapshot: &Snapshot,
+        validator: &Validator,
+        delegator: &Delegator,
+        bp: [u64; 3],
+    ) -> Result<(), BanksClientError> {
+        self.modify_vote_override_as(
+            proposal,
+            snapshot,
+            validator,
+            delegator,
+            &delegator.staker,
+            bp,
+        )
+        .await
+    }
+
+    /// Signs the override with `signer`, passing the stake account's proxy registration when
+    /// `signer` is not the staker
+    async fn modify_vote_override_as(
+        &mut self,
+        proposal: Pubkey,
+        snapshot: &Snapshot,
+        validator: &Validator,
+        delegator: &Delegator,
+        signer: &Keypair,
+        bp: [u64; 3],
+    ) -> Result<(), BanksClientError> {
+        let stake_proxy = (signer.pubkey() != delegator.staker.pubkey())
+            .then(|| stake_proxy_pda(&delegator.stake_account));
+        let validator_vote = vote_pda(&proposal, &validator.vote_account);
+        let (stake_merkle_leaf, stake_merkle_proof) =
+            snapshot.stake_proof(&delegator.stake_account);
+        self.send(
+            Instruction {
+                program_id: govcontract::ID,
+                accounts: govcontract::accounts::ModifyVoteOverride {
+                    signer: signer.pubkey(),
+                    proposal,
+                    validator_vote,
+                    spl_vote_account: validator.vote_account,
+                    vote_override: vote_override_pda(
+                        &proposal,
+                        &delegator.stake_account,
+                        &validator_vote,
+                    ),
+                    vote_override_cache: vote_override_cache_pda(&proposal, &validator_vote),
+                    spl_stake_account: delegator.stake_account,
+                    stake_proxy,
+                    snapshot_program: snapshot.snapshot_program(),
+                    consensus_result: snapshot.consensus_result,
+                    meta_merkle_proof: snapshot.meta_merkle_proof(&validator.vote_account),
+                    system_program: system_program::ID,
+                    event_authority: event_authority_pda(),
+                    program: govcontract::ID,
+                }
+                .to_account_metas(None),
+                data: govcontract::instruction::ModifyVoteOverride {
+                    for_votes_bp: bp[0],
+                    against_votes_bp: bp[1],
+                    abstain_votes_bp: bp[2],
+                    stake_merkle_proof,
+                    stake_merkle_leaf,
+                }
+                .data(),
+            },
+            &[signer],
+        )
+        .await
+    }
+
+    /// Commits the validator's placeholder vote to `bp` with `salt`
+    async fn commit_vote(
+        &mut self,
+        proposal: Pubkey,
+        validator: &Validator,
+        bp: [u64; 3],
+        salt: [u8; 32],
+    ) -> Result<(), BanksClientError> {
+        let vote = vote_pda(&proposal, &validator.vote_account);
+        self.send(
+            Instruction {
+                program_id: govcontract::ID,
+                accounts: govcontract::accounts::CommitVote {
+                    signer: validator.identity.pubkey(),
+                    proposal,
+                    vote,
+                    ballot: ballot_pda(&vote),
+                    system_program: system_program::ID,
+                    event_authority: event_authority_pda(),
+                    program: govcontract::ID,
+                }
+                .to_account_metas(None),
+                data: govcontract::instruction::CommitVote {
+                    commitment: Ballot::commitment(&vote, bp[0], bp[1], bp[2], &salt),
+                }
+                .data(),
+            },
+            &[&validator.identity],
+        )
+        .await
+    }
+
+    /// Commits the delegator's placeholder override to `bp` with `salt`
+    async fn commit_vote_override(
+        &mut self,
+        proposal: Pubkey,
+        validator: &Validator,
+        delegator: &Delegator,
+        bp: [u64; 3],
+        salt: [u8; 32],
+    ) -> Result<(), BanksClientError> {
+        self.commit_vote_override_as(proposal, validator, delegator, &delegator.staker, bp, salt)
+            .await
+    }
+
+    /// Signs the commitment with `signer`, passing the stake account's proxy registration
+    /// when `signer` is not the staker
+    async fn commit_vote_override_as(
+        &mut self,
+        proposal: Pubkey,
+        validator: &Validator,
+        delegator: &Delegator,
+        signer: &Keypair,
+        bp: [u64; 3],
+        salt: [u8; 32],
+    ) -> Result<(), BanksClientError> {
+        let stake_proxy = (signer.pubkey() != delegator.staker.pubkey())
+            .then(|| stake_proxy_pda(&delegator.stake_account));
+        let validator_vote = vote_pda(&proposal, &validator.vote_account);
+        let vote_override = vote_override_pda(&proposal, &delegator.stake_account, &validator_vote);
+        self.send(
+            Instruction {
+                program_id: govcontract::ID,
+                accounts: govcontract::accounts::CommitVoteOverride {
+                    signer: signer.pubkey(),
+                    proposal,
+                    vote_override,
+                    stake_proxy,
+                    ballot: ballot_pda(&vote_override),
+                    system_program: system_program::ID,
+                    event_authority: event_authority_pda(),
+                    program: govcontract::ID,
+                }
+                .to_account_metas(None),
+                data: govcontract::instruction::CommitVoteOverride {
+                    commitment: Ballot::commitment(&vote_override, bp[0], bp[1], bp[2], &salt),
+                }
+                .data(),
+            },
+            &[signer],
+        )
+        .await
+    }
+
+    async fn reveal_vote(
+        &mut self,
+        proposal: Pubkey,
+        validator: &Validator,
+        bp: [u64; 3],
+        salt: [u8; 32],
+    ) -> Result<(), BanksClientError> {
+        let vote = vote_pda(&proposal, &validator.vote_account);
+        let payer = self.context.payer.pubkey();
+        self.send(
+            Instruction {
+                program_id: govcontract::ID,
+                accounts: govcontract::accounts::RevealVote {
+                    signer: payer,
+                    proposal,
+                    vote,
+                    ballot: ballot_pda(&vote),
+                    event_authority: event_authority_pda(),
+                    program: govcontract::ID,
+                }
+                .to_account_metas(None),
+                data: govcontract::instruction::RevealVote {
+                    for_votes_bp: bp[0],
+                    against_votes_bp: bp[1],
+                    abstain_votes_bp: bp[2],
+                    salt,
+                }
+                .data(),
+            },
+            &[],
+        )
+        .await
+    }
+
+    async fn reveal_vote_override(
+        &mut self,
+        proposal: Pubkey,
+        validator: &Validator,
+        delegator: &Delegator,
+        bp: [u64; 3],
+        salt: [u8; 32],
+    ) -> Result<(), BanksClientError> {
+        let validator_vote = vote_pda(&proposal, &validator.vote_account);
+        let vote_override = vote_override_pda(&proposal, &delegator.stake_account, &validator_vote);
+        let payer = self.context.payer.pubkey();
+        self.send(
+            Instruction {
+                program_id: govcontract::ID,
+                accounts: govcontract::accounts::RevealVoteOverride {
+                    signer: payer,
+                    proposal,
+                    vote_override,
+                    validator_vote,
+                    ballot: ballot_pda(&vote_override),
+                    event_authority: event_authority_pda(),
+                    program: govcontract::ID,
+                }
+                .to_account_metas(None),
+                data: govcontract::instruction::RevealVoteOverride {
+                    for_votes_bp: bp[0],
+                    against_votes_bp: bp[1],
+                    abstain_votes_bp: bp[2],
+                    salt,
+                }
+                .data(),
+            },
+            &[],
+        )
+        .await
+    }
+
+    async fn finalize_proposal(&mut self, proposal: Pubkey) -> Result<(), BanksClientError> {
+        let payer = self.context.payer.pubkey();
+        let current = self.proposal(proposal).await;
+        self.send(
+            Instruction {
+                program_id: govcontract::ID,
+                accounts: govcontract::accounts::FinalizeProposal {
+                    signer: payer,
+                    proposal,
+                    registry_page: registry_page_pda(0),
+                    author_profile: validator_profile_pda(&current.vote_account_pubkey),
+                    superseded_proposal: current.supersedes(),
+                    event_authority: event_authority_pda(),
+                    program: govcontract::ID,
+                }
+                .to_account_metas(None),
+                data: govcontract::instruction::FinalizeProposal {}.data(),
+            },
+            &[],
+        )
+        .await
+    }
+
+    async fn close_meta_merkle_proof(
+        &mut self,
+        proposal: Pubkey,
+        meta_merkle_proof: Pubkey,
+    ) -> Result<(), BanksClientError> {
+        let payer = self.context.payer.pubkey();
+        self.send(
+            Instruction {
+                program_id: govcontract::ID,
+                accounts: govcontract::accounts::CloseMetaMerkleProof {
+                    signer: payer,
+                    proposal,
+                    meta_merkle_proof,
+                    payer,
+                    snapshot_program: mock_gov_v1::ID,
+                    system_program: system_program::ID,
+                    event_authority: event_authority_pda(),
+                    program: govcontract::ID,
+                }
+                .to_account_metas(None),
+                data: govcontract::instruction::CloseMetaMerkleProof {}.data(),
+            },
+            &[],
+        )
+        .await
+    }
+
+    async fn create_treasury_transfer(
+        &mut self,
+        proposal: Pubkey,
+        author: &Validator,
+        destination: Pubkey,
+        amount: u64,
+    ) -> Result<(), BanksClientError> {
+        self.send(
+            Instruction {
+                program_id: govcontract::ID,
+                accounts: govcontract::accounts::CreateTreasuryTransfer {
+                    signer: author.identity.pubkey(),
+                    proposal,
+                    treasury_transfer: treasury_transfer_pda(&proposal),
+                    system_program: system_program::ID,
+                    event_authority: event_authority_pda(),
+                    program: govcontract::ID,
+                }
+                .to_account_metas(None),
+                data: govcontract::instruction::CreateTreasuryTransfer {
+                    destination,
+                    mint: None,
+                    amount,
+                    timelock_seconds: 0,
+                }
+                .data(),
+            },
+            &[&author.identity],
+        )
+        .await
+    }
+
+    async fn execute_treasury_transfer(
+        &mut self,
+        proposal: Pubkey,
+        destination: Pubkey,
+    ) -> Result<(), BanksClientError> {
+        let payer = self.context.payer.pubkey();
+        self.send(
+            Instruction {
+                program_id: govcontract::ID,
+                accounts: govcontract::accounts::ExecuteTreasuryTransfer {
+                    signer: payer,
+                    proposal,
+                    treasury_transfer: treasury_transfer_pda(&proposal),
+                    treasury: treasury_pda(),
+                    destination,
+                    mint: None,
+                    treasury_token_account: None,
+                    token_program: None,
+                    system_program: system_program::ID,
+                    event_authority: event_authority_pda(),
+                    program: govcontract::ID,
+                }
+                .to_account_metas(None),
+                data: govcontract::instruction::ExecuteTreasuryTransfer {}.data(),
+            },
+            &[],
+        )
+        .await
+    }
+
+    async fn create_proposal_transaction(
+        &mut self,
+        proposal: Pubkey,
+        author: &Validator,
+        instructions: Vec<ProposalInstruction>,
+    ) -> Result<(), BanksClientError> {
+        self.send(
+            Instruction {
+                program_id: govcontract::ID,
+                accounts: govcontract::accounts::CreateProposalTransaction {
+                    signer: author.identity.pubkey(),
+                    proposal,
+                    proposal_transaction: proposal_transaction_pda(&proposal),
+                    governance_authority: governance_authority_pda(),
+                    system_program: system_program::ID,
+                    event_authority: event_authority_pda(),
+                    program: govcontract::ID,
+                }
+                .to_account_metas(None),
+                data: govcontract::instruction::CreateProposalTransaction {
+                    instructions,
+                    timelock_seconds: 0,
+                }
+                .data(),
+            },
+            &[&author.identity],
+        )
+        .await
+    }
+
+    /// Executes the proposal's transaction, passing `recipient` and the system program as
+    /// the accounts its instructions reference
+    async fn execute_proposal_transaction(
+        &mut self,
+        proposal: Pubkey,
+        recipient: Pubkey,
+    ) -> Result<(), BanksClientError> {
+        let payer = self.context.payer.pubkey();
+        let mut accounts = govcontract::accounts::ExecuteProposalTransaction {
+            signer: payer,
+            proposal,
+            proposal_transaction: proposal_transaction_pda(&proposal),
+            governance_authority: governance_authority_pda(),
+            event_authority: event_authority_pda(),
+            program: govcontract::ID,
+        }
+        .to_account_metas(None);
+        accounts.push(AccountMeta::new(recipient, false));
+        accounts.push(AccountMeta::new_readonly(system_program::ID, false));
+        self.send(
+            Instruction {
+                program_id: govcontract::ID,
+                accounts,
+                data: govcontract::instruction::ExecuteProposalTransaction {}.data(),
+            },
+            &[],
+        )
+        .await
+    }
+
+    async fn create_parameter_change(
+        &mut self,
+        proposal: Pubkey,
+        author: &Validator,
+        changes: Vec<GovernanceParameter>,
+    ) -> Result<(), BanksClientError> {
+        self.send(
+            Instruction {
+                program_id: govcontract::ID,
+                accounts: govcontract::accounts::CreateParameterChange {
+                    signer: author.identity.pubkey(),
+                    proposal,
+                    parameter_change: parameter_change_pda(&proposal),
+                    system_program: system_program::ID,
+                    event_authority: event_authority_pda(),
+                    program: govcontract::ID,
+                }
+                .to_account_metas(None),
+                data: govcontract::instruction::CreateParameterChange { changes }.data(),
+            },
+            &[&author.identity],
+        )
+        .await
+    }
+
+    async fn enact_parameter_change(&mut self, proposal: Pubkey) -> Result<(), BanksClientError> {
+        let payer = self.context.payer.pubkey();
+        self.send(
+            Instruction {
+                program_id: govcontract::ID,
+                accounts: govcontract::accounts::EnactParameterChange {
+                    signer: payer,
+                    proposal,
+                    parameter_change: parameter_change_pda(&proposal),
+                    governance_config: governance_config_pda(),
+                    event_authority: event_authority_pda(),
+                    program: govcontract::ID,
+                }
+                .to_account_metas(None),
+                data: govcontract::instruction::EnactParameterChange {}.data(),
+            },
+            &[],
+        )
+        .await
+    }
+
+    async fn update_validator_profile(
+        &mut self,
+        validator: &Validator,
+        display_name: Option<&str>,
+        website: Option<&str>,
+    ) -> Result<(), BanksClientError> {
+        self.send(
+            Instruction {
+                program_id: govcontract::ID,
+                accounts: govcontract::accounts::UpdateValidatorProfile {
+                    signer: validator.identity.pubkey(),
+                    spl_vote_account: validator.vote_account,
+                    validator_profile: validator_profile_pda(&validator.vote_account),
+                    system_program: system_program::ID,
+                    event_authority: event_authority_pda(),
+                    program: govcontract::ID,
+                }
+                .to_account_metas(None),
+                data: govcontract::instruction::UpdateValidatorProfile {
+                    display_name: display_name.map(str::to_string),
+                    website: website.map(str::to_string),
+                    governance_policy_url: None,
+                }
+                .data(),
+            },
+            &[&validator.identity],
+        )
+        .await
+    }
+
+    async fn settle_proposal_bond(
+        &mut self,
+        proposal: Pubkey,
+        author: &Validator,
+    ) -> Result<(), BanksClientError> {
+        let payer = self.context.payer.pubkey();
+        self.send(
+            Instruction {
+                program_id: govcontract::ID,
+                accounts: govcontract::accounts::SettleProposalBond {
+                    signer: payer,
+                    proposal,
+                    proposal_bond: proposal_bond_pda(&proposal),
+                    author: author.identity.pubkey(),
+                    treasury: treasury_pda(),
+                    author_profile: validator_profile_pda(&author.vote_account),
+                    event_authority: event_authority_pda(),
+                    program: govcontract::ID,
+                }
+                .to_account_metas(None),
+                data: govcontract::instruction::SettleProposalBond {}.data(),
+            },
+            &[],
+        )
+        .await
+    }
+
+    async fn cancel_proposal(
+        &mut self,
+        proposal: Pubkey,
+        author: &Validator,
+    ) -> Result<(), BanksClientError> {
+        self.cancel_proposal_as(proposal, author, &author.identity)
+            .await
+    }
+
+    /// Cancels `author`'s proposal, signed by `signer`
+    async fn cancel_proposal_as(
+        &mut self,
+        proposal: Pubkey,
+        author: &Validator,
+        signer: &Keypair,
+    ) -> Result<(), BanksClientError> {
+        self.send(
+            Instruction {
+                program_id: govcontract::ID,
+                accounts: govcontract::accounts::CancelProposal {
+                    signer: signer.pubkey(),
+                    proposal,
+                    registry_page: registry_page_pda(0),
+                    proposal_bond: proposal_bond_pda(&proposal),
+                    treasury: treasury_pda(),
+                    author_profile: validator_profile_pda(&author.vote_account),
+                    event_authority: event_authority_pda(),
+                    program: govcontract::ID,
+                }
+                .to_account_metas(None),
+                data: govcontract::instruction::CancelProposal {}.data(),
+            },
+            &[signer],
+        )
+        .await
+    }
+}
+
+fn sol(amount: u64) -> u64 {
+    amount * LAMPORTS_PER_SOL
+}
+
+async fn assert_tally(
+    env: &mut TestEnv,
+    proposal: Pubkey,
+    for_sol: u64,
+    against_sol: u64,
+    abstain_sol: u64,
+) {
+    let proposal = env.proposal(proposal).await;
+    assert_eq!(proposal.for_votes_lamports, sol(for_sol));
+    assert_eq!(proposal.against_votes_lamports, sol(against_sol));
+    assert_eq!(proposal.abstain_votes_lamports, sol(abstain_sol));
+}
+
+#[tokio::test]
+async fn proposal_lifecycle() {
+    let alice = Validator::new(350_000, &[50_000]);
+    let bob = Validator::new(170_000, &[30_000]);
+    let mut env = TestEnv::start(&[&alice, &bob]).await;
+
+    // Create and support
+    let proposal = env.create_proposal(&alice).await;
+    let created = env.proposal(proposal).await;
+    assert_eq!(created.author, alice.identity.pubkey());
+    assert_eq!(created.creation_epoch, 2);
+    assert_eq!(created.index, 1);
+    assert!(!created.is_voting());
+    let content: ProposalContent = env.account(proposal_content_pda(&proposal)).await;
+    assert_eq!(content.proposal, proposal);
+    assert_eq!(content.title, PROPOSAL_TITLE);
+    assert_eq!(content.description, PROPOSAL_DESCRIPTION);
+    let registry: ProposalRegistryPage = env.account(registry_page_pda(0)).await;
+    assert_eq!(registry.page, 0);
+    assert_eq!(registry.entries.len(), 1);
+    assert_eq!(registry.entries[0].index, 1);
+    assert_eq!(registry.entries[0].proposal, proposal);
+    assert_eq!(registry.entries[0].outcome, ProposalOutcome::Undecided);
+    let bond: ProposalBond = env.account(proposal_bond_pda(&proposal)).await;
+    assert_eq!(bond.author, alice.identity.pubkey());
+    assert_eq!(bond.amount, PROPOSAL_BOND_LAMPORTS);
+    assert_eq!(bond.status, BondStatus::Locked);
+    assert_custom_error(
+        env.settle_proposal_bond(proposal, &alice).await,
+        governance_error(GovernanceError::BondNotSettleable),
+    );
+
+    env.warp_to_epoch(3).await;
+    env.support_proposal(proposal, &alice).await.unwrap();
+    let supported = env.proposal(proposal).await;
+    let start_epoch = 3 + DISCUSSION_EPOCHS + SNAPSHOT_EPOCH_EXTENSION + 1;
+    assert!(supported.is_voting());
+    assert_eq!(supported.cluster_support_lamports, alice.active_stake());
+    assert_eq!(supported.start_epoch, start_epoch);
+    assert_eq!(supported.end_epoch, start_epoch + VOTING_EPOCHS);
+    assert_eq!(
+        supported.consensus_result(),
+        Some(consensus_result_pda(supported.snapshot_slot))
+    );
+
+    // Reaching voting returns the bond to the author
+    let author_balance = env.balance(alice.identity.pubkey()).await;
+    env.settle_proposal_bond(proposal, &alice).await.unwrap();
+    assert_eq!(
+        env.balance(alice.identity.pubkey()).await,
+        author_balance + PROPOSAL_BOND_LAMPORTS
+    );
+    let bond: ProposalBond = env.account(proposal_bond_pda(&proposal)).await;
+    assert_eq!(bond.status, BondStatus::Returned);
+
+    let snapshot = env.publish_snapshot(proposal, &[&alice, &bob]).await;
+
+    // Voting opens after the snapshot epoch
+    assert_custom_error(
+        env.cast_vote(proposal, &snapshot, &alice, [10_000, 0, 0])
+            .await,
+        governance_error(GovernanceError::VotingNotStarted),
+    );
+    env.warp_to_epoch(start_epoch).await;
+
+    env.cast_vote(proposal, &snapshot, &alice, [10_000, 0, 0])
+        .await
+        .unwrap();
+    assert_tally(&mut env, proposal, 400_000, 0, 0).await;
+
+    // Override after the validator voted moves the delegator's share out of the validator vote
+    let alice_delegator = &alice.delegators[0];
+    env.cast_vote_override(proposal, &snapshot, &alice, alice_delegator, [0, 10_000, 0])
+        .await
+        .unwrap();
+    assert_tally(&mut env, proposal, 350_000, 50_000, 0).await;
+    let alice_vote: Vote = env.account(vote_pda(&proposal, &alice.vote_account)).await;
+    assert_eq!(alice_vote.override_lamports, sol(50_000));
+
+    env.modify_vote_override(proposal, &snapshot, &alice, alice_delegator, [0, 0, 10_000])
+        .await
+        .unwrap();
+    assert_tally(&mut env, proposal, 350_000, 0, 50_000).await;
+
+    // Modifying keeps the overridden stake out of the validator's share
+    env.modify_vote(proposal, &snapshot, &alice, [5_000, 5_000, 0])
+        .await
+        .unwrap();
+    assert_tally(&mut env, proposal, 175_000, 175_000, 50_000).await;
+
+    // Override before the validator votes is cached and applied when the validator votes
+    let bob_delegator = &bob.delegators[0];
+    env.cast_vote_override(proposal, &snapshot, &bob, bob_delegator, [0, 10_000, 0])
+        .await
+        .unwrap();
+    assert_tally(&mut env, proposal, 175_000, 175_000, 50_000).await;
+
+    env.cast_vote(proposal, &snapshot, &bob, [10_000, 0, 0])
+        .await
+        .unwrap();
+    assert_tally(&mut env, proposal, 345_000, 205_000, 50_000).await;
+
+    // Finalize
+    assert_custom_error(
+        env.finalize_proposal(proposal).await,
+        governance_error(GovernanceError::VotingPeriodNotEnded),
+    );
+    env.warp_to_epoch(start_epoch + VOTING_EPOCHS).await;
+    env.finalize_proposal(proposal).await.unwrap();
+
+    let finalized = env.proposal(proposal).await;
+    assert!(finalized.is_finalized());
+    // 345k For out of 550k decisive stake is below the 66.67% threshold
+    assert_eq!(finalized.outcome(), ProposalOutcome::Rejected);
+    let registry: ProposalRegistryPage = env.account(registry_page_pda(0)).await;
+    assert_eq!(registry.entries[0].outcome, ProposalOutcome::Rejected);
+
+    // Participation counters
+    let alice_profile: ValidatorGovernanceProfile = env
+        .account(validator_profile_pda(&alice.vote_account))
+        .await;
+    assert_eq!(alice_profile.vote_account, alice.vote_account);
+    assert_eq!(alice_profile.proposals_authored, 1);
+    assert_eq!(alice_profile.open_proposals, 0);
+    assert_eq!(alice_profile.proposals_supported, 1);
+    assert_eq!(alice_profile.proposals_voted, 1);
+    assert_eq!(alice_profile.last_vote_epoch, start_epoch);
+    let bob_profile: ValidatorGovernanceProfile =
+        env.account(validator_profile_pda(&bob.vote_account)).await;
+    assert_eq!(bob_profile.proposals_authored, 0);
+    assert_eq!(bob_profile.proposals_voted, 1);
+}
+
+#[tokio::test]
+async fn open_proposals_limited_per_author() {
+    let alice = Validator::new(350_000, &[]);
+    let mut env = TestEnv::start(&[&alice]).await;
+
+    let mut proposals = Vec::new();
+    for seed in 0..MAX_OPEN_PROPOSALS_PER_AUTHOR as u64 {
+        let proposal = env
+            .create_proposal_seeded(&alice, seed + 10, ProposalOptions::default())
+            .await
+            .unwrap();
+        proposals.push(proposal);
+    }
+    assert_custom_error(
+        env.create_proposal_seeded(&alice, 20, ProposalOptions::default())
+            .await
+            .map(|_| ()),
+        governance_error(GovernanceError::TooManyOpenProposals),
+    );
+
+    // One proposal reaches voting, another expires in support
+    env.warp_to_epoch(3).await;
+    env.support_proposal(proposals[1], &alice).await.unwrap();
+    env.warp_to_epoch(4).await;
+    let profile: ValidatorGovernanceProfile = env
+        .account(validator_profile_pda(&alice.vote_account))
+        .await;
+    assert_eq!(profile.open_proposals, MAX_OPEN_PROPOSALS_PER_AUTHOR);
+
+    // Settling the expired proposal's bond frees its slot
+    env.settle_proposal_bond(proposals[0], &alice)
+        .await
+        .unwrap();
+    env.create_proposal_seeded(&alice, 20, ProposalOptions::default())
+        .await
+        .unwrap();
+    assert_custom_error(
+        env.create_proposal_seeded(&alice, 21, ProposalOptions::default())
+            .await
+            .map(|_| ()),
+        governance_error(GovernanceError::TooManyOpenProposals),
+    );
+
+    // So does finalizing
+    let end_epoch = env.proposal(proposals[1]).await.end_epoch;
+    env.warp_to_epoch(end_epoch).await;
+    env.finalize_proposal(proposals[1]).await.unwrap();
+    env.create_proposal_seeded(&alice, 21, ProposalOptions::default())
+        .await
+        .unwrap();
+    let profile: ValidatorGovernanceProfile = env
+        .account(validator_profile_pda(&alice.vote_account))
+        .await;
+    assert_eq!(profile.proposals_authored, 5);
+    assert_eq!(profile.open_proposals, MAX_OPEN_PROPOSALS_PER_AUTHOR);
+}
+
+#[tokio::test]
+async fn votes_rejected_after_voting_ends() {
+    let alice = Validator::new(400_000, &[50_000]);
+    let mut env = TestEnv::start(&[&alice]).await;
+    let proposal = env.open_proposal(&alice).await;
+    let snapshot = env.publish_snapshot(proposal, &[&alice]).await;
+
+    let end_epoch = env.proposal(proposal).await.end_epoch;
+    env.warp_to_epoch(end_epoch).await;
+
+    assert_custom_error(
+        env.cast_vote(proposal, &snapshot, &alice, [10_000, 0, 0])
+            .await,
+        governance_error(GovernanceError::ProposalClosed),
+    );
+    assert_custom_error(
+        env.cast_vote_override(
+            proposal,
+            &snapshot,
+            &alice,
+            &alice.delegators[0],
+            [10_000, 0, 0],
+        )
+        .await,
+        governance_error(GovernanceError::ProposalClosed),
+    );
+
+    env.finalize_proposal(proposal).await.unwrap();
+    assert_eq!(
+        env.proposal(proposal).await.outcome(),
+        ProposalOutcome::Rejected
+    );
+}
+
+#[tokio::test]
+async fn proposal_rejected_without_quorum() {
+    let alice = Validator::new(600_000, &[]);
+    let carol = Validator::new(9_400_000, &[]);
+    let mut env = TestEnv::start(&[&alice, &carol]).await;
+
+    // Alice's 6% of cluster stake activates voting but is short of the 10% quorum
+    let proposal = env.open_proposal(&alice).await;
+    let supported = env.proposal(proposal).await;
+    assert!(supported.is_voting());
+    assert_eq!(supported.quorum_bp, QUORUM_BP);
+    let snapshot = env.publish_snapshot(proposal, &[&alice, &carol]).await;
+    env.warp_to_epoch(supported.start_epoch).await;
+
+    // An unopposed vote does not pass a proposal on its own
+    env.cast_vote(proposal, &snapshot, &alice, [10_000, 0, 0])
+        .await
+        .unwrap();
+    assert!(!env.proposal(proposal).await.is_passing());
+    env.warp_to_epoch(supported.end_epoch).await;
+    env.finalize_proposal(proposal).await.unwrap();
+    assert_eq!(
+        env.proposal(proposal).await.outcome(),
+        ProposalOutcome::Rejected
+    );
+}
+
+#[tokio::test]
+async fn voting_extended_when_lead_changes() {
+    let alice = Validator::new(300_000, &[]);
+    let bob = Validator::new(400_000, &[]);
+    let mut env = TestEnv::start(&[&alice, &bob]).await;
+
+    let proposal = env
+        .create_proposal_seeded(
+            &alice,
+            PROPOSAL_SEED,
+            ProposalOptions {
+                anti_sniping: Some(AntiSnipingConfig {
+                    window_slots: SLOTS_PER_EPOCH,
+                    extension_epochs: 1,
+                    max_extensions: 1,
+                }),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    env.warp_to_epoch(3).await;
+    env.support_proposal(proposal, &alice).await.unwrap();
+    let snapshot = env.publish_snapshot(proposal, &[&alice, &bob]).await;
+    let supported = env.proposal(proposal).await;
+
+    // Taking the lead before the window doesn't extend voting
+    env.warp_to_epoch(supported.start_epoch).await;
+    env.cast_vote(proposal, &snapshot, &alice, [0, 10_000, 0])
+        .await
+        .unwrap();
+    assert_eq!(env.proposal(proposal).await.voting_extensions, 0);
+
+    // Abstain overtakes Against in the last epoch; the proposal fails either way
+    env.warp_to_epoch(supported.end_epoch - 1).await;
+    env.cast_vote(proposal, &snapshot, &bob, [0, 0, 10_000])
+        .await
+        .unwrap();
+    let extended = env.proposal(proposal).await;
+    assert!(!extended.is_passing());
+    assert_eq!(extended.leading_option(), Some(VoteChoice::Abstain));
+    assert_eq!(extended.voting_extensions, 1);
+    assert_eq!(extended.end_epoch, supported.end_epoch + 1);
+}
+
+#[tokio::test]
+async fn meta_merkle_proofs_closed_after_finalization() {
+    let alice = Validator::new(400_000, &[]);
+    let mut env = TestEnv::start(&[&alice]).await;
+    let proposal = env.open_proposal(&alice).await;
+    let snapshot = env.publish_snapshot(proposal, &[&alice]).await;
+    let meta_merkle_proof = snapshot.meta_merkle_proof(&alice.vote_account).unwrap();
+
+    env.cast_vote(proposal, &snapshot, &alice, [10_000, 0, 0])
+        .await
+        .unwrap();
+    assert_custom_error(
+        env.close_meta_merkle_proof(proposal, meta_merkle_proof)
+            .await,
+        governance_error(GovernanceError::ProposalNotFinalized),
+    );
+
+    let end_epoch = env.proposal(proposal).await.end_epoch;
+    env.warp_to_epoch(end_epoch).await;
+    env.finalize_proposal(proposal).await.unwrap();
+    assert_custom_error(
+        env.close_meta_merkle_proof(proposal, meta_merkle_proof)
+            .await,
+        governance_error(GovernanceError::SnapshotInUse),
+    );
+
+    let release_epoch = env.proposal(proposal).await.snapshot_release_epoch();
+    env.warp_to_epoch(release_epoch).await;
+    env.close_meta_merkle_proof(proposal, meta_merkle_proof)
+        .await
+        .unwrap();
+    let closed = env
+        .context
+        .banks_client
+        .get_account(meta_merkle_proof)
+        .await
+        .unwrap();
+    assert!(closed.is_none());
+}
+
+#[tokio::test]
+async fn meta_merkle_proofs_kept_while_snapshot_shared() {
+    let alice = Validator::new(400_000, &[]);
+    let bob = Validator::new(170_000, &[]);
+    let mut env = TestEnv::start(&[&alice, &bob]).await;
+
+    // Both proposals activate in the same epoch, so they vote with the same snapshot
+    let first = env.create_proposal(&alice).await;
+    let second = env
+        .create_proposal_seeded(
+            &bob,
+            PROPOSAL_SEED,
+            ProposalOptions {
+                secret_ballot: Some(SecretBallotConfig {
+                    reveal_epochs: 2,
+                    unrevealed: UnrevealedBallots::Abstain,
+                }),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    let creation_epoch = env.current_epoch().await;
+    env.warp_to_epoch(creation_epoch + 1).await;
+    env.support_proposal(first, &alice).await.unwrap();
+    env.support_proposal(second, &bob).await.unwrap();
+    let first_state = env.proposal(first).await;
+    let second_state = env.proposal(second).await;
+    assert_eq!(first_state.consensus_result, second_state.consensus_result);
+
+    let snapshot = env.publish_snapshot(first, &[&alice, &bob]).await;
+    let meta_merkle_proof = snapshot.meta_merkle_proof(&alice.vote_account).unwrap();
+    env.warp_to_epoch(first_state.start_epoch).await;
+    env.cast_vote(first, &snapshot, &alice, [10_000, 0, 0])
+        .await
+        .unwrap();
+    env.cast_vote(second, &snapshot, &alice, [0, 0, 10_000])
+        .await
+        .unwrap();
+
+    // The second proposal is still revealing when the first is finalized
+    env.warp_to_epoch(first_state.end_epoch).await;
+    env.finalize_proposal(first).await.unwrap();
+    assert_custom_error(
+        env.finalize_proposal(second).await,
+        governance_error(GovernanceError::VotingPeriodNotEnded),
+    );
+    assert_custom_error(
+        env.close_meta_merkle_proof(first, meta_merkle_proof).await,
+        governance_error(GovernanceError::SnapshotInUse),
+    );
+
+    env.warp_to_epoch(first_state.snapshot_release_epoch())
+        .await;
+    env.close_meta_merkle_proof(first, meta_merkle_proof)
+        .await
+        .unwrap();
+    let closed = env
+        .context
+        .banks_client
+        .get_account(meta_merkle_proof)
+        .await
+        .unwrap();
+    assert!(closed.is_none());
+}
+
+#[tokio::test]
+async fn bond_forfeited_without_support() {
+    let alice = Validator::new(400_000, &[]);
+    let mut env = TestEnv::start(&[&alice]).await;
+    let proposal = env.create_proposal(&alice).await;
+
+    // Nobody supports the proposal during epoch 3
+    env.warp_to_epoch(4).await;
+    env.settle_proposal_bond(proposal, &alice).await.unwrap();
+    assert_eq!(env.balance(treasury_pda()).await, PROPOSAL_BOND_LAMPORTS);
+    let bond: ProposalBond = env.account(proposal_bond_pda(&proposal)).await;
+    assert_eq!(bond.status, BondStatus::Forfeited);
+
+    // Warp so the retry isn't rejected as an already processed transaction
+    env.warp_to_epoch(5).await;
+    assert_custom_error(
+        env.settle_proposal_bond(proposal, &alice).await,
+        governance_error(GovernanceError::BondAlreadySettled),
+    );
+}
+
+#[tokio::test]
+async fn bond_settled_on_cancel() {
+    let alice = Validator::new(400_000, &[]);
+    let bob = Validator::new(300_000, &[]);
+    let carol = Validator::new(10_000, &[]);
+    let mut env = TestEnv::start(&[&alice, &bob, &carol]).await;
+    let cancelled = env
+        .create_proposal_seeded(&alice, 10, ProposalOptions::default())
+        .await
+        .unwrap();
+    let activated = env
+        .create_proposal_seeded(&alice, 11, ProposalOptions::default())
+        .await
+        .unwrap();
+    let supported = env
+        .create_proposal_seeded(&alice, 12, ProposalOptions::default())
+        .await
+        .unwrap();
+
+    // Only the author can cancel
+    assert_custom_error(
+        env.cancel_proposal_as(cancelled, &alice, &bob.identity)
+            .await,
+        governance_error(GovernanceError::Unauthorized),
+    );
+
+    // Without support the author withdraws the proposal with its bond
+    let author_balance = env.balance(alice.identity.pubkey()).await;
+    env.cancel_proposal(cancelled, &alice).await.unwrap();
+    assert_eq!(
+        env.balance(alice.identity.pubkey()).await,
+        author_balance + PROPOSAL_BOND_LAMPORTS
+    );
+    assert_eq!(env.balance(treasury_pda()).await, 0);
+    let bond: ProposalBond = env.account(proposal_bond_pda(&cancelled)).await;
+    assert_eq!(bond.status, BondStatus::Returned);
+    let proposal = env.proposal(cancelled).await;
+    assert!(proposal.is_finalized());
+    assert_eq!(proposal.outcome(), ProposalOutcome::Cancelled);
+    let registry: ProposalRegistryPage = env.account(registry_page_pda(0)).await;
+    assert_eq!(registry.entries[0].outcome, ProposalOutcome::Cancelled);
+    assert_eq!(registry.entries[1].outcome, ProposalOutcome::Undecided);
+    let profile: ValidatorGovernanceProfile = env
+        .account(validator_profile_pda(&alice.vote_account))
+        .await;
+    assert_eq!(profile.open_proposals, 2);
+    assert_custom_error(
+        env.settle_proposal_bond(cancelled, &alice).await,
+        governance_error(GovernanceError::BondAlreadySettled),
+    );
+
+    // A cancelled proposal can't gather support, and one in voting can't be cancelled
+    env.warp_to_epoch(3).await;
+    assert_custom_error(
+        env.support_proposal(cancelled, &alice).await,
+        governance_error(GovernanceError::ProposalClosed),
+    );
+    env.support_proposal(activated, &alice).await.unwrap();
+    assert_custom_error(
+        env.cancel_proposal(activated, &alice).await,
+        governance_error(GovernanceError::CannotModifyAfterStart),
+    );
+
+    // Once it has support, withdrawing forfeits the bond to the treasury
+    env.support_proposal(supported, &carol).await.unwrap();
+    assert!(!env.proposal(supported).await.is_voting());
+    env.cancel_proposal(supported, &alice).await.unwrap();
+    assert_eq!(env.balance(treasury_pda()).await, PROPOSAL_BOND_LAMPORTS);
+    let bond: ProposalBond = env.account(proposal_bond_pda(&supported)).await;
+    assert_eq!(bond.status, BondStatus::Forfeited);
+    let profile: ValidatorGovernanceProfile = env
+        .account(validator_profile_pda(&alice.vote_account))
+        .await;
+    assert_eq!(profile.open_proposals, 1);
+}
+
+#[tokio::test]
+async fn support_gathered_over_multiple_epochs() {
+    let alice = Validator::new(400_000, &[]);
+    let bob = Validator::new(300_000, &[]);
+    let carol = Validator::new(9_300_000, &[]);
+    let mut env = TestEnv::start(&[&alice, &bob, &carol]).await;
+    env.set_governance_config(|config| config.support_epochs = 3)
+        .await;
+
+    // Created in epoch 2, the proposal gathers support in epochs 3 to 5
+    let proposal = env.create_proposal(&alice).await;
+    let created = env.proposal(proposal).await;
+    assert_eq!(created.support_epochs, 3);
+    assert_eq!(created.support_end_epoch(), 5);
+
+    // Alice's 4% of cluster stake is short of the 5% threshold
+    env.warp_to_epoch(3).await;
+    // Only the validator's identity can support with its vote account
+    assert_custom_error(
+        env.support_proposal_as(proposal, &alice, &bob.identity)
+            .await,
+        governance_error(GovernanceError::InvalidVoteAccount),
+    );
+    env.support_proposal(proposal, &alice).await.unwrap();
+    assert!(!env.proposal(proposal).await.is_voting());
+
+    // Her stake is only measured once per epoch
+    env.context
+        .warp_to_slot(3 * SLOTS_PER_EPOCH + SNAPSHOT_SLOT_OFFSET)
+        .unwrap();
+    assert_custom_error(
+        env.support_proposal(proposal, &alice).await,
+        governance_error(GovernanceError::SupportAlreadyMeasured),
+    );
+
+    // Supporting again in a later epoch replaces her stake rather than adding to it
+    env.warp_to_epoch(4).await;
+    env.support_proposal(proposal, &alice).await.unwrap();
+    let remeasured = env.proposal(proposal).await;
+    assert!(!remeasured.is_voting());
+    assert_eq!(remeasured.cluster_support_lamports, alice.active_stake());
+    let support: Support = env
+        .account(support_pda(&proposal, &alice.vote_account))
+        .await;
+    assert_eq!(support.stake, alice.active_stake());
+    assert_eq!(support.epoch, 4);
+    let profile: ValidatorGovernanceProfile = env
+        .account(validator_profile_pda(&alice.vote_account))
+        .await;
+    assert_eq!(profile.proposals_supported, 1);
+
+    // Bob's support in the last epoch of the window activates voting
+    env.warp_to_epoch(5).await;
+    env.support_proposal(proposal, &bob).await.unwrap();
+    let supported = env.proposal(proposal).await;
+    assert!(supported.is_voting());
+    assert_eq!(
+        supported.cluster_support_lamports,
+        alice.active_stake() + bob.active_stake()
+    );
+    assert_eq!(
+        supported.start_epoch,
+        5 + DISCUSSION_EPOCHS + SNAPSHOT_EPOCH_EXTENSION + 1
+    );
+
+    // A proposal created now can be supported up to epoch 8, and its bond stays locked
+    // until the window has passed
+    let missed = env
+        .create_proposal_seeded(&alice, 2, ProposalOptions::default())
+        .await
+        .unwrap();
+    for epoch in 6..=8 {
+        env.warp_to_epoch(epoch).await;
+    }
+    assert_custom_error(
+        env.settle_proposal_bond(missed, &alice).await,
+        governance_error(GovernanceError::BondNotSettleable),
+    );
+    env.warp_to_epoch(9).await;
+    assert_custom_error(
+        env.support_proposal(missed, &bob).await,
+        governance_error(GovernanceError::NotInSupportPeriod),
+    );
+    env.settle_proposal_bond(missed, &alice).await.unwrap();
+    let bond: ProposalBond = env.account(proposal_bond_pda(&missed)).await;
+    assert_eq!(bond.status, BondStatus::Forfeited);
+}
+
+#[tokio::test]
+async fn treasury_transfer_paid_after_proposal_passes() {
+    let alice = Validator::new(400_000, &[]);
+    let mut env = TestEnv::start(&[&alice]).await;
+    env.context.set_account(
+        &treasury_pda(),
+        &Account::new(sol(20), 0, &system_program::ID).into(),
+    );
+    let destination = Pubkey::new_unique();
+
+    let proposal = env.create_proposal(&alice).await;
+    env.create_treasury_transfer(proposal, &alice, destination, sol(5))
+        .await
+        .unwrap();
+    let transfer: TreasuryTransfer = env.account(treasury_transfer_pda(&proposal)).await;
+    assert!(transfer.is_sol());
+    assert_eq!(transfer.amount, sol(5));
+
+    env.warp_to_epoch(3).await;
+    env.support_proposal(proposal, &alice).await.unwrap();
+    let snapshot = env.publish_snapshot(proposal, &[&alice]).await;
+    let start_epoch = env.proposal(proposal).await.start_epoch;
+    env.warp_to_epoch(start_epoch).await;
+    env.cast_vote(proposal, &snapshot, &alice, [10_000, 0, 0])
+        .await
+        .unwrap();
+
+    // Funds stay in the treasury until the proposal has passed
+    assert_custom_error(
+        env.execute_treasury_transfer(proposal, destination).await,
+        governance_error(GovernanceError::ProposalNotFinalized),
+    );
+
+    env.warp_to_epoch(start_epoch + VOTING_EPOCHS).await;
+    env.finalize_proposal(proposal).await.unwrap();
+    assert_eq!(
+        env.proposal(proposal).await.outcome(),
+        ProposalOutcome::Passed
+    );
+
+    // A payout may empty the treasury but not leave it below rent exemption
+    env.context.set_account(
+        &treasury_pda(),
+        &Account::new(sol(5) + 1_000, 0, &system_program::ID).into(),
+    );
+    assert_custom_error(
+        env.execute_treasury_transfer(proposal, destination).await,
+        governance_error(GovernanceError::TreasuryBelowRentExempt),
+    );
+    env.context.set_account(
+        &treasury_pda(),
+        &Account::new(sol(20), 0, &system_program::ID).into(),
+    );
+
+    env.warp_to_epoch(start_epoch + VOTING_EPOCHS + 1).await;
+    env.execute_treasury_transfer(proposal, destination)
+        .await
+        .unwrap();
+    assert_eq!(env.balance(destination).await, sol(5));
+    assert_eq!(env.balance(treasury_pda()).await, sol(15));
+    env.warp_to_epoch(start_epoch + VOTING_EPOCHS + 2).await;
+    assert_custom_error(
+        env.execute_treasury_transfer(proposal, destination).await,
+        governance_error(GovernanceError::TreasuryTransferAlreadyExecuted),
+    );
+}
+
+#[tokio::test]
+async fn proposal_transaction_executed_after_proposal_passes() {
+    let alice = Validator::new(400_000, &[]);
+    let bob = Validator::new(400_000, &[]);
+    let mut env = TestEnv::start(&[&alice, &bob]).await;
+    env.context.set_account(
+        &governance_authority_pda(),
+        &Account::new(sol(20), 0, &system_program::ID).into(),
+    );
+    let recipient = Pubkey::new_unique();
+
+    // Stored instructions cannot call back into the governance program
+    let passing = env.create_proposal(&alice).await;
+    let mut reentrant = governance_transfer(recipient, sol(5));
+    reentrant.program_id = govcontract::ID;
+    assert_custom_error(
+        env.create_proposal_transaction(passing, &alice, vec![reentrant])
+            .await,
+        governance_error(GovernanceError::ProposalInstructionInvokesGovernance),
+    );
+    env.create_proposal_transaction(
+        passing,
+        &alice,
+        vec![governance_transfer(recipient, sol(5))],
+    )
+    .await
+    .unwrap();
+
+    // Created and supported in different epochs so each draws its own snapshot
+    env.warp_to_epoch(3).await;
+    env.support_proposal(passing, &alice).await.unwrap();
+    let passing_snapshot = env.publish_snapshot(passing, &[&alice, &bob]).await;
+    let failing = env.create_proposal(&bob).await;
+    env.create_proposal_transaction(failing, &bob, vec![governance_transfer(recipient, sol(5))])
+        .await
+        .unwrap();
+    env.warp_to_epoch(4).await;
+    env.support_proposal(failing, &bob).await.unwrap();
+    let failing_snapshot = env.publish_snapshot(failing, &[&alice, &bob]).await;
+
+    let passing_start = env.proposal(passing).await.start_epoch;
+    env.warp_to_epoch(passing_start).await;
+    env.cast_vote(passing, &passing_snapshot, &alice, [10_000, 0, 0])
+        .await
+        .unwrap();
+    let failing_start = env.proposal(failing).await.start_epoch;
+    env.warp_to_epoch(failing_start).await;
+    env.cast_vote(failing, &failing_snapshot, &bob, [0, 10_000, 0])
+        .await
+        .unwrap();
+
+    // Nothing runs before the proposal is finalized
+    assert_custom_error(
+        env.execute_proposal_transaction(passing, recipient).await,
+        governance_error(GovernanceError::ProposalNotFinalized),
+    );
+
+    let end_epoch = env.proposal(failing).await.end_epoch;
+    env.warp_to_epoch(end_epoch).await;
+    env.finalize_proposal(passing).await.unwrap();
+    env.finalize_proposal(failing).await.unwrap();
+    assert_eq!(
+        env.proposal(failing).await.outcome(),
+        ProposalOutcome::Rejected
+    );
+
+    env.execute_proposal_transaction(passing, recipient)
+        .await
+        .unwrap();
+    assert_eq!(env.balance(recipient).await, sol(5));
+    assert_eq!(env.balance(governance_authority_pda()).await, sol(15));
+    assert_custom_error(
+        env.execute_proposal_transaction(failing, recipient).await,
+        governance_error(GovernanceError::ProposalNotPassed),
+    );
+
+    env.warp_to_epoch(end_epoch + 1).await;
+    assert_custom_error(
+        env.execute_proposal_transaction(passing, recipient).await,
+        governance_error(GovernanceError::ProposalTransactionAlreadyExecuted),
+    );
+}
+
+#[tokio::test]
+async fn parameter_change_enacted_after_proposal_passes() {
+    let alice = Validator::new(400_000, &[]);
+    let mut env = TestEnv::start(&[&alice]).await;
+
+    let proposal = env.create_proposal(&alice).await;
+    // Values that would make passing, supporting or proposing unreachable are rejected
+    for out_of_range in [
+        GovernanceParameter::PassThresholdBp(5_000),
+        GovernanceParameter::PassThresholdBp(MAX_PASS_THRESHOLD_BP + 1),
+        GovernanceParameter::SupportThresholdBp(MAX_SUPPORT_THRESHOLD_BP + 1),
+        GovernanceParameter::EmergencySupportThresholdBp(10_000),
+        GovernanceParameter::QuorumBp(MAX_QUORUM_BP + 1),
+        GovernanceParameter::MinProposalStakeLamports(MAX_MIN_PROPOSAL_STAKE_LAMPORTS + 1),
+        GovernanceParameter::ProposalBondLamports(MIN_PROPOSAL_BOND_LAMPORTS - 1),
+        GovernanceParameter::ProposalBondLamports(MAX_PROPOSAL_BOND_LAMPORTS + 1),
+    ] {
+        assert_custom_error(
+            env.create_parameter_change(proposal, &alice, vec![out_of_range])
+                .await,
+            governance_error(GovernanceError::InvalidParameterChange),
+        );
+    }
+    env.create_parameter_change(
+        proposal,
+        &alice,
+        vec![
+            GovernanceParameter::VotingEpochs(2),
+            GovernanceParameter::PassThresholdBp(7_500),
+        ],
+    )
+    .await
+    .unwrap();
+
+    env.warp_to_epoch(3).await;
+    env.support_proposal(proposal, &alice).await.unwrap();
+    let snapshot = env.publish_snapshot(proposal, &[&alice]).await;
+    let start_epoch = env.proposal(proposal).await.start_epoch;
+    env.warp_to_epoch(start_epoch).await;
+    env.cast_vote(proposal, &snapshot, &alice, [10_000, 0, 0])
+        .await
+        .unwrap();
+
+    // The config only changes once the proposal has passed
+    assert_custom_error(
+        env.enact_parameter_change(proposal).await,
+        governance_error(GovernanceError::ProposalNotFinalized),
+    );
+    env.warp_to_epoch(start_epoch + VOTING_EPOCHS).await;
+    env.finalize_proposal(proposal).await.unwrap();
+    env.enact_parameter_change(proposal).await.unwrap();
+    let config: GovernanceConfig = env.account(governance_config_pda()).await;
+    assert_eq!(config.voting_epochs, 2);
+    assert_eq!(config.pass_threshold_bp, 7_500);
+    let change: ParameterChange = env.account(parameter_change_pda(&proposal)).await;
+    assert!(change.is_enacted());
+    env.warp_to_epoch(start_epoch + VOTING_EPOCHS + 1).await;
+    assert_custom_error(
+        env.enact_parameter_change(proposal).await,
+        governance_error(GovernanceError::ParameterChangeAlreadyEnacted),
+    );
+
+    // New proposals follow the changed rules
+    let next = env
+        .create_proposal_seeded(&alice, 2, ProposalOptions::default())
+        .await
+        .unwrap();
+    let created = env.proposal(next).await;
+    assert_eq!(created.voting_epochs, 2);
+    assert_eq!(created.pass_threshold_bp, 7_500);
+}
+
+#[tokio::test]
+async fn stale_parameter_change_rejected() {
+    let alice = Validator::new(400_000, &[]);
+    let bob = Validator::new(400_000, &[]);
+    let mut env = TestEnv::start(&[&alice, &bob]).await;
+
+    let older = env.create_proposal(&alice).await;
+    env.create_parameter_change(
+        older,
+        &alice,
+        vec![
+            GovernanceParameter::VotingEpochs(2),
+            GovernanceParameter::PassThresholdBp(7_500),
+        ],
+    )
+    .await
+    .unwrap();
+
+    // Created and supported in different epochs so each draws its own snapshot
+    env.warp_to_epoch(3).await;
+    env.support_proposal(older, &alice).await.unwrap();
+    let older_snapshot = env.publish_snapshot(older, &[&alice, &bob]).await;
+    let newer = env.create_proposal(&bob).await;
+    env.create_parameter_change(
+        newer,
+        &bob,
+        vec![GovernanceParameter::PassThresholdBp(8_000)],
+    )
+    .await
+    .unwrap();
+    env.warp_to_epoch(4).await;
+    env.support_proposal(newer, &bob).await.unwrap();
+    let newer_snapshot = env.publish_snapshot(newer, &[&alice, &bob]).await;
+
+    let older_start = env.proposal(older).await.start_epoch;
+    env.warp_to_epoch(older_start).await;
+    env.cast_vote(older, &older_snapshot, &alice, [10_000, 0, 0])
+        .await
+        .unwrap();
+    let newer_start = env.proposal(newer).await.start_epoch;
+    env.warp_to_epoch(newer_start).await;
+    env.cast_vote(newer, &newer_snapshot, &bob, [10_000, 0, 0])
+        .await
+        .unwrap();
+
+    let older_end = env.proposal(older).await.end_epoch;
+    env.warp_to_epoch(older_end).await;
+    env.finalize_proposal(older).await.unwrap();
+    let newer_end = env.proposal(newer).await.end_epoch;
+    env.warp_to_epoch(newer_end).await;
+    let mut clock: Clock = env.context.banks_client.get_sysvar().await.unwrap();
+    clock.unix_timestamp = env.proposal(older).await.finalization_timestamp + 3600;
+    env.context.set_sysvar(&clock);
+    env.finalize_proposal(newer).await.unwrap();
+
+    // Enacting the older change after the newer one would revert the pass threshold
+    env.enact_parameter_change(newer).await.unwrap();
+    assert_custom_error(
+        env.enact_parameter_change(older).await,
+        governance_error(GovernanceError::StaleParameterChange),
+    );
+    let config: GovernanceConfig = env.account(governance_config_pda()).await;
+    assert_eq!(config.pass_threshold_bp, 8_000);
+    assert_eq!(config.voting_epochs, VOTING_EPOCHS);
+}
+
+#[tokio::test]
+async fn validator_profile_metadata() {
+    let alice = Validator::new(400_000, &[]);
+    let mut env = TestEnv::start(&[&alice]).await;
+
+    env.update_validator_profile(&alice, Some("Alice"), Some("https://alice.example"))
+        .await
+        .unwrap();
+    // Omitted fields are kept
+    env.update_validator_profile(&alice, None, Some("https://alice.example/gov"))
+        .await
+        .unwrap();
+    let profile: ValidatorGovernanceProfile = env
+        .account(validator_profile_pda(&alice.vote_account))
+        .await;
+    assert_eq!(profile.display_name, "Alice");
+    assert_eq!(profile.website, "https://alice.example/gov");
+    assert_eq!(profile.governance_policy_url, "");
+
+    // Counters keep accumulating on a profile created through metadata
+    env.create_proposal(&alice).await;
+    let profile: ValidatorGovernanceProfile = env
+        .account(validator_profile_pda(&alice.vote_account))
+        .await;
+    assert_eq!(profile.proposals_authored, 1);
+    assert_eq!(profile.display_name, "Alice");
+
+    assert_custom_error(
+        env.update_validator_profile(&alice, Some(&"a".repeat(65)), None)
+            .await,
+        governance_error(GovernanceError::ProfileFieldTooLong),
+    );
+}
+
+#[tokio::test]
+async fn delegated_vote_follows_delegate() {
+    let alice = Validator::new(400_000, &[]);
+    let bob = Validator::new(170_000, &[30_000]);
+    let mut env = TestEnv::start(&[&alice, &bob]).await;
+
+    env.set_governance_delegation(&bob, &alice, None)
+        .await
+        .unwrap();
+    let proposal = env.open_proposal(&alice).await;
+    let snapshot = env.publish_snapshot(proposal, &[&alice, &bob]).await;
+    let start_epoch = env.proposal(proposal).await.start_epoch;
+    env.warp_to_epoch(start_epoch).await;
+    env.cast_vote(proposal, &snapshot, &alice, [10_000, 0, 0])
+        .await
+        .unwrap();
+
+    // A delegation for this proposal takes precedence over the standing one
+    env.set_governance_delegation(&bob, &alice, Some(proposal))
+        .await
+        .unwrap();
+    assert_custom_error(
+        env.cast_delegated_vote(proposal, &snapshot, &bob, &alice, None)
+            .await,
+        governance_error(GovernanceError::DelegationOverridden),
+    );
+    env.cast_delegated_vote(proposal, &snapshot, &bob, &alice, Some(proposal))
+        .await
+        .unwrap();
+    assert_tally(&mut env, proposal, 600_000, 0, 0).await;
+    let bob_vote: Vote = env.account(vote_pda(&proposal, &bob.vote_account)).await;
+    assert_eq!(bob_vote.delegate, alice.vote_account);
+    assert_eq!(bob_vote.validator, bob.identity.pubkey());
+
+    // The delegator's own stake delegators still override its share
+    env.cast_vote_override(
+        proposal,
+        &snapshot,
+        &bob,
+        &bob.delegators[0],
+        [0, 10_000, 0],
+    )
+    .await
+    .unwrap();
+    assert_tally(&mut env, proposal, 570_000, 30_000, 0).await;
+
+    // Delegation is pull-based: the delegate's modification leaves the delegated vote on the
+    // old split until it is refreshed
+    env.modify_vote(proposal, &snapshot, &alice, [0, 10_000, 0])
+        .await
+        .unwrap();
+    assert_tally(&mut env, proposal, 170_000, 430_000, 0).await;
+    let stale: Vote = env.account(vote_pda(&proposal, &bob.vote_account)).await;
+    assert_eq!((stale.for_votes_bp, stale.against_votes_bp), (10_000, 0));
+    env.warp_to_epoch(start_epoch + 1).await;
+    env.cast_delegated_vote(proposal, &snapshot, &bob, &alice, Some(proposal))
+        .await
+        .unwrap();
+    assert_tally(&mut env, proposal, 0, 600_000, 0).await;
+    let refreshed: Vote = env.account(vote_pda(&proposal, &bob.vote_account)).await;
+    assert_eq!(
+        (refreshed.for_votes_bp, refreshed.against_votes_bp),
+        (0, 10_000)
+    );
+
+    // Voting directly stops following the delegate
+    env.modify_vote(proposal, &snapshot, &bob, [10_000, 0, 0])
+        .await
+        .unwrap();
+    assert_tally(&mut env, proposal, 170_000, 430_000, 0).await;
+    env.warp_to_epoch(start_epoch + 2).await;
+    assert_custom_error(
+        env.cast_delegated_vote(proposal, &snapshot, &bob, &alice, Some(proposal))
+            .await,
+        governance_error(GovernanceError::DelegatorVotedDirectly),
+    );
+}
+
+#[tokio::test]
+async fn proxy_overrides_for_stake_account() {
+    let alice = Validator::new(350_000, &[50_000, 20_000]);
+    let mut env = TestEnv::start(&[&alice]).await;
+    let staker = &alice.delegators[0];
+    let custodian = &alice.delegators[1].staker;
+
+    let proposal = env.open_proposal(&alice).await;
+    let snapshot = env.publish_snapshot(proposal, &[&alice]).await;
+    let start_epoch = env.proposal(proposal).await.start_epoch;
+    env.warp_to_epoch(start_epoch).await;
+    env.cast_vote(proposal, &snapshot, &alice, [10_000, 0, 0])
+        .await
+        .unwrap();
+
+    // Only the staker may override until it registers a proxy
+    assert_custom_error(
+        env.cast_vote_override_as(
+            proposal,
+            &snapshot,
+            &alice,
+            staker,
+            custodian,
+            [0, 10_000, 0],
+        )
+        .await,
+        governance_error(GovernanceError::StakeAccountOwnerMismatch),
+    );
+
+    env.register_stake_proxy(staker, &custodian.pubkey())
+        .await
+        .unwrap();
+    env.cast_vote_override_as(
+        proposal,
+        &snapshot,
+        &alice,
+        staker,
+        custodian,
+        [0, 10_000, 0],
+    )
+    .await
+    .unwrap();
+    assert_tally(&mut env, proposal, 370_000, 50_000, 0).await;
+    let validator_vote = vote_pda(&proposal, &alice.vote_account);
+    let vote_override: VoteOverride = env
+        .account(vote_override_pda(
+            &proposal,
+            &staker.stake_account,
+            &validator_vote,
+        ))
+        .await;
+    assert_eq!(vote_override.delegator, staker.staker.pubkey());
+    assert_eq!(vote_override.proxy, custodian.pubkey());
+
+    // Revoking stops the proxy, the staker can still change the override itself
+    env.revoke_stake_proxy(staker).await.unwrap();
+    assert_custom_error(
+        env.modify_vote_override_as(
+            proposal,
+            &snapshot,
+            &alice,
+            staker,
+            custodian,
+            [0, 0, 10_000],
+        )
+        .await,
+        ErrorCode::AccountNotInitialized.into(),
+    );
+    env.modify_vote_override(proposal, &snapshot, &alice, staker, [0, 0, 10_000])
+        .await
+        .unwrap();
+    assert_tally(&mut env, proposal, 370_000, 0, 50_000).await;
+    let vote_override: VoteOverride = env
+        .account(vote_override_pda(
+            &proposal,
+            &staker.stake_account,
+            &validator_vote,
+        ))
+        .await;
+    assert_eq!(vote_override.proxy, Pubkey::default());
+
+    // A proxy registered by a former owner stops working once the stake account changes
+    // hands; the snapshot records the custodian as its owner
+    let sold = &alice.delegators[1];
+    let former_owner = Keypair::new();
+    env.context
+        .set_account(&former_owner.pubkey(), &funded_account().into());
+    env.context.set_account(
+        &sold.stake_account,
+        &stake_account(&alice.vote_account, &former_owner.pubkey(), sold.stake).into(),
+    );
+    env.register_stake_proxy_as(sold, &former_owner, &former_owner.pubkey())
+        .await
+        .unwrap();
+    assert_custom_error(
+        env.cast_vote_override_as(
+            proposal,
+            &snapshot,
+            &alice,
+            sold,
+            &former_owner,
+            [0, 10_000, 0],
+        )
+        .await,
+        governance_error(GovernanceError::StakeProxyAuthorityMismatch),
+    );
+}
+
+#[tokio::test]
+async fn secret_ballot_tallied_on_reveal() {
+    let alice = Validator::new(400_000, &[]);
+    let bob = Validator::new(170_000, &[30_000]);
+    let mut env = TestEnv::start(&[&alice, &bob]).await;
+    let bob_delegator = &bob.delegators[0];
+    let placeholder = [0, 0, 10_000];
+
+    let proposal = env
+        .open_proposal_with(
+            &alice,
+            Some(SecretBallotConfig {
+                reveal_epochs: 1,
+                unrevealed: UnrevealedBallots::Abstain,
+            }),
+        )
+        .await;
+    let snapshot = env.publish_snapshot(proposal, &[&alice, &bob]).await;
+    let start_epoch = env.proposal(proposal).await.start_epoch;
+    env.warp_to_epoch(start_epoch).await;
+
+    // Votes carry the placeholder, the real split only goes into the commitment
+    assert_custom_error(
+        env.cast_vote(proposal, &snapshot, &alice, [10_000, 0, 0])
+            .await,
+        governance_error(GovernanceError::SecretBallotPlaceholderRequired),
+    );
+    env.cast_vote(proposal, &snapshot, &alice, placeholder)
+        .await
+        .unwrap();
+    env.commit_vote(proposal, &alice, [10_000, 0, 0], [1; 32])
+        .await
+        .unwrap();
+    env.cast_vote(proposal, &snapshot, &bob, placeholder)
+        .await
+        .unwrap();
+    env.commit_vote(proposal, &bob, [0, 10_000, 0], [2; 32])
+        .await
+        .unwrap();
+    env.cast_vote_override(proposal, &snapshot, &bob, bob_delegator, placeholder)
+        .await
+        .unwrap();
+    env.commit_vote_override(proposal, &bob, bob_delegator, [10_000, 0, 0], [3; 32])
+        .await
+        .unwrap();
+    assert_tally(&mut env, proposal, 0, 0, 600_000).await;
+    assert_custom_error(
+        env.modify_vote(proposal, &snapshot, &bob, [10_000, 0, 0])
+            .await,
+        governance_error(GovernanceError::SecretBallotProposal),
+    );
+    assert_custom_error(
+        env.reveal_vote(proposal, &alice, [10_000, 0, 0], [1; 32])
+            .await,
+        governance_error(GovernanceError::NotInRevealPeriod),
+    );
+
+    let end_epoch = env.proposal(proposal).await.end_epoch;
+    env.warp_to_epoch(end_epoch).await;
+    assert_custom_error(
+        env.reveal_vote(proposal, &alice, [10_000, 0, 0], [9; 32])
+            .await,
+        governance_error(GovernanceError::CommitmentMismatch),
+    );
+    env.reveal_vote(proposal, &alice, [10_000, 0, 0], [1; 32])
+        .await
+        .unwrap();
+    env.reveal_vote_override(proposal, &bob, bob_delegator, [10_000, 0, 0], [3; 32])
+        .await
+        .unwrap();
+    // Bob never reveals, so his share stays abstained
+    assert_tally(&mut env, proposal, 430_000, 0, 170_000).await;
+
+    assert_custom_error(
+        env.finalize_proposal(proposal).await,
+        governance_error(GovernanceError::VotingPeriodNotEnded),
+    );
+    env.warp_to_epoch(end_epoch + 1).await;
+    assert_custom_error(
+        env.reveal_vote(proposal, &bob, [0, 10_000, 0], [2; 32])
+            .await,
+        governance_error(GovernanceError::NotInRevealPeriod),
+    );
+    env.finalize_proposal(proposal).await.unwrap();
+    assert_eq!(
+        env.proposal(proposal).await.outcome(),
+        ProposalOutcome::Passed
+    );
+}
+
+#[tokio::test]
+async fn secret_ballot_override_checks_proxy_and_validator_vote() {
+    let alice = Validator::new(400_000, &[]);
+    let bob = Validator::new(170_000, &[30_000]);
+    let mut env = TestEnv::start(&[&alice, &bob]).await;
+    let bob_delegator = &bob.delegators[0];
+    let custodian = Keypair::new();
+    env.context
+        .set_account(&custodian.pubkey(), &funded_account().into());
+    let placeholder = [0, 0, 10_000];
+
+    let proposal = env
+        .open_proposal_with(
+            &alice,
+            Some(SecretBallotConfig {
+                reveal_epochs: 1,
+                unrevealed: UnrevealedBallots::Abstain,
+            }),
+        )
+        .await;
+    let snapshot = env.publish_snapshot(proposal, &[&alice, &bob]).await;
+    let start_epoch = env.proposal(proposal).await.start_epoch;
+    env.warp_to_epoch(start_epoch).await;
+    env.cast_vote(proposal, &snapshot, &alice, placeholder)
+        .await
+        .unwrap();
+
+    // Bob never votes, so the override his delegator's proxy casts stays in the cache
+    env.register_stake_proxy(bob_delegator, &custodian.pubkey())
+        .await
+        .unwrap();
+    env.cast_vote_override_as(
+        proposal,
+        &snapshot,
+        &bob,
+        bob_delegator,
+        &custodian,
+        placeholder,
+    )
+    .await
+    .unwrap();
+    env.commit_vote_override_as(
+        proposal,
+        &bob,
+        bob_delegator,
+        &custodian,
+        [0, 10_000, 0],
+        [1; 32],
+    )
+    .await
+    .unwrap();
+    assert_tally(&mut env, proposal, 0, 0, 400_000).await;
+
+    // A revoked proxy can no longer commit for the override it cast
+    env.revoke_stake_proxy(bob_delegator).await.unwrap();
+    assert_custom_error(
+        env.commit_vote_override_as(
+            proposal,
+            &bob,
+            bob_delegator,
+            &custodian,
+            [10_000, 0, 0],
+            [2; 32],
+        )
+        .await,
+        ErrorCode::AccountNotInitialized.into(),
+    );
+    env.commit_vote_override(proposal, &bob, bob_delegator, [10_000, 0, 0], [3; 32])
+        .await
+        .unwrap();
+
+    // The cached override was never counted, so revealing it is rejected
+    let end_epoch = env.proposal(proposal).await.end_epoch;
+    env.warp_to_epoch(end_epoch).await;
+    assert_custom_error(
+        env.reveal_vote_override(proposal, &bob, bob_delegator, [10_000, 0, 0], [3; 32])
+            .await,
+        governance_error(GovernanceError::OverrideNotCounted),
+    );
+    assert_tally(&mut env, proposal, 0, 0, 400_000).await;
+}
+
+#[tokio::test]
+async fn emergency_proposal_fast_tracked() {
+    let alice = Validator::new(350_000, &[50_000]);
+    let bob = Validator::new(40_000, &[]);
+    let mut env = TestEnv::start(&[&alice, &bob]).await;
+
+    let proposal = env.create_emergency_proposal(&alice).await;
+    assert_eq!(
+        env.proposal(proposal).await.class(),
+        ProposalClass::Emergency
+    );
+    env.warp_to_epoch(3).await;
+
+    // Bob's stake would activate a standard proposal but is short of the emergency threshold
+    env.support_proposal(proposal, &bob).await.unwrap();
+    assert!(!env.proposal(proposal).await.is_voting());
+
+    // Without discussion the snapshot is taken next epoch and voting follows it
+    env.support_proposal(proposal, &alice).await.unwrap();
+    let supported = env.proposal(proposal).await;
+    assert!(supported.is_voting());
+    assert_eq!(
+        supported.snapshot_slot,
+        4 * SLOTS_PER_EPOCH + SNAPSHOT_SLOT_OFFSET
+    );
+    assert_eq!(supported.start_epoch, 5);
+    assert_eq!(supported.end_epoch, 5 + EMERGENCY_VOTING_EPOCHS);
+
+    let snapshot = env.publish_snapshot(proposal, &[&alice, &bob]).await;
+    env.warp_to_epoch(4).await;
+    assert_custom_error(
+        env.cast_vote(proposal, &snapshot, &alice, [10_000, 0, 0])
+            .await,
+        governance_error(GovernanceError::VotingNotStarted),
+    );
+    env.warp_to_epoch(supported.start_epoch).await;
+    env.cast_vote(proposal, &snapshot, &alice, [10_000, 0, 0])
+        .await
+        .unwrap();
+
+    env.warp_to_epoch(supported.end_epoch).await;
+    env.finalize_proposal(proposal).await.unwrap();
+    assert_eq!(
+        env.proposal(proposal).await.outcome(),
+        ProposalOutcome::Passed
+    );
+}
+
+#[tokio::test]
+async fn superseding_proposal_marks_predecessor() {
+    let alice = Validator::new(350_000, &[50_000]);
+    let bob = Validator::new(170_000, &[30_000]);
+    let mut env = TestEnv::start(&[&alice, &bob]).await;
+    env.context.set_account(
+        &treasury_pda(),
+        &Account::new(sol(20), 0, &system_program::ID).into(),
+    );
+    let destination = Pubkey::new_unique();
+
+    let original = env.create_proposal(&alice).await;
+    env.create_treasury_transfer(original, &alice, destination, sol(5))
+        .await
+        .unwrap();
+    let base = env.create_proposal(&bob).await;
+
+    // Links must point at existing proposals, and only the author can revise a proposal
+    assert_custom_error(
+        env.create_proposal_seeded(
+            &alice,
+            3,
+            ProposalOptions {
+                supersedes: Some(proposal_content_pda(&original)),
+                ..Default::default()
+            },
+        )
+        .await
+        .map(|_| ()),
+        ErrorCode::AccountDiscriminatorMismatch.into(),
+    );
+    assert_custom_error(
+        env.create_proposal_seeded(
+            &bob,
+            3,
+            ProposalOptions {
+                supersedes: Some(original),
+                ..Default::default()
+            },
+        )
+        .await
+        .map(|_| ()),
+        governance_error(GovernanceError::NotSupersededProposalAuthor),
+    );
+
+    // Supported in different epochs so each draws its own snapshot
+    env.warp_to_epoch(3).await;
+    let revision = env
+        .create_proposal_seeded(
+            &alice,
+            3,
+            ProposalOptions {
+                supersedes: Some(original),
+                depends_on: Some(base),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    let created = env.proposal(revision).await;
+    assert_eq!(created.supersedes(), Some(original));
+    assert_eq!(created.depends_on(), Some(base));
+    assert!(!env.proposal(original).await.is_superseded());
+    env.support_proposal(original, &alice).await.unwrap();
+    let original_snapshot = env.publish_snapshot(original, &[&alice, &bob]).await;
+
+    env.warp_to_epoch(4).await;
+    let rival = env
+        .create_proposal_seeded(
+            &alice,
+            4,
+            ProposalOptions {
+                supersedes: Some(original),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    env.support_proposal(revision, &alice).await.unwrap();
+    let revision_snapshot = env.publish_snapshot(revision, &[&alice, &bob]).await;
+
+    env.warp_to_epoch(5).await;
+    env.support_proposal(rival, &alice).await.unwrap();
+    let rival_snapshot = env.publish_snapshot(rival, &[&alice, &bob]).await;
+
+    for (proposal, snapshot) in [
+        (original, &original_snapshot),
+        (revision, &revision_snapshot),
+        (rival, &rival_snapshot),
+    ] {
+        let start_epoch = env.proposal(proposal).await.start_epoch;
+        env.warp_to_epoch(start_epoch).await;
+        env.cast_vote(proposal, snapshot, &alice, [10_000, 0, 0])
+            .await
+            .unwrap();
+    }
+    let end_epoch = env.proposal(rival).await.end_epoch;
+    env.warp_to_epoch(end_epoch).await;
+
+    // Once the revision passes, the original is marked as superseded
+    env.finalize_proposal(original).await.unwrap();
+    env.finalize_proposal(revision).await.unwrap();
+    assert_eq!(
+        env.proposal(revision).await.outcome(),
+        ProposalOutcome::Passed
+    );
+    assert_eq!(env.proposal(original).await.superseded_by, revision);
+    assert!(!env.proposal(base).await.is_superseded());
+
+    // A second revision of the same proposal cannot pass or take over the link
+    env.finalize_proposal(rival).await.unwrap();
+    assert_eq!(
+        env.proposal(rival).await.outcome(),
+        ProposalOutcome::Rejected
+    );
+    assert_eq!(env.proposal(original).await.superseded_by, revision);
+
+    // The superseded proposal passed, but its payout is blocked
+    assert_eq!(
+        env.proposal(original).await.outcome(),
+        ProposalOutcome::Passed
+    );
+    assert_custom_error(
+        env.execute_treasury_transfer(original, destination).await,
+        governance_error(GovernanceError::ProposalSuperseded),
+    );
+
+    // A superseded proposal cannot be superseded again
+    assert_custom_error(
+        env.create_proposal_seeded(
+            &alice,
+            5,
+            ProposalOptions {
+                supersedes: Some(original),
+                ..Default::default()
+            },
+        )
+        .await
+        .map(|_| ()),
+        governance_error(GovernanceError::ProposalAlreadySuperseded),
+    );
+}
+
+#[tokio::test]
+async fn random_snapshot_slot_drawn_in_snapshot_epoch() {
+    let alice = Validator::new(350_000, &[50_000]);
+    let bob = Validator::new(170_000, &[30_000]);
+    let mut env = TestEnv::start(&[&alice, &bob]).await;
+
+    let proposal = env.create_proposal_with(&alice, None, true).await;
+    env.warp_to_epoch(3).await;
+    env.support_proposal(proposal, &alice).await.unwrap();
+
+    // Nothing is drawn at activation, so the slot stays unknown through discussion
+    let supported = env.proposal(proposal).await;
+    assert!(supported.is_voting());
+    assert!(supported.is_random_snapshot());
+    assert_eq!(supported.snapshot_slot, 0);
+    assert_eq!(supported.consensus_result(), None);
+    assert_custom_error(
+        env.init_ballot_box(proposal).await,
+        governance_error(GovernanceError::SnapshotSlotNotDrawn),
+    );
+    let snapshot_epoch = 3 + DISCUSSION_EPOCHS + SNAPSHOT_EPOCH_EXTENSION;
+    assert_eq!(supported.start_epoch, snapshot_epoch + 1);
+    env.warp_to_epoch(snapshot_epoch - 1).await;
+    assert_custom_error(
+        env.draw_snapshot_slot(proposal).await,
+        governance_error(GovernanceError::SnapshotDrawNotOpen),
+    );
+    env.signal(proposal, &bob, SignalLean::For).await.unwrap();
+
+    // Once the snapshot epoch has started, signals close and anyone draws the slot from the
+    // newest slot hash, which is recorded
+    env.warp_to_epoch(snapshot_epoch).await;
+    assert_custom_error(
+        env.signal(proposal, &alice, SignalLean::For).await,
+        governance_error(GovernanceError::NotInDiscussionPeriod),
+    );
+    env.draw_snapshot_slot(proposal).await.unwrap();
+    let drawn = env.proposal(proposal).await;
+    let slot_hashes = env
+        .context
+        .banks_client
+        .get_sysvar::<SlotHashes>()
+        .await
+        .unwrap();
+    let (seed_slot, seed_hash) = slot_hashes.first().unwrap();
+    assert_eq!(drawn.snapshot_seed_slot, *seed_slot);
+    assert_eq!(drawn.snapshot_seed_hash, seed_hash.to_bytes());
+
+    // Drawn at the first slot of the epoch, so any slot but the first and last
+    // SNAPSHOT_SLOT_OFFSET can come up
+    let snapshot_start = snapshot_epoch * SLOTS_PER_EPOCH;
+    let draw = hashv(&[&drawn.snapshot_seed_hash, proposal.as_ref()]).to_bytes();
+    let offset = u64::from_le_bytes(draw[..8].try_into().unwrap())
+        % (SLOTS_PER_EPOCH - 2 * SNAPSHOT_SLOT_OFFSET);
+    assert_eq!(
+        drawn.snapshot_slot,
+        snapshot_start + SNAPSHOT_SLOT_OFFSET + offset
+    );
+    assert_eq!(
+        drawn.consensus_result(),
+        Some(consensus_result_pda(drawn.snapshot_slot))
+    );
+
+    // The ballot box for the drawn slot is created separately, once
+    let ballot_box = ballot_box_pda(drawn.snapshot_slot);
+    let missing = env.context.banks_client.get_account(ballot_box).await;
+    assert!(missing.unwrap().is_none());
+    env.init_ballot_box(proposal).await.unwrap();
+    let created = env.context.banks_client.get_account(ballot_box).await;
+    assert!(created.unwrap().is_some());
+    let snapshot = env.publish_snapshot(proposal, &[&alice, &bob]).await;
+
+    // The slot is drawn only once
+    env.warp_to_epoch(drawn.start_epoch).await;
+    assert_custom_error(
+        env.draw_snapshot_slot(proposal).await,
+        governance_error(GovernanceError::SnapshotSlotAlreadyDrawn),
+    );
+    assert_custom_error(
+        env.init_ballot_box(proposal).await,
+        governance_error(GovernanceError::BallotBoxExists),
+    );
+
+    // Voting proceeds as usual on the drawn snapshot
+    env.cast_vote(proposal, &snapshot, &alice, [10_000, 0, 0])
+        .await
+        .unwrap();
+    assert_tally(&mut env, proposal, 400_000, 0, 0).await;
+}
+
+#[tokio::test]
+async fn flush_keeps_random_snapshot_slot() {
+    let alice = Validator::new(350_000, &[50_000]);
+    let bob = Validator::new(170_000, &[30_000]);
+    let mut env = TestEnv::start(&[&alice, &bob]).await;
+
+    let fixed = env.create_proposal(&alice).await;
+    let random = env.create_proposal_with(&bob, None, true).await;
+    env.warp_to_epoch(3).await;
+    env.support_proposal(fixed, &alice).await.unwrap();
+    env.support_proposal(random, &alice).await.unwrap();
+
+    // A fixed snapshot moves to the snapshot epoch after the flush, with a new ballot box
+    let supported = env.proposal(fixed).await;
+    env.flush_merkle_root(fixed, &alice).await.unwrap();
+    let flushed = env.proposal(fixed).await;
+    let target_epoch = 3 + SNAPSHOT_EPOCH_EXTENSION;
+    assert_eq!(
+        flushed.snapshot_slot,
+        target_epoch * SLOTS_PER_EPOCH + SNAPSHOT_SLOT_OFFSET
+    );
+    assert_eq!(flushed.start_epoch, target_epoch + 1);
+    assert_eq!(flushed.prior_snapshot_slots[0], supported.snapshot_slot);
+    assert_eq!(
+        flushed.consensus_result(),
+        Some(consensus_result_pda(flushed.snapshot_slot))
+    );
+    let ballot_box = ballot_box_pda(flushed.snapshot_slot);
+    let created = env.context.banks_client.get_account(ballot_box).await;
+    assert!(created.unwrap().is_some());
+
+    // A random snapshot keeps its drawn slot and schedule, so flushing cannot re-roll it
+    let snapshot_epoch = env.proposal(random).await.start_epoch - 1;
+    env.warp_to_epoch(snapshot_epoch).await;
+    env.draw_snapshot_slot(random).await.unwrap();
+    let drawn = env.proposal(random).await;
+    env.flush_merkle_root(random, &bob).await.unwrap();
+    let flushed = env.proposal(random).await;
+    assert_eq!(flushed.flush_count, 1);
+    assert_eq!(flushed.prior_snapshot_slots[0], drawn.snapshot_slot);
+    assert_eq!(flushed.snapshot_slot, drawn.snapshot_slot);
+    assert_eq!(flushed.start_epoch, drawn.start_epoch);
+    assert_eq!(flushed.end_epoch, drawn.end_epoch);
+    assert_eq!(flushed.consensus_result(), drawn.consensus_result());
+}
+
+#[tokio::test]
+async fn signals_tallied_during_discussion() {
+    let alice = Validator::new(350_000, &[50_000]);
+    let bob = Validator::new(170_000, &[30_000]);
+    let mut env = TestEnv::start(&[&alice, &bob]).await;
+
+    // No signals before voting is activated
+    let proposal = env.create_proposal(&alice).await;
+    assert_custom_error(
+        env.signal(proposal, &alice, SignalLean::For).await,
+        governance_error(GovernanceError::NotInDiscussionPeriod),
+    );
+
+    env.warp_to_epoch(3).await;
+    env.support_proposal(proposal, &alice).await.unwrap();
+    env.warp_to_epoch(4).await;
+    env.signal(proposal, &alice, SignalLean::For).await.unwrap();
+    env.signal(proposal, &bob, SignalLean::Against)
+        .await
+        .unwrap();
+    let tally: SignalTally = env.account(signal_tally_pda(&proposal)).await;
+    assert_eq!(tally.for_lamports, alice.active_stake());
+    assert_eq!(tally.against_lamports, bob.active_stake());
+    assert_eq!(tally.signal_count, 2);
+
+    // Signalling again moves the stake to the new lean
+    env.signal(proposal, &alice, SignalLean::Undecided)
+        .await
+        .unwrap();
+    let tally: SignalTally = env.account(signal_tally_pda(&proposal)).await;
+    assert_eq!(tally.for_lamports, 0);
+    assert_eq!(tally.undecided_lamports, alice.active_stake());
+    assert_eq!(tally.signal_count, 2);
+    let signal: Signal = env
+        .account(signal_pda(&proposal, &alice.vote_account))
+        .await;
+    assert_eq!(signal.lean, SignalLean::Undecided);
+
+    // Signals never touch the binding tally
+    assert_tally(&mut env, proposal, 0, 0, 0).await;
+
+    // Discussion ends at the snapshot
+    let start_epoch = env.proposal(proposal).await.start_epoch;
+    env.warp_to_epoch(start_epoch).await;
+    assert_custom_error(
+        env.signal(proposal, &bob, SignalLean::For).await,
+        governance_error(GovernanceError::NotInDiscussionPeriod),
+    );
+}
+
+#[tokio::test]
+async fn vote_with_inflated_stake_rejected() {
+    let alice = Validator::new(400_000, &[]);
+    let mut env = TestEnv::start(&[&alice]).await;
+    let proposal = env.open_proposal(&alice).await;
+    let snapshot_slot = env.proposal(proposal).await.snapshot_slot;
+
+    let stake_hashes = alice
+        .stake_leaves()
+        .iter()
+        .map(merkle::leaf_hash)
+        .collect::<Vec<_>>();
+    let (stake_merkle_root, _) = merkle::build(&stake_hashes);
+    let mut leaf = MetaMerkleLeaf {
+        voting_wallet: alice.identity.pubkey(),
+        vote_account: alice.vote_account,
+        stake_merkle_root,
+        active_stake: alice.active_stake(),
+    };
+    let (meta_merkle_root, mut proofs) = merkle::build(&[merkle::leaf_hash(&leaf)]);
+    env.create_consensus_result(snapshot_slot, meta_merkle_root)
+        .await;
+
+    // Publish a proof account whose leaf claims more stake than the snapshot holds
+    let consensus_result = consensus_result_pda(snapshot_slot);
+    leaf.active_stake *= 2;
+    env.init_meta_merkle_proof(consensus_result, leaf, proofs.remove(0))
+        .await;
+
+    let start_epoch = env.proposal(proposal).await.start_epoch;
+    env.warp_to_epoch(start_epoch).await;
+    let snapshot = Snapshot {
+        consensus_result: Some(consensus_result),
+        stake_proofs: Vec::new(),
+    };
+    assert_custom_error(
+        env.cast_vote(proposal, &snapshot, &alice, [10_000, 0, 0])
+            .await,
+        mock_gov_error(MockGovError::InvalidMerkleProof),
+    );
+    assert_eq!(env.proposal(proposal).await.vote_count, 0);
+}
+
+#[tokio::test]
+async fn epoch_stake_proposal_votes_without_snapshot() {
+    let alice = Validator::new(400_000, &[50_000]);
+    let bob = Validator::new(150_000, &[]);
+    let carol = Validator::new(100_000, &[]);
+    let mut env = TestEnv::start(&[&alice, &bob, &carol]).await;
+
+    let options = ProposalOptions {
+        stake_source: StakeSource::EpochStake,
+        ..Default::default()
+    };
+    let proposal = env
+        .create_proposal_seeded(&alice, PROPOSAL_SEED, options)
+        .await
+        .unwrap();
+    let creation_epoch = env.current_epoch().await;
+    env.warp_to_epoch(creation_epoch + 1).await;
+    env.support_proposal(proposal, &alice).await.unwrap();
+
+    // Voting starts without a consensus result or ballot box
+    let supported = env.proposal(proposal).await;
+    assert!(supported.is_voting());
+    assert_eq!(supported.stake_source(), StakeSource::EpochStake);
+    assert_eq!(supported.consensus_result(), None);
+    assert_custom_error(
+        env.init_ballot_box(proposal).await,
+        governance_error(GovernanceError::EpochStakeProposal),
+    );
+
+    // Stake is read from the vote accounts in the first voting epoch, when the runtime
+    // reports the stake of the epoch before voting
+    env.warp_to_epoch(supported.start_epoch).await;
+    let stake = Snapshot::epoch_stake(&[&alice, &bob, &carol]);
+    env.cast_vote(proposal, &stake, &alice, [10_000, 0, 0])
+        .await
+        .unwrap();
+    assert_tally(&mut env, proposal, 450_000, 0, 0).await;
+
+    // Delegators cannot override, also not after splitting their stake account: the split
+    // stake would look like any other delegation to alice and be counted twice
+    let delegator = &alice.delegators[0];
+    let split = Validator::new(0, &[delegator.stake / 2]);
+    let split_off = &split.delegators[0];
+    env.context.set_account(
+        &delegator.stake_account,
+        &stake_account(
+            &alice.vote_account,
+            &delegator.staker.pubkey(),
+            delegator.stake - split_off.stake,
+        )
+        .into(),
+    );
+    env.context
+        .set_account(&split_off.staker.pubkey(), &funded_account().into());
+    env.context.set_account(
+        &split_off.stake_account,
+        &stake_account(
+            &alice.vote_account,
+            &split_off.staker.pubkey(),
+            split_off.stake,
+        )
+        .into(),
+    );
+    for override_with in [delegator, split_off] {
+        assert_custom_error(
+            env.cast_vote_override(proposal, &stake, &alice, override_with, [0, 10_000, 0])
+                .await,
+            governance_error(GovernanceError::EpochStakeProposal),
+        );
+    }
+    assert_tally(&mut env, proposal, 450_000, 0, 0).await;
+
+    env.cast_vote(proposal, &stake, &bob, [0, 0, 10_000])
+        .await
+        .unwrap();
+    assert_tally(&mut env, proposal, 450_000, 0, 150_000).await;
+
+    // Later the runtime reports stake that may have moved during voting. New votes are
+    // rejected, and modified votes keep the stake they were cast with.
+    env.warp_to_epoch(supported.start_epoch + 1).await;
+    assert_custom_error(
+        env.cast_vote(proposal, &stake, &carol, [10_000, 0, 0])
+            .await,
+        governance_error(GovernanceError::EpochStakeVotingStarted),
+    );
+    env.modify_vote(proposal, &stake, &alice, [0, 10_000, 0])
+        .await
+        .unwrap();
+    assert_tally(&mut env, proposal, 0, 450_000, 150_000).await;
+
+    env.warp_to_epoch(supported.end_epoch).await;
+    env.finalize_proposal(proposal).await.unwrap();
+    assert_eq!(
+        env.proposal(proposal).await.outcome(),
+        ProposalOutcome::Rejected
+    );
+}
+
+#[tokio::test]
+async fn signed_vote_relayed() {
+    let alice = Validator::new(350_000, &[50_000]);
+    let bob = Validator::new(150_000, &[]);
+    let mut env = TestEnv::start(&[&alice, &bob]).await;
+    let proposal = env.open_proposal(&alice).await;
+    let snapshot = env.publish_snapshot(proposal, &[&alice, &bob]).await;
+    let start_epoch = env.proposal(proposal).await.start_epoch;
+    env.warp_to_epoch(start_epoch).await;
+
+    let now = env
+        .context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp;
+    let vote = SignedVote {
+        proposal,
+        vote_account: alice.vote_account,
+        for_votes_bp: 10_000,
+        against_votes_bp: 0,
+        abstain_votes_bp: 0,
+        nonce: 7,
+        expiry: now + 3_600,
+    };
+
+    // Only the vote account's voting wallet can sign for it, and only for the split submitted
+    assert_custom_error(
+        env.cast_vote_signed(proposal, &snapshot, &vote, None).await,
+        governance_error(GovernanceError::InvalidVoteSignature),
+    );
+    assert_custom_error(
+        env.cast_vote_signed(proposal, &snapshot, &vote, Some((&bob.identity, &vote)))
+            .await,
+        governance_error(GovernanceError::InvalidVoteSignature),
+    );
+    let against = SignedVote {
+        for_votes_bp: 0,
+        against_votes_bp: 10_000,
+        ..vote.clone()
+    };
+    assert_custom_error(
+        env.cast_vote_signed(
+            proposal,
+            &snapshot,
+            &against,
+            Some((&alice.identity, &vote)),
+        )
+        .await,
+        governance_error(GovernanceError::InvalidVoteSignature),
+    );
+    let expired = SignedVote {
+        expiry: now - 1,
+        ..vote.clone()
+    };
+    assert_custom_error(
+        env.cast_vote_signed(
+            proposal,
+            &snapshot,
+            &expired,
+            Some((&alice.identity, &expired)),
+        )
+        .await,
+        governance_error(GovernanceError::SignedVoteExpired),
+    );
+
+    // A vote signed for another proposal can't be submitted against this one
+    let misdirected = SignedVote {
+        proposal: Pubkey::new_unique(),
+        ..vote.clone()
+    };
+    assert_custom_error(
+        env.cast_vote_signed(
+            proposal,
+            &snapshot,
+            &misdirected,
+            Some((&alice.identity, &misdirected)),
+        )
+        .await,
+        governance_error(GovernanceError::SignedVoteMismatch),
+    );
+
+    env.cast_vote_signed(proposal, &snapshot, &vote, Some((&alice.identity, &vote)))
+        .await
+        .unwrap();
+    let record: Vote = env.account(vote_pda(&proposal, &alice.vote_account)).await;
+    assert_eq!(record.validator, alice.identity.pubkey());
+    assert_tally(&mut env, proposal, 400_000, 0, 0).await;
+
+    // The vote account already exists, so the same signed vote can't be relayed twice
+    env.warp_to_epoch(start_epoch + 1).await;
+    assert!(env
+        .cast_vote_signed(proposal, &snapshot, &vote, Some((&alice.identity, &vote)))
+        .await
+        .is_err());
+    assert_tally(&mut env, proposal, 400_000, 0, 0).await;
+}
+
+#[tokio::test]
+#[ignore = "needs SBF builds of both programs, run with `cargo test-sbf -- --ignored`"]
+async fn compute_unit_budgets() {
+    let alice = Validator::new(350_000, &[50_000]);
+    let bob = Validator::new(170_000, &[30_000]);
+    let mut env = TestEnv::start_with(&[&alice, &bob], true).await;
+
+    let proposal = env.create_proposal(&alice).await;
+    env.assert_within_budget("create_proposal");
+
+    env.warp_to_epoch(3).await;
+    env.support_proposal(proposal, &alice).await.unwrap();
+    env.assert_within_budget("support_proposal");
+
+    let snapshot = env.publish_snapshot(proposal, &[&alice, &bob]).await;
+    let start_epoch = env.proposal(proposal).await.start_epoch;
+    env.warp_to_epoch(start_epoch).await;
+
+    // Overrides on both sides of the validator vote, since they take different paths
+    let bob_delegator = &bob.delegators[0];
+    env.cast_vote_override(proposal, &snapshot, &bob, bob_delegator, [0, 10_000, 0])
+        .await
+        .unwrap();
+    env.assert_within_budget("cast_vote_override");
+
+    env.cast_vote(proposal, &snapshot, &bob, [10_000, 0, 0])
+        .await
+        .unwrap();
+    env.assert_within_budget("cast_vote");
+
+    env.cast_vote(proposal, &snapshot, &alice, [10_000, 0, 0])
+        .await
+        .unwrap();
+    env.assert_within_budget("cast_vote");
+
+    let alice_delegator = &alice.delegators[0];
+    env.cast_vote_override(proposal, &snapshot, &alice, alice_delegator, [0, 10_000, 0])
+        .await
+        .unwrap();
+    env.assert_within_budget("cast_vote_override");
+
+    env.modify_vote_override(proposal, &snapshot, &alice, alice_delegator, [0, 0, 10_000])
+        .await
+        .unwrap();
+    env.assert_within_budget("modify_vote_override");
+
+    env.modify_vote(proposal, &snapshot, &alice, [5_000, 5_000, 0])
+        .await
+        .unwrap();
+    env.assert_within_budget("modify_vote");
+
+    env.warp_to_epoch(start_epoch + VOTING_EPOCHS).await;
+    env.finalize_proposal(proposal).await.unwrap();
+    env.assert_within_budget("finalize_proposal");
+}
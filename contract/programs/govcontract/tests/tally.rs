@@ -0,0 +1,327 @@
+//! Property tests for the vote tally arithmetic.
+//!
+//! A model of the vote accounts replays random sequences of validator votes and delegator
+//! overrides through `govcontract::tally`, the same way the instructions do. After every step
+//! the proposal totals must match a tally recomputed from scratch out of each account's
+//! effective stake and vote distribution.
+
+use govcontract::{
+    constants::BASIS_POINTS_MAX,
+    tally::{self, VoteLamports},
+};
+use proptest::{prelude::*, sample::Index};
+
+type Bp = [u64; 3];
+
+fn split(stake: u64, bp: Bp) -> VoteLamports {
+    VoteLamports::from_stake(stake, bp[0], bp[1], bp[2]).unwrap()
+}
+
+fn sum(lamports: VoteLamports) -> u64 {
+    lamports.for_votes + lamports.against_votes + lamports.abstain_votes
+}
+
+#[derive(Clone, Debug)]
+enum Action {
+    CastVote { validator: Index, bp: Bp },
+    ModifyVote { validator: Index, bp: Bp },
+    CastVoteOverride { delegator: Index, bp: Bp },
+    ModifyVoteOverride { delegator: Index, bp: Bp },
+}
+
+/// Mirrors a `Vote` account
+struct ValidatorVote {
+    bp: Bp,
+    lamports: VoteLamports,
+    override_stake: u64,
+}
+
+/// Mirrors a `VoteOverride` account
+struct DelegatorOverride {
+    bp: Bp,
+    lamports: VoteLamports,
+}
+
+struct Validator {
+    stake: u64,
+    vote: Option<ValidatorVote>,
+    // Mirrors the `VoteOverrideCache` account
+    cached: VoteLamports,
+    cached_stake: u64,
+}
+
+struct Delegator {
+    validator: usize,
+    stake: u64,
+    vote_override: Option<DelegatorOverride>,
+}
+
+struct Model {
+    totals: VoteLamports,
+    validators: Vec<Validator>,
+    delegators: Vec<Delegator>,
+}
+
+impl Model {
+    fn new(validators: Vec<(u64, Vec<u64>)>) -> Self {
+        let mut model = Model {
+            totals: VoteLamports::default(),
+            validators: Vec::new(),
+            delegators: Vec::new(),
+        };
+        for (own_stake, delegator_stakes) in validators {
+            let validator = model.validators.len();
+            model
+                .delegators
+                .extend(delegator_stakes.iter().map(|&stake| Delegator {
+                    validator,
+                    stake,
+                    vote_override: None,
+                }));
+            model.validators.push(Validator {
+                stake: own_stake + delegator_stakes.iter().sum::<u64>(),
+                vote: None,
+                cached: VoteLamports::default(),
+                cached_stake: 0,
+            });
+        }
+        model
+    }
+
+    /// Applies an action the way the matching instruction would. Actions the program would
+    /// reject, like voting twice, are skipped.
+    fn apply(&mut self, action: &Action) {
+        match action {
+            Action::CastVote { validator, bp } => {
+                let index = validator.index(self.validators.len());
+                let validator = &mut self.validators[index];
+                if validator.vote.is_some() {
+                    return;
+                }
+                let result = tally::cast_vote(
+                    self.totals,
+                    validator.stake,
+                    validator.cached,
+                    validator.cached_stake,
+                    bp[0],
+                    bp[1],
+                    bp[2],
+                )
+                .unwrap();
+                self.totals = result.totals;
+                validator.vote = Some(ValidatorVote {
+                    bp: *bp,
+                    lamports: result.validator,
+                    override_stake: validator.cached_stake,
+                });
+            }
+            Action::ModifyVote { validator, bp } => {
+                let index = validator.index(self.validators.len());
+                let validator = &mut self.validators[index];
+                let Some(vote) = validator.vote.as_mut() else {
+                    return;
+                };
+                let result = tally::modify_vote(
+                    self.totals,
+                    vote.lamports,
+                    validator.stake,
+                    vote.override_stake,
+                    bp[0],
+                    bp[1],
+                    bp[2],
+                )
+                .unwrap();
+                self.totals = result.totals;
+                vote.bp = *bp;
+                vote.lamports = result.validator;
+            }
+            Action::CastVoteOverride { delegator, bp } => {
+                if self.delegators.is_empty() {
+                    return;
+                }
+                let index = delegator.index(self.delegators.len());
+                let delegator = &mut self.delegators[index];
+                if delegator.vote_override.is_some() {
+                    return;
+                }
+                let validator = &mut self.validators[delegator.validator];
+                let lamports = split(delegator.stake, *bp);
+
+                if let Some(vote) = validator.vote.as_mut() {
+                    let override_stake = vote.override_stake + delegator.stake;
+                    let result = tally::override_vote(
+                        self.totals,
+                        vote.lamports,
+                        lamports,
+                        validator.stake,
+                        override_stake,
+                        vote.bp[0],
+                        vote.bp[1],
+                        vote.bp[2],
+                    )
+                    .unwrap();
+                    self.totals = result.totals;
+                    vote.lamports = result.validator;
+                    vote.override_stake = override_stake;
+                }
+                // The cache keeps accumulating after the validator voted, it is just never read again
+                validator.cached = validator.cached.checked_add(lamports).unwrap();
+                validator.cached_stake += delegator.stake;
+                delegator.vote_override = Some(DelegatorOverride { bp: *bp, lamports });
+            }
+            Action::ModifyVoteOverride { delegator, bp } => {
+                if self.delegators.is_empty() {
+                    return;
+                }
+                let index = delegator.index(self.delegators.len());
+                let delegator = &mut self.delegators[index];
+                let Some(vote_override) = delegator.vote_override.as_mut() else {
+                    return;
+                };
+                let validator = &mut self.validators[delegator.validator];
+                let lamports = split(delegator.stake, *bp);
+
+                if validator.vote.is_some() {
+                    self.totals =
+                        tally::replace_votes(self.totals, vote_override.lamports, lamports)
+                            .unwrap();
+                } else {
+                    validator.cached =
+                        tally::replace_votes(validator.cached, vote_override.lamports, lamports)
+                            .unwrap();
+                }
+                vote_override.bp = *bp;
+                vote_override.lamports = lamports;
+            }
+        }
+    }
+
+    /// Recomputes the totals from each account's effective stake, ignoring stored lamports:
+    /// a voted validator counts its stake minus every overriding delegator, and each
+    /// overriding delegator counts its own stake once its validator has voted.
+    fn expected_totals(&self) -> VoteLamports {
+        let mut expected = VoteLamports::default();
+        for (index, validator) in self.validators.iter().enumerate() {
+            let Some(vote) = validator.vote.as_ref() else {
+                continue;
+            };
+            let overrides = self
+                .delegators
+                .iter()
+                .filter(|delegator| delegator.validator == index)
+                .filter_map(|delegator| {
+                    delegator
+                        .vote_override
+                        .as_ref()
+                        .map(|vote_override| (delegator.stake, vote_override.bp))
+                })
+                .collect::<Vec<_>>();
+            let overridden_stake = overrides.iter().map(|(stake, _)| stake).sum::<u64>();
+
+            expected = expected
+                .checked_add(split(validator.stake - overridden_stake, vote.bp))
+                .unwrap();
+            for (stake, bp) in overrides {
+                expected = expected.checked_add(split(stake, bp)).unwrap();
+            }
+        }
+        expected
+    }
+
+    /// Stake of every validator that has voted, and the number of stake splits behind it
+    fn counted_stake(&self) -> (u64, u64) {
+        let mut stake = 0;
+        let mut splits = 0;
+        for (index, validator) in self.validators.iter().enumerate() {
+            if validator.vote.is_none() {
+                continue;
+            }
+            stake += validator.stake;
+            splits += 1 + self
+                .delegators
+                .iter()
+                .filter(|d| d.validator == index && d.vote_override.is_some())
+                .count() as u64;
+        }
+        (stake, splits)
+    }
+}
+
+fn bp() -> impl Strategy<Value = Bp> {
+    (0..=BASIS_POINTS_MAX)
+        .prop_flat_map(|for_bp| (Just(for_bp), 0..=BASIS_POINTS_MAX - for_bp))
+        .prop_map(|(for_bp, against_bp)| {
+            [for_bp, against_bp, BASIS_POINTS_MAX - for_bp - against_bp]
+        })
+}
+
+fn action() -> impl Strategy<Value = Action> {
+    prop_oneof![
+        (any::<Index>(), bp()).prop_map(|(validator, bp)| Action::CastVote { validator, bp }),
+        (any::<Index>(), bp()).prop_map(|(validator, bp)| Action::ModifyVote { validator, bp }),
+        (any::<Index>(), bp())
+            .prop_map(|(delegator, bp)| Action::CastVoteOverride { delegator, bp }),
+        (any::<Index>(), bp())
+            .prop_map(|(delegator, bp)| Action::ModifyVoteOverride { delegator, bp }),
+    ]
+}
+
+/// Validators as (own stake, delegator stakes), up to ~100M SOL each
+fn validators() -> impl Strategy<Value = Vec<(u64, Vec<u64>)>> {
+    let stake = 1..=20_000_000_000_000_000u64;
+    prop::collection::vec(
+        (
+            0..=20_000_000_000_000_000u64,
+            prop::collection::vec(stake, 0..5),
+        ),
+        1..4,
+    )
+}
+
+proptest! {
+    #[test]
+    fn totals_match_effective_stake(
+        validators in validators(),
+        actions in prop::collection::vec(action(), 1..64),
+    ) {
+        let mut model = Model::new(validators);
+        for action in actions.iter() {
+            model.apply(action);
+
+            prop_assert_eq!(model.totals, model.expected_totals());
+
+            // Rounding each split down loses at most 2 lamports, and nothing is counted twice
+            let (stake, splits) = model.counted_stake();
+            let counted = sum(model.totals);
+            prop_assert!(counted <= stake);
+            prop_assert!(stake - counted <= 2 * splits);
+        }
+    }
+
+    #[test]
+    fn split_never_exceeds_stake(stake in any::<u64>(), bp in bp()) {
+        let lamports = split(stake, bp);
+        let counted = lamports.for_votes as u128
+            + lamports.against_votes as u128
+            + lamports.abstain_votes as u128;
+        prop_assert!(counted <= stake as u128);
+        prop_assert!(stake as u128 - counted <= 2);
+    }
+}
+
+#[test]
+fn overrides_above_validator_stake_rejected() {
+    // Overrides are proven against the validator's own snapshot, so they never add up to more
+    // than its stake
+    let cached = split(150, [0, BASIS_POINTS_MAX, 0]);
+    assert!(tally::cast_vote(
+        VoteLamports::default(),
+        100,
+        cached,
+        150,
+        BASIS_POINTS_MAX,
+        0,
+        0,
+    )
+    .is_err());
+}
@@ -0,0 +1,210 @@
+//! Minimal stand-in for the gov-v1 snapshot program, used by the govcontract test suite.
+//!
+//! Account and instruction names match gov-v1 so discriminators line up, and merkle proofs are
+//! actually verified. Operator voting on ballot boxes is skipped: consensus results are created
+//! directly by anyone.
+#![allow(unexpected_cfgs)]
+pub mod merkle;
+pub mod state;
+
+use anchor_lang::prelude::*;
+
+pub use state::*;
+
+declare_id!("8qua2VAnWaNFDAhUakryMuRdNRVuUehkjnhLECvueGSV");
+
+#[program]
+pub mod mock_gov_v1 {
+    use super::*;
+
+    pub fn init_program_config(ctx: Context<InitProgramConfig>) -> Result<()> {
+        ctx.accounts.program_config.authority = ctx.accounts.authority.key();
+        Ok(())
+    }
+
+    pub fn init_ballot_box(
+        ctx: Context<InitBallotBox>,
+        snapshot_slot: u64,
+        _proposal_seed: u64,
+        _spl_vote_account: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.ballot_box.set_inner(BallotBox {
+            bump: ctx.bumps.ballot_box,
+            snapshot_slot,
+        });
+        Ok(())
+    }
+
+    pub fn create_consensus_result(
+        ctx: Context<CreateConsensusResult>,
+        snapshot_slot: u64,
+        meta_merkle_root: [u8; 32],
+        snapshot_hash: [u8; 32],
+        tie_breaker_consensus: bool,
+    ) -> Result<()> {
+        ctx.accounts.consensus_result.set_inner(ConsensusResult {
+            snapshot_slot,
+            ballot: Ballot {
+                meta_merkle_root,
+                snapshot_hash,
+            },
+            tie_breaker_consensus,
+        });
+        Ok(())
+    }
+
+    pub fn init_meta_merkle_proof(
+        ctx: Context<InitMetaMerkleProof>,
+        meta_merkle_leaf: MetaMerkleLeaf,
+        meta_merkle_proof: Vec<[u8; 32]>,
+        close_timestamp: i64,
+    ) -> Result<()> {
+        ctx.accounts.merkle_proof.set_inner(MetaMerkleProof {
+            payer: ctx.accounts.payer.key(),
+            consensus_result: ctx.accounts.consensus_result.key(),
+            meta_merkle_leaf,
+            meta_merkle_proof,
+            close_timestamp,
+        });
+        Ok(())
+    }
+
+    pub fn close_meta_merkle_proof(ctx: Context<CloseMetaMerkleProof>) -> Result<()> {
+        require!(
+            Clock::get()?.unix_timestamp >= ctx.accounts.meta_merkle_proof.close_timestamp,
+            MockGovError::CloseTimestampNotReached
+        );
+        Ok(())
+    }
+
+    pub fn verify_merkle_proof(
+        ctx: Context<VerifyMerkleProof>,
+        stake_merkle_proof: Option<Vec<[u8; 32]>>,
+        stake_merkle_leaf: Option<StakeMerkleLeaf>,
+    ) -> Result<()> {
+        let meta_merkle_proof = &ctx.accounts.meta_merkle_proof;
+        require!(
+            merkle::verify(
+                &meta_merkle_proof.meta_merkle_proof,
+                &ctx.accounts.consensus_result.ballot.meta_merkle_root,
+                merkle::leaf_hash(&meta_merkle_proof.meta_merkle_leaf),
+            ),
+            MockGovError::InvalidMerkleProof
+        );
+
+        match (stake_merkle_proof, stake_merkle_leaf) {
+            (None, None) => Ok(()),
+            (Some(proof), Some(leaf)) => {
+                require!(
+                    merkle::verify(
+                        &proof,
+                        &meta_merkle_proof.meta_merkle_leaf.stake_merkle_root,
+                        merkle::leaf_hash(&leaf),
+                    ),
+                    MockGovError::InvalidMerkleProof
+                );
+                Ok(())
+            }
+            _ => err!(MockGovError::InvalidMerkleInputs),
+        }
+    }
+}
+
+#[derive(Accounts)]
+pub struct InitProgramConfig<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ProgramConfig::INIT_SPACE,
+        seeds = [b"ProgramConfig"],
+        bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(snapshot_slot: u64)]
+pub struct InitBallotBox<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// Signed by govcontract with the proposal PDA seeds
+    pub proposal: Signer<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + BallotBox::INIT_SPACE,
+        seeds = [b"BallotBox", snapshot_slot.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub ballot_box: Account<'info, BallotBox>,
+    pub program_config: Account<'info, ProgramConfig>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(snapshot_slot: u64)]
+pub struct CreateConsensusResult<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ConsensusResult::INIT_SPACE,
+        seeds = [b"ConsensusResult", snapshot_slot.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub consensus_result: Account<'info, ConsensusResult>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(meta_merkle_leaf: MetaMerkleLeaf, meta_merkle_proof: Vec<[u8; 32]>)]
+pub struct InitMetaMerkleProof<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + MetaMerkleProof::space(meta_merkle_proof.len()),
+        seeds = [
+            b"MetaMerkleProof",
+            consensus_result.key().as_ref(),
+            meta_merkle_leaf.vote_account.as_ref()
+        ],
+        bump
+    )]
+    pub merkle_proof: Account<'info, MetaMerkleProof>,
+    pub consensus_result: Account<'info, ConsensusResult>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CloseMetaMerkleProof<'info> {
+    /// CHECK: Receives the proof's rent, checked against the proof account
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+    #[account(mut, has_one = payer, close = payer)]
+    pub meta_merkle_proof: Account<'info, MetaMerkleProof>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct VerifyMerkleProof<'info> {
+    #[account(has_one = consensus_result)]
+    pub meta_merkle_proof: Account<'info, MetaMerkleProof>,
+    pub consensus_result: Account<'info, ConsensusResult>,
+}
+
+#[error_code]
+pub enum MockGovError {
+    #[msg("Merkle proof does not match the root")]
+    InvalidMerkleProof,
+    #[msg("Stake merkle proof and leaf must be provided together")]
+    InvalidMerkleInputs,
+    #[msg("Meta merkle proof cannot be closed before its close timestamp")]
+    CloseTimestampNotReached,
+}
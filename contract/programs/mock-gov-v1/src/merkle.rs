@@ -0,0 +1,51 @@
+use anchor_lang::{prelude::*, solana_program::hash::hashv};
+
+const LEAF_PREFIX: &[u8] = &[0];
+const INTERMEDIATE_PREFIX: &[u8] = &[1];
+
+/// Hashes a borsh-serializable leaf
+pub fn leaf_hash<T: AnchorSerialize>(leaf: &T) -> [u8; 32] {
+    let data = borsh::to_vec(leaf).unwrap();
+    hashv(&[LEAF_PREFIX, &hashv(&[&data]).to_bytes()]).to_bytes()
+}
+
+/// Hashes two sibling nodes, ordered so proofs don't need to carry left/right flags
+pub fn node_hash(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    hashv(&[INTERMEDIATE_PREFIX, left, right]).to_bytes()
+}
+
+pub fn verify(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    proof.iter().fold(leaf, |node, sibling| node_hash(&node, sibling)) == *root
+}
+
+/// Builds a tree over the given leaf hashes and returns its root with a proof for every leaf.
+/// An odd node at the end of a level is promoted unchanged.
+pub fn build(leaves: &[[u8; 32]]) -> ([u8; 32], Vec<Vec<[u8; 32]>>) {
+    assert!(!leaves.is_empty(), "merkle tree needs at least one leaf");
+
+    let mut proofs = vec![Vec::new(); leaves.len()];
+    // Index of each original leaf's ancestor in the current level
+    let mut positions = (0..leaves.len()).collect::<Vec<_>>();
+    let mut level = leaves.to_vec();
+
+    while level.len() > 1 {
+        for (leaf, position) in positions.iter_mut().enumerate() {
+            let sibling = *position ^ 1;
+            if sibling < level.len() {
+                proofs[leaf].push(level[sibling]);
+            }
+            *position /= 2;
+        }
+        level = level
+            .chunks(2)
+            .map(|pair| match pair {
+                [a, b] => node_hash(a, b),
+                [a] => *a,
+                _ => unreachable!(),
+            })
+            .collect();
+    }
+
+    (level[0], proofs)
+}
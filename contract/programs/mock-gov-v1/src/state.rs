@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+// Layouts mirror gov-v1 so govcontract can deserialize these accounts with the gov-v1 types
+
+#[account]
+#[derive(InitSpace)]
+pub struct ProgramConfig {
+    pub authority: Pubkey,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct BallotBox {
+    pub bump: u8,
+    pub snapshot_slot: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace)]
+pub struct Ballot {
+    pub meta_merkle_root: [u8; 32],
+    pub snapshot_hash: [u8; 32],
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct ConsensusResult {
+    pub snapshot_slot: u64,
+    pub ballot: Ballot,
+    pub tie_breaker_consensus: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
+pub struct MetaMerkleLeaf {
+    pub voting_wallet: Pubkey,
+    pub vote_account: Pubkey,
+    pub stake_merkle_root: [u8; 32],
+    pub active_stake: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
+pub struct StakeMerkleLeaf {
+    pub voting_wallet: Pubkey,
+    pub stake_account: Pubkey,
+    pub active_stake: u64,
+}
+
+#[account]
+pub struct MetaMerkleProof {
+    pub payer: Pubkey,
+    pub consensus_result: Pubkey,
+    pub meta_merkle_leaf: MetaMerkleLeaf,
+    pub meta_merkle_proof: Vec<[u8; 32]>,
+    pub close_timestamp: i64,
+}
+
+impl MetaMerkleProof {
+    pub fn space(proof_len: usize) -> usize {
+        32 + 32 + MetaMerkleLeaf::INIT_SPACE + 4 + proof_len * 32 + 8
+    }
+}
@@ -7,6 +7,7 @@ import { LAMPORTS_PER_SOL, StakeProgram, SystemProgram } from "@solana/web3.js";
 import { setupTestEnvironment, TestAccounts } from "./test-setup";
 import { TEST_PROPOSAL_PARAMS, MERKLE_ROOT_HASH } from "./test-constants";
 import {
+  createProposal,
   deriveVoteOverrideAccount,
   deriveVoteOverrideCacheAccount,
   createEventListener,
@@ -27,23 +28,13 @@ describe.only("Cache Serialization Isolated Test", () => {
     testAccounts = await setupTestEnvironment(program, mockProgram, seed);
 
     // Create proposal
-    await program.methods
-      .createProposal(
-        seed,
-        TEST_PROPOSAL_PARAMS.title,
-        TEST_PROPOSAL_PARAMS.description
-      )
-      .accountsPartial({
-        signer: provider.publicKey,
-        proposal: testAccounts.proposalAccount,
-        proposalIndex: testAccounts.proposalIndexAccount,
-        splVoteAccount: testAccounts.splVoteAccounts[0].publicKey,
-        snapshotProgram: mockProgram.programId,
-        consensusResult: testAccounts.consensusResult,
-        metaMerkleProof: testAccounts.metaMerkleProofs[0],
-        systemProgram: anchor.web3.SystemProgram.programId,
-      })
-      .rpc();
+    await createProposal(
+      program,
+      seed,
+      testAccounts.splVoteAccounts[0].publicKey,
+      TEST_PROPOSAL_PARAMS.title,
+      TEST_PROPOSAL_PARAMS.description
+    );
 
     // Add merkle root
     await program.methods
@@ -14,6 +14,7 @@ import {
   MERKLE_ROOT_HASH,
 } from "./test-constants";
 import {
+  createProposal,
   deriveVoteOverrideAccount,
   deriveVoteOverrideCacheAccount,
 } from "./test-helpers";
@@ -215,23 +216,13 @@ describe.only("Detailed Vote Tracking Test", () => {
     );
 
     // STEP 1: Create Proposal
-    await program.methods
-      .createProposal(
-        seed,
-        TEST_PROPOSAL_PARAMS.title,
-        TEST_PROPOSAL_PARAMS.description
-      )
-      .accountsPartial({
-        signer: provider.publicKey,
-        proposal: testAccounts.proposalAccount,
-        proposalIndex: testAccounts.proposalIndexAccount,
-        splVoteAccount: testAccounts.splVoteAccounts[0].publicKey,
-        snapshotProgram: mockProgram.programId,
-        consensusResult: testAccounts.consensusResult,
-        metaMerkleProof: testAccounts.metaMerkleProofs[0],
-        systemProgram: anchor.web3.SystemProgram.programId,
-      })
-      .rpc();
+    await createProposal(
+      program,
+      seed,
+      testAccounts.splVoteAccounts[0].publicKey,
+      TEST_PROPOSAL_PARAMS.title,
+      TEST_PROPOSAL_PARAMS.description
+    );
 
     await program.methods
       .addMerkleRoot(MERKLE_ROOT_HASH)
@@ -16,6 +16,7 @@ import {
   MERKLE_ROOT_HASH,
 } from "./test-constants";
 import {
+  createProposal,
   deriveVoteOverrideAccount,
   deriveVoteOverrideCacheAccount,
 } from "./test-helpers";
@@ -50,23 +51,13 @@ describe("DoS Prefunding Attack Test", () => {
     }
 
     // Create proposal
-    await program.methods
-      .createProposal(
-        seed,
-        TEST_PROPOSAL_PARAMS.title,
-        TEST_PROPOSAL_PARAMS.description
-      )
-      .accountsPartial({
-        signer: provider.publicKey,
-        proposal: testAccounts.proposalAccount,
-        proposalIndex: testAccounts.proposalIndexAccount,
-        splVoteAccount: testAccounts.splVoteAccounts[0].publicKey,
-        snapshotProgram: mockProgram.programId,
-        consensusResult: testAccounts.consensusResult,
-        metaMerkleProof: testAccounts.metaMerkleProofs[0],
-        systemProgram: anchor.web3.SystemProgram.programId,
-      })
-      .rpc();
+    await createProposal(
+      program,
+      seed,
+      testAccounts.splVoteAccounts[0].publicKey,
+      TEST_PROPOSAL_PARAMS.title,
+      TEST_PROPOSAL_PARAMS.description
+    );
 
     // Add merkle root
     await program.methods
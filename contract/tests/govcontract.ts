@@ -16,12 +16,9 @@ import {
   ERROR_TEST_PARAMS,
 } from "./test-constants";
 import {
-  deriveProposalAccount,
-  deriveProposalIndexAccount,
+  createProposal,
   deriveSupportAccount,
   deriveVoteAccount,
-  deriveConsensusResultAccount,
-  deriveMetaMerkleProofAccount,
   deriveVoteOverrideAccount,
   deriveVoteOverrideCacheAccount,
   createEventListener,
@@ -65,23 +62,13 @@ describe("govcontract", () => {
     });
 
     try {
-      const tx = await program.methods
-        .createProposal(
-          seed,
-          TEST_PROPOSAL_PARAMS.title,
-          TEST_PROPOSAL_PARAMS.description
-        )
-        .accountsPartial({
-          signer: provider.publicKey,
-          proposal: testAccounts.proposalAccount,
-          proposalIndex: testAccounts.proposalIndexAccount,
-          splVoteAccount: testAccounts.splVoteAccounts[0].publicKey,
-          snapshotProgram: mockProgram.programId,
-          consensusResult: testAccounts.consensusResult,
-          metaMerkleProof: testAccounts.metaMerkleProofs[0],
-          systemProgram: anchor.web3.SystemProgram.programId,
-        })
-        .rpc();
+      const tx = await createProposal(
+        program,
+        seed,
+        testAccounts.splVoteAccounts[0].publicKey,
+        TEST_PROPOSAL_PARAMS.title,
+        TEST_PROPOSAL_PARAMS.description
+      );
 
       console.log("Proposal created, signature:", tx);
       await new Promise(resolve => setTimeout(resolve, 1000));
@@ -715,23 +702,13 @@ describe("govcontract - Error Cases", () => {
     );
 
     try {
-      await program.methods
-        .createProposal(
-          testSeed,
-          ERROR_TEST_PARAMS.emptyTitle,
-          TEST_PROPOSAL_PARAMS.description
-        )
-        .accountsPartial({
-          signer: provider.publicKey,
-          proposal: deriveProposalAccount(program, testSeed, splVoteAccount.publicKey),
-          proposalIndex: deriveProposalIndexAccount(program),
-          splVoteAccount: splVoteAccount.publicKey,
-          snapshotProgram: mockProgram.programId,
-          consensusResult: deriveConsensusResultAccount(mockProgram),
-          metaMerkleProof: deriveMetaMerkleProofAccount(mockProgram, deriveConsensusResultAccount(mockProgram), splVoteAccount.publicKey),
-          systemProgram: anchor.web3.SystemProgram.programId,
-        })
-        .rpc();
+      await createProposal(
+        program,
+        testSeed,
+        splVoteAccount.publicKey,
+        ERROR_TEST_PARAMS.emptyTitle,
+        TEST_PROPOSAL_PARAMS.description
+      );
 
       throw new Error("Expected error was not thrown - empty title should be rejected");
     } catch (error: any) {
@@ -774,23 +751,13 @@ describe("govcontract - Error Cases", () => {
     );
 
     try {
-      await program.methods
-        .createProposal(
-          testSeed,
-          TEST_PROPOSAL_PARAMS.title,
-          ERROR_TEST_PARAMS.emptyDescription
-        )
-        .accountsPartial({
-          signer: provider.publicKey,
-          proposal: deriveProposalAccount(program, testSeed, splVoteAccount.publicKey),
-          proposalIndex: deriveProposalIndexAccount(program),
-          splVoteAccount: splVoteAccount.publicKey,
-          snapshotProgram: mockProgram.programId,
-          consensusResult: deriveConsensusResultAccount(mockProgram),
-          metaMerkleProof: deriveMetaMerkleProofAccount(mockProgram, deriveConsensusResultAccount(mockProgram), splVoteAccount.publicKey),
-          systemProgram: anchor.web3.SystemProgram.programId,
-        })
-        .rpc();
+      await createProposal(
+        program,
+        testSeed,
+        splVoteAccount.publicKey,
+        TEST_PROPOSAL_PARAMS.title,
+        ERROR_TEST_PARAMS.emptyDescription
+      );
 
       throw new Error("Expected error was not thrown - empty description should be rejected");
     } catch (error: any) {
@@ -113,6 +113,49 @@ export function deriveVoteOverrideCacheAccount(
   )[0];
 }
 
+// Number of proposals recorded in each proposal registry page
+export const REGISTRY_PAGE_SIZE = 32;
+
+export function deriveRegistryPageAccount(
+  program: anchor.Program<Govcontract>,
+  page: number
+): anchor.web3.PublicKey {
+  const pageBytes = Buffer.alloc(4);
+  pageBytes.writeUInt32LE(page);
+  return anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("registry"), pageBytes],
+    program.programId
+  )[0];
+}
+
+// Creates a standard snapshot proposal and returns the transaction signature. Anchor
+// resolves the content, bond, profile and config PDAs; the registry page is the one
+// holding the next proposal index.
+export async function createProposal(
+  program: anchor.Program<Govcontract>,
+  seed: anchor.BN,
+  splVoteAccount: anchor.web3.PublicKey,
+  title: string,
+  description: string
+): Promise<string> {
+  const proposalIndex = await program.account.proposalIndex.fetch(
+    deriveProposalIndexAccount(program)
+  );
+  const page = Math.floor(proposalIndex.currentIndex / REGISTRY_PAGE_SIZE);
+
+  return program.methods
+    .createProposal(seed, title, description, null, null, false, { standard: {} }, { snapshot: {} })
+    .accountsPartial({
+      signer: program.provider.publicKey,
+      proposal: deriveProposalAccount(program, seed, splVoteAccount),
+      registryPage: deriveRegistryPageAccount(program, page),
+      splVoteAccount,
+      supersedes: null,
+      dependsOn: null,
+    })
+    .rpc();
+}
+
 // Event listener helpers
 export function createEventListener<T>(
   program: anchor.Program<Govcontract>,
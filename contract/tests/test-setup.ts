@@ -177,6 +177,28 @@ export async function initializeProposalIndex(
   console.log("Current Index:", indexAccount.currentIndex.toString());
 }
 
+// Initialize governance config with the default rules
+export async function initializeGovernanceConfig(
+  program: anchor.Program<Govcontract>
+): Promise<void> {
+  const governanceConfig = anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("governance_config")],
+    program.programId
+  )[0];
+
+  const tx = await program.methods
+    .initializeGovernanceConfig()
+    .accountsPartial({
+      signer: program.provider.publicKey,
+      governanceConfig,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .rpc();
+
+  console.log("Governance Config Initialized Successfully!");
+  console.log("Transaction signature:", tx);
+}
+
 // Create consensus result
 export async function createConsensusResult(
   mockProgram: anchor.Program<MockGovV1>,
@@ -287,6 +309,7 @@ export async function setupTestEnvironment(
 
   // Initialize components
   await initializeProposalIndex(program, proposalIndexAccount);
+  await initializeGovernanceConfig(program);
   await createConsensusResult(mockProgram, consensusResult);
   await createMetaMerkleProofs(mockProgram, consensusResult, splVoteAccounts);
 
@@ -2,8 +2,20 @@ export default {
   'cast-vote-override': {
     title: 'Cast Vote Override',
   },
+  'cast-vote-override-batch': {
+    title: 'Cast Vote Override Batch',
+  },
   'modify-vote-override': {
     title: 'Modify Vote Override',
   },
+  'reveal-vote-override': {
+    title: 'Reveal Vote Override',
+  },
+  'register-proxy': {
+    title: 'Register Proxy',
+  },
+  'revoke-proxy': {
+    title: 'Revoke Proxy',
+  },
 };
 
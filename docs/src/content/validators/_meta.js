@@ -2,26 +2,71 @@ export default {
   'init-index': {
     title: 'Initialize Index',
   },
+  'init-config': {
+    title: 'Initialize Config',
+  },
   'create-proposal': {
     title: 'Create Proposal',
   },
   'support-proposal': {
     title: 'Support Proposal',
   },
+  signal: {
+    title: 'Signal',
+  },
   'cast-vote': {
     title: 'Cast Vote',
   },
   'modify-vote': {
     title: 'Modify Vote',
   },
+  'reveal-vote': {
+    title: 'Reveal Vote',
+  },
+  'sign-vote': {
+    title: 'Sign Vote',
+  },
+  'relay-vote': {
+    title: 'Relay Vote',
+  },
+  delegate: {
+    title: 'Delegate',
+  },
+  undelegate: {
+    title: 'Undelegate',
+  },
+  'cast-delegated-vote': {
+    title: 'Cast Delegated Vote',
+  },
   'finalize-proposal': {
     title: 'Finalize Proposal',
   },
+  'cancel-proposal': {
+    title: 'Cancel Proposal',
+  },
+  'settle-bond': {
+    title: 'Settle Bond',
+  },
+  'close-proofs': {
+    title: 'Close Proofs',
+  },
   'get-proposal': {
     title: 'Get Proposal',
   },
   'list-proposals': {
     title: 'List Proposals',
   },
+  validator: {
+    title: 'Validator',
+  },
+  'update-profile': {
+    title: 'Update Profile',
+  },
+  treasury: {
+    title: 'Treasury',
+  },
+  'governance-config': {
+    title: 'Governance Config',
+  },
 };
 
@@ -38,6 +38,30 @@ export function deriveProposalIndexPda(programId: PublicKey): PublicKey {
   return pda;
 }
 
+export function deriveRegistryPagePda(
+  page: number,
+  programId: PublicKey
+): PublicKey {
+  const pageBytes = Buffer.alloc(4);
+  pageBytes.writeUInt32LE(page);
+  const [pda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("registry"), pageBytes],
+    programId
+  );
+  return pda;
+}
+
+export function deriveProposalContentPda(
+  proposal: PublicKey,
+  programId: PublicKey
+): PublicKey {
+  const [pda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("proposal_content"), proposal.toBuffer()],
+    programId
+  );
+  return pda;
+}
+
 export function deriveVotePda(
   proposal: PublicKey,
   voteAccount: PublicKey,
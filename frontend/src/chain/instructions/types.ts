@@ -149,5 +149,7 @@ export interface NetworkMetaResponse {
 
 // Constants
 export const BASIS_POINTS_TOTAL = 10000;
+// Number of proposals recorded in each proposal registry page
+export const REGISTRY_PAGE_SIZE = 32;
 export const GOVCONTRACT_PROGRAM_ID = new PublicKey(govContractIdl.address);
 export const SNAPSHOT_PROGRAM_ID = new PublicKey(govV1idl.address);
@@ -14,8 +14,8 @@ export type Govcontract = {
   };
   instructions: [
     {
-      name: 'adjustProposalTiming';
-      discriminator: [82, 66, 219, 217, 123, 150, 223, 224];
+      name: 'cancelProposal';
+      discriminator: [106, 74, 128, 146, 19, 65, 39, 23];
       accounts: [
         {
           name: 'signer';
@@ -25,44 +25,111 @@ export type Govcontract = {
         {
           name: 'proposal';
           writable: true;
-        }
-      ];
-      args: [
+        },
         {
-          name: 'creationTimestamp';
-          type: {
-            option: 'i64';
-          };
+          name: 'registryPage';
+          writable: true;
         },
         {
-          name: 'creationEpoch';
-          type: {
-            option: 'u64';
+          name: 'proposalBond';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [98, 111, 110, 100];
+              },
+              {
+                kind: 'account';
+                path: 'proposal';
+              }
+            ];
           };
         },
         {
-          name: 'startEpoch';
-          type: {
-            option: 'u64';
+          name: 'treasury';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [116, 114, 101, 97, 115, 117, 114, 121];
+              }
+            ];
           };
         },
         {
-          name: 'endEpoch';
-          type: {
-            option: 'u64';
+          name: 'authorProfile';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  118,
+                  97,
+                  108,
+                  105,
+                  100,
+                  97,
+                  116,
+                  111,
+                  114,
+                  95,
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'proposal';
+              }
+            ];
           };
         },
         {
-          name: 'snapshotSlot';
-          type: {
-            option: 'u64';
+          name: 'eventAuthority';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ];
+              }
+            ];
           };
+        },
+        {
+          name: 'program';
         }
       ];
+      args: [];
     },
     {
-      name: 'castVote';
-      discriminator: [20, 212, 15, 189, 69, 180, 69, 151];
+      name: 'castDelegatedVote';
+      discriminator: [218, 162, 136, 254, 198, 95, 60, 236];
       accounts: [
         {
           name: 'signer';
@@ -73,6 +140,31 @@ export type Govcontract = {
           name: 'proposal';
           writable: true;
         },
+        {
+          name: 'delegation';
+        },
+        {
+          name: 'proposalDelegation';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [100, 101, 108, 101, 103, 97, 116, 105, 111, 110];
+              },
+              {
+                kind: 'account';
+                path: 'splVoteAccount';
+              },
+              {
+                kind: 'account';
+                path: 'proposal';
+              }
+            ];
+          };
+        },
+        {
+          name: 'splVoteAccount';
+        },
         {
           name: 'vote';
           writable: true;
@@ -94,7 +186,24 @@ export type Govcontract = {
           };
         },
         {
-          name: 'splVoteAccount';
+          name: 'delegateVote';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [118, 111, 116, 101];
+              },
+              {
+                kind: 'account';
+                path: 'proposal';
+              },
+              {
+                kind: 'account';
+                path: 'delegation.delegate';
+                account: 'governanceDelegation';
+              }
+            ];
+          };
         },
         {
           name: 'voteOverrideCache';
@@ -138,36 +247,59 @@ export type Govcontract = {
         },
         {
           name: 'snapshotProgram';
+          optional: true;
         },
         {
           name: 'consensusResult';
+          optional: true;
         },
         {
           name: 'metaMerkleProof';
+          docs: ['epoch stake'];
+          optional: true;
         },
         {
           name: 'systemProgram';
           address: '11111111111111111111111111111111';
-        }
-      ];
-      args: [
-        {
-          name: 'forVotesBp';
-          type: 'u64';
         },
         {
-          name: 'againstVotesBp';
-          type: 'u64';
+          name: 'eventAuthority';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ];
+              }
+            ];
+          };
         },
         {
-          name: 'abstainVotesBp';
-          type: 'u64';
+          name: 'program';
         }
       ];
+      args: [];
     },
     {
-      name: 'castVoteOverride';
-      discriminator: [225, 8, 137, 98, 214, 156, 183, 62];
+      name: 'castVote';
+      discriminator: [20, 212, 15, 189, 69, 180, 69, 151];
       accounts: [
         {
           name: 'signer';
@@ -179,7 +311,7 @@ export type Govcontract = {
           writable: true;
         },
         {
-          name: 'validatorVote';
+          name: 'vote';
           writable: true;
           pda: {
             seeds: [
@@ -202,7 +334,7 @@ export type Govcontract = {
           name: 'splVoteAccount';
         },
         {
-          name: 'voteOverride';
+          name: 'validatorProfile';
           writable: true;
           pda: {
             seeds: [
@@ -210,31 +342,27 @@ export type Govcontract = {
                 kind: 'const';
                 value: [
                   118,
-                  111,
+                  97,
+                  108,
+                  105,
+                  100,
+                  97,
                   116,
-                  101,
-                  95,
                   111,
-                  118,
-                  101,
                   114,
+                  95,
+                  112,
                   114,
+                  111,
+                  102,
                   105,
-                  100,
+                  108,
                   101
                 ];
               },
               {
                 kind: 'account';
-                path: 'proposal';
-              },
-              {
-                kind: 'account';
-                path: 'splStakeAccount';
-              },
-              {
-                kind: 'account';
-                path: 'validatorVote';
+                path: 'splVoteAccount';
               }
             ];
           };
@@ -274,26 +402,58 @@ export type Govcontract = {
               },
               {
                 kind: 'account';
-                path: 'validatorVote';
+                path: 'vote';
               }
             ];
           };
         },
-        {
-          name: 'splStakeAccount';
-        },
         {
           name: 'snapshotProgram';
+          optional: true;
         },
         {
           name: 'consensusResult';
+          optional: true;
         },
         {
           name: 'metaMerkleProof';
+          optional: true;
         },
         {
           name: 'systemProgram';
           address: '11111111111111111111111111111111';
+        },
+        {
+          name: 'eventAuthority';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: 'program';
         }
       ];
       args: [
@@ -308,28 +468,12 @@ export type Govcontract = {
         {
           name: 'abstainVotesBp';
           type: 'u64';
-        },
-        {
-          name: 'stakeMerkleProof';
-          type: {
-            vec: {
-              array: ['u8', 32];
-            };
-          };
-        },
-        {
-          name: 'stakeMerkleLeaf';
-          type: {
-            defined: {
-              name: 'stakeMerkleLeaf';
-            };
-          };
         }
       ];
     },
     {
-      name: 'createProposal';
-      discriminator: [132, 116, 68, 174, 216, 160, 198, 22];
+      name: 'castVoteOverride';
+      discriminator: [225, 8, 137, 98, 214, 156, 183, 62];
       accounts: [
         {
           name: 'signer';
@@ -339,15 +483,19 @@ export type Govcontract = {
         {
           name: 'proposal';
           writable: true;
+        },
+        {
+          name: 'validatorVote';
+          writable: true;
           pda: {
             seeds: [
               {
                 kind: 'const';
-                value: [112, 114, 111, 112, 111, 115, 97, 108];
+                value: [118, 111, 116, 101];
               },
               {
-                kind: 'arg';
-                path: 'seed';
+                kind: 'account';
+                path: 'proposal';
               },
               {
                 kind: 'account';
@@ -357,213 +505,10 @@ export type Govcontract = {
           };
         },
         {
-          name: 'proposalIndex';
-          writable: true;
-          pda: {
-            seeds: [
-              {
-                kind: 'const';
-                value: [105, 110, 100, 101, 120];
-              }
-            ];
-          };
-        },
-        {
-          name: 'splVoteAccount';
-        },
-        {
-          name: 'systemProgram';
-          address: '11111111111111111111111111111111';
-        }
-      ];
-      args: [
-        {
-          name: 'seed';
-          type: 'u64';
-        },
-        {
-          name: 'title';
-          type: 'string';
-        },
-        {
-          name: 'description';
-          type: 'string';
-        }
-      ];
-    },
-    {
-      name: 'finalizeProposal';
-      discriminator: [23, 68, 51, 167, 109, 173, 187, 164];
-      accounts: [
-        {
-          name: 'signer';
-          signer: true;
-        },
-        {
-          name: 'proposal';
-          writable: true;
-        }
-      ];
-      args: [];
-    },
-    {
-      name: 'flushMerkleRoot';
-      discriminator: [10, 71, 17, 246, 162, 57, 144, 87];
-      accounts: [
-        {
-          name: 'signer';
-          writable: true;
-          signer: true;
-        },
-        {
-          name: 'proposal';
-          writable: true;
-        },
-        {
-          name: 'splVoteAccount';
-        },
-        {
-          name: 'ballotBox';
-        },
-        {
-          name: 'ballotProgram';
-        },
-        {
-          name: 'systemProgram';
-          address: '11111111111111111111111111111111';
-        }
-      ];
-      args: [];
-    },
-    {
-      name: 'initializeIndex';
-      discriminator: [204, 67, 3, 74, 139, 139, 233, 10];
-      accounts: [
-        {
-          name: 'signer';
-          writable: true;
-          signer: true;
-        },
-        {
-          name: 'proposalIndex';
-          writable: true;
-          pda: {
-            seeds: [
-              {
-                kind: 'const';
-                value: [105, 110, 100, 101, 120];
-              }
-            ];
-          };
-        },
-        {
-          name: 'systemProgram';
-          address: '11111111111111111111111111111111';
-        }
-      ];
-      args: [];
-    },
-    {
-      name: 'modifyVote';
-      discriminator: [116, 52, 102, 0, 121, 145, 27, 139];
-      accounts: [
-        {
-          name: 'signer';
-          signer: true;
-        },
-        {
-          name: 'proposal';
-          writable: true;
-        },
-        {
-          name: 'vote';
-          writable: true;
-          pda: {
-            seeds: [
-              {
-                kind: 'const';
-                value: [118, 111, 116, 101];
-              },
-              {
-                kind: 'account';
-                path: 'proposal';
-              },
-              {
-                kind: 'account';
-                path: 'splVoteAccount';
-              }
-            ];
-          };
-        },
-        {
-          name: 'splVoteAccount';
-        },
-        {
-          name: 'snapshotProgram';
-        },
-        {
-          name: 'consensusResult';
-        },
-        {
-          name: 'metaMerkleProof';
-        },
-        {
-          name: 'systemProgram';
-          address: '11111111111111111111111111111111';
-        }
-      ];
-      args: [
-        {
-          name: 'forVotesBp';
-          type: 'u64';
-        },
-        {
-          name: 'againstVotesBp';
-          type: 'u64';
-        },
-        {
-          name: 'abstainVotesBp';
-          type: 'u64';
-        }
-      ];
-    },
-    {
-      name: 'modifyVoteOverride';
-      discriminator: [42, 54, 123, 87, 239, 152, 22, 186];
-      accounts: [
-        {
-          name: 'signer';
-          signer: true;
-        },
-        {
-          name: 'proposal';
-          writable: true;
-        },
-        {
-          name: 'validatorVote';
-          writable: true;
-          pda: {
-            seeds: [
-              {
-                kind: 'const';
-                value: [118, 111, 116, 101];
-              },
-              {
-                kind: 'account';
-                path: 'proposal';
-              },
-              {
-                kind: 'account';
-                path: 'splVoteAccount';
-              }
-            ];
-          };
-        },
-        {
-          name: 'splVoteAccount';
-        },
-        {
-          name: 'voteOverride';
+          name: 'splVoteAccount';
+        },
+        {
+          name: 'voteOverride';
           writable: true;
           pda: {
             seeds: [
@@ -643,18 +588,60 @@ export type Govcontract = {
         {
           name: 'splStakeAccount';
         },
+        {
+          name: 'stakeProxy';
+          docs: [
+            'Proxy registered for the stake account, required when the proxy signs'
+          ];
+          optional: true;
+        },
         {
           name: 'snapshotProgram';
+          optional: true;
         },
         {
           name: 'consensusResult';
+          optional: true;
         },
         {
           name: 'metaMerkleProof';
+          optional: true;
         },
         {
           name: 'systemProgram';
           address: '11111111111111111111111111111111';
+        },
+        {
+          name: 'eventAuthority';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: 'program';
         }
       ];
       args: [
@@ -689,8 +676,8 @@ export type Govcontract = {
       ];
     },
     {
-      name: 'supportProposal';
-      discriminator: [95, 239, 233, 199, 201, 62, 90, 27];
+      name: 'castVoteOverrideBatch';
+      discriminator: [62, 126, 7, 77, 146, 50, 197, 188];
       accounts: [
         {
           name: 'signer';
@@ -702,13 +689,13 @@ export type Govcontract = {
           writable: true;
         },
         {
-          name: 'support';
+          name: 'validatorVote';
           writable: true;
           pda: {
             seeds: [
               {
                 kind: 'const';
-                value: [115, 117, 112, 112, 111, 114, 116];
+                value: [118, 111, 116, 101];
               },
               {
                 kind: 'account';
@@ -725,530 +712,5782 @@ export type Govcontract = {
           name: 'splVoteAccount';
         },
         {
-          name: 'ballotBox';
+          name: 'voteOverrideCache';
           writable: true;
-        },
-        {
-          name: 'ballotProgram';
-        },
-        {
-          name: 'programConfig';
           pda: {
             seeds: [
               {
                 kind: 'const';
                 value: [
-                  80,
-                  114,
+                  118,
                   111,
-                  103,
-                  114,
-                  97,
-                  109,
-                  67,
+                  116,
+                  101,
+                  95,
                   111,
-                  110,
-                  102,
+                  118,
+                  101,
+                  114,
+                  114,
                   105,
-                  103
+                  100,
+                  101,
+                  95,
+                  99,
+                  97,
+                  99,
+                  104,
+                  101
                 ];
+              },
+              {
+                kind: 'account';
+                path: 'proposal';
+              },
+              {
+                kind: 'account';
+                path: 'validatorVote';
               }
             ];
-            program: {
-              kind: 'account';
-              path: 'ballotProgram';
-            };
           };
         },
+        {
+          name: 'snapshotProgram';
+          optional: true;
+        },
+        {
+          name: 'consensusResult';
+          optional: true;
+        },
+        {
+          name: 'metaMerkleProof';
+          optional: true;
+        },
         {
           name: 'systemProgram';
           address: '11111111111111111111111111111111';
+        },
+        {
+          name: 'eventAuthority';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: 'program';
+        }
+      ];
+      args: [
+        {
+          name: 'forVotesBp';
+          type: 'u64';
+        },
+        {
+          name: 'againstVotesBp';
+          type: 'u64';
+        },
+        {
+          name: 'abstainVotesBp';
+          type: 'u64';
+        },
+        {
+          name: 'entries';
+          type: {
+            vec: {
+              defined: {
+                name: 'stakeOverrideEntry';
+              };
+            };
+          };
         }
       ];
-      args: [];
-    }
-  ];
-  accounts: [
-    {
-      name: 'proposal';
-      discriminator: [26, 94, 189, 187, 116, 136, 53, 33];
-    },
-    {
-      name: 'proposalIndex';
-      discriminator: [83, 97, 143, 58, 176, 46, 177, 195];
     },
     {
-      name: 'support';
-      discriminator: [247, 108, 3, 111, 84, 51, 217, 107];
+      name: 'castVoteSigned';
+      discriminator: [63, 240, 60, 242, 186, 153, 76, 232];
+      accounts: [
+        {
+          name: 'payer';
+          writable: true;
+          signer: true;
+        },
+        {
+          name: 'proposal';
+          writable: true;
+        },
+        {
+          name: 'vote';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [118, 111, 116, 101];
+              },
+              {
+                kind: 'account';
+                path: 'proposal';
+              },
+              {
+                kind: 'account';
+                path: 'splVoteAccount';
+              }
+            ];
+          };
+        },
+        {
+          name: 'splVoteAccount';
+        },
+        {
+          name: 'validatorProfile';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  118,
+                  97,
+                  108,
+                  105,
+                  100,
+                  97,
+                  116,
+                  111,
+                  114,
+                  95,
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'splVoteAccount';
+              }
+            ];
+          };
+        },
+        {
+          name: 'voteOverrideCache';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  118,
+                  111,
+                  116,
+                  101,
+                  95,
+                  111,
+                  118,
+                  101,
+                  114,
+                  114,
+                  105,
+                  100,
+                  101,
+                  95,
+                  99,
+                  97,
+                  99,
+                  104,
+                  101
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'proposal';
+              },
+              {
+                kind: 'account';
+                path: 'vote';
+              }
+            ];
+          };
+        },
+        {
+          name: 'snapshotProgram';
+          optional: true;
+        },
+        {
+          name: 'consensusResult';
+          optional: true;
+        },
+        {
+          name: 'metaMerkleProof';
+          optional: true;
+        },
+        {
+          name: 'instructions';
+          address: 'Sysvar1nstructions1111111111111111111111111';
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        },
+        {
+          name: 'eventAuthority';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: 'program';
+        }
+      ];
+      args: [
+        {
+          name: 'signedVote';
+          type: {
+            defined: {
+              name: 'signedVote';
+            };
+          };
+        }
+      ];
     },
     {
-      name: 'vote';
+      name: 'closeMetaMerkleProof';
+      discriminator: [248, 239, 182, 146, 23, 215, 172, 3];
+      accounts: [
+        {
+          name: 'signer';
+          signer: true;
+        },
+        {
+          name: 'proposal';
+        },
+        {
+          name: 'metaMerkleProof';
+          writable: true;
+        },
+        {
+          name: 'payer';
+          writable: true;
+        },
+        {
+          name: 'snapshotProgram';
+          address: '8qua2VAnWaNFDAhUakryMuRdNRVuUehkjnhLECvueGSV';
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        },
+        {
+          name: 'eventAuthority';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: 'program';
+        }
+      ];
+      args: [];
+    },
+    {
+      name: 'commitVote';
+      discriminator: [134, 97, 90, 126, 91, 66, 16, 26];
+      accounts: [
+        {
+          name: 'signer';
+          writable: true;
+          signer: true;
+        },
+        {
+          name: 'proposal';
+        },
+        {
+          name: 'vote';
+        },
+        {
+          name: 'ballot';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [98, 97, 108, 108, 111, 116];
+              },
+              {
+                kind: 'account';
+                path: 'vote';
+              }
+            ];
+          };
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        },
+        {
+          name: 'eventAuthority';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: 'program';
+        }
+      ];
+      args: [
+        {
+          name: 'commitment';
+          type: {
+            array: ['u8', 32];
+          };
+        }
+      ];
+    },
+    {
+      name: 'commitVoteOverride';
+      discriminator: [231, 209, 224, 38, 111, 194, 136, 141];
+      accounts: [
+        {
+          name: 'signer';
+          writable: true;
+          signer: true;
+        },
+        {
+          name: 'proposal';
+        },
+        {
+          name: 'voteOverride';
+        },
+        {
+          name: 'stakeProxy';
+          docs: [
+            'Proxy registered for the stake account, required when the proxy signs'
+          ];
+          optional: true;
+        },
+        {
+          name: 'ballot';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [98, 97, 108, 108, 111, 116];
+              },
+              {
+                kind: 'account';
+                path: 'voteOverride';
+              }
+            ];
+          };
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        },
+        {
+          name: 'eventAuthority';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: 'program';
+        }
+      ];
+      args: [
+        {
+          name: 'commitment';
+          type: {
+            array: ['u8', 32];
+          };
+        }
+      ];
+    },
+    {
+      name: 'createParameterChange';
+      discriminator: [82, 190, 79, 199, 227, 192, 95, 21];
+      accounts: [
+        {
+          name: 'signer';
+          writable: true;
+          signer: true;
+        },
+        {
+          name: 'proposal';
+        },
+        {
+          name: 'parameterChange';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  112,
+                  97,
+                  114,
+                  97,
+                  109,
+                  101,
+                  116,
+                  101,
+                  114,
+                  95,
+                  99,
+                  104,
+                  97,
+                  110,
+                  103,
+                  101
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'proposal';
+              }
+            ];
+          };
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        },
+        {
+          name: 'eventAuthority';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: 'program';
+        }
+      ];
+      args: [
+        {
+          name: 'changes';
+          type: {
+            vec: {
+              defined: {
+                name: 'governanceParameter';
+              };
+            };
+          };
+        }
+      ];
+    },
+    {
+      name: 'createProposal';
+      discriminator: [132, 116, 68, 174, 216, 160, 198, 22];
+      accounts: [
+        {
+          name: 'signer';
+          writable: true;
+          signer: true;
+        },
+        {
+          name: 'proposal';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [112, 114, 111, 112, 111, 115, 97, 108];
+              },
+              {
+                kind: 'arg';
+                path: 'seed';
+              },
+              {
+                kind: 'account';
+                path: 'splVoteAccount';
+              }
+            ];
+          };
+        },
+        {
+          name: 'proposalContent';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  112,
+                  114,
+                  111,
+                  112,
+                  111,
+                  115,
+                  97,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  116,
+                  101,
+                  110,
+                  116
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'proposal';
+              }
+            ];
+          };
+        },
+        {
+          name: 'proposalBond';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [98, 111, 110, 100];
+              },
+              {
+                kind: 'account';
+                path: 'proposal';
+              }
+            ];
+          };
+        },
+        {
+          name: 'proposalIndex';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [105, 110, 100, 101, 120];
+              }
+            ];
+          };
+        },
+        {
+          name: 'registryPage';
+          writable: true;
+        },
+        {
+          name: 'splVoteAccount';
+        },
+        {
+          name: 'validatorProfile';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  118,
+                  97,
+                  108,
+                  105,
+                  100,
+                  97,
+                  116,
+                  111,
+                  114,
+                  95,
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'splVoteAccount';
+              }
+            ];
+          };
+        },
+        {
+          name: 'governanceConfig';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  103,
+                  111,
+                  118,
+                  101,
+                  114,
+                  110,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: 'supersedes';
+          docs: ['Earlier proposal this one supersedes, if any'];
+          optional: true;
+        },
+        {
+          name: 'dependsOn';
+          docs: ['Earlier proposal this one depends on, if any'];
+          optional: true;
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        },
+        {
+          name: 'eventAuthority';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: 'program';
+        }
+      ];
+      args: [
+        {
+          name: 'seed';
+          type: 'u64';
+        },
+        {
+          name: 'title';
+          type: 'string';
+        },
+        {
+          name: 'description';
+          type: 'string';
+        },
+        {
+          name: 'antiSniping';
+          type: {
+            option: {
+              defined: {
+                name: 'antiSnipingConfig';
+              };
+            };
+          };
+        },
+        {
+          name: 'secretBallot';
+          type: {
+            option: {
+              defined: {
+                name: 'secretBallotConfig';
+              };
+            };
+          };
+        },
+        {
+          name: 'randomSnapshot';
+          type: 'bool';
+        },
+        {
+          name: 'class';
+          type: {
+            defined: {
+              name: 'proposalClass';
+            };
+          };
+        },
+        {
+          name: 'stakeSource';
+          type: {
+            defined: {
+              name: 'stakeSource';
+            };
+          };
+        }
+      ];
+    },
+    {
+      name: 'createProposalTransaction';
+      discriminator: [230, 77, 204, 174, 1, 109, 160, 57];
+      accounts: [
+        {
+          name: 'signer';
+          writable: true;
+          signer: true;
+        },
+        {
+          name: 'proposal';
+        },
+        {
+          name: 'proposalTransaction';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  112,
+                  114,
+                  111,
+                  112,
+                  111,
+                  115,
+                  97,
+                  108,
+                  95,
+                  116,
+                  114,
+                  97,
+                  110,
+                  115,
+                  97,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'proposal';
+              }
+            ];
+          };
+        },
+        {
+          name: 'governanceAuthority';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [103, 111, 118, 101, 114, 110, 97, 110, 99, 101];
+              }
+            ];
+          };
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        },
+        {
+          name: 'eventAuthority';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: 'program';
+        }
+      ];
+      args: [
+        {
+          name: 'instructions';
+          type: {
+            vec: {
+              defined: {
+                name: 'proposalInstruction';
+              };
+            };
+          };
+        },
+        {
+          name: 'timelockSeconds';
+          type: 'i64';
+        }
+      ];
+    },
+    {
+      name: 'createTreasuryTransfer';
+      discriminator: [73, 166, 78, 54, 215, 237, 176, 217];
+      accounts: [
+        {
+          name: 'signer';
+          writable: true;
+          signer: true;
+        },
+        {
+          name: 'proposal';
+        },
+        {
+          name: 'treasuryTransfer';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  116,
+                  114,
+                  97,
+                  110,
+                  115,
+                  102,
+                  101,
+                  114
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'proposal';
+              }
+            ];
+          };
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        },
+        {
+          name: 'eventAuthority';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: 'program';
+        }
+      ];
+      args: [
+        {
+          name: 'destination';
+          type: 'pubkey';
+        },
+        {
+          name: 'mint';
+          type: {
+            option: 'pubkey';
+          };
+        },
+        {
+          name: 'amount';
+          type: 'u64';
+        },
+        {
+          name: 'timelockSeconds';
+          type: 'i64';
+        }
+      ];
+    },
+    {
+      name: 'drawSnapshotSlot';
+      discriminator: [70, 121, 163, 181, 195, 173, 59, 190];
+      accounts: [
+        {
+          name: 'signer';
+          signer: true;
+        },
+        {
+          name: 'proposal';
+          writable: true;
+        },
+        {
+          name: 'ballotProgram';
+        },
+        {
+          name: 'slotHashes';
+          address: 'SysvarS1otHashes111111111111111111111111111';
+        },
+        {
+          name: 'eventAuthority';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: 'program';
+        }
+      ];
+      args: [];
+    },
+    {
+      name: 'enactParameterChange';
+      discriminator: [92, 75, 13, 184, 188, 167, 67, 197];
+      accounts: [
+        {
+          name: 'signer';
+          signer: true;
+        },
+        {
+          name: 'proposal';
+          relations: ['parameterChange'];
+        },
+        {
+          name: 'parameterChange';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  112,
+                  97,
+                  114,
+                  97,
+                  109,
+                  101,
+                  116,
+                  101,
+                  114,
+                  95,
+                  99,
+                  104,
+                  97,
+                  110,
+                  103,
+                  101
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'proposal';
+              }
+            ];
+          };
+        },
+        {
+          name: 'governanceConfig';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  103,
+                  111,
+                  118,
+                  101,
+                  114,
+                  110,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: 'eventAuthority';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: 'program';
+        }
+      ];
+      args: [];
+    },
+    {
+      name: 'executeProposalTransaction';
+      discriminator: [241, 221, 56, 165, 155, 172, 67, 52];
+      accounts: [
+        {
+          name: 'signer';
+          signer: true;
+        },
+        {
+          name: 'proposal';
+          relations: ['proposalTransaction'];
+        },
+        {
+          name: 'proposalTransaction';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  112,
+                  114,
+                  111,
+                  112,
+                  111,
+                  115,
+                  97,
+                  108,
+                  95,
+                  116,
+                  114,
+                  97,
+                  110,
+                  115,
+                  97,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'proposal';
+              }
+            ];
+          };
+        },
+        {
+          name: 'governanceAuthority';
+          docs: [
+            'shared by every passed proposal, so stored instructions can move any lamports, tokens',
+            'or authorities (such as upgrade or mint authorities) it holds, whichever proposal',
+            'placed them there. They cannot invoke this program.'
+          ];
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [103, 111, 118, 101, 114, 110, 97, 110, 99, 101];
+              }
+            ];
+          };
+        },
+        {
+          name: 'eventAuthority';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: 'program';
+        }
+      ];
+      args: [];
+    },
+    {
+      name: 'executeTreasuryTransfer';
+      discriminator: [168, 161, 111, 154, 196, 116, 197, 137];
+      accounts: [
+        {
+          name: 'signer';
+          signer: true;
+        },
+        {
+          name: 'proposal';
+          relations: ['treasuryTransfer'];
+        },
+        {
+          name: 'treasuryTransfer';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  116,
+                  114,
+                  97,
+                  110,
+                  115,
+                  102,
+                  101,
+                  114
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'proposal';
+              }
+            ];
+          };
+        },
+        {
+          name: 'treasury';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [116, 114, 101, 97, 115, 117, 114, 121];
+              }
+            ];
+          };
+        },
+        {
+          name: 'destination';
+          writable: true;
+          relations: ['treasuryTransfer'];
+        },
+        {
+          name: 'mint';
+          optional: true;
+        },
+        {
+          name: 'treasuryTokenAccount';
+          writable: true;
+          optional: true;
+        },
+        {
+          name: 'tokenProgram';
+          optional: true;
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        },
+        {
+          name: 'eventAuthority';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: 'program';
+        }
+      ];
+      args: [];
+    },
+    {
+      name: 'finalizeProposal';
+      discriminator: [23, 68, 51, 167, 109, 173, 187, 164];
+      accounts: [
+        {
+          name: 'signer';
+          signer: true;
+        },
+        {
+          name: 'proposal';
+          writable: true;
+        },
+        {
+          name: 'registryPage';
+          writable: true;
+        },
+        {
+          name: 'authorProfile';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  118,
+                  97,
+                  108,
+                  105,
+                  100,
+                  97,
+                  116,
+                  111,
+                  114,
+                  95,
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'proposal';
+              }
+            ];
+          };
+        },
+        {
+          name: 'supersededProposal';
+          docs: [
+            'Proposal this one supersedes, marked as superseded if this one passes'
+          ];
+          writable: true;
+          optional: true;
+        },
+        {
+          name: 'eventAuthority';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: 'program';
+        }
+      ];
+      args: [];
+    },
+    {
+      name: 'flushMerkleRoot';
+      discriminator: [10, 71, 17, 246, 162, 57, 144, 87];
+      accounts: [
+        {
+          name: 'signer';
+          writable: true;
+          signer: true;
+        },
+        {
+          name: 'proposal';
+          writable: true;
+        },
+        {
+          name: 'ballotBox';
+          docs: [
+            'Omitted for a random snapshot, which keeps its slot; `init_ballot_box` creates its',
+            'ballot box if it is missing.'
+          ];
+          optional: true;
+        },
+        {
+          name: 'ballotProgram';
+        },
+        {
+          name: 'programConfig';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  80,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  67,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ];
+              }
+            ];
+            program: {
+              kind: 'account';
+              path: 'ballotProgram';
+            };
+          };
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        },
+        {
+          name: 'eventAuthority';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: 'program';
+        }
+      ];
+      args: [];
+    },
+    {
+      name: 'initBallotBox';
+      discriminator: [164, 20, 45, 213, 67, 43, 193, 212];
+      accounts: [
+        {
+          name: 'signer';
+          writable: true;
+          signer: true;
+        },
+        {
+          name: 'proposal';
+        },
+        {
+          name: 'ballotBox';
+          docs: ["proposal's snapshot slot"];
+          writable: true;
+        },
+        {
+          name: 'ballotProgram';
+        },
+        {
+          name: 'programConfig';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  80,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  67,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ];
+              }
+            ];
+            program: {
+              kind: 'account';
+              path: 'ballotProgram';
+            };
+          };
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        }
+      ];
+      args: [];
+    },
+    {
+      name: 'initializeGovernanceConfig';
+      discriminator: [15, 40, 42, 141, 94, 104, 27, 201];
+      accounts: [
+        {
+          name: 'signer';
+          writable: true;
+          signer: true;
+        },
+        {
+          name: 'governanceConfig';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  103,
+                  111,
+                  118,
+                  101,
+                  114,
+                  110,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        },
+        {
+          name: 'eventAuthority';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: 'program';
+        }
+      ];
+      args: [];
+    },
+    {
+      name: 'initializeIndex';
+      discriminator: [204, 67, 3, 74, 139, 139, 233, 10];
+      accounts: [
+        {
+          name: 'signer';
+          writable: true;
+          signer: true;
+        },
+        {
+          name: 'proposalIndex';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [105, 110, 100, 101, 120];
+              }
+            ];
+          };
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        },
+        {
+          name: 'eventAuthority';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: 'program';
+        }
+      ];
+      args: [];
+    },
+    {
+      name: 'modifyVote';
+      discriminator: [116, 52, 102, 0, 121, 145, 27, 139];
+      accounts: [
+        {
+          name: 'signer';
+          signer: true;
+        },
+        {
+          name: 'proposal';
+          writable: true;
+        },
+        {
+          name: 'vote';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [118, 111, 116, 101];
+              },
+              {
+                kind: 'account';
+                path: 'proposal';
+              },
+              {
+                kind: 'account';
+                path: 'splVoteAccount';
+              }
+            ];
+          };
+        },
+        {
+          name: 'splVoteAccount';
+        },
+        {
+          name: 'snapshotProgram';
+          optional: true;
+        },
+        {
+          name: 'consensusResult';
+          optional: true;
+        },
+        {
+          name: 'metaMerkleProof';
+          optional: true;
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        },
+        {
+          name: 'eventAuthority';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: 'program';
+        }
+      ];
+      args: [
+        {
+          name: 'forVotesBp';
+          type: 'u64';
+        },
+        {
+          name: 'againstVotesBp';
+          type: 'u64';
+        },
+        {
+          name: 'abstainVotesBp';
+          type: 'u64';
+        }
+      ];
+    },
+    {
+      name: 'modifyVoteOverride';
+      discriminator: [42, 54, 123, 87, 239, 152, 22, 186];
+      accounts: [
+        {
+          name: 'signer';
+          signer: true;
+        },
+        {
+          name: 'proposal';
+          writable: true;
+        },
+        {
+          name: 'validatorVote';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [118, 111, 116, 101];
+              },
+              {
+                kind: 'account';
+                path: 'proposal';
+              },
+              {
+                kind: 'account';
+                path: 'splVoteAccount';
+              }
+            ];
+          };
+        },
+        {
+          name: 'splVoteAccount';
+        },
+        {
+          name: 'voteOverride';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  118,
+                  111,
+                  116,
+                  101,
+                  95,
+                  111,
+                  118,
+                  101,
+                  114,
+                  114,
+                  105,
+                  100,
+                  101
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'proposal';
+              },
+              {
+                kind: 'account';
+                path: 'splStakeAccount';
+              },
+              {
+                kind: 'account';
+                path: 'validatorVote';
+              }
+            ];
+          };
+        },
+        {
+          name: 'voteOverrideCache';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  118,
+                  111,
+                  116,
+                  101,
+                  95,
+                  111,
+                  118,
+                  101,
+                  114,
+                  114,
+                  105,
+                  100,
+                  101,
+                  95,
+                  99,
+                  97,
+                  99,
+                  104,
+                  101
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'proposal';
+              },
+              {
+                kind: 'account';
+                path: 'validatorVote';
+              }
+            ];
+          };
+        },
+        {
+          name: 'splStakeAccount';
+        },
+        {
+          name: 'stakeProxy';
+          docs: [
+            'Proxy registered for the stake account, required when the proxy signs'
+          ];
+          optional: true;
+        },
+        {
+          name: 'snapshotProgram';
+          optional: true;
+        },
+        {
+          name: 'consensusResult';
+          optional: true;
+        },
+        {
+          name: 'metaMerkleProof';
+          optional: true;
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        },
+        {
+          name: 'eventAuthority';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: 'program';
+        }
+      ];
+      args: [
+        {
+          name: 'forVotesBp';
+          type: 'u64';
+        },
+        {
+          name: 'againstVotesBp';
+          type: 'u64';
+        },
+        {
+          name: 'abstainVotesBp';
+          type: 'u64';
+        },
+        {
+          name: 'stakeMerkleProof';
+          type: {
+            vec: {
+              array: ['u8', 32];
+            };
+          };
+        },
+        {
+          name: 'stakeMerkleLeaf';
+          type: {
+            defined: {
+              name: 'stakeMerkleLeaf';
+            };
+          };
+        }
+      ];
+    },
+    {
+      name: 'registerStakeProxy';
+      discriminator: [1, 170, 21, 76, 69, 226, 187, 45];
+      accounts: [
+        {
+          name: 'signer';
+          writable: true;
+          signer: true;
+        },
+        {
+          name: 'splStakeAccount';
+        },
+        {
+          name: 'stakeProxy';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [115, 116, 97, 107, 101, 95, 112, 114, 111, 120, 121];
+              },
+              {
+                kind: 'account';
+                path: 'splStakeAccount';
+              }
+            ];
+          };
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        },
+        {
+          name: 'eventAuthority';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: 'program';
+        }
+      ];
+      args: [
+        {
+          name: 'proxy';
+          type: 'pubkey';
+        }
+      ];
+    },
+    {
+      name: 'removeGovernanceDelegation';
+      discriminator: [208, 4, 76, 24, 42, 58, 215, 119];
+      accounts: [
+        {
+          name: 'signer';
+          writable: true;
+          signer: true;
+        },
+        {
+          name: 'splVoteAccount';
+        },
+        {
+          name: 'delegation';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [100, 101, 108, 101, 103, 97, 116, 105, 111, 110];
+              },
+              {
+                kind: 'account';
+                path: 'splVoteAccount';
+              },
+              {
+                kind: 'arg';
+                path: 'proposal';
+              }
+            ];
+          };
+        },
+        {
+          name: 'eventAuthority';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: 'program';
+        }
+      ];
+      args: [
+        {
+          name: 'proposal';
+          type: {
+            option: 'pubkey';
+          };
+        }
+      ];
+    },
+    {
+      name: 'revealVote';
+      discriminator: [100, 157, 139, 17, 186, 75, 185, 149];
+      accounts: [
+        {
+          name: 'signer';
+          signer: true;
+        },
+        {
+          name: 'proposal';
+          writable: true;
+        },
+        {
+          name: 'vote';
+          writable: true;
+        },
+        {
+          name: 'ballot';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [98, 97, 108, 108, 111, 116];
+              },
+              {
+                kind: 'account';
+                path: 'vote';
+              }
+            ];
+          };
+        },
+        {
+          name: 'eventAuthority';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: 'program';
+        }
+      ];
+      args: [
+        {
+          name: 'forVotesBp';
+          type: 'u64';
+        },
+        {
+          name: 'againstVotesBp';
+          type: 'u64';
+        },
+        {
+          name: 'abstainVotesBp';
+          type: 'u64';
+        },
+        {
+          name: 'salt';
+          type: {
+            array: ['u8', 32];
+          };
+        }
+      ];
+    },
+    {
+      name: 'revealVoteOverride';
+      discriminator: [153, 82, 177, 0, 131, 69, 196, 34];
+      accounts: [
+        {
+          name: 'signer';
+          signer: true;
+        },
+        {
+          name: 'proposal';
+          writable: true;
+        },
+        {
+          name: 'voteOverride';
+          writable: true;
+        },
+        {
+          name: 'validatorVote';
+        },
+        {
+          name: 'ballot';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [98, 97, 108, 108, 111, 116];
+              },
+              {
+                kind: 'account';
+                path: 'voteOverride';
+              }
+            ];
+          };
+        },
+        {
+          name: 'eventAuthority';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: 'program';
+        }
+      ];
+      args: [
+        {
+          name: 'forVotesBp';
+          type: 'u64';
+        },
+        {
+          name: 'againstVotesBp';
+          type: 'u64';
+        },
+        {
+          name: 'abstainVotesBp';
+          type: 'u64';
+        },
+        {
+          name: 'salt';
+          type: {
+            array: ['u8', 32];
+          };
+        }
+      ];
+    },
+    {
+      name: 'revokeStakeProxy';
+      discriminator: [5, 63, 139, 24, 59, 69, 75, 13];
+      accounts: [
+        {
+          name: 'signer';
+          writable: true;
+          signer: true;
+        },
+        {
+          name: 'splStakeAccount';
+        },
+        {
+          name: 'stakeProxy';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [115, 116, 97, 107, 101, 95, 112, 114, 111, 120, 121];
+              },
+              {
+                kind: 'account';
+                path: 'splStakeAccount';
+              }
+            ];
+          };
+        },
+        {
+          name: 'eventAuthority';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: 'program';
+        }
+      ];
+      args: [];
+    },
+    {
+      name: 'setGovernanceDelegation';
+      discriminator: [109, 238, 52, 184, 84, 241, 101, 214];
+      accounts: [
+        {
+          name: 'signer';
+          writable: true;
+          signer: true;
+        },
+        {
+          name: 'splVoteAccount';
+        },
+        {
+          name: 'delegation';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [100, 101, 108, 101, 103, 97, 116, 105, 111, 110];
+              },
+              {
+                kind: 'account';
+                path: 'splVoteAccount';
+              },
+              {
+                kind: 'arg';
+                path: 'proposal';
+              }
+            ];
+          };
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        },
+        {
+          name: 'eventAuthority';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: 'program';
+        }
+      ];
+      args: [
+        {
+          name: 'delegate';
+          type: 'pubkey';
+        },
+        {
+          name: 'proposal';
+          type: {
+            option: 'pubkey';
+          };
+        }
+      ];
+    },
+    {
+      name: 'settleProposalBond';
+      discriminator: [25, 250, 254, 212, 161, 19, 191, 15];
+      accounts: [
+        {
+          name: 'signer';
+          signer: true;
+        },
+        {
+          name: 'proposal';
+        },
+        {
+          name: 'proposalBond';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [98, 111, 110, 100];
+              },
+              {
+                kind: 'account';
+                path: 'proposal';
+              }
+            ];
+          };
+        },
+        {
+          name: 'author';
+          writable: true;
+          relations: ['proposalBond'];
+        },
+        {
+          name: 'treasury';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [116, 114, 101, 97, 115, 117, 114, 121];
+              }
+            ];
+          };
+        },
+        {
+          name: 'authorProfile';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  118,
+                  97,
+                  108,
+                  105,
+                  100,
+                  97,
+                  116,
+                  111,
+                  114,
+                  95,
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'proposal';
+              }
+            ];
+          };
+        },
+        {
+          name: 'eventAuthority';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: 'program';
+        }
+      ];
+      args: [];
+    },
+    {
+      name: 'signal';
+      discriminator: [106, 129, 52, 212, 183, 190, 163, 21];
+      accounts: [
+        {
+          name: 'signer';
+          writable: true;
+          signer: true;
+        },
+        {
+          name: 'proposal';
+        },
+        {
+          name: 'signal';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [115, 105, 103, 110, 97, 108];
+              },
+              {
+                kind: 'account';
+                path: 'proposal';
+              },
+              {
+                kind: 'account';
+                path: 'splVoteAccount';
+              }
+            ];
+          };
+        },
+        {
+          name: 'signalTally';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  115,
+                  105,
+                  103,
+                  110,
+                  97,
+                  108,
+                  95,
+                  116,
+                  97,
+                  108,
+                  108,
+                  121
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'proposal';
+              }
+            ];
+          };
+        },
+        {
+          name: 'splVoteAccount';
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        },
+        {
+          name: 'eventAuthority';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: 'program';
+        }
+      ];
+      args: [
+        {
+          name: 'lean';
+          type: {
+            defined: {
+              name: 'signalLean';
+            };
+          };
+        }
+      ];
+    },
+    {
+      name: 'supportProposal';
+      discriminator: [95, 239, 233, 199, 201, 62, 90, 27];
+      accounts: [
+        {
+          name: 'signer';
+          writable: true;
+          signer: true;
+        },
+        {
+          name: 'proposal';
+          writable: true;
+        },
+        {
+          name: 'support';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [115, 117, 112, 112, 111, 114, 116];
+              },
+              {
+                kind: 'account';
+                path: 'proposal';
+              },
+              {
+                kind: 'account';
+                path: 'splVoteAccount';
+              }
+            ];
+          };
+        },
+        {
+          name: 'splVoteAccount';
+        },
+        {
+          name: 'validatorProfile';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  118,
+                  97,
+                  108,
+                  105,
+                  100,
+                  97,
+                  116,
+                  111,
+                  114,
+                  95,
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'splVoteAccount';
+              }
+            ];
+          };
+        },
+        {
+          name: 'ballotBox';
+          docs: [
+            'Omitted for a random snapshot, whose slot is only known once drawn; `init_ballot_box`',
+            'creates it afterwards. Also omitted for epoch stake, which needs no snapshot.'
+          ];
+          writable: true;
+          optional: true;
+        },
+        {
+          name: 'ballotProgram';
+        },
+        {
+          name: 'programConfig';
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  80,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  67,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ];
+              }
+            ];
+            program: {
+              kind: 'account';
+              path: 'ballotProgram';
+            };
+          };
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        },
+        {
+          name: 'eventAuthority';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: 'program';
+        }
+      ];
+      args: [];
+    },
+    {
+      name: 'updateValidatorProfile';
+      discriminator: [31, 20, 102, 26, 128, 98, 167, 153];
+      accounts: [
+        {
+          name: 'signer';
+          writable: true;
+          signer: true;
+        },
+        {
+          name: 'splVoteAccount';
+        },
+        {
+          name: 'validatorProfile';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  118,
+                  97,
+                  108,
+                  105,
+                  100,
+                  97,
+                  116,
+                  111,
+                  114,
+                  95,
+                  112,
+                  114,
+                  111,
+                  102,
+                  105,
+                  108,
+                  101
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'splVoteAccount';
+              }
+            ];
+          };
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        },
+        {
+          name: 'eventAuthority';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: 'program';
+        }
+      ];
+      args: [
+        {
+          name: 'displayName';
+          type: {
+            option: 'string';
+          };
+        },
+        {
+          name: 'website';
+          type: {
+            option: 'string';
+          };
+        },
+        {
+          name: 'governancePolicyUrl';
+          type: {
+            option: 'string';
+          };
+        }
+      ];
+    }
+  ];
+  accounts: [
+    {
+      name: 'ballot';
+      discriminator: [3, 232, 121, 204, 232, 137, 138, 164];
+    },
+    {
+      name: 'governanceConfig';
+      discriminator: [81, 63, 124, 107, 210, 100, 145, 70];
+    },
+    {
+      name: 'governanceDelegation';
+      discriminator: [9, 94, 222, 50, 142, 141, 7, 251];
+    },
+    {
+      name: 'parameterChange';
+      discriminator: [139, 182, 105, 58, 64, 7, 184, 12];
+    },
+    {
+      name: 'proposal';
+      discriminator: [26, 94, 189, 187, 116, 136, 53, 33];
+    },
+    {
+      name: 'proposalBond';
+      discriminator: [162, 12, 200, 75, 185, 215, 34, 120];
+    },
+    {
+      name: 'proposalContent';
+      discriminator: [202, 177, 154, 75, 236, 244, 80, 126];
+    },
+    {
+      name: 'proposalIndex';
+      discriminator: [83, 97, 143, 58, 176, 46, 177, 195];
+    },
+    {
+      name: 'proposalRegistryPage';
+      discriminator: [201, 146, 183, 137, 12, 180, 17, 132];
+    },
+    {
+      name: 'proposalTransaction';
+      discriminator: [28, 171, 211, 239, 212, 104, 14, 97];
+    },
+    {
+      name: 'signal';
+      discriminator: [20, 6, 227, 69, 183, 62, 78, 246];
+    },
+    {
+      name: 'signalTally';
+      discriminator: [242, 127, 186, 205, 170, 40, 151, 176];
+    },
+    {
+      name: 'stakeProxy';
+      discriminator: [147, 174, 94, 92, 38, 0, 79, 34];
+    },
+    {
+      name: 'support';
+      discriminator: [247, 108, 3, 111, 84, 51, 217, 107];
+    },
+    {
+      name: 'treasuryTransfer';
+      discriminator: [1, 223, 17, 208, 159, 156, 246, 81];
+    },
+    {
+      name: 'validatorGovernanceProfile';
+      discriminator: [177, 240, 136, 25, 57, 151, 220, 188];
+    },
+    {
+      name: 'vote';
       discriminator: [96, 91, 104, 57, 145, 35, 172, 155];
     },
     {
-      name: 'voteOverride';
-      discriminator: [130, 93, 172, 50, 168, 151, 176, 188];
+      name: 'voteOverride';
+      discriminator: [130, 93, 172, 50, 168, 151, 176, 188];
+    },
+    {
+      name: 'voteOverrideCache';
+      discriminator: [195, 82, 50, 219, 140, 34, 108, 57];
+    }
+  ];
+  events: [
+    {
+      name: 'ballotCommitted';
+      discriminator: [22, 97, 101, 165, 154, 237, 133, 77];
+    },
+    {
+      name: 'ballotRevealed';
+      discriminator: [55, 226, 65, 107, 193, 177, 5, 111];
+    },
+    {
+      name: 'delegatedVoteCast';
+      discriminator: [145, 127, 61, 137, 170, 103, 230, 178];
+    },
+    {
+      name: 'governanceConfigInitialized';
+      discriminator: [165, 136, 97, 201, 208, 165, 199, 162];
+    },
+    {
+      name: 'governanceDelegationRemoved';
+      discriminator: [157, 175, 221, 68, 245, 44, 252, 127];
+    },
+    {
+      name: 'governanceDelegationSet';
+      discriminator: [237, 40, 122, 131, 156, 151, 9, 113];
+    },
+    {
+      name: 'merkleRootFlushed';
+      discriminator: [120, 37, 53, 216, 119, 172, 17, 144];
+    },
+    {
+      name: 'metaMerkleProofClosed';
+      discriminator: [164, 122, 46, 225, 173, 125, 191, 151];
+    },
+    {
+      name: 'parameterChangeCreated';
+      discriminator: [145, 228, 248, 77, 210, 162, 227, 244];
+    },
+    {
+      name: 'parameterChangeEnacted';
+      discriminator: [162, 91, 101, 110, 16, 93, 131, 70];
+    },
+    {
+      name: 'proposalBondLocked';
+      discriminator: [157, 126, 102, 92, 39, 69, 29, 206];
+    },
+    {
+      name: 'proposalBondSettled';
+      discriminator: [173, 38, 68, 91, 251, 250, 99, 241];
+    },
+    {
+      name: 'proposalCancelled';
+      discriminator: [253, 59, 104, 46, 129, 78, 9, 14];
+    },
+    {
+      name: 'proposalCreated';
+      discriminator: [186, 8, 160, 108, 81, 13, 51, 206];
+    },
+    {
+      name: 'proposalFinalized';
+      discriminator: [159, 104, 210, 220, 86, 209, 61, 51];
+    },
+    {
+      name: 'proposalIndexInitialized';
+      discriminator: [205, 137, 220, 98, 103, 1, 119, 57];
+    },
+    {
+      name: 'proposalSignalled';
+      discriminator: [11, 61, 94, 16, 225, 240, 136, 193];
+    },
+    {
+      name: 'proposalSuperseded';
+      discriminator: [177, 129, 137, 80, 21, 151, 111, 102];
+    },
+    {
+      name: 'proposalSupported';
+      discriminator: [248, 220, 71, 30, 127, 209, 67, 231];
+    },
+    {
+      name: 'proposalTransactionCreated';
+      discriminator: [65, 69, 177, 122, 185, 79, 180, 81];
+    },
+    {
+      name: 'proposalTransactionExecuted';
+      discriminator: [63, 207, 139, 206, 97, 88, 137, 107];
+    },
+    {
+      name: 'snapshotSlotDrawn';
+      discriminator: [214, 41, 226, 250, 86, 104, 32, 147];
+    },
+    {
+      name: 'stakeProxyRegistered';
+      discriminator: [50, 167, 215, 105, 108, 155, 197, 126];
+    },
+    {
+      name: 'stakeProxyRevoked';
+      discriminator: [227, 183, 32, 243, 99, 35, 246, 18];
+    },
+    {
+      name: 'treasuryTransferCreated';
+      discriminator: [53, 133, 220, 39, 20, 79, 91, 137];
+    },
+    {
+      name: 'treasuryTransferExecuted';
+      discriminator: [216, 63, 225, 244, 149, 176, 101, 215];
+    },
+    {
+      name: 'validatorProfileUpdated';
+      discriminator: [40, 88, 228, 225, 76, 214, 134, 24];
+    },
+    {
+      name: 'voteCast';
+      discriminator: [39, 53, 195, 104, 188, 17, 225, 213];
+    },
+    {
+      name: 'voteModified';
+      discriminator: [192, 64, 130, 9, 210, 47, 57, 175];
+    },
+    {
+      name: 'voteOverrideCacheUpdated';
+      discriminator: [38, 23, 204, 185, 173, 104, 133, 190];
+    },
+    {
+      name: 'voteOverrideCast';
+      discriminator: [111, 204, 225, 252, 254, 218, 120, 236];
+    },
+    {
+      name: 'voteOverrideModified';
+      discriminator: [235, 74, 153, 225, 242, 72, 228, 9];
+    },
+    {
+      name: 'votingPeriodExtended';
+      discriminator: [182, 209, 156, 168, 108, 122, 79, 233];
+    }
+  ];
+  errors: [
+    {
+      code: 6000;
+      name: 'notEnoughStake';
+      msg: 'Insufficient stake to perform this action';
+    },
+    {
+      code: 6001;
+      name: 'titleEmpty';
+      msg: 'The title of the proposal cannot be empty';
+    },
+    {
+      code: 6002;
+      name: 'titleTooLong';
+      msg: 'The title of the proposal is too long, max 50 char';
+    },
+    {
+      code: 6003;
+      name: 'descriptionEmpty';
+      msg: 'The description of the proposal cannot be empty';
+    },
+    {
+      code: 6004;
+      name: 'descriptionTooLong';
+      msg: 'The description of the proposal is too long, max 250 char';
+    },
+    {
+      code: 6005;
+      name: 'descriptionInvalid';
+      msg: 'The description of the proposal must point to a github link';
+    },
+    {
+      code: 6006;
+      name: 'invalidProposalId';
+      msg: 'Invalid proposal ID';
+    },
+    {
+      code: 6007;
+      name: 'votingNotStarted';
+      msg: 'Voting on proposal not yet started';
+    },
+    {
+      code: 6008;
+      name: 'proposalClosed';
+      msg: 'Proposal voting period has ended';
+    },
+    {
+      code: 6009;
+      name: 'proposalFinalized';
+      msg: 'Proposal has already been finalized';
+    },
+    {
+      code: 6010;
+      name: 'invalidVoteDistribution';
+      msg: 'Vote distribution must add up to 100% in Basis Points';
+    },
+    {
+      code: 6011;
+      name: 'votingPeriodNotEnded';
+      msg: 'Voting period not yet ended';
+    },
+    {
+      code: 6012;
+      name: 'invalidVoteAccount';
+      msg: 'Invalid vote account';
+    },
+    {
+      code: 6013;
+      name: 'failedDeserializeNodePubkey';
+      msg: 'Failed to deserialize node_pubkey from Vote account';
+    },
+    {
+      code: 6014;
+      name: 'voteNodePubkeyMismatch';
+      msg: 'Deserialized node_pubkey from Vote accounts does not match';
+    },
+    {
+      code: 6015;
+      name: 'notEnoughAccounts';
+      msg: 'Not enough accounts for tally';
+    },
+    {
+      code: 6016;
+      name: 'invalidClusterStake';
+      msg: 'Cluster stake cannot be zero';
+    },
+    {
+      code: 6017;
+      name: 'invalidStartEpoch';
+      msg: 'Start epoch must be current or future epoch';
+    },
+    {
+      code: 6018;
+      name: 'invalidVotingLength';
+      msg: 'Voting length must be bigger than 0';
+    },
+    {
+      code: 6019;
+      name: 'invalidVoteAccountVersion';
+      msg: 'Invalid Vote account version';
+    },
+    {
+      code: 6020;
+      name: 'invalidVoteAccountSize';
+      msg: 'Invalid Vote account size';
+    },
+    {
+      code: 6021;
+      name: 'invalidStakeAccount';
+      msg: 'Invalid stake account';
+    },
+    {
+      code: 6022;
+      name: 'invalidStakeState';
+      msg: 'Invalid stake account state';
+    },
+    {
+      code: 6023;
+      name: 'invalidStakeAccountSize';
+      msg: 'Invalid Stake account size';
+    },
+    {
+      code: 6024;
+      name: 'invalidSnapshotProgram';
+      msg: 'Invalid Snapshot program: provided program ID does not match the expected Merkle Verifier Service program';
+    },
+    {
+      code: 6025;
+      name: 'unauthorizedMerkleRootUpdate';
+      msg: 'Only the original proposal author can add the merkle root hash';
+    },
+    {
+      code: 6026;
+      name: 'merkleRootAlreadySet';
+      msg: 'Merkle root hash is already set for this proposal';
+    },
+    {
+      code: 6027;
+      name: 'invalidMerkleRoot';
+      msg: 'Merkle root hash cannot be all zeros';
+    },
+    {
+      code: 6028;
+      name: 'invalidSnapshotSlot';
+      msg: 'Invalid snapshot slot: snapshot slot must be past or current slot';
+    },
+    {
+      code: 6029;
+      name: 'mustBeOwnedBySnapshotProgram';
+      msg: 'Account must be owned by Snapshot program';
+    },
+    {
+      code: 6030;
+      name: 'invalidConsensusResultPda';
+      msg: 'Invalid consensus result PDA';
+    },
+    {
+      code: 6031;
+      name: 'cannotDeserializeMetaMerkleProofPda';
+      msg: 'Cannot deserialize MetaMerkleProof PDA';
+    },
+    {
+      code: 6032;
+      name: 'cannotDeserializeConsensusResult';
+      msg: 'Cannot deserialize ConsensusResult';
+    },
+    {
+      code: 6033;
+      name: 'cannotModifyAfterStart';
+      msg: 'Cannot modify proposal after voting has started';
+    },
+    {
+      code: 6034;
+      name: 'votingLengthTooLong';
+      msg: 'Voting length exceeds maximum allowed epochs';
+    },
+    {
+      code: 6035;
+      name: 'arithmeticOverflow';
+      msg: 'Arithmetic overflow occurred';
+    },
+    {
+      code: 6036;
+      name: 'snapshotProgramUpgraded';
+      msg: 'Snapshot program has been upgraded, update protection triggered';
+    },
+    {
+      code: 6037;
+      name: 'merkleRootNotSet';
+      msg: 'Merkle root hash has not been set for this proposal';
+    },
+    {
+      code: 6038;
+      name: 'supportPeriodExpired';
+      msg: 'Support period has expired for this proposal';
+    },
+    {
+      code: 6039;
+      name: 'notInSupportPeriod';
+      msg: 'Not within the support period';
+    },
+    {
+      code: 6040;
+      name: 'consensusResultNotSet';
+      msg: 'Consensus result has not been set for this proposal';
+    },
+    {
+      code: 6041;
+      name: 'unauthorized';
+      msg: 'Unauthorized: caller is not authorized to perform this action';
     },
     {
-      name: 'voteOverrideCache';
-      discriminator: [195, 82, 50, 219, 140, 34, 108, 57];
-    }
-  ];
-  events: [
+      code: 6042;
+      name: 'proposalNotInVotingPhase';
+      msg: 'Proposal is not in voting phase';
+    },
     {
-      name: 'merkleRootFlushed';
-      discriminator: [120, 37, 53, 216, 119, 172, 17, 144];
+      code: 6043;
+      name: 'invalidVoteOverrideCache';
+      msg: 'Invalid vote override cache';
     },
     {
-      name: 'proposalCreated';
-      discriminator: [186, 8, 160, 108, 81, 13, 51, 206];
+      code: 6044;
+      name: 'stakeAccountOwnerMismatch';
+      msg: 'Stake account owner mismatch';
     },
     {
-      name: 'proposalFinalized';
-      discriminator: [159, 104, 210, 220, 86, 209, 61, 51];
+      code: 6045;
+      name: 'emptyProposalTransaction';
+      msg: 'Proposal transaction must contain at least one instruction';
     },
     {
-      name: 'proposalSupported';
-      discriminator: [248, 220, 71, 30, 127, 209, 67, 231];
+      code: 6046;
+      name: 'proposalTransactionTooLarge';
+      msg: 'Proposal transaction exceeds the maximum number of instructions or size';
     },
     {
-      name: 'proposalTimingAdjusted';
-      discriminator: [151, 58, 224, 49, 142, 133, 182, 107];
+      code: 6047;
+      name: 'invalidExecutionTimelock';
+      msg: 'Execution timelock is out of range';
     },
     {
-      name: 'voteCast';
-      discriminator: [39, 53, 195, 104, 188, 17, 225, 213];
+      code: 6048;
+      name: 'invalidProposalInstructionSigner';
+      msg: 'Only the governance authority can sign proposal instructions';
     },
     {
-      name: 'voteModified';
-      discriminator: [192, 64, 130, 9, 210, 47, 57, 175];
+      code: 6049;
+      name: 'proposalNotPassed';
+      msg: 'Proposal has not passed';
     },
     {
-      name: 'voteOverrideCast';
-      discriminator: [111, 204, 225, 252, 254, 218, 120, 236];
+      code: 6050;
+      name: 'executionTimelockNotElapsed';
+      msg: 'Execution timelock has not elapsed';
     },
     {
-      name: 'voteOverrideModified';
-      discriminator: [235, 74, 153, 225, 242, 72, 228, 9];
+      code: 6051;
+      name: 'proposalTransactionAlreadyExecuted';
+      msg: 'Proposal transaction has already been executed';
+    },
+    {
+      code: 6052;
+      name: 'missingInstructionAccount';
+      msg: 'Account required by a proposal instruction was not provided';
+    },
+    {
+      code: 6053;
+      name: 'invalidAntiSnipingConfig';
+      msg: 'Invalid anti-sniping configuration';
+    },
+    {
+      code: 6054;
+      name: 'merkleRootFlushLimitReached';
+      msg: 'Merkle root flush limit reached';
+    },
+    {
+      code: 6055;
+      name: 'cannotFlushAfterVotes';
+      msg: 'Cannot flush the merkle root after votes have been cast';
+    },
+    {
+      code: 6056;
+      name: 'invalidVoteOverrideBatchSize';
+      msg: 'Vote override batch must contain between 1 and the maximum number of entries';
+    },
+    {
+      code: 6057;
+      name: 'invalidVoteOverrideAccount';
+      msg: 'Vote override account is invalid or already initialized';
+    },
+    {
+      code: 6058;
+      name: 'registryPageFull';
+      msg: 'Proposal registry page is full';
+    },
+    {
+      code: 6059;
+      name: 'invalidRegistryPage';
+      msg: 'Registry page does not hold this proposal';
+    },
+    {
+      code: 6060;
+      name: 'bondNotSettleable';
+      msg: 'Proposal bond cannot be settled until the proposal reaches voting or misses support';
+    },
+    {
+      code: 6061;
+      name: 'bondAlreadySettled';
+      msg: 'Proposal bond has already been settled';
+    },
+    {
+      code: 6062;
+      name: 'invalidTreasuryTransferAmount';
+      msg: 'Treasury transfer amount must be greater than zero';
+    },
+    {
+      code: 6063;
+      name: 'treasuryTransferAlreadyExecuted';
+      msg: 'Treasury transfer has already been executed';
+    },
+    {
+      code: 6064;
+      name: 'insufficientTreasuryBalance';
+      msg: 'Treasury balance is too low for this transfer';
+    },
+    {
+      code: 6065;
+      name: 'missingTreasuryTokenAccounts';
+      msg: 'SPL treasury transfers need the mint, treasury token account and token program';
+    },
+    {
+      code: 6066;
+      name: 'profileFieldTooLong';
+      msg: 'Validator profile field is too long';
+    },
+    {
+      code: 6067;
+      name: 'notInDiscussionPeriod';
+      msg: 'Signals can only be sent between voting activation and the snapshot';
+    },
+    {
+      code: 6068;
+      name: 'selfDelegation';
+      msg: 'A validator cannot delegate its governance vote to itself';
+    },
+    {
+      code: 6069;
+      name: 'invalidDelegation';
+      msg: 'Delegation does not apply to this proposal or vote account';
+    },
+    {
+      code: 6070;
+      name: 'delegationOverridden';
+      msg: 'A delegation for this specific proposal takes precedence';
+    },
+    {
+      code: 6071;
+      name: 'delegatorVotedDirectly';
+      msg: 'The delegator has voted directly on this proposal';
+    },
+    {
+      code: 6072;
+      name: 'invalidSecretBallotConfig';
+      msg: 'Invalid secret ballot configuration';
+    },
+    {
+      code: 6073;
+      name: 'secretBallotPlaceholderRequired';
+      msg: 'Secret ballot votes are cast with the placeholder split, then committed and revealed';
+    },
+    {
+      code: 6074;
+      name: 'secretBallotProposal';
+      msg: 'Not available on secret ballot proposals';
+    },
+    {
+      code: 6075;
+      name: 'notSecretBallot';
+      msg: 'Proposal does not use secret ballots';
+    },
+    {
+      code: 6076;
+      name: 'notInRevealPeriod';
+      msg: 'Ballots can only be revealed after voting ends and before the reveal window closes';
+    },
+    {
+      code: 6077;
+      name: 'commitmentMismatch';
+      msg: 'Revealed split and salt do not match the commitment';
+    },
+    {
+      code: 6078;
+      name: 'ballotAlreadyRevealed';
+      msg: 'Ballot has already been revealed';
+    },
+    {
+      code: 6079;
+      name: 'slotHashesUnavailable';
+      msg: 'SlotHashes sysvar has no entries';
+    },
+    {
+      code: 6080;
+      name: 'ballotBoxRequired';
+      msg: 'Ballot box account is required for a fixed snapshot slot';
+    },
+    {
+      code: 6081;
+      name: 'ballotBoxExists';
+      msg: 'Ballot box already exists';
+    },
+    {
+      code: 6082;
+      name: 'tooManyOpenProposals';
+      msg: 'Vote account has reached its limit of open proposals';
+    },
+    {
+      code: 6083;
+      name: 'proposalAlreadySuperseded';
+      msg: 'Proposal has already been superseded';
+    },
+    {
+      code: 6084;
+      name: 'invalidSupersededProposal';
+      msg: 'Account is not the proposal this one supersedes';
+    },
+    {
+      code: 6085;
+      name: 'supersededProposalRequired';
+      msg: 'Superseded proposal account is required to finalize a superseding proposal';
+    },
+    {
+      code: 6086;
+      name: 'invalidParameterChange';
+      msg: 'Invalid governance parameter change';
+    },
+    {
+      code: 6087;
+      name: 'parameterChangeAlreadyEnacted';
+      msg: 'Parameter change has already been enacted';
+    },
+    {
+      code: 6088;
+      name: 'proposalNotFinalized';
+      msg: 'Proposal has not been finalized';
+    },
+    {
+      code: 6089;
+      name: 'invalidMetaMerkleProof';
+      msg: "Meta merkle proof does not belong to the proposal's snapshot";
+    },
+    {
+      code: 6090;
+      name: 'snapshotAccountsRequired';
+      msg: 'Snapshot accounts are required on proposals that use snapshot stake';
+    },
+    {
+      code: 6091;
+      name: 'epochStakeProposal';
+      msg: 'Not available on proposals that read epoch stake at vote time';
+    },
+    {
+      code: 6092;
+      name: 'supportAlreadyMeasured';
+      msg: 'Support was already measured in this epoch';
+    },
+    {
+      code: 6093;
+      name: 'invalidVoteSignature';
+      msg: "Previous instruction does not verify the voting wallet's signature of this vote";
+    },
+    {
+      code: 6094;
+      name: 'signedVoteExpired';
+      msg: 'Signed vote has expired';
+    },
+    {
+      code: 6095;
+      name: 'signedVoteMismatch';
+      msg: 'Signed vote is for a different proposal or vote account';
+    },
+    {
+      code: 6096;
+      name: 'staleParameterChange';
+      msg: 'A proposal finalized later has already changed this parameter';
+    },
+    {
+      code: 6097;
+      name: 'stakeProxyAuthorityMismatch';
+      msg: 'Stake proxy was registered by a previous owner of the stake account';
+    },
+    {
+      code: 6098;
+      name: 'epochStakeVotingStarted';
+      msg: 'Epoch stake votes can only be cast in the first voting epoch';
+    },
+    {
+      code: 6099;
+      name: 'snapshotInUse';
+      msg: 'Proposals voting with this snapshot may still be open';
+    },
+    {
+      code: 6100;
+      name: 'overrideNotCounted';
+      msg: 'Override is not counted because its validator did not vote';
+    },
+    {
+      code: 6101;
+      name: 'treasuryBelowRentExempt';
+      msg: 'Transfer would leave the treasury below rent exemption';
+    },
+    {
+      code: 6102;
+      name: 'notSupersededProposalAuthor';
+      msg: 'Only the author of a proposal can supersede it';
+    },
+    {
+      code: 6103;
+      name: 'proposalSuperseded';
+      msg: 'Proposal has been superseded';
+    },
+    {
+      code: 6104;
+      name: 'notRandomSnapshot';
+      msg: 'Proposal does not use a random snapshot slot';
+    },
+    {
+      code: 6105;
+      name: 'snapshotSlotAlreadyDrawn';
+      msg: 'Snapshot slot has already been drawn';
+    },
+    {
+      code: 6106;
+      name: 'snapshotDrawNotOpen';
+      msg: 'Snapshot slot can only be drawn during the snapshot epoch';
+    },
+    {
+      code: 6107;
+      name: 'snapshotSlotNotDrawn';
+      msg: 'Snapshot slot has not been drawn yet';
+    },
+    {
+      code: 6108;
+      name: 'proposalInstructionInvokesGovernance';
+      msg: 'Proposal instructions cannot invoke the governance program';
     }
   ];
-  errors: [
+  types: [
     {
-      code: 6000;
-      name: 'notEnoughStake';
-      msg: 'Minimum stake required to create proposal is 100k';
+      name: 'antiSnipingConfig';
+      docs: [
+        'Anti-sniping rule: a vote that flips the result, changing the leading option or whether',
+        'the proposal passes, within `window_slots` of the end of voting pushes `end_epoch` back',
+        'by `extension_epochs`, at most `max_extensions` times. All zeroes disables the rule.'
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'windowSlots';
+            type: 'u64';
+          },
+          {
+            name: 'extensionEpochs';
+            type: 'u64';
+          },
+          {
+            name: 'maxExtensions';
+            type: 'u8';
+          }
+        ];
+      };
     },
     {
-      code: 6001;
-      name: 'titleEmpty';
-      msg: 'The title of the proposal cannot be empty';
+      name: 'ballot';
+      docs: [
+        'Commitment to the split of a secret ballot vote, kept next to the `Vote` or',
+        '`VoteOverride` that carries its placeholder'
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'proposal';
+            type: 'pubkey';
+          },
+          {
+            name: 'voteRecord';
+            docs: ['`Vote` or `VoteOverride` the commitment belongs to'];
+            type: 'pubkey';
+          },
+          {
+            name: 'commitment';
+            type: {
+              array: ['u8', 32];
+            };
+          },
+          {
+            name: 'commitTimestamp';
+            type: 'i64';
+          },
+          {
+            name: 'revealed';
+            type: 'bool';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          }
+        ];
+      };
     },
     {
-      code: 6002;
-      name: 'titleTooLong';
-      msg: 'The title of the proposal is too long, max 50 char';
+      name: 'ballotCommitted';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'proposalId';
+            type: 'pubkey';
+          },
+          {
+            name: 'voteRecord';
+            docs: ['`Vote` or `VoteOverride` the commitment belongs to'];
+            type: 'pubkey';
+          },
+          {
+            name: 'voter';
+            type: 'pubkey';
+          },
+          {
+            name: 'commitTimestamp';
+            type: 'i64';
+          }
+        ];
+      };
     },
     {
-      code: 6003;
-      name: 'descriptionEmpty';
-      msg: 'The description of the proposal cannot be empty';
+      name: 'ballotRevealed';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'proposalId';
+            type: 'pubkey';
+          },
+          {
+            name: 'voteRecord';
+            type: 'pubkey';
+          },
+          {
+            name: 'revealer';
+            type: 'pubkey';
+          },
+          {
+            name: 'forVotesBp';
+            type: 'u64';
+          },
+          {
+            name: 'againstVotesBp';
+            type: 'u64';
+          },
+          {
+            name: 'abstainVotesBp';
+            type: 'u64';
+          },
+          {
+            name: 'forVotesLamports';
+            type: 'u64';
+          },
+          {
+            name: 'againstVotesLamports';
+            type: 'u64';
+          },
+          {
+            name: 'abstainVotesLamports';
+            type: 'u64';
+          },
+          {
+            name: 'revealTimestamp';
+            type: 'i64';
+          }
+        ];
+      };
     },
     {
-      code: 6004;
-      name: 'descriptionTooLong';
-      msg: 'The description of the proposal is too long, max 250 char';
+      name: 'bondStatus';
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'locked';
+          },
+          {
+            name: 'returned';
+          },
+          {
+            name: 'forfeited';
+          }
+        ];
+      };
     },
     {
-      code: 6005;
-      name: 'descriptionInvalid';
-      msg: 'The description of the proposal must point to a github link';
+      name: 'delegatedVoteCast';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'proposalId';
+            type: 'pubkey';
+          },
+          {
+            name: 'delegator';
+            type: 'pubkey';
+          },
+          {
+            name: 'delegate';
+            type: 'pubkey';
+          },
+          {
+            name: 'forVotesBp';
+            type: 'u64';
+          },
+          {
+            name: 'againstVotesBp';
+            type: 'u64';
+          },
+          {
+            name: 'abstainVotesBp';
+            type: 'u64';
+          },
+          {
+            name: 'forVotesLamports';
+            type: 'u64';
+          },
+          {
+            name: 'againstVotesLamports';
+            type: 'u64';
+          },
+          {
+            name: 'abstainVotesLamports';
+            type: 'u64';
+          },
+          {
+            name: 'voteTimestamp';
+            type: 'i64';
+          }
+        ];
+      };
     },
     {
-      code: 6006;
-      name: 'invalidProposalId';
-      msg: 'Invalid proposal ID';
+      name: 'governanceConfig';
+      docs: [
+        'Governance rules that passed proposals change through `enact_parameter_change`.',
+        'Proposals copy the rules that apply to them at creation, so a change never affects',
+        'proposals already in flight.'
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'supportThresholdBp';
+            docs: [
+              'Share of cluster stake whose support activates voting on a standard proposal'
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'emergencySupportThresholdBp';
+            type: 'u64';
+          },
+          {
+            name: 'supportEpochs';
+            docs: [
+              'Epochs in which a proposal can gather support, starting the epoch after creation'
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'emergencySupportEpochs';
+            type: 'u64';
+          },
+          {
+            name: 'discussionEpochs';
+            docs: [
+              'Full epochs between the support epoch and the snapshot epoch'
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'emergencyDiscussionEpochs';
+            type: 'u64';
+          },
+          {
+            name: 'votingEpochs';
+            type: 'u64';
+          },
+          {
+            name: 'emergencyVotingEpochs';
+            type: 'u64';
+          },
+          {
+            name: 'passThresholdBp';
+            docs: [
+              'Share of decisive (for + against) stake that must vote `For` for a proposal to pass'
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'quorumBp';
+            docs: [
+              'Share of cluster stake that must vote, abstentions included, for a proposal to pass'
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'minProposalStakeLamports';
+            type: 'u64';
+          },
+          {
+            name: 'proposalBondLamports';
+            type: 'u64';
+          },
+          {
+            name: 'maxOpenProposalsPerAuthor';
+            type: 'u16';
+          },
+          {
+            name: 'parameterFinalizedAt';
+            docs: [
+              'Finalization timestamp of the proposal that last changed each rule, indexed by',
+              "`GovernanceParameter::index`, so an older change can't revert a newer one"
+            ];
+            type: {
+              array: ['i64', 13];
+            };
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          }
+        ];
+      };
     },
     {
-      code: 6007;
-      name: 'votingNotStarted';
-      msg: 'Voting on proposal not yet started';
+      name: 'governanceConfigInitialized';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'governanceConfig';
+            type: 'pubkey';
+          },
+          {
+            name: 'initializer';
+            type: 'pubkey';
+          },
+          {
+            name: 'supportThresholdBp';
+            type: 'u64';
+          },
+          {
+            name: 'emergencySupportThresholdBp';
+            type: 'u64';
+          },
+          {
+            name: 'supportEpochs';
+            type: 'u64';
+          },
+          {
+            name: 'emergencySupportEpochs';
+            type: 'u64';
+          },
+          {
+            name: 'discussionEpochs';
+            type: 'u64';
+          },
+          {
+            name: 'emergencyDiscussionEpochs';
+            type: 'u64';
+          },
+          {
+            name: 'votingEpochs';
+            type: 'u64';
+          },
+          {
+            name: 'emergencyVotingEpochs';
+            type: 'u64';
+          },
+          {
+            name: 'passThresholdBp';
+            type: 'u64';
+          },
+          {
+            name: 'quorumBp';
+            type: 'u64';
+          },
+          {
+            name: 'minProposalStakeLamports';
+            type: 'u64';
+          },
+          {
+            name: 'proposalBondLamports';
+            type: 'u64';
+          },
+          {
+            name: 'maxOpenProposalsPerAuthor';
+            type: 'u16';
+          }
+        ];
+      };
     },
     {
-      code: 6008;
-      name: 'proposalClosed';
-      msg: 'Proposal closed';
+      name: 'governanceDelegation';
+      docs: [
+        "A validator following another validator's vote. `proposal` is `Pubkey::default()` for a",
+        'standing delegation covering every proposal; a delegation for a specific proposal takes',
+        'precedence over it.'
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'delegator';
+            docs: ['Vote account whose voting power is delegated'];
+            type: 'pubkey';
+          },
+          {
+            name: 'delegate';
+            docs: ['Vote account whose split the delegator follows'];
+            type: 'pubkey';
+          },
+          {
+            name: 'proposal';
+            type: 'pubkey';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          }
+        ];
+      };
     },
     {
-      code: 6009;
-      name: 'proposalFinalized';
-      msg: 'Proposal finalized';
+      name: 'governanceDelegationRemoved';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'delegator';
+            type: 'pubkey';
+          },
+          {
+            name: 'delegate';
+            type: 'pubkey';
+          },
+          {
+            name: 'proposal';
+            type: 'pubkey';
+          }
+        ];
+      };
     },
     {
-      code: 6010;
-      name: 'invalidVoteDistribution';
-      msg: 'Vote distribution must add up to 100% in Basis Points';
+      name: 'governanceDelegationSet';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'delegator';
+            type: 'pubkey';
+          },
+          {
+            name: 'delegate';
+            type: 'pubkey';
+          },
+          {
+            name: 'proposal';
+            docs: ['`Pubkey::default()` for a standing delegation'];
+            type: 'pubkey';
+          }
+        ];
+      };
     },
     {
-      code: 6011;
-      name: 'votingPeriodNotEnded';
-      msg: 'Voting period not yet ended';
+      name: 'governanceParameter';
+      docs: [
+        'A single `GovernanceConfig` rule and the value a proposal sets it to'
+      ];
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'supportThresholdBp';
+            fields: ['u64'];
+          },
+          {
+            name: 'emergencySupportThresholdBp';
+            fields: ['u64'];
+          },
+          {
+            name: 'discussionEpochs';
+            fields: ['u64'];
+          },
+          {
+            name: 'emergencyDiscussionEpochs';
+            fields: ['u64'];
+          },
+          {
+            name: 'votingEpochs';
+            fields: ['u64'];
+          },
+          {
+            name: 'emergencyVotingEpochs';
+            fields: ['u64'];
+          },
+          {
+            name: 'passThresholdBp';
+            fields: ['u64'];
+          },
+          {
+            name: 'minProposalStakeLamports';
+            fields: ['u64'];
+          },
+          {
+            name: 'proposalBondLamports';
+            fields: ['u64'];
+          },
+          {
+            name: 'maxOpenProposalsPerAuthor';
+            fields: ['u16'];
+          },
+          {
+            name: 'supportEpochs';
+            fields: ['u64'];
+          },
+          {
+            name: 'emergencySupportEpochs';
+            fields: ['u64'];
+          },
+          {
+            name: 'quorumBp';
+            fields: ['u64'];
+          }
+        ];
+      };
     },
     {
-      code: 6012;
-      name: 'invalidVoteAccount';
-      msg: 'Invalid vote account, proposal id mismatch';
+      name: 'merkleRootFlushed';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'proposalId';
+            type: 'pubkey';
+          },
+          {
+            name: 'author';
+            type: 'pubkey';
+          },
+          {
+            name: 'previousSnapshotSlot';
+            type: 'u64';
+          },
+          {
+            name: 'newSnapshotSlot';
+            type: 'u64';
+          },
+          {
+            name: 'flushCount';
+            type: 'u8';
+          },
+          {
+            name: 'flushTimestamp';
+            type: 'i64';
+          }
+        ];
+      };
     },
     {
-      code: 6013;
-      name: 'failedDeserializeNodePubkey';
-      msg: 'Failed to deserialize node_pubkey from Vote account';
+      name: 'metaMerkleProofClosed';
+      docs: [
+        'A snapshot proof account created for voting was closed after its proposal was finalized'
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'proposalId';
+            type: 'pubkey';
+          },
+          {
+            name: 'metaMerkleProof';
+            type: 'pubkey';
+          },
+          {
+            name: 'voteAccount';
+            type: 'pubkey';
+          },
+          {
+            name: 'payer';
+            docs: ['Account that created the proof and received its rent'];
+            type: 'pubkey';
+          },
+          {
+            name: 'refundedLamports';
+            type: 'u64';
+          }
+        ];
+      };
     },
     {
-      code: 6014;
-      name: 'voteNodePubkeyMismatch';
-      msg: 'Deserialized node_pubkey from Vote accounts does not match';
+      name: 'parameterChange';
+      docs: [
+        'Parameter changes attached to a proposal, applied to the `GovernanceConfig` by',
+        '`enact_parameter_change` once the proposal passes'
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'proposal';
+            type: 'pubkey';
+          },
+          {
+            name: 'changes';
+            type: {
+              vec: {
+                defined: {
+                  name: 'governanceParameter';
+                };
+              };
+            };
+          },
+          {
+            name: 'enactedTimestamp';
+            docs: ['Unix timestamp of enactment, 0 while pending'];
+            type: 'i64';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          }
+        ];
+      };
     },
     {
-      code: 6015;
-      name: 'notEnoughAccounts';
-      msg: 'Not enough accounts for tally';
+      name: 'parameterChangeCreated';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'proposalId';
+            type: 'pubkey';
+          },
+          {
+            name: 'parameterChange';
+            type: 'pubkey';
+          },
+          {
+            name: 'author';
+            type: 'pubkey';
+          },
+          {
+            name: 'changes';
+            type: {
+              vec: {
+                defined: {
+                  name: 'governanceParameter';
+                };
+              };
+            };
+          }
+        ];
+      };
     },
     {
-      code: 6016;
-      name: 'invalidClusterStake';
-      msg: 'Cluster stake cannot be zero';
+      name: 'parameterChangeEnacted';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'proposalId';
+            type: 'pubkey';
+          },
+          {
+            name: 'parameterChange';
+            type: 'pubkey';
+          },
+          {
+            name: 'executor';
+            type: 'pubkey';
+          },
+          {
+            name: 'changes';
+            type: {
+              vec: {
+                defined: {
+                  name: 'governanceParameter';
+                };
+              };
+            };
+          },
+          {
+            name: 'enactmentTimestamp';
+            type: 'i64';
+          }
+        ];
+      };
     },
     {
-      code: 6017;
-      name: 'invalidStartEpoch';
-      msg: 'Start epoch must be current or future epoch';
+      name: 'proposal';
+      docs: [
+        'Hot voting state, read and written by every vote instruction. Kept in a fixed',
+        "zero-copy layout so votes don't pay for (de)serializing it; the title and description",
+        'live in `ProposalContent`.'
+      ];
+      serialization: 'bytemuck';
+      repr: {
+        kind: 'c';
+      };
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'author';
+            docs: ['The public key of the validator who created this proposal'];
+            type: 'pubkey';
+          },
+          {
+            name: 'voteAccountPubkey';
+            type: 'pubkey';
+          },
+          {
+            name: 'consensusResult';
+            docs: [
+              'Consensus result PDA of the snapshot program, `Pubkey::default()` until voting starts'
+            ];
+            type: 'pubkey';
+          },
+          {
+            name: 'supersedes';
+            docs: [
+              'Earlier proposal this one replaces, `Pubkey::default()` if none'
+            ];
+            type: 'pubkey';
+          },
+          {
+            name: 'dependsOn';
+            docs: [
+              'Earlier proposal this one builds on, `Pubkey::default()` if none'
+            ];
+            type: 'pubkey';
+          },
+          {
+            name: 'supersededBy';
+            docs: [
+              'Passed proposal that replaced this one, `Pubkey::default()` until then'
+            ];
+            type: 'pubkey';
+          },
+          {
+            name: 'snapshotSeedHash';
+            docs: ['Slot hash a random snapshot slot was drawn from'];
+            type: {
+              array: ['u8', 32];
+            };
+          },
+          {
+            name: 'creationEpoch';
+            type: 'u64';
+          },
+          {
+            name: 'startEpoch';
+            type: 'u64';
+          },
+          {
+            name: 'endEpoch';
+            type: 'u64';
+          },
+          {
+            name: 'proposerStakeWeightBp';
+            type: 'u64';
+          },
+          {
+            name: 'clusterSupportLamports';
+            type: 'u64';
+          },
+          {
+            name: 'clusterStakeLamports';
+            docs: [
+              'Cluster stake in the epoch voting was activated, which the quorum is measured against'
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'forVotesLamports';
+            docs: ['Total lamports voted in favor of this proposal'];
+            type: 'u64';
+          },
+          {
+            name: 'againstVotesLamports';
+            docs: ['Total lamports voted against this proposal'];
+            type: 'u64';
+          },
+          {
+            name: 'abstainVotesLamports';
+            docs: [
+              'Total lamports that abstained from voting on this proposal'
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'finalizationTimestamp';
+            docs: [
+              'Unix timestamp of finalization, used as the start of any execution timelock'
+            ];
+            type: 'i64';
+          },
+          {
+            name: 'creationTimestamp';
+            type: 'i64';
+          },
+          {
+            name: 'snapshotSlot';
+            docs: [
+              'Slot number when the validator stake snapshot was taken, 0 until a random one is drawn'
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'proposalSeed';
+            type: 'u64';
+          },
+          {
+            name: 'antiSnipingWindowSlots';
+            type: 'u64';
+          },
+          {
+            name: 'antiSnipingExtensionEpochs';
+            type: 'u64';
+          },
+          {
+            name: 'revealEpochs';
+            docs: [
+              'Epochs after `end_epoch` for revealing secret ballots, 0 for an open ballot'
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'snapshotSeedSlot';
+            docs: ['Slot of `snapshot_seed_hash`, 0 for a fixed snapshot slot'];
+            type: 'u64';
+          },
+          {
+            name: 'supportThresholdBp';
+            type: 'u64';
+          },
+          {
+            name: 'passThresholdBp';
+            type: 'u64';
+          },
+          {
+            name: 'quorumBp';
+            type: 'u64';
+          },
+          {
+            name: 'supportEpochs';
+            type: 'u64';
+          },
+          {
+            name: 'discussionEpochs';
+            type: 'u64';
+          },
+          {
+            name: 'votingEpochs';
+            type: 'u64';
+          },
+          {
+            name: 'priorSnapshotSlots';
+            docs: ['Snapshot slots replaced by each flush, in order'];
+            type: {
+              array: ['u64', 3];
+            };
+          },
+          {
+            name: 'voteCount';
+            type: 'u32';
+          },
+          {
+            name: 'index';
+            type: 'u32';
+          },
+          {
+            name: 'proposalBump';
+            type: 'u8';
+          },
+          {
+            name: 'voting';
+            docs: ['1 once enough cluster support activated voting'];
+            type: 'u8';
+          },
+          {
+            name: 'finalized';
+            docs: ['1 once `finalize_proposal` recorded the outcome'];
+            type: 'u8';
+          },
+          {
+            name: 'outcome';
+            docs: ['`ProposalOutcome` as a `u8`'];
+            type: 'u8';
+          },
+          {
+            name: 'antiSnipingMaxExtensions';
+            type: 'u8';
+          },
+          {
+            name: 'votingExtensions';
+            docs: [
+              'Number of times `end_epoch` has been extended by the anti-sniping rule'
+            ];
+            type: 'u8';
+          },
+          {
+            name: 'flushCount';
+            docs: ['Number of times the merkle root has been flushed'];
+            type: 'u8';
+          },
+          {
+            name: 'unrevealedBallots';
+            docs: [
+              '`UnrevealedBallots` as a `u8`, only meaningful for secret ballots'
+            ];
+            type: 'u8';
+          },
+          {
+            name: 'randomSnapshot';
+            docs: [
+              '1 when the snapshot slot is drawn from SlotHashes instead of fixed'
+            ];
+            type: 'u8';
+          },
+          {
+            name: 'class';
+            docs: ['`ProposalClass` as a `u8`'];
+            type: 'u8';
+          },
+          {
+            name: 'stakeSource';
+            docs: ['`StakeSource` as a `u8`'];
+            type: 'u8';
+          },
+          {
+            name: 'padding';
+            type: {
+              array: ['u8', 5];
+            };
+          }
+        ];
+      };
     },
     {
-      code: 6018;
-      name: 'invalidVotingLength';
-      msg: 'Voting length must be bigger than 0';
+      name: 'proposalAccountMeta';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'pubkey';
+            type: 'pubkey';
+          },
+          {
+            name: 'isSigner';
+            type: 'bool';
+          },
+          {
+            name: 'isWritable';
+            type: 'bool';
+          }
+        ];
+      };
     },
     {
-      code: 6019;
-      name: 'invalidVoteAccountVersion';
-      msg: 'Invalid Vote account version';
+      name: 'proposalBond';
+      docs: [
+        'SOL locked by the author in `create_proposal`. The bond lamports sit on top of this',
+        "account's rent and leave it once `settle_proposal_bond` decides where they go."
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'proposal';
+            type: 'pubkey';
+          },
+          {
+            name: 'author';
+            type: 'pubkey';
+          },
+          {
+            name: 'amount';
+            type: 'u64';
+          },
+          {
+            name: 'status';
+            type: {
+              defined: {
+                name: 'bondStatus';
+              };
+            };
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          }
+        ];
+      };
     },
     {
-      code: 6020;
-      name: 'invalidVoteAccountSize';
-      msg: 'Invalid Vote account size';
+      name: 'proposalBondLocked';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'proposalId';
+            type: 'pubkey';
+          },
+          {
+            name: 'author';
+            type: 'pubkey';
+          },
+          {
+            name: 'amount';
+            type: 'u64';
+          }
+        ];
+      };
     },
     {
-      code: 6021;
-      name: 'invalidStakeAccount';
-      msg: 'Stake account invalid';
+      name: 'proposalBondSettled';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'proposalId';
+            type: 'pubkey';
+          },
+          {
+            name: 'author';
+            type: 'pubkey';
+          },
+          {
+            name: 'amount';
+            type: 'u64';
+          },
+          {
+            name: 'returned';
+            type: 'bool';
+          },
+          {
+            name: 'settlementTimestamp';
+            type: 'i64';
+          }
+        ];
+      };
     },
     {
-      code: 6022;
-      name: 'invalidStakeState';
-      msg: 'Stake account invalid';
+      name: 'proposalCancelled';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'proposalId';
+            type: 'pubkey';
+          },
+          {
+            name: 'author';
+            type: 'pubkey';
+          },
+          {
+            name: 'bondForfeited';
+            type: 'u64';
+          },
+          {
+            name: 'cancellationTimestamp';
+            type: 'i64';
+          }
+        ];
+      };
     },
     {
-      code: 6023;
-      name: 'invalidStakeAccountSize';
-      msg: 'Invalid Stake account size';
+      name: 'proposalClass';
+      docs: [
+        'Path a proposal takes from support to voting, each with its own thresholds and timings',
+        'in the `GovernanceConfig`'
+      ];
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'standard';
+          },
+          {
+            name: 'emergency';
+          }
+        ];
+      };
     },
     {
-      code: 6024;
-      name: 'invalidSnapshotProgram';
-      msg: 'Invalid Snapshot program: provided program ID does not match the expected Merkle Verifier Service program';
+      name: 'proposalContent';
+      docs: [
+        'Title and description of a proposal, written once by `create_proposal` and never',
+        'loaded by the vote instructions'
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'proposal';
+            type: 'pubkey';
+          },
+          {
+            name: 'title';
+            type: 'string';
+          },
+          {
+            name: 'description';
+            type: 'string';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          }
+        ];
+      };
     },
     {
-      code: 6025;
-      name: 'unauthorizedMerkleRootUpdate';
-      msg: 'Only the original proposal author can add the merkle root hash';
+      name: 'proposalCreated';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'proposalId';
+            type: 'pubkey';
+          },
+          {
+            name: 'author';
+            type: 'pubkey';
+          },
+          {
+            name: 'title';
+            type: 'string';
+          },
+          {
+            name: 'description';
+            type: 'string';
+          },
+          {
+            name: 'creationTimestamp';
+            type: 'i64';
+          }
+        ];
+      };
     },
     {
-      code: 6026;
-      name: 'merkleRootAlreadySet';
-      msg: 'Merkle root hash is already set for this proposal';
+      name: 'proposalFinalized';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'proposalId';
+            type: 'pubkey';
+          },
+          {
+            name: 'finalizer';
+            type: 'pubkey';
+          },
+          {
+            name: 'totalForVotes';
+            type: 'u64';
+          },
+          {
+            name: 'totalAgainstVotes';
+            type: 'u64';
+          },
+          {
+            name: 'totalAbstainVotes';
+            type: 'u64';
+          },
+          {
+            name: 'totalVotesCount';
+            type: 'u32';
+          },
+          {
+            name: 'passed';
+            type: 'bool';
+          },
+          {
+            name: 'finalizationTimestamp';
+            type: 'i64';
+          },
+          {
+            name: 'snapshotSlot';
+            type: 'u64';
+          },
+          {
+            name: 'consensusResult';
+            type: 'pubkey';
+          },
+          {
+            name: 'startEpoch';
+            type: 'u64';
+          },
+          {
+            name: 'endEpoch';
+            type: 'u64';
+          },
+          {
+            name: 'passThresholdBp';
+            type: 'u64';
+          },
+          {
+            name: 'quorumBp';
+            type: 'u64';
+          },
+          {
+            name: 'participationLamports';
+            docs: ['Stake that voted, including abstentions'];
+            type: 'u64';
+          },
+          {
+            name: 'clusterStakeLamports';
+            docs: [
+              'Cluster stake when voting was activated, which the quorum is measured against'
+            ];
+            type: 'u64';
+          }
+        ];
+      };
     },
     {
-      code: 6027;
-      name: 'invalidMerkleRoot';
-      msg: 'Merkle root hash cannot be all zeros';
+      name: 'proposalIndex';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'currentIndex';
+            type: 'u32';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          }
+        ];
+      };
     },
     {
-      code: 6028;
-      name: 'invalidSnapshotSlot';
-      msg: 'Invalid snapshot slot: snapshot slot must be less past or current slot';
+      name: 'proposalIndexInitialized';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'proposalIndex';
+            type: 'pubkey';
+          },
+          {
+            name: 'initializer';
+            type: 'pubkey';
+          }
+        ];
+      };
     },
     {
-      code: 6029;
-      name: 'mustBeOwnedBySnapshotProgram';
-      msg: 'Account must be owned by Snapshot program';
+      name: 'proposalInstruction';
+      docs: [
+        'A serialized instruction invoked by the governance authority once the proposal passes'
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'programId';
+            type: 'pubkey';
+          },
+          {
+            name: 'accounts';
+            type: {
+              vec: {
+                defined: {
+                  name: 'proposalAccountMeta';
+                };
+              };
+            };
+          },
+          {
+            name: 'data';
+            type: 'bytes';
+          }
+        ];
+      };
     },
     {
-      code: 6030;
-      name: 'invalidConsensusResultPda';
-      msg: 'Invalid consensus result PDA';
+      name: 'proposalOutcome';
+      docs: ['Result recorded by `finalize_proposal`'];
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'undecided';
+          },
+          {
+            name: 'passed';
+          },
+          {
+            name: 'rejected';
+          },
+          {
+            name: 'cancelled';
+          }
+        ];
+      };
     },
     {
-      code: 6031;
-      name: 'cantDeserializeMmppda';
-      msg: "Can't deserialize MetaMerkleProof PDA";
+      name: 'proposalRegistryPage';
+      docs: [
+        'One page of the proposal registry, holding the proposals whose index falls in',
+        '`[page * REGISTRY_PAGE_SIZE + 1, (page + 1) * REGISTRY_PAGE_SIZE]`.',
+        'Clients list proposals by reading `ProposalIndex` and the pages up to its current index.'
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'page';
+            type: 'u32';
+          },
+          {
+            name: 'entries';
+            type: {
+              vec: {
+                defined: {
+                  name: 'registryEntry';
+                };
+              };
+            };
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          }
+        ];
+      };
     },
     {
-      code: 6032;
-      name: 'cantDeserializeConsensusResult';
-      msg: "Can't deserialize ConsensusResult";
+      name: 'proposalSignalled';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'proposalId';
+            type: 'pubkey';
+          },
+          {
+            name: 'voteAccount';
+            type: 'pubkey';
+          },
+          {
+            name: 'validator';
+            type: 'pubkey';
+          },
+          {
+            name: 'lean';
+            type: {
+              defined: {
+                name: 'signalLean';
+              };
+            };
+          },
+          {
+            name: 'stake';
+            type: 'u64';
+          },
+          {
+            name: 'forLamports';
+            type: 'u64';
+          },
+          {
+            name: 'againstLamports';
+            type: 'u64';
+          },
+          {
+            name: 'undecidedLamports';
+            type: 'u64';
+          }
+        ];
+      };
     },
     {
-      code: 6033;
-      name: 'cannotModifyAfterStart';
-      msg: 'Cannot modify proposal after voting has started';
+      name: 'proposalSuperseded';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'proposalId';
+            type: 'pubkey';
+          },
+          {
+            name: 'supersededBy';
+            type: 'pubkey';
+          }
+        ];
+      };
     },
     {
-      code: 6034;
-      name: 'votingLengthTooLong';
-      msg: 'Voting length exceeds maximum allowed epochs';
+      name: 'proposalSupported';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'proposalId';
+            type: 'pubkey';
+          },
+          {
+            name: 'supporter';
+            type: 'pubkey';
+          },
+          {
+            name: 'supportLamports';
+            type: 'u64';
+          },
+          {
+            name: 'clusterSupportLamports';
+            type: 'u64';
+          },
+          {
+            name: 'votingActivated';
+            type: 'bool';
+          },
+          {
+            name: 'snapshotSlot';
+            type: 'u64';
+          }
+        ];
+      };
     },
     {
-      code: 6035;
-      name: 'arithmeticOverflow';
-      msg: 'Arithmetic overflow occurred';
+      name: 'proposalTransaction';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'proposal';
+            type: 'pubkey';
+          },
+          {
+            name: 'timelockSeconds';
+            docs: [
+              'Seconds that must pass after finalization before the transaction can be executed'
+            ];
+            type: 'i64';
+          },
+          {
+            name: 'executedTimestamp';
+            docs: ['Unix timestamp of execution, 0 while pending'];
+            type: 'i64';
+          },
+          {
+            name: 'instructions';
+            type: {
+              vec: {
+                defined: {
+                  name: 'proposalInstruction';
+                };
+              };
+            };
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          }
+        ];
+      };
     },
     {
-      code: 6036;
-      name: 'snapshotProgramUpgraded';
-      msg: 'Snapshot program has been upgraded, update protection triggered';
+      name: 'proposalTransactionCreated';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'proposalId';
+            type: 'pubkey';
+          },
+          {
+            name: 'proposalTransaction';
+            type: 'pubkey';
+          },
+          {
+            name: 'author';
+            type: 'pubkey';
+          },
+          {
+            name: 'instructionCount';
+            type: 'u8';
+          },
+          {
+            name: 'timelockSeconds';
+            type: 'i64';
+          }
+        ];
+      };
     },
     {
-      code: 6037;
-      name: 'merkleRootNotSet';
-      msg: 'Merkle root hash has not been set for this proposal';
+      name: 'proposalTransactionExecuted';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'proposalId';
+            type: 'pubkey';
+          },
+          {
+            name: 'proposalTransaction';
+            type: 'pubkey';
+          },
+          {
+            name: 'executor';
+            type: 'pubkey';
+          },
+          {
+            name: 'instructionCount';
+            type: 'u8';
+          },
+          {
+            name: 'executionTimestamp';
+            type: 'i64';
+          }
+        ];
+      };
     },
     {
-      code: 6038;
-      name: 'supportPeriodExpired';
-      msg: 'Support period has expired for this proposal';
+      name: 'registryEntry';
+      docs: ['A registered proposal and where it stands'];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'index';
+            type: 'u32';
+          },
+          {
+            name: 'proposal';
+            type: 'pubkey';
+          },
+          {
+            name: 'outcome';
+            docs: ['`Undecided` until the proposal is finalized'];
+            type: {
+              defined: {
+                name: 'proposalOutcome';
+              };
+            };
+          }
+        ];
+      };
     },
     {
-      code: 6039;
-      name: 'consensusResultNotSet';
-      msg: 'Consensus result has not been set for this proposal';
+      name: 'secretBallotConfig';
+      docs: [
+        'Commit-reveal voting: votes are cast with a placeholder split and committed to a hash of',
+        'the real one, which is revealed during the `reveal_epochs` after voting ends. Until then',
+        'the placeholder stands in for the vote, as `unrevealed` configures.'
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'revealEpochs';
+            type: 'u64';
+          },
+          {
+            name: 'unrevealed';
+            type: {
+              defined: {
+                name: 'unrevealedBallots';
+              };
+            };
+          }
+        ];
+      };
     },
     {
-      code: 6040;
-      name: 'unauthorized';
-      msg: 'Unauthorized: caller is not authorized to perform this action';
-    }
-  ];
-  types: [
-    {
-      name: 'merkleRootFlushed';
+      name: 'signal';
+      docs: [
+        "A validator's temperature-check signal on a proposal. Signalling again replaces it."
+      ];
       type: {
         kind: 'struct';
         fields: [
           {
-            name: 'proposalId';
+            name: 'proposal';
             type: 'pubkey';
           },
           {
-            name: 'author';
+            name: 'voteAccount';
             type: 'pubkey';
           },
           {
-            name: 'newSnapshotSlot';
+            name: 'lean';
+            type: {
+              defined: {
+                name: 'signalLean';
+              };
+            };
+          },
+          {
+            name: 'stake';
+            docs: ['Epoch stake of the vote account when it last signalled'];
             type: 'u64';
           },
           {
-            name: 'flushTimestamp';
-            type: 'i64';
+            name: 'signalEpoch';
+            type: 'u64';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
           }
         ];
       };
     },
     {
-      name: 'proposal';
+      name: 'signalLean';
+      docs: ['Non-binding lean recorded with `signal`'];
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'for';
+          },
+          {
+            name: 'against';
+          },
+          {
+            name: 'undecided';
+          }
+        ];
+      };
+    },
+    {
+      name: 'signalTally';
+      docs: [
+        'Stake-weighted signals on a proposal, kept apart from the binding vote tally'
+      ];
       type: {
         kind: 'struct';
         fields: [
           {
-            name: 'author';
-            docs: ['The public key of the validator who created this proposal'];
+            name: 'proposal';
             type: 'pubkey';
           },
           {
-            name: 'title';
-            type: 'string';
+            name: 'forLamports';
+            type: 'u64';
           },
           {
-            name: 'description';
-            type: 'string';
+            name: 'againstLamports';
+            type: 'u64';
           },
           {
-            name: 'creationEpoch';
+            name: 'undecidedLamports';
             type: 'u64';
           },
           {
-            name: 'startEpoch';
-            type: 'u64';
+            name: 'signalCount';
+            type: 'u32';
           },
           {
-            name: 'endEpoch';
-            type: 'u64';
+            name: 'bump';
+            type: 'u8';
+          }
+        ];
+      };
+    },
+    {
+      name: 'signedVote';
+      docs: [
+        "Vote signed off-chain by the vote account's voting wallet. The wallet signs",
+        '`SignedVote::message`, and a relayer submits it with an Ed25519 program instruction',
+        'verifying that signature right before `cast_vote_signed`.'
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'proposal';
+            type: 'pubkey';
           },
           {
-            name: 'proposerStakeWeightBp';
-            type: 'u64';
+            name: 'voteAccount';
+            type: 'pubkey';
           },
           {
-            name: 'clusterSupportLamports';
+            name: 'forVotesBp';
             type: 'u64';
           },
           {
-            name: 'forVotesLamports';
-            docs: ['Total lamports voted in favor of this proposal'];
+            name: 'againstVotesBp';
             type: 'u64';
           },
           {
-            name: 'againstVotesLamports';
-            docs: ['Total lamports voted against this proposal'];
+            name: 'abstainVotesBp';
             type: 'u64';
           },
           {
-            name: 'abstainVotesLamports';
+            name: 'nonce';
             docs: [
-              'Total lamports that abstained from voting on this proposal'
+              'Chosen by the signer so otherwise identical votes sign different messages'
             ];
             type: 'u64';
           },
           {
-            name: 'voting';
-            type: 'bool';
+            name: 'expiry';
+            docs: [
+              'Unix timestamp after which the vote can no longer be relayed'
+            ];
+            type: 'i64';
+          }
+        ];
+      };
+    },
+    {
+      name: 'snapshotSlotDrawn';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'proposalId';
+            type: 'pubkey';
           },
           {
-            name: 'finalized';
-            type: 'bool';
+            name: 'snapshotSlot';
+            type: 'u64';
           },
           {
-            name: 'proposalBump';
-            type: 'u8';
+            name: 'seedSlot';
+            type: 'u64';
           },
           {
-            name: 'creationTimestamp';
-            type: 'i64';
+            name: 'seedHash';
+            type: {
+              array: ['u8', 32];
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: 'stakeMerkleLeaf';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'votingWallet';
+            type: 'pubkey';
           },
           {
-            name: 'voteCount';
-            type: 'u32';
+            name: 'stakeAccount';
+            type: 'pubkey';
           },
           {
-            name: 'index';
-            type: 'u32';
+            name: 'activeStake';
+            type: 'u64';
+          }
+        ];
+      };
+    },
+    {
+      name: 'stakeOverrideEntry';
+      docs: ['A single stake account override within a batch'];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'stakeMerkleProof';
+            type: {
+              vec: {
+                array: ['u8', 32];
+              };
+            };
           },
           {
-            name: 'consensusResult';
+            name: 'stakeMerkleLeaf';
             type: {
-              option: 'pubkey';
+              defined: {
+                name: 'stakeMerkleLeaf';
+              };
             };
+          }
+        ];
+      };
+    },
+    {
+      name: 'stakeProxy';
+      docs: [
+        'Wallet allowed to cast and modify vote overrides for a stake account, registered by the',
+        "stake account's withdraw authority"
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'stakeAccount';
+            type: 'pubkey';
           },
           {
-            name: 'snapshotSlot';
-            docs: ['Slot number when the validator stake snapshot was taken'];
-            type: 'u64';
+            name: 'authority';
+            docs: ['Withdraw authority that registered the proxy'];
+            type: 'pubkey';
+          },
+          {
+            name: 'proxy';
+            type: 'pubkey';
           },
           {
-            name: 'proposalSeed';
-            type: 'u64';
+            name: 'registeredTimestamp';
+            type: 'i64';
           },
           {
-            name: 'voteAccountPubkey';
-            type: 'pubkey';
+            name: 'bump';
+            type: 'u8';
           }
         ];
       };
     },
     {
-      name: 'proposalCreated';
+      name: 'stakeProxyRegistered';
       type: {
         kind: 'struct';
         fields: [
           {
-            name: 'proposalId';
+            name: 'stakeAccount';
             type: 'pubkey';
           },
           {
-            name: 'author';
+            name: 'authority';
             type: 'pubkey';
           },
           {
-            name: 'title';
-            type: 'string';
+            name: 'proxy';
+            type: 'pubkey';
           },
           {
-            name: 'description';
-            type: 'string';
-          },
+            name: 'registeredTimestamp';
+            type: 'i64';
+          }
+        ];
+      };
+    },
+    {
+      name: 'stakeProxyRevoked';
+      type: {
+        kind: 'struct';
+        fields: [
           {
-            name: 'startEpoch';
-            type: 'u64';
+            name: 'stakeAccount';
+            type: 'pubkey';
           },
           {
-            name: 'endEpoch';
-            type: 'u64';
+            name: 'authority';
+            type: 'pubkey';
           },
           {
-            name: 'snapshotSlot';
-            type: 'u64';
+            name: 'proxy';
+            type: 'pubkey';
           },
           {
-            name: 'creationTimestamp';
+            name: 'revokedTimestamp';
             type: 'i64';
           }
         ];
       };
     },
     {
-      name: 'proposalFinalized';
+      name: 'stakeSource';
+      docs: [
+        'Where a proposal reads validator and delegator stake from when votes are cast'
+      ];
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'snapshot';
+          },
+          {
+            name: 'epochStake';
+          }
+        ];
+      };
+    },
+    {
+      name: 'support';
       type: {
         kind: 'struct';
         fields: [
           {
-            name: 'proposalId';
+            name: 'proposal';
             type: 'pubkey';
           },
           {
-            name: 'finalizer';
+            name: 'validator';
             type: 'pubkey';
           },
           {
-            name: 'totalForVotes';
-            type: 'u64';
-          },
-          {
-            name: 'totalAgainstVotes';
+            name: 'stake';
+            docs: [
+              "Stake counted towards the proposal's support, measured in `epoch`"
+            ];
             type: 'u64';
           },
           {
-            name: 'totalAbstainVotes';
+            name: 'epoch';
+            docs: ['Epoch of the latest measurement'];
             type: 'u64';
           },
           {
-            name: 'totalVotesCount';
-            type: 'u32';
-          },
-          {
-            name: 'finalizationTimestamp';
-            type: 'i64';
+            name: 'bump';
+            type: 'u8';
           }
         ];
       };
     },
     {
-      name: 'proposalIndex';
+      name: 'treasuryTransfer';
+      docs: [
+        'Treasury withdrawal attached to a proposal, paid out once the proposal passes.',
+        'Only `execute_treasury_transfer` can move funds out of the treasury PDA.'
+      ];
       type: {
         kind: 'struct';
         fields: [
           {
-            name: 'currentIndex';
-            type: 'u32';
+            name: 'proposal';
+            type: 'pubkey';
+          },
+          {
+            name: 'destination';
+            docs: [
+              'SOL recipient, or the recipient token account for SPL transfers'
+            ];
+            type: 'pubkey';
+          },
+          {
+            name: 'mint';
+            docs: ['Token mint, `Pubkey::default()` for SOL'];
+            type: 'pubkey';
+          },
+          {
+            name: 'amount';
+            docs: ['Lamports, or base units of `mint`'];
+            type: 'u64';
+          },
+          {
+            name: 'timelockSeconds';
+            docs: [
+              'Seconds that must pass after finalization before the transfer can be executed'
+            ];
+            type: 'i64';
+          },
+          {
+            name: 'executedTimestamp';
+            docs: ['Unix timestamp of execution, 0 while pending'];
+            type: 'i64';
           },
           {
             name: 'bump';
@@ -1258,7 +6497,7 @@ export type Govcontract = {
       };
     },
     {
-      name: 'proposalSupported';
+      name: 'treasuryTransferCreated';
       type: {
         kind: 'struct';
         fields: [
@@ -1267,26 +6506,34 @@ export type Govcontract = {
             type: 'pubkey';
           },
           {
-            name: 'supporter';
+            name: 'treasuryTransfer';
             type: 'pubkey';
           },
           {
-            name: 'clusterSupportLamports';
-            type: 'u64';
+            name: 'author';
+            type: 'pubkey';
           },
           {
-            name: 'votingActivated';
-            type: 'bool';
+            name: 'destination';
+            type: 'pubkey';
           },
           {
-            name: 'snapshotSlot';
+            name: 'mint';
+            type: 'pubkey';
+          },
+          {
+            name: 'amount';
             type: 'u64';
+          },
+          {
+            name: 'timelockSeconds';
+            type: 'i64';
           }
         ];
       };
     },
     {
-      name: 'proposalTimingAdjusted';
+      name: 'treasuryTransferExecuted';
       type: {
         kind: 'struct';
         fields: [
@@ -1295,68 +6542,113 @@ export type Govcontract = {
             type: 'pubkey';
           },
           {
-            name: 'author';
+            name: 'treasuryTransfer';
             type: 'pubkey';
           },
           {
-            name: 'newCreationTimestamp';
-            type: 'i64';
+            name: 'executor';
+            type: 'pubkey';
           },
           {
-            name: 'newCreationEpoch';
-            type: 'u64';
+            name: 'destination';
+            type: 'pubkey';
           },
           {
-            name: 'newStartEpoch';
-            type: 'u64';
+            name: 'mint';
+            type: 'pubkey';
           },
           {
-            name: 'newEndEpoch';
+            name: 'amount';
             type: 'u64';
           },
           {
-            name: 'newSnapshotSlot';
-            type: 'u64';
+            name: 'executionTimestamp';
+            type: 'i64';
+          }
+        ];
+      };
+    },
+    {
+      name: 'unrevealedBallots';
+      docs: ['What a secret ballot that was never revealed counts as'];
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'abstain';
           },
           {
-            name: 'adjustmentTimestamp';
-            type: 'i64';
+            name: 'exclude';
           }
         ];
       };
     },
     {
-      name: 'stakeMerkleLeaf';
+      name: 'validatorGovernanceProfile';
+      docs: [
+        'Per vote account governance profile: self-reported metadata and participation counters',
+        'kept up to date by `create_proposal`, `support_proposal` and `cast_vote`, plus the',
+        'number of its proposals still open. Metadata fields are empty until the validator sets them.'
+      ];
       type: {
         kind: 'struct';
         fields: [
           {
-            name: 'votingWallet';
-            docs: [
-              'Wallet designated for governance voting for the stake account.'
-            ];
+            name: 'voteAccount';
             type: 'pubkey';
           },
           {
-            name: 'stakeAccount';
-            docs: ['The stake account address.'];
-            type: 'pubkey';
+            name: 'displayName';
+            type: 'string';
           },
           {
-            name: 'activeStake';
-            docs: ['Active delegated stake amount.'];
+            name: 'website';
+            type: 'string';
+          },
+          {
+            name: 'governancePolicyUrl';
+            type: 'string';
+          },
+          {
+            name: 'proposalsAuthored';
+            type: 'u32';
+          },
+          {
+            name: 'proposalsSupported';
+            type: 'u32';
+          },
+          {
+            name: 'proposalsVoted';
+            type: 'u32';
+          },
+          {
+            name: 'openProposals';
+            docs: [
+              'Authored proposals not yet finalized or expired, capped by the governance config'
+            ];
+            type: 'u16';
+          },
+          {
+            name: 'lastVoteEpoch';
+            docs: [
+              'Epoch of the latest `cast_vote`, 0 if the validator never voted'
+            ];
             type: 'u64';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
           }
         ];
       };
     },
     {
-      name: 'support';
+      name: 'validatorProfileUpdated';
       type: {
         kind: 'struct';
         fields: [
           {
-            name: 'proposal';
+            name: 'voteAccount';
             type: 'pubkey';
           },
           {
@@ -1364,8 +6656,16 @@ export type Govcontract = {
             type: 'pubkey';
           },
           {
-            name: 'bump';
-            type: 'u8';
+            name: 'displayName';
+            type: 'string';
+          },
+          {
+            name: 'website';
+            type: 'string';
+          },
+          {
+            name: 'governancePolicyUrl';
+            type: 'string';
           }
         ];
       };
@@ -1415,6 +6715,14 @@ export type Govcontract = {
             name: 'overrideLamports';
             type: 'u64';
           },
+          {
+            name: 'delegate';
+            docs: [
+              'Vote account whose split this vote follows through a `GovernanceDelegation`,',
+              '`Pubkey::default()` once the validator votes directly'
+            ];
+            type: 'pubkey';
+          },
           {
             name: 'voteTimestamp';
             type: 'i64';
@@ -1587,6 +6895,13 @@ export type Govcontract = {
             name: 'stakeAmount';
             type: 'u64';
           },
+          {
+            name: 'proxy';
+            docs: [
+              'Registered proxy that cast the override, `Pubkey::default()` if the staker did'
+            ];
+            type: 'pubkey';
+          },
           {
             name: 'voteOverrideTimestamp';
             type: 'i64';
@@ -1600,6 +6915,14 @@ export type Govcontract = {
     },
     {
       name: 'voteOverrideCache';
+      docs: [
+        'Delegator overrides made before their validator voted, applied by `cast_vote`.',
+        'Zero-copy so the override instructions only touch the fields they update.'
+      ];
+      serialization: 'bytemuck';
+      repr: {
+        kind: 'c';
+      };
       type: {
         kind: 'struct';
         fields: [
@@ -1646,6 +6969,56 @@ export type Govcontract = {
           {
             name: 'bump';
             type: 'u8';
+          },
+          {
+            name: 'padding';
+            type: {
+              array: ['u8', 7];
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: 'voteOverrideCacheUpdated';
+      docs: [
+        "Running totals of the delegator overrides cached for a validator's vote, emitted",
+        'whenever an override creates or changes them'
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'proposalId';
+            type: 'pubkey';
+          },
+          {
+            name: 'validator';
+            type: 'pubkey';
+          },
+          {
+            name: 'voteOverrideCache';
+            type: 'pubkey';
+          },
+          {
+            name: 'created';
+            type: 'bool';
+          },
+          {
+            name: 'forVotesLamports';
+            type: 'u64';
+          },
+          {
+            name: 'againstVotesLamports';
+            type: 'u64';
+          },
+          {
+            name: 'abstainVotesLamports';
+            type: 'u64';
+          },
+          {
+            name: 'totalStake';
+            type: 'u64';
           }
         ];
       };
@@ -1773,6 +7146,34 @@ export type Govcontract = {
           }
         ];
       };
+    },
+    {
+      name: 'votingPeriodExtended';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'proposalId';
+            type: 'pubkey';
+          },
+          {
+            name: 'voter';
+            type: 'pubkey';
+          },
+          {
+            name: 'newEndEpoch';
+            type: 'u64';
+          },
+          {
+            name: 'votingExtensions';
+            type: 'u8';
+          },
+          {
+            name: 'extensionTimestamp';
+            type: 'i64';
+          }
+        ];
+      };
     }
   ];
 };
@@ -5,6 +5,10 @@ import { Govcontract } from "./govcontractV2";
 export type GovContractProgram = Program<Govcontract>;
 
 export type ProposalAccount = IdlAccounts<Govcontract>["proposal"];
+export type ProposalContentAccount =
+  IdlAccounts<Govcontract>["proposalContent"];
+export type ProposalRegistryPageAccount =
+  IdlAccounts<Govcontract>["proposalRegistryPage"];
 export type VoteAccount = IdlAccounts<Govcontract>["vote"];
 export type VoteOverrideAccount = IdlAccounts<Govcontract>["voteOverride"];
 export type SupportAccount = IdlAccounts<Govcontract>["support"];
@@ -1,9 +1,20 @@
-import { createProgramWitDummyWallet } from "@/chain";
+import {
+  createProgramWitDummyWallet,
+  deriveProposalContentPda,
+  deriveProposalIndexPda,
+  deriveRegistryPagePda,
+  REGISTRY_PAGE_SIZE,
+} from "@/chain";
+import type {
+  GovContractProgram,
+  ProposalContentAccount,
+  ProposalRegistryPageAccount,
+} from "@/chain";
 import { getSimd } from "@/hooks";
 import { getProposalStatus } from "@/lib/proposals";
 import type { ProposalRecord, RawProposalAccount } from "@/types";
 import type { RPCEndpoint } from "@/types";
-import { EpochInfo, VoteAccountInfo } from "@solana/web3.js";
+import { EpochInfo, PublicKey, VoteAccountInfo } from "@solana/web3.js";
 
 export interface RawVoteAccountsData {
   current: VoteAccountInfo[];
@@ -24,8 +35,16 @@ export const getProposals = async (
 ): Promise<ProposalRecord[]> => {
   const program = createProgramWitDummyWallet(endpoint);
 
-  // Fetch proposals
-  const proposalAccs = await program.account.proposal.all();
+  // List proposals from the registry instead of scanning the program's accounts
+  const entries = await getRegistryEntries(program);
+  const proposalKeys = entries.map((entry) => entry.proposal);
+  const contentKeys = proposalKeys.map((proposal) =>
+    deriveProposalContentPda(proposal, program.programId),
+  );
+  const [proposalAccs, contentAccs] = await Promise.all([
+    program.account.proposal.fetchMultiple(proposalKeys),
+    program.account.proposalContent.fetchMultiple(contentKeys),
+  ]);
 
   // Calculate total staked lamports from all vote accounts
   const allVotes = [
@@ -39,9 +58,20 @@ export const getProposals = async (
 
   const currentEpoch = epochInfo.epoch;
 
-  let data = proposalAccs.map((acc, index) =>
-    mapProposalDto(acc, index, currentEpoch, totalStakedLamports, endpointType),
-  );
+  let data = entries.flatMap((entry, i) => {
+    const account = proposalAccs[i];
+    const content = contentAccs[i];
+    if (!account || !content) return [];
+    return [
+      mapProposalDto(
+        { publicKey: entry.proposal, account },
+        content,
+        currentEpoch,
+        totalStakedLamports,
+        endpointType,
+      ),
+    ];
+  });
 
   if (filters) {
     if (filters.voting !== undefined) {
@@ -59,9 +89,32 @@ export const getProposals = async (
   return data;
 };
 
+/**
+ * Reads every registry entry, in index order, from the pages up to the proposal
+ * index's current index
+ */
+async function getRegistryEntries(
+  program: GovContractProgram,
+): Promise<ProposalRegistryPageAccount["entries"]> {
+  const proposalIndex = await program.account.proposalIndex.fetchNullable(
+    deriveProposalIndexPda(program.programId),
+  );
+  if (!proposalIndex || proposalIndex.currentIndex === 0) return [];
+
+  const pageCount =
+    Math.floor((proposalIndex.currentIndex - 1) / REGISTRY_PAGE_SIZE) + 1;
+  const pageKeys = Array.from({ length: pageCount }, (_, page) =>
+    deriveRegistryPagePda(page, program.programId),
+  );
+  const pages = await program.account.proposalRegistryPage.fetchMultiple(
+    pageKeys,
+  );
+  return pages.flatMap((page) => page?.entries ?? []);
+}
+
 export function mapProposalDto(
   rawAccount: RawProposalAccount,
-  index: number,
+  content: ProposalContentAccount,
   currentEpoch: number,
   totalStakedLamports: number,
   endpointType?: RPCEndpoint,
@@ -71,8 +124,12 @@ export function mapProposalDto(
   const startEpoch = raw.startEpoch.toNumber();
   const endEpoch = raw.endEpoch.toNumber();
   const clusterSupportLamports = +raw.clusterSupportLamports?.toString() || 0;
-  const consensusResult = rawAccount.account.consensusResult || undefined;
-  const finalized = raw.finalized;
+  // Unset until voting starts
+  const consensusResult = raw.consensusResult.equals(PublicKey.default)
+    ? undefined
+    : raw.consensusResult;
+  const voting = raw.voting !== 0;
+  const finalized = raw.finalized !== 0;
 
   const status = getProposalStatus({
     creationEpoch,
@@ -83,18 +140,18 @@ export function mapProposalDto(
     totalStakedLamports,
     consensusResult,
     finalized,
-    voting: raw.voting,
+    voting,
     endpointType,
   });
 
-  const simd = getSimd(raw.description);
+  const simd = getSimd(content.description);
 
   return {
     publicKey: rawAccount.publicKey,
-    id: index.toString(),
+    id: raw.index.toString(),
     simd,
-    title: raw.title,
-    description: raw.description,
+    title: content.title,
+    description: content.description,
     author: raw.author.toBase58(),
 
     creationEpoch,
@@ -118,7 +175,7 @@ export function mapProposalDto(
     proposerStakeWeightBp: raw.proposerStakeWeightBp?.toNumber() || 0,
 
     status,
-    voting: raw.voting,
+    voting,
     finalized,
 
     consensusResult,
@@ -14,6 +14,28 @@ pub const DEFAULT_DEVNET_PROGRAM_ID: &str = "GoVpHPV3EY89hwKJjfw19jTdgMsGKG4UFSE
 
 // Voting constants
 pub const BASIS_POINTS_TOTAL: u64 = 10_000;
+// ProposalOutcome::Passed, stored as a u8 in the zero-copy Proposal account
+pub const PROPOSAL_OUTCOME_PASSED: u8 = 1;
+// ProposalOutcome::Cancelled
+pub const PROPOSAL_OUTCOME_CANCELLED: u8 = 3;
+
+// Proposal registry: must match the program's REGISTRY_PAGE_SIZE
+pub const REGISTRY_PAGE_SIZE: u32 = 32;
+
+// Vote override batching: must not exceed the program's MAX_VOTE_OVERRIDE_BATCH_SIZE
+pub const MAX_VOTE_OVERRIDE_BATCH_SIZE: usize = 8;
+pub const OVERRIDE_BATCH_COMPUTE_UNITS: u32 = 1_400_000;
+
+// Signed votes: must match the program's SIGNED_VOTE_DOMAIN
+pub const SIGNED_VOTE_DOMAIN: &[u8] = b"svmgov signed vote";
+pub const DEFAULT_SIGNED_VOTE_EXPIRY_HOURS: i64 = 24;
+
+// Meta merkle proofs: gov-v1 lets anyone close them after this timestamp. The governance
+// program closes them once their proposal is finalized, see `svmgov close-proofs`
+pub const META_MERKLE_PROOF_CLOSE_TIMESTAMP: i64 = 1;
+// Epochs after its snapshot epoch until a snapshot's proofs can be closed: must match the
+// program's SNAPSHOT_RETENTION_EPOCHS
+pub const SNAPSHOT_RETENTION_EPOCHS: u64 = 16;
 
 // UI constants
 pub const SPINNER_TICK_DURATION_MS: u64 = 100;
@@ -22,6 +44,5 @@ pub const SPINNER_TICK_DURATION_MS: u64 = 100;
 pub const SVMGOV_KEY_ENV: &str = "SVMGOV_KEY";
 pub const SVMGOV_RPC_ENV: &str = "SVMGOV_RPC";
 
-pub const DISCUSSION_EPOCHS: u64 = 4;
 pub const VOTING_EPOCHS: u64 = 3;
 pub const SNAPSHOT_EPOCH_EXTENSION: u64 = 1;
@@ -0,0 +1,75 @@
+use std::str::FromStr;
+
+use anchor_client::solana_sdk::{pubkey::Pubkey, signer::Signer};
+use anyhow::{Result, anyhow};
+
+use crate::{
+    govcontract::{
+        accounts::{Proposal, ProposalBond},
+        client::{accounts, args},
+    },
+    utils::utils::{
+        create_spinner, derive_event_authority_pda, derive_proposal_bond_pda,
+        derive_registry_page_pda, derive_treasury_pda, derive_validator_profile_pda,
+        registry_page_for, setup_all,
+    },
+};
+
+pub async fn cancel_proposal(
+    proposal_id: String,
+    identity_keypair: Option<String>,
+    rpc_url: Option<String>,
+) -> Result<()> {
+    let proposal_pubkey = Pubkey::from_str(&proposal_id)
+        .map_err(|_| anyhow!("Invalid proposal ID: {}", proposal_id))?;
+
+    let (payer, _vote_account, program, _merkle_proof_program) =
+        setup_all(identity_keypair, rpc_url).await?;
+
+    let proposal = program.account::<Proposal>(proposal_pubkey).await?;
+    if proposal.author != payer.pubkey() {
+        return Err(anyhow!("Only the proposal author can cancel it"));
+    }
+
+    let proposal_bond_pda = derive_proposal_bond_pda(&proposal_pubkey, &program.id());
+    let bond = program.account::<ProposalBond>(proposal_bond_pda).await?;
+    // The bond comes back while nobody has supported the proposal
+    let returned = proposal.cluster_support_lamports == 0;
+
+    let spinner = create_spinner("Cancelling proposal...");
+
+    let sig = program
+        .request()
+        .args(args::CancelProposal {})
+        .accounts(accounts::CancelProposal {
+            signer: payer.pubkey(),
+            proposal: proposal_pubkey,
+            registry_page: derive_registry_page_pda(
+                registry_page_for(proposal.index),
+                &program.id(),
+            ),
+            proposal_bond: proposal_bond_pda,
+            treasury: derive_treasury_pda(&program.id()),
+            author_profile: derive_validator_profile_pda(
+                &proposal.vote_account_pubkey,
+                &program.id(),
+            ),
+            event_authority: derive_event_authority_pda(&program.id()),
+            program: program.id(),
+        })
+        .send()
+        .await?;
+
+    spinner.finish_with_message(format!(
+        "Proposal cancelled, its bond of {:.9} SOL was {}. https://explorer.solana.com/tx/{}",
+        bond.amount as f64 / 1_000_000_000.0,
+        if returned {
+            "returned"
+        } else {
+            "forfeited to the treasury"
+        },
+        sig
+    ));
+
+    Ok(())
+}
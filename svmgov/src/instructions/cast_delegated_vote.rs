@@ -0,0 +1,127 @@
+use std::str::FromStr;
+
+use anchor_client::solana_sdk::{pubkey::Pubkey, signer::Signer};
+use anchor_lang::system_program;
+use anyhow::{Result, anyhow};
+
+use crate::{
+    govcontract::{
+        accounts::{GovernanceDelegation, Proposal},
+        client::{accounts, args},
+    },
+    instructions::cast_vote::init_meta_merkle_proof_if_missing,
+    utils::{
+        api_helpers::{self, get_vote_account_proof},
+        utils::{
+            SnapshotAccounts, create_spinner, derive_delegation_pda, derive_event_authority_pda,
+            derive_vote_override_cache_pda, derive_vote_pda, is_epoch_stake,
+            proposal_consensus_result, setup_all,
+        },
+    },
+};
+
+pub async fn cast_delegated_vote(
+    proposal_id: String,
+    delegator: String,
+    identity_keypair: Option<String>,
+    rpc_url: Option<String>,
+    network: String,
+) -> Result<()> {
+    let proposal_pubkey = Pubkey::from_str(&proposal_id)
+        .map_err(|_| anyhow!("Invalid proposal ID: {}", proposal_id))?;
+    let delegator_vote_account = Pubkey::from_str(&delegator)
+        .map_err(|_| anyhow!("Invalid delegator vote account: {}", delegator))?;
+
+    let (payer, _vote_account, program, merkle_proof_program) =
+        setup_all(identity_keypair, rpc_url).await?;
+
+    let proposal = program
+        .account::<Proposal>(proposal_pubkey)
+        .await
+        .map_err(|e| anyhow!("Failed to fetch proposal: {}", e))?;
+
+    // A delegation for this proposal takes precedence over the standing one
+    let proposal_delegation_pda = derive_delegation_pda(
+        &delegator_vote_account,
+        Some(&proposal_pubkey),
+        &program.id(),
+    );
+    let standing_delegation_pda =
+        derive_delegation_pda(&delegator_vote_account, None, &program.id());
+    let (delegation_pda, delegation) = match program
+        .account::<GovernanceDelegation>(proposal_delegation_pda)
+        .await
+    {
+        Ok(delegation) => (proposal_delegation_pda, delegation),
+        Err(_) => {
+            let delegation = program
+                .account::<GovernanceDelegation>(standing_delegation_pda)
+                .await
+                .map_err(|_| anyhow!("Vote account {} has no governance delegation", delegator))?;
+            (standing_delegation_pda, delegation)
+        }
+    };
+
+    // Epoch stake proposals read the delegator's stake when the vote lands
+    let snapshot = if is_epoch_stake(&proposal) {
+        SnapshotAccounts::default()
+    } else {
+        let consensus_result_pda = proposal_consensus_result(&proposal)?;
+        let proof_response = get_vote_account_proof(
+            &delegator_vote_account.to_string(),
+            proposal.snapshot_slot,
+            &network,
+        )
+        .await?;
+        let meta_merkle_proof_pda = api_helpers::generate_meta_merkle_proof_pda(
+            &consensus_result_pda,
+            &delegator_vote_account,
+        )?;
+        init_meta_merkle_proof_if_missing(
+            &merkle_proof_program,
+            &payer,
+            consensus_result_pda,
+            meta_merkle_proof_pda,
+            &proof_response,
+        )
+        .await?;
+        SnapshotAccounts::new(consensus_result_pda, meta_merkle_proof_pda)
+    };
+
+    let vote_pda = derive_vote_pda(&proposal_pubkey, &delegator_vote_account, &program.id());
+
+    let spinner = create_spinner("Casting delegated vote...");
+
+    let sig = program
+        .request()
+        .args(args::CastDelegatedVote {})
+        .accounts(accounts::CastDelegatedVote {
+            signer: payer.pubkey(),
+            proposal: proposal_pubkey,
+            delegation: delegation_pda,
+            proposal_delegation: proposal_delegation_pda,
+            spl_vote_account: delegator_vote_account,
+            vote: vote_pda,
+            delegate_vote: derive_vote_pda(&proposal_pubkey, &delegation.delegate, &program.id()),
+            vote_override_cache: derive_vote_override_cache_pda(
+                &proposal_pubkey,
+                &vote_pda,
+                &program.id(),
+            ),
+            snapshot_program: snapshot.snapshot_program,
+            consensus_result: snapshot.consensus_result,
+            meta_merkle_proof: snapshot.meta_merkle_proof,
+            system_program: system_program::ID,
+            event_authority: derive_event_authority_pda(&program.id()),
+            program: program.id(),
+        })
+        .send()
+        .await?;
+
+    spinner.finish_with_message(format!(
+        "Delegated vote cast with {}'s split. https://explorer.solana.com/tx/{}",
+        delegation.delegate, sig
+    ));
+
+    Ok(())
+}
@@ -1,17 +1,25 @@
-use std::str::FromStr;
+use std::{str::FromStr, sync::Arc};
 
-use anchor_client::solana_sdk::{pubkey::Pubkey, signer::Signer, transaction::Transaction};
+use anchor_client::{
+    Program,
+    solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer, transaction::Transaction},
+};
 use anchor_lang::system_program;
 use anyhow::{Result, anyhow};
-use gov_v1::{ID as SNAPSHOT_PROGRAM_ID, MetaMerkleLeaf, MetaMerkleProof};
+use gov_v1::{MetaMerkleLeaf, MetaMerkleProof};
 use log::info;
 
 use crate::{
     constants::*,
     govcontract::{accounts::Proposal, client::{accounts, args}},
     utils::{
-        api_helpers::{self, get_vote_account_proof},
-        utils::{create_spinner, derive_vote_override_cache_pda, derive_vote_pda, setup_all},
+        api_helpers::{self, VoteAccountProofResponse, get_vote_account_proof},
+        ballots::{SecretBallot, is_secret_ballot, placeholder_split},
+        utils::{
+            SnapshotAccounts, create_spinner, derive_ballot_pda, derive_event_authority_pda,
+            derive_validator_profile_pda, derive_vote_override_cache_pda, derive_vote_pda,
+            is_epoch_stake, proposal_consensus_result, setup_all,
+        },
     },
 };
 
@@ -43,25 +51,147 @@ pub async fn cast_vote(
         .await
         .map_err(|e| anyhow!("Failed to fetch proposal: {}", e))?;
 
-    let snapshot_slot = proposal.snapshot_slot;
-    let consensus_result_pda = proposal
-        .consensus_result
-        .ok_or_else(|| anyhow!("Proposal consensus_result is not set"))?;
-
-    let proof_response =
-        get_vote_account_proof(&vote_account.to_string(), snapshot_slot, &network).await?;
-
-    // Generate meta_merkle_proof_pda using the consensus_result from proposal
-    let vote_account_pubkey = Pubkey::from_str(&proof_response.meta_merkle_leaf.vote_account)
-        .map_err(|e| anyhow!("Invalid vote_account pubkey in response: {}", e))?;
-    let meta_merkle_proof_pda = api_helpers::generate_meta_merkle_proof_pda(&consensus_result_pda, &vote_account_pubkey)?;
-
     let vote_pda = derive_vote_pda(&proposal_pubkey, &vote_account, &program.id());
     let vote_override_cache_pda =
         derive_vote_override_cache_pda(&proposal_pubkey, &vote_pda, &program.id());
 
+    // Epoch stake proposals read the vote account's stake when the vote lands
+    let snapshot = if is_epoch_stake(&proposal) {
+        SnapshotAccounts::default()
+    } else {
+        let snapshot_slot = proposal.snapshot_slot;
+        let consensus_result_pda = proposal_consensus_result(&proposal)?;
+
+        let proof_response =
+            get_vote_account_proof(&vote_account.to_string(), snapshot_slot, &network).await?;
+
+        // Generate meta_merkle_proof_pda using the consensus_result from proposal
+        let vote_account_pubkey =
+            Pubkey::from_str(&proof_response.meta_merkle_leaf.vote_account)
+                .map_err(|e| anyhow!("Invalid vote_account pubkey in response: {}", e))?;
+        let meta_merkle_proof_pda = api_helpers::generate_meta_merkle_proof_pda(
+            &consensus_result_pda,
+            &vote_account_pubkey,
+        )?;
+
+        // First transaction: Initialize meta merkle proof if needed
+        init_meta_merkle_proof_if_missing(
+            &merkle_proof_program,
+            &payer,
+            consensus_result_pda,
+            meta_merkle_proof_pda,
+            &proof_response,
+        )
+        .await?;
+
+        SnapshotAccounts::new(consensus_result_pda, meta_merkle_proof_pda)
+    };
+
+    // Second transaction: Cast vote
+    let spinner = create_spinner("Sending cast-vote transaction...");
+
+    // Secret ballots are cast with the placeholder split and committed to the real one in
+    // the same transaction; the split and salt are saved locally for the reveal
+    let secret_ballot = is_secret_ballot(&proposal).then(|| {
+        SecretBallot::new(
+            &proposal_pubkey,
+            &vote_pda,
+            votes_for,
+            votes_against,
+            abstain,
+        )
+    });
+    let (cast_for, cast_against, cast_abstain) = if secret_ballot.is_some() {
+        placeholder_split(&proposal)
+    } else {
+        (votes_for, votes_against, abstain)
+    };
+
+    let mut cast_vote_ixs = program
+        .request()
+        .args(args::CastVote {
+            for_votes_bp: cast_for,
+            against_votes_bp: cast_against,
+            abstain_votes_bp: cast_abstain,
+        })
+        .accounts(accounts::CastVote {
+            signer: payer.pubkey(),
+            spl_vote_account: vote_account,
+            validator_profile: derive_validator_profile_pda(&vote_account, &program.id()),
+            proposal: proposal_pubkey,
+            vote: vote_pda,
+            vote_override_cache: vote_override_cache_pda,
+            consensus_result: snapshot.consensus_result,
+            meta_merkle_proof: snapshot.meta_merkle_proof,
+            snapshot_program: snapshot.snapshot_program,
+            system_program: system_program::ID,
+            event_authority: derive_event_authority_pda(&program.id()),
+            program: program.id(),
+        })
+        .instructions()?;
+
+    if let Some(ballot) = &secret_ballot {
+        cast_vote_ixs.extend(
+            program
+                .request()
+                .args(args::CommitVote {
+                    commitment: ballot.commitment()?,
+                })
+                .accounts(accounts::CommitVote {
+                    signer: payer.pubkey(),
+                    proposal: proposal_pubkey,
+                    vote: vote_pda,
+                    ballot: derive_ballot_pda(&vote_pda, &program.id()),
+                    system_program: system_program::ID,
+                    event_authority: derive_event_authority_pda(&program.id()),
+                    program: program.id(),
+                })
+                .instructions()?,
+        );
+        let path = ballot.save()?;
+        info!("Secret ballot saved to {}", path.display());
+    }
+
+    let blockhash = program.rpc().get_latest_blockhash().await?;
+    let transaction = Transaction::new_signed_with_payer(
+        &cast_vote_ixs,
+        Some(&payer.pubkey()),
+        &[&payer],
+        blockhash,
+    );
+
+    let sig = program
+        .rpc()
+        .send_and_confirm_transaction(&transaction)
+        .await?;
+    log::debug!("Cast vote transaction sent successfully: signature={}", sig);
+
+    if secret_ballot.is_some() {
+        spinner.finish_with_message(format!(
+            "Secret ballot committed; reveal it with reveal-vote once voting ends. https://explorer.solana.com/tx/{}",
+            sig
+        ));
+    } else {
+        spinner.finish_with_message(format!(
+            "Vote cast successfully. https://explorer.solana.com/tx/{}",
+            sig
+        ));
+    }
+
+    Ok(())
+}
+
+/// Creates the snapshot program's meta merkle proof account for a vote account from its API
+/// proof, unless it already exists
+pub(crate) async fn init_meta_merkle_proof_if_missing(
+    merkle_proof_program: &Program<Arc<Keypair>>,
+    payer: &Arc<Keypair>,
+    consensus_result_pda: Pubkey,
+    meta_merkle_proof_pda: Pubkey,
+    proof_response: &VoteAccountProofResponse,
+) -> Result<()> {
     // Check if meta merkle proof account exists, create if missing
-    let meta_merkle_proof_account = match program
+    let meta_merkle_proof_account = match merkle_proof_program
         .account::<MetaMerkleProof>(meta_merkle_proof_pda)
         .await
     {
@@ -72,7 +202,6 @@ pub async fn cast_vote(
         }
     };
 
-    // First transaction: Initialize meta merkle proof if needed
     if meta_merkle_proof_account.is_none() {
         info!("Creating meta merkle proof account");
 
@@ -80,11 +209,13 @@ pub async fn cast_vote(
 
         let voting_wallet = Pubkey::from_str(&proof_response.meta_merkle_leaf.voting_wallet)
             .map_err(|e| anyhow!("Invalid voting wallet in proof: {}", e))?;
+        let vote_account = Pubkey::from_str(&proof_response.meta_merkle_leaf.vote_account)
+            .map_err(|e| anyhow!("Invalid vote_account pubkey in response: {}", e))?;
 
         let init_meta_merkle_proof_ix = merkle_proof_program
             .request()
             .args(gov_v1::instruction::InitMetaMerkleProof {
-                close_timestamp: 1,
+                close_timestamp: META_MERKLE_PROOF_CLOSE_TIMESTAMP,
                 meta_merkle_leaf: MetaMerkleLeaf {
                     voting_wallet,
                     vote_account,
@@ -112,7 +243,7 @@ pub async fn cast_vote(
         let transaction = Transaction::new_signed_with_payer(
             &init_meta_merkle_proof_ix,
             Some(&payer.pubkey()),
-            &[&payer],
+            &[payer.as_ref()],
             blockhash,
         );
 
@@ -131,50 +262,5 @@ pub async fn cast_vote(
         ));
     }
 
-    // Second transaction: Cast vote
-    let spinner = create_spinner("Sending cast-vote transaction...");
-
-    let cast_vote_ixs = program
-        .request()
-        .args(args::CastVote {
-            for_votes_bp: votes_for,
-            against_votes_bp: votes_against,
-            abstain_votes_bp: abstain,
-        })
-        .accounts(accounts::CastVote {
-            signer: payer.pubkey(),
-            spl_vote_account: vote_account,
-            proposal: proposal_pubkey,
-            vote: vote_pda,
-            vote_override_cache: vote_override_cache_pda,
-            consensus_result: consensus_result_pda,
-            meta_merkle_proof: meta_merkle_proof_pda,
-            snapshot_program: SNAPSHOT_PROGRAM_ID,
-            system_program: system_program::ID,
-        })
-        .instructions()?;
-
-    let blockhash = program.rpc().get_latest_blockhash().await?;
-    let transaction = Transaction::new_signed_with_payer(
-        &cast_vote_ixs,
-        Some(&payer.pubkey()),
-        &[&payer],
-        blockhash,
-    );
-
-    let sig = program
-        .rpc()
-        .send_and_confirm_transaction(&transaction)
-        .await?;
-    log::debug!(
-        "Cast vote transaction sent successfully: signature={}",
-        sig
-    );
-
-    spinner.finish_with_message(format!(
-        "Vote cast successfully. https://explorer.solana.com/tx/{}",
-        sig
-    ));
-
     Ok(())
 }
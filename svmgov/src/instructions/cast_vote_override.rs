@@ -1,9 +1,12 @@
-use std::str::FromStr;
+use std::{str::FromStr, sync::Arc};
 
-use anchor_client::solana_sdk::{pubkey::Pubkey, signer::Signer, transaction::Transaction};
+use anchor_client::{
+    Program,
+    solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer, transaction::Transaction},
+};
 use anchor_lang::system_program;
 use anyhow::{Result, anyhow};
-use gov_v1::{ID as SNAPSHOT_PROGRAM_ID, MetaMerkleLeaf, MetaMerkleProof};
+use gov_v1::{MetaMerkleLeaf, MetaMerkleProof};
 use log::info;
 
 use crate::{
@@ -14,12 +17,15 @@ use crate::{
     },
     utils::{
         api_helpers::{
-            self, convert_merkle_proof_strings, convert_stake_merkle_leaf_data_to_idl_type,
-            get_stake_account_proof, get_vote_account_proof,
+            self, VoteAccountProofResponse, convert_merkle_proof_strings,
+            convert_stake_merkle_leaf_data_to_idl_type, get_stake_account_proof,
+            get_vote_account_proof,
         },
+        ballots::{SecretBallot, is_secret_ballot, placeholder_split},
         utils::{
-            create_spinner, derive_vote_override_cache_pda, derive_vote_override_pda,
-            derive_vote_pda, setup_all_with_staker,
+            SnapshotAccounts, create_spinner, derive_ballot_pda, derive_event_authority_pda,
+            derive_stake_proxy_pda, derive_vote_override_cache_pda, derive_vote_override_pda,
+            derive_vote_pda, is_epoch_stake, proposal_consensus_result, setup_all_with_staker,
         },
     },
 };
@@ -53,42 +59,177 @@ pub async fn cast_vote_override(
         .await
         .map_err(|e| anyhow!("Failed to fetch proposal: {}", e))?;
 
-    let snapshot_slot = proposal.snapshot_slot;
-    let consensus_result_pda = proposal
-        .consensus_result
-        .ok_or_else(|| anyhow!("Proposal consensus_result is not set"))?;
-
-    let stake_account_str = stake_account_override.clone();
+    let stake_account_pubkey = Pubkey::from_str(&stake_account_override)
+        .map_err(|_| anyhow!("Invalid stake account: {}", stake_account_override))?;
     let vote_account_pubkey = Pubkey::from_str(&vote_account)
         .map_err(|_| anyhow!("Invalid vote account: {}", vote_account))?;
 
-    let meta_merkle_proof = get_vote_account_proof(&vote_account, snapshot_slot, &network).await?;
-
-    let stake_merkle_proof =
-        get_stake_account_proof(&stake_account_str, snapshot_slot, &network).await?;
-
-    // Generate meta_merkle_proof_pda using the consensus_result from proposal
-    let meta_merkle_proof_pda =
-        api_helpers::generate_meta_merkle_proof_pda(&consensus_result_pda, &vote_account_pubkey)?;
-
     let validator_vote_pda = derive_vote_pda(&proposal_pubkey, &vote_account_pubkey, &program.id());
     let vote_override_pda = derive_vote_override_pda(
         &proposal_pubkey,
-        &Pubkey::from_str(&stake_account_str)?,
+        &stake_account_pubkey,
         &validator_vote_pda,
         &program.id(),
     );
     let vote_override_cache_pda =
         derive_vote_override_cache_pda(&proposal_pubkey, &validator_vote_pda, &program.id());
 
+    // Delegators cannot override on epoch stake proposals
+    if is_epoch_stake(&proposal) {
+        return Err(anyhow!(
+            "Proposal {} reads epoch stake; only validators vote on it",
+            proposal_pubkey
+        ));
+    }
+
+    let snapshot_slot = proposal.snapshot_slot;
+    let consensus_result_pda = proposal_consensus_result(&proposal)?;
+
+    let meta_merkle_proof = get_vote_account_proof(&vote_account, snapshot_slot, &network).await?;
+
+    let stake_merkle_proof =
+        get_stake_account_proof(&stake_account_override, snapshot_slot, &network).await?;
+
+    // Generate meta_merkle_proof_pda using the consensus_result from proposal
+    let meta_merkle_proof_pda =
+        api_helpers::generate_meta_merkle_proof_pda(&consensus_result_pda, &vote_account_pubkey)?;
+
     let stake_merkle_proof_vec =
         convert_merkle_proof_strings(&stake_merkle_proof.stake_merkle_proof)?;
 
     let stake_merkle_leaf =
         convert_stake_merkle_leaf_data_to_idl_type(&stake_merkle_proof.stake_merkle_leaf)?;
 
+    // First transaction: Initialize meta merkle proof if needed
+    init_meta_merkle_proof_if_missing(
+        &payer,
+        &merkle_proof_program,
+        &meta_merkle_proof,
+        consensus_result_pda,
+        meta_merkle_proof_pda,
+        vote_account_pubkey,
+    )
+    .await?;
+
+    let snapshot = SnapshotAccounts::new(consensus_result_pda, meta_merkle_proof_pda);
+
+    // Signing for someone else's stake account goes through its registered proxy
+    let stake_proxy = (stake_merkle_leaf.voting_wallet != payer.pubkey())
+        .then(|| derive_stake_proxy_pda(&stake_account_pubkey, &program.id()));
+
+    // Second transaction: Cast vote override
+    let spinner = create_spinner("Sending vote override transaction...");
+
+    // Secret ballots are cast with the placeholder split and committed to the real one in
+    // the same transaction; the split and salt are saved locally for the reveal
+    let secret_ballot = is_secret_ballot(&proposal).then(|| {
+        SecretBallot::new(
+            &proposal_pubkey,
+            &vote_override_pda,
+            for_votes,
+            against_votes,
+            abstain_votes,
+        )
+    });
+    let (cast_for, cast_against, cast_abstain) = if secret_ballot.is_some() {
+        placeholder_split(&proposal)
+    } else {
+        (for_votes, against_votes, abstain_votes)
+    };
+
+    let mut cast_vote_override_ixs = program
+        .request()
+        .args(args::CastVoteOverride {
+            for_votes_bp: cast_for,
+            against_votes_bp: cast_against,
+            abstain_votes_bp: cast_abstain,
+            stake_merkle_proof: stake_merkle_proof_vec,
+            stake_merkle_leaf,
+        })
+        .accounts(accounts::CastVoteOverride {
+            signer: payer.pubkey(),
+            spl_vote_account: vote_account_pubkey,
+            spl_stake_account: stake_account_pubkey,
+            stake_proxy,
+            proposal: proposal_pubkey,
+            validator_vote: validator_vote_pda,
+            vote_override: vote_override_pda,
+            vote_override_cache: vote_override_cache_pda,
+            consensus_result: snapshot.consensus_result,
+            meta_merkle_proof: snapshot.meta_merkle_proof,
+            snapshot_program: snapshot.snapshot_program,
+            system_program: system_program::ID,
+            event_authority: derive_event_authority_pda(&program.id()),
+            program: program.id(),
+        })
+        .instructions()?;
+
+    if let Some(ballot) = &secret_ballot {
+        cast_vote_override_ixs.extend(
+            program
+                .request()
+                .args(args::CommitVoteOverride {
+                    commitment: ballot.commitment()?,
+                })
+                .accounts(accounts::CommitVoteOverride {
+                    signer: payer.pubkey(),
+                    proposal: proposal_pubkey,
+                    vote_override: vote_override_pda,
+                    stake_proxy,
+                    ballot: derive_ballot_pda(&vote_override_pda, &program.id()),
+                    system_program: system_program::ID,
+                    event_authority: derive_event_authority_pda(&program.id()),
+                    program: program.id(),
+                })
+                .instructions()?,
+        );
+        let path = ballot.save()?;
+        info!("Secret ballot saved to {}", path.display());
+    }
+
+    let blockhash = program.rpc().get_latest_blockhash().await?;
+    let transaction = Transaction::new_signed_with_payer(
+        &cast_vote_override_ixs,
+        Some(&payer.pubkey()),
+        &[&payer],
+        blockhash,
+    );
+
+    let sig = program
+        .rpc()
+        .send_and_confirm_transaction(&transaction)
+        .await?;
+    log::debug!(
+        "Cast vote override transaction sent successfully: signature={}",
+        sig
+    );
+
+    if secret_ballot.is_some() {
+        spinner.finish_with_message(format!(
+            "Secret ballot committed; reveal it with reveal-vote-override once voting ends. https://explorer.solana.com/tx/{}",
+            sig
+        ));
+    } else {
+        spinner.finish_with_message(format!(
+            "Vote override cast successfully. https://explorer.solana.com/tx/{}",
+            sig
+        ));
+    }
+
+    Ok(())
+}
+
+/// Creates the validator's meta merkle proof account on the snapshot program if it does not exist yet
+pub async fn init_meta_merkle_proof_if_missing(
+    payer: &Keypair,
+    merkle_proof_program: &Program<Arc<Keypair>>,
+    meta_merkle_proof: &VoteAccountProofResponse,
+    consensus_result_pda: Pubkey,
+    meta_merkle_proof_pda: Pubkey,
+    vote_account_pubkey: Pubkey,
+) -> Result<()> {
     // Check if meta merkle proof account exists, create if missing
-    let meta_merkle_proof_account = match program
+    let meta_merkle_proof_account = match merkle_proof_program
         .account::<MetaMerkleProof>(meta_merkle_proof_pda)
         .await
     {
@@ -99,7 +240,6 @@ pub async fn cast_vote_override(
         }
     };
 
-    // First transaction: Initialize meta merkle proof if needed
     if meta_merkle_proof_account.is_none() {
         info!("Creating meta merkle proof account");
 
@@ -111,7 +251,7 @@ pub async fn cast_vote_override(
         let init_meta_merkle_proof_ix = merkle_proof_program
             .request()
             .args(gov_v1::instruction::InitMetaMerkleProof {
-                close_timestamp: 1,
+                close_timestamp: META_MERKLE_PROOF_CLOSE_TIMESTAMP,
                 meta_merkle_leaf: MetaMerkleLeaf {
                     voting_wallet,
                     vote_account: vote_account_pubkey,
@@ -142,7 +282,7 @@ pub async fn cast_vote_override(
         let transaction = Transaction::new_signed_with_payer(
             &init_meta_merkle_proof_ix,
             Some(&payer.pubkey()),
-            &[&payer],
+            &[payer],
             blockhash,
         );
 
@@ -161,54 +301,5 @@ pub async fn cast_vote_override(
         ));
     }
 
-    // Second transaction: Cast vote override
-    let spinner = create_spinner("Sending vote override transaction...");
-
-    let cast_vote_override_ixs = program
-        .request()
-        .args(args::CastVoteOverride {
-            for_votes_bp: for_votes,
-            against_votes_bp: against_votes,
-            abstain_votes_bp: abstain_votes,
-            stake_merkle_proof: stake_merkle_proof_vec,
-            stake_merkle_leaf,
-        })
-        .accounts(accounts::CastVoteOverride {
-            signer: payer.pubkey(),
-            spl_vote_account: vote_account_pubkey,
-            spl_stake_account: Pubkey::from_str(&stake_account_str)?,
-            proposal: proposal_pubkey,
-            validator_vote: validator_vote_pda,
-            vote_override: vote_override_pda,
-            vote_override_cache: vote_override_cache_pda,
-            consensus_result: consensus_result_pda,
-            meta_merkle_proof: meta_merkle_proof_pda,
-            snapshot_program: SNAPSHOT_PROGRAM_ID,
-            system_program: system_program::ID,
-        })
-        .instructions()?;
-
-    let blockhash = program.rpc().get_latest_blockhash().await?;
-    let transaction = Transaction::new_signed_with_payer(
-        &cast_vote_override_ixs,
-        Some(&payer.pubkey()),
-        &[&payer],
-        blockhash,
-    );
-
-    let sig = program
-        .rpc()
-        .send_and_confirm_transaction(&transaction)
-        .await?;
-    log::debug!(
-        "Cast vote override transaction sent successfully: signature={}",
-        sig
-    );
-
-    spinner.finish_with_message(format!(
-        "Vote override cast successfully. https://explorer.solana.com/tx/{}",
-        sig
-    ));
-
     Ok(())
 }
@@ -0,0 +1,260 @@
+use std::str::FromStr;
+
+use anchor_client::solana_sdk::{
+    compute_budget::ComputeBudgetInstruction, hash::Hash, instruction::AccountMeta,
+    packet::PACKET_DATA_SIZE, pubkey::Pubkey, signer::Signer, transaction::Transaction,
+};
+use anchor_lang::system_program;
+use anyhow::{Result, anyhow};
+
+use crate::{
+    constants::*,
+    govcontract::{
+        accounts::Proposal,
+        client::{accounts, args},
+        types::StakeOverrideEntry,
+    },
+    instructions::cast_vote_override::init_meta_merkle_proof_if_missing,
+    utils::{
+        api_helpers::{
+            self, convert_merkle_proof_strings, convert_stake_merkle_leaf_data_to_idl_type,
+            get_stake_account_proof, get_vote_account_proof,
+        },
+        ballots::is_secret_ballot,
+        utils::{
+            SnapshotAccounts, create_spinner, derive_event_authority_pda, derive_stake_proxy_pda,
+            derive_vote_override_cache_pda, derive_vote_override_pda, derive_vote_pda,
+            is_epoch_stake, proposal_consensus_result, setup_all_with_staker,
+        },
+    },
+};
+
+/// Override entry with its stake account, `VoteOverride` PDA and, when signing as a proxy,
+/// the stake account's `StakeProxy` PDA
+type OverrideBatchEntry = (StakeOverrideEntry, Pubkey, Pubkey, Option<Pubkey>);
+
+pub async fn cast_vote_override_batch(
+    proposal_id: String,
+    for_votes: u64,
+    against_votes: u64,
+    abstain_votes: u64,
+    staker_keypair: String,
+    rpc_url: Option<String>,
+    stake_accounts: Vec<String>,
+    vote_account: String,
+    network: String,
+) -> Result<()> {
+    if for_votes + against_votes + abstain_votes != BASIS_POINTS_TOTAL {
+        return Err(anyhow!(
+            "Total vote basis points must sum to {}",
+            BASIS_POINTS_TOTAL
+        ));
+    }
+    if stake_accounts.is_empty() {
+        return Err(anyhow!("At least one stake account is required"));
+    }
+
+    let proposal_pubkey = Pubkey::from_str(&proposal_id)
+        .map_err(|_| anyhow!("Invalid proposal ID: {}", proposal_id))?;
+
+    let (payer, program, merkle_proof_program) = setup_all_with_staker(staker_keypair, rpc_url)?;
+
+    // Fetch proposal to get snapshot_slot and consensus_result
+    let proposal = program
+        .account::<Proposal>(proposal_pubkey)
+        .await
+        .map_err(|e| anyhow!("Failed to fetch proposal: {}", e))?;
+
+    // Each secret ballot needs its own commitment and saved salt
+    if is_secret_ballot(&proposal) {
+        return Err(anyhow!(
+            "Proposal {} is a secret ballot; use cast-vote-override for each stake account",
+            proposal_pubkey
+        ));
+    }
+
+    let vote_account_pubkey = Pubkey::from_str(&vote_account)
+        .map_err(|_| anyhow!("Invalid vote account: {}", vote_account))?;
+
+    // Delegators cannot override on epoch stake proposals
+    if is_epoch_stake(&proposal) {
+        return Err(anyhow!(
+            "Proposal {} reads epoch stake; only validators vote on it",
+            proposal_pubkey
+        ));
+    }
+
+    let consensus_result_pda = proposal_consensus_result(&proposal)?;
+    let meta_merkle_proof =
+        get_vote_account_proof(&vote_account, proposal.snapshot_slot, &network).await?;
+    let meta_merkle_proof_pda =
+        api_helpers::generate_meta_merkle_proof_pda(&consensus_result_pda, &vote_account_pubkey)?;
+
+    let validator_vote_pda = derive_vote_pda(&proposal_pubkey, &vote_account_pubkey, &program.id());
+    let vote_override_cache_pda =
+        derive_vote_override_cache_pda(&proposal_pubkey, &validator_vote_pda, &program.id());
+
+    // Fetch every stake proof up front so a bad stake account fails before anything is sent
+    let mut entries = Vec::with_capacity(stake_accounts.len());
+    for stake_account in stake_accounts.iter() {
+        let stake_account_pubkey = Pubkey::from_str(stake_account)
+            .map_err(|_| anyhow!("Invalid stake account: {}", stake_account))?;
+        let stake_merkle_proof =
+            get_stake_account_proof(stake_account, proposal.snapshot_slot, &network).await?;
+        if stake_merkle_proof.vote_account != vote_account {
+            return Err(anyhow!(
+                "Stake account {} is delegated to {}, not {}",
+                stake_account,
+                stake_merkle_proof.vote_account,
+                vote_account
+            ));
+        }
+
+        let entry = StakeOverrideEntry {
+            stake_merkle_proof: convert_merkle_proof_strings(
+                &stake_merkle_proof.stake_merkle_proof,
+            )?,
+            stake_merkle_leaf: convert_stake_merkle_leaf_data_to_idl_type(
+                &stake_merkle_proof.stake_merkle_leaf,
+            )?,
+        };
+        let vote_override_pda = derive_vote_override_pda(
+            &proposal_pubkey,
+            &stake_account_pubkey,
+            &validator_vote_pda,
+            &program.id(),
+        );
+        // Stake accounts of other stakers are overridden through their registered proxy
+        let stake_proxy = (entry.stake_merkle_leaf.voting_wallet != payer.pubkey())
+            .then(|| derive_stake_proxy_pda(&stake_account_pubkey, &program.id()));
+        entries.push((entry, stake_account_pubkey, vote_override_pda, stake_proxy));
+    }
+
+    init_meta_merkle_proof_if_missing(
+        &payer,
+        &merkle_proof_program,
+        &meta_merkle_proof,
+        consensus_result_pda,
+        meta_merkle_proof_pda,
+        vote_account_pubkey,
+    )
+    .await?;
+    let snapshot = SnapshotAccounts::new(consensus_result_pda, meta_merkle_proof_pda);
+
+    // Builds a signed transaction overriding the given slice of stake accounts
+    let build_transaction =
+        |batch: &[OverrideBatchEntry], blockhash: Hash| -> Result<Transaction> {
+            // Entries carry a proxy slot only when one of them needs it, the program ID
+            // standing in for entries signed by their own staker
+            let with_proxies = batch
+                .iter()
+                .any(|(_, _, _, stake_proxy)| stake_proxy.is_some());
+            let remaining_accounts = batch
+                .iter()
+                .flat_map(|(_, stake_account, vote_override, stake_proxy)| {
+                    let mut metas = vec![
+                        AccountMeta::new_readonly(*stake_account, false),
+                        AccountMeta::new(*vote_override, false),
+                    ];
+                    if with_proxies {
+                        metas.push(AccountMeta::new_readonly(
+                            stake_proxy.unwrap_or(program.id()),
+                            false,
+                        ));
+                    }
+                    metas
+                })
+                .collect::<Vec<_>>();
+
+            let mut ixs = vec![ComputeBudgetInstruction::set_compute_unit_limit(
+                OVERRIDE_BATCH_COMPUTE_UNITS,
+            )];
+            ixs.extend(
+                program
+                    .request()
+                    .args(args::CastVoteOverrideBatch {
+                        for_votes_bp: for_votes,
+                        against_votes_bp: against_votes,
+                        abstain_votes_bp: abstain_votes,
+                        entries: batch.iter().map(|(entry, _, _, _)| entry.clone()).collect(),
+                    })
+                    .accounts(accounts::CastVoteOverrideBatch {
+                        signer: payer.pubkey(),
+                        proposal: proposal_pubkey,
+                        validator_vote: validator_vote_pda,
+                        spl_vote_account: vote_account_pubkey,
+                        vote_override_cache: vote_override_cache_pda,
+                        snapshot_program: snapshot.snapshot_program,
+                        consensus_result: snapshot.consensus_result,
+                        meta_merkle_proof: snapshot.meta_merkle_proof,
+                        system_program: system_program::ID,
+                        event_authority: derive_event_authority_pda(&program.id()),
+                        program: program.id(),
+                    })
+                    .accounts(remaining_accounts)
+                    .instructions()?,
+            );
+
+            Ok(Transaction::new_signed_with_payer(
+                &ixs,
+                Some(&payer.pubkey()),
+                &[&payer],
+                blockhash,
+            ))
+        };
+
+    let mut start = 0;
+    let mut batch_number = 1;
+    while start < entries.len() {
+        let blockhash = program.rpc().get_latest_blockhash().await?;
+
+        // Pack as many stake accounts as fit in one transaction packet
+        let mut end = start + 1;
+        let mut transaction = build_transaction(&entries[start..end], blockhash)?;
+        while end < entries.len() && end - start < MAX_VOTE_OVERRIDE_BATCH_SIZE {
+            let candidate = build_transaction(&entries[start..end + 1], blockhash)?;
+            if transaction_size(&candidate) > PACKET_DATA_SIZE {
+                break;
+            }
+            transaction = candidate;
+            end += 1;
+        }
+        if transaction_size(&transaction) > PACKET_DATA_SIZE {
+            return Err(anyhow!(
+                "Stake account {} does not fit in a single transaction",
+                stake_accounts[start]
+            ));
+        }
+
+        let spinner = create_spinner(&format!(
+            "Sending vote override batch {} ({} stake accounts)...",
+            batch_number,
+            end - start
+        ));
+
+        let sig = program
+            .rpc()
+            .send_and_confirm_transaction(&transaction)
+            .await?;
+        log::debug!(
+            "Cast vote override batch transaction sent successfully: signature={}",
+            sig
+        );
+
+        spinner.finish_with_message(format!(
+            "Vote override cast for {} stake accounts. https://explorer.solana.com/tx/{}",
+            end - start,
+            sig
+        ));
+
+        start = end;
+        batch_number += 1;
+    }
+
+    Ok(())
+}
+
+/// Serialized size of a transaction: signature count, signatures and message
+fn transaction_size(transaction: &Transaction) -> usize {
+    1 + transaction.signatures.len() * 64 + transaction.message.serialize().len()
+}
@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+
+use anchor_client::{
+    solana_client::rpc_filter::{Memcmp, RpcFilterType},
+    solana_sdk::{signer::Signer, system_program},
+};
+use anchor_lang::AccountDeserialize;
+use anyhow::Result;
+use gov_v1::MetaMerkleProof;
+
+use crate::{
+    govcontract::{
+        accounts::Proposal,
+        client::{accounts, args},
+        types::ProposalOutcome,
+    },
+    utils::{
+        commands::fetch_registry_entries,
+        utils::{
+            create_spinner, derive_event_authority_pda, get_snapshot_release_epoch, setup_all,
+            setup_all_with_staker,
+        },
+    },
+};
+
+/// Offset of `payer` in a `MetaMerkleProof` account, right after the discriminator
+const META_MERKLE_PROOF_PAYER_OFFSET: usize = 8;
+
+pub async fn close_meta_merkle_proofs(
+    staker_keypair: Option<String>,
+    identity_keypair: Option<String>,
+    rpc_url: Option<String>,
+) -> Result<()> {
+    let (payer, program, merkle_proof_program) = match staker_keypair {
+        Some(staker_keypair) => setup_all_with_staker(staker_keypair, rpc_url)?,
+        None => {
+            let (payer, _vote_account, program, merkle_proof_program) =
+                setup_all(identity_keypair, rpc_url).await?;
+            (payer, program, merkle_proof_program)
+        }
+    };
+
+    let proofs = merkle_proof_program
+        .accounts::<MetaMerkleProof>(vec![RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+            META_MERKLE_PROOF_PAYER_OFFSET,
+            payer.pubkey().as_ref(),
+        ))])
+        .await?;
+    if proofs.is_empty() {
+        println!(
+            "No meta merkle proofs paid by {} were found.",
+            payer.pubkey()
+        );
+        return Ok(());
+    }
+
+    // Proofs can be closed through a finalized proposal that voted with their snapshot, once
+    // no other proposal on the snapshot can still be open
+    let current_epoch = program.rpc().get_epoch_info().await?.epoch;
+    let finalized = fetch_registry_entries(&program)
+        .await?
+        .into_iter()
+        .filter(|entry| !matches!(entry.outcome, ProposalOutcome::Undecided))
+        .map(|entry| entry.proposal)
+        .collect::<Vec<_>>();
+    let mut proposal_by_consensus_result = HashMap::new();
+    for chunk in finalized.chunks(100) {
+        let accounts = program.rpc().get_multiple_accounts(chunk).await?;
+        for (proposal_pubkey, account) in chunk.iter().zip(accounts) {
+            let Some(account) = account else {
+                continue;
+            };
+            let proposal = Proposal::try_deserialize(&mut account.data.as_slice())?;
+            if get_snapshot_release_epoch(proposal.snapshot_slot) > current_epoch {
+                continue;
+            }
+            proposal_by_consensus_result.insert(proposal.consensus_result, *proposal_pubkey);
+        }
+    }
+
+    let mut closed = 0;
+    let mut refunded_lamports = 0;
+    for (proof_pubkey, proof) in &proofs {
+        let Some(proposal_pubkey) = proposal_by_consensus_result.get(&proof.consensus_result)
+        else {
+            continue;
+        };
+        let lamports = program.rpc().get_balance(proof_pubkey).await?;
+
+        let spinner = create_spinner(&format!("Closing meta merkle proof {}...", proof_pubkey));
+
+        let sig = program
+            .request()
+            .args(args::CloseMetaMerkleProof {})
+            .accounts(accounts::CloseMetaMerkleProof {
+                signer: payer.pubkey(),
+                proposal: *proposal_pubkey,
+                meta_merkle_proof: *proof_pubkey,
+                payer: proof.payer,
+                snapshot_program: merkle_proof_program.id(),
+                system_program: system_program::ID,
+                event_authority: derive_event_authority_pda(&program.id()),
+                program: program.id(),
+            })
+            .send()
+            .await?;
+
+        spinner.finish_with_message(format!(
+            "Closed meta merkle proof {}. https://explorer.solana.com/tx/{}",
+            proof_pubkey, sig
+        ));
+        closed += 1;
+        refunded_lamports += lamports;
+    }
+
+    println!(
+        "Closed {} of {} meta merkle proofs, refunding {:.9} SOL. Proofs of snapshots that open proposals may still use are kept.",
+        closed,
+        proofs.len(),
+        refunded_lamports as f64 / 1_000_000_000.0
+    );
+
+    Ok(())
+}
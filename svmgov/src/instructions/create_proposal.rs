@@ -1,17 +1,34 @@
+use std::str::FromStr;
 
-use anchor_client::solana_sdk::{signer::Signer, transaction::Transaction};
+use anchor_client::solana_sdk::{pubkey::Pubkey, signer::Signer, transaction::Transaction};
 use anchor_lang::system_program;
-use anyhow::Result;
+use anyhow::{Result, anyhow};
 
 use crate::{
-    govcontract::client::{accounts, args},
-    utils::utils::{create_spinner, derive_proposal_index_pda, derive_proposal_pda, setup_all},
+    govcontract::{
+        accounts::ProposalIndex,
+        client::{accounts, args},
+        types::{AntiSnipingConfig, ProposalClass, SecretBallotConfig, StakeSource},
+    },
+    utils::utils::{
+        create_spinner, derive_event_authority_pda, derive_governance_config_pda,
+        derive_proposal_bond_pda, derive_proposal_content_pda, derive_proposal_index_pda,
+        derive_proposal_pda, derive_registry_page_pda, derive_validator_profile_pda,
+        registry_page_for, setup_all,
+    },
 };
 
 pub async fn create_proposal(
     proposal_title: String,
     proposal_description: String,
     seed: Option<u64>,
+    anti_sniping: Option<AntiSnipingConfig>,
+    secret_ballot: Option<SecretBallotConfig>,
+    random_snapshot: bool,
+    class: ProposalClass,
+    stake_source: StakeSource,
+    supersedes: Option<String>,
+    depends_on: Option<String>,
     identity_keypair: Option<String>,
     rpc_url: Option<String>,
     _network: String,
@@ -25,6 +42,13 @@ pub async fn create_proposal(
         rpc_url
     );
 
+    let supersedes = supersedes
+        .map(|id| Pubkey::from_str(&id).map_err(|_| anyhow!("Invalid proposal ID: {}", id)))
+        .transpose()?;
+    let depends_on = depends_on
+        .map(|id| Pubkey::from_str(&id).map_err(|_| anyhow!("Invalid proposal ID: {}", id)))
+        .transpose()?;
+
     let (payer, vote_account, program, _merkle_proof_program) =
         setup_all(identity_keypair, rpc_url).await?;
 
@@ -32,8 +56,17 @@ pub async fn create_proposal(
 
     let proposal_pda = derive_proposal_pda(seed_value, &vote_account, &program.id());
 
+    let proposal_content_pda = derive_proposal_content_pda(&proposal_pda, &program.id());
+
     let proposal_index_pda = derive_proposal_index_pda(&program.id());
 
+    // The new proposal is recorded on the registry page of the next index
+    let proposal_index = program.account::<ProposalIndex>(proposal_index_pda).await?;
+    let registry_page_pda = derive_registry_page_pda(
+        registry_page_for(proposal_index.current_index + 1),
+        &program.id(),
+    );
+
     // Create proposal - snapshot_slot and consensus_result will be set later in support_proposal
     let spinner = create_spinner("Creating proposal...");
 
@@ -43,13 +76,27 @@ pub async fn create_proposal(
             title: proposal_title,
             description: proposal_description,
             seed: seed_value,
+            anti_sniping,
+            secret_ballot,
+            random_snapshot,
+            class,
+            stake_source,
         })
         .accounts(accounts::CreateProposal {
             signer: payer.pubkey(),
             spl_vote_account: vote_account,
+            validator_profile: derive_validator_profile_pda(&vote_account, &program.id()),
+            governance_config: derive_governance_config_pda(&program.id()),
             proposal: proposal_pda,
+            proposal_content: proposal_content_pda,
+            proposal_bond: derive_proposal_bond_pda(&proposal_pda, &program.id()),
             proposal_index: proposal_index_pda,
+            registry_page: registry_page_pda,
+            supersedes,
+            depends_on,
             system_program: system_program::ID,
+            event_authority: derive_event_authority_pda(&program.id()),
+            program: program.id(),
         })
         .instructions()?;
 
@@ -0,0 +1,73 @@
+use std::str::FromStr;
+
+use anchor_client::solana_sdk::{pubkey::Pubkey, signer::Signer, sysvar::slot_hashes};
+use anyhow::{Result, anyhow};
+use gov_v1::ID as SNAPSHOT_PROGRAM_ID;
+
+use crate::{
+    govcontract::{
+        accounts::Proposal,
+        client::{accounts, args},
+    },
+    instructions::support_proposal::init_ballot_box,
+    utils::utils::{create_spinner, derive_event_authority_pda, setup_all},
+};
+
+pub async fn draw_snapshot_slot(
+    proposal_id: String,
+    identity_keypair: Option<String>,
+    rpc_url: Option<String>,
+) -> Result<()> {
+    let proposal_pubkey = Pubkey::from_str(&proposal_id)
+        .map_err(|_| anyhow!("Invalid proposal ID: {}", proposal_id))?;
+
+    let (payer, _vote_account, program, _merkle_proof_program) =
+        setup_all(identity_keypair, rpc_url).await?;
+
+    let proposal = program.account::<Proposal>(proposal_pubkey).await?;
+    if proposal.random_snapshot == 0 {
+        return Err(anyhow!("Proposal uses a fixed snapshot slot"));
+    }
+    if proposal.voting == 0 {
+        return Err(anyhow!("Voting has not been activated for this proposal"));
+    }
+
+    if proposal.snapshot_slot == 0 {
+        let snapshot_epoch = proposal.start_epoch - 1;
+        let epoch = program.rpc().get_epoch_info().await?.epoch;
+        if epoch != snapshot_epoch {
+            return Err(anyhow!(
+                "The snapshot slot can only be drawn during epoch {} (current epoch {})",
+                snapshot_epoch,
+                epoch
+            ));
+        }
+
+        let spinner = create_spinner("Drawing snapshot slot...");
+
+        let sig = program
+            .request()
+            .args(args::DrawSnapshotSlot {})
+            .accounts(accounts::DrawSnapshotSlot {
+                signer: payer.pubkey(),
+                proposal: proposal_pubkey,
+                ballot_program: SNAPSHOT_PROGRAM_ID,
+                slot_hashes: slot_hashes::ID,
+                event_authority: derive_event_authority_pda(&program.id()),
+                program: program.id(),
+            })
+            .send()
+            .await?;
+
+        spinner.finish_with_message(format!(
+            "Snapshot slot drawn. https://explorer.solana.com/tx/{}",
+            sig
+        ));
+    }
+
+    // Someone else may have drawn it already; the ballot box is created either way
+    let proposal = program.account::<Proposal>(proposal_pubkey).await?;
+    init_ballot_box(&program, &payer, proposal_pubkey, proposal.snapshot_slot).await?;
+
+    Ok(())
+}
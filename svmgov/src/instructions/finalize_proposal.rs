@@ -4,8 +4,14 @@ use anchor_client::solana_sdk::{pubkey::Pubkey, signer::Signer};
 use anyhow::{anyhow, Result};
 
 use crate::{
-    govcontract::client::{accounts, args},
-    utils::utils::{create_spinner, setup_all},
+    govcontract::{
+        accounts::Proposal,
+        client::{accounts, args},
+    },
+    utils::utils::{
+        create_spinner, derive_event_authority_pda, derive_registry_page_pda,
+        derive_validator_profile_pda, registry_page_for, setup_all,
+    },
 };
 
 pub async fn finalize_proposal(
@@ -18,6 +24,9 @@ pub async fn finalize_proposal(
 
     let (payer, _vote_account, program, _merkle_proof_program) = setup_all(identity_keypair, rpc_url).await?;
 
+    let proposal = program.account::<Proposal>(proposal_pubkey).await?;
+    let registry_page_pda = derive_registry_page_pda(registry_page_for(proposal.index), &program.id());
+
     let spinner = create_spinner("Finalizing proposal...");
 
     let sig = program
@@ -26,6 +35,15 @@ pub async fn finalize_proposal(
         .accounts(accounts::FinalizeProposal {
             signer: payer.pubkey(),
             proposal: proposal_pubkey,
+            registry_page: registry_page_pda,
+            author_profile: derive_validator_profile_pda(
+                &proposal.vote_account_pubkey,
+                &program.id(),
+            ),
+            superseded_proposal: (proposal.supersedes != Pubkey::default())
+                .then_some(proposal.supersedes),
+            event_authority: derive_event_authority_pda(&program.id()),
+            program: program.id(),
         })
         .send()
         .await?;
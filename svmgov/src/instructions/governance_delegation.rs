@@ -0,0 +1,89 @@
+use std::str::FromStr;
+
+use anchor_client::solana_sdk::{pubkey::Pubkey, signer::Signer};
+use anchor_lang::system_program;
+use anyhow::{Result, anyhow};
+
+use crate::{
+    govcontract::client::{accounts, args},
+    utils::utils::{create_spinner, derive_delegation_pda, derive_event_authority_pda, setup_all},
+};
+
+fn parse_proposal(proposal_id: Option<String>) -> Result<Option<Pubkey>> {
+    proposal_id
+        .map(|id| Pubkey::from_str(&id).map_err(|_| anyhow!("Invalid proposal ID: {}", id)))
+        .transpose()
+}
+
+pub async fn set_governance_delegation(
+    delegate: String,
+    proposal_id: Option<String>,
+    identity_keypair: Option<String>,
+    rpc_url: Option<String>,
+) -> Result<()> {
+    let delegate_pubkey = Pubkey::from_str(&delegate)
+        .map_err(|_| anyhow!("Invalid delegate vote account: {}", delegate))?;
+    let proposal = parse_proposal(proposal_id)?;
+
+    let (payer, vote_account, program, _merkle_proof_program) =
+        setup_all(identity_keypair, rpc_url).await?;
+
+    let spinner = create_spinner("Delegating governance vote...");
+
+    let sig = program
+        .request()
+        .args(args::SetGovernanceDelegation {
+            delegate: delegate_pubkey,
+            proposal,
+        })
+        .accounts(accounts::SetGovernanceDelegation {
+            signer: payer.pubkey(),
+            spl_vote_account: vote_account,
+            delegation: derive_delegation_pda(&vote_account, proposal.as_ref(), &program.id()),
+            system_program: system_program::ID,
+            event_authority: derive_event_authority_pda(&program.id()),
+            program: program.id(),
+        })
+        .send()
+        .await?;
+
+    spinner.finish_with_message(format!(
+        "Governance vote delegated to {}. https://explorer.solana.com/tx/{}",
+        delegate_pubkey, sig
+    ));
+
+    Ok(())
+}
+
+pub async fn remove_governance_delegation(
+    proposal_id: Option<String>,
+    identity_keypair: Option<String>,
+    rpc_url: Option<String>,
+) -> Result<()> {
+    let proposal = parse_proposal(proposal_id)?;
+
+    let (payer, vote_account, program, _merkle_proof_program) =
+        setup_all(identity_keypair, rpc_url).await?;
+
+    let spinner = create_spinner("Removing governance delegation...");
+
+    let sig = program
+        .request()
+        .args(args::RemoveGovernanceDelegation { proposal })
+        .accounts(accounts::RemoveGovernanceDelegation {
+            signer: payer.pubkey(),
+            spl_vote_account: vote_account,
+            delegation: derive_delegation_pda(&vote_account, proposal.as_ref(), &program.id()),
+            event_authority: derive_event_authority_pda(&program.id()),
+            program: program.id(),
+        })
+        .send()
+        .await?;
+
+    spinner.finish_with_message(format!(
+        "Governance delegation removed. https://explorer.solana.com/tx/{}",
+        sig
+    ));
+
+    Ok(())
+}
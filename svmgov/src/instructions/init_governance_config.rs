@@ -0,0 +1,42 @@
+use anchor_client::solana_sdk::{signer::Signer, system_program};
+use anyhow::Result;
+
+use crate::{
+    govcontract::client::{accounts, args},
+    utils::utils::{
+        create_spinner, derive_event_authority_pda, derive_governance_config_pda, setup_all,
+    },
+};
+
+pub async fn initialize_governance_config(
+    identity_keypair: Option<String>,
+    rpc_url: Option<String>,
+) -> Result<()> {
+    let (payer, _vote_account, program, _merkle_proof_program) =
+        setup_all(identity_keypair, rpc_url).await?;
+
+    let governance_config = derive_governance_config_pda(&program.id());
+
+    let spinner = create_spinner("Sending init_governance_config transaction...");
+
+    let sig = program
+        .request()
+        .args(args::InitializeGovernanceConfig {})
+        .accounts(accounts::InitializeGovernanceConfig {
+            signer: payer.pubkey(),
+            governance_config,
+            system_program: system_program::ID,
+            event_authority: derive_event_authority_pda(&program.id()),
+            program: program.id(),
+        })
+        .send()
+        .await?;
+    log::debug!("Transaction sent successfully: signature={}", sig);
+
+    spinner.finish_with_message(format!(
+        "Governance config initialized successfully. https://explorer.solana.com/tx/{}",
+        sig
+    ));
+
+    Ok(())
+}
@@ -3,7 +3,9 @@ use anyhow::Result;
 
 use crate::{
     govcontract::client::{accounts, args},
-    utils::utils::{create_spinner, derive_proposal_index_pda, setup_all},
+    utils::utils::{
+        create_spinner, derive_event_authority_pda, derive_proposal_index_pda, setup_all,
+    },
 };
 
 pub async fn initialize_index(
@@ -24,6 +26,8 @@ pub async fn initialize_index(
             signer: payer.pubkey(),
             proposal_index,
             system_program: system_program::ID,
+            event_authority: derive_event_authority_pda(&program.id()),
+            program: program.id(),
         })
         .send()
         .await?;
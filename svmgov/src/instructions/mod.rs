@@ -1,17 +1,45 @@
+pub mod cancel_proposal;
+pub mod cast_delegated_vote;
 pub mod cast_vote;
 pub mod cast_vote_override;
+pub mod cast_vote_override_batch;
+pub mod close_meta_merkle_proofs;
 pub mod create_proposal;
+pub mod draw_snapshot_slot;
 pub mod finalize_proposal;
+pub mod governance_delegation;
+pub mod init_governance_config;
 pub mod init_index;
 pub mod modify_vote;
 pub mod modify_vote_override;
+pub mod relay_vote;
+pub mod reveal_vote;
+pub mod settle_proposal_bond;
+pub mod sign_vote;
+pub mod signal;
+pub mod stake_proxy;
 pub mod support_proposal;
+pub mod update_validator_profile;
 
+pub use cancel_proposal::cancel_proposal;
+pub use cast_delegated_vote::cast_delegated_vote;
 pub use cast_vote::cast_vote;
 pub use cast_vote_override::cast_vote_override;
+pub use cast_vote_override_batch::cast_vote_override_batch;
+pub use close_meta_merkle_proofs::close_meta_merkle_proofs;
 pub use create_proposal::create_proposal;
+pub use draw_snapshot_slot::draw_snapshot_slot;
 pub use finalize_proposal::finalize_proposal;
+pub use governance_delegation::{remove_governance_delegation, set_governance_delegation};
+pub use init_governance_config::initialize_governance_config;
 pub use init_index::initialize_index;
 pub use modify_vote::modify_vote;
 pub use modify_vote_override::modify_vote_override;
+pub use relay_vote::relay_vote;
+pub use reveal_vote::{reveal_vote, reveal_vote_override};
+pub use settle_proposal_bond::settle_proposal_bond;
+pub use sign_vote::sign_vote;
+pub use signal::signal_proposal;
+pub use stake_proxy::{register_stake_proxy, revoke_stake_proxy};
 pub use support_proposal::support_proposal;
+pub use update_validator_profile::update_validator_profile;
@@ -3,14 +3,16 @@ use std::str::FromStr;
 use anchor_client::solana_sdk::{pubkey::Pubkey, signer::Signer};
 use anchor_lang::system_program;
 use anyhow::{Result, anyhow};
-use gov_v1::ID as SNAPSHOT_PROGRAM_ID;
 
 use crate::{
     constants::*,
     govcontract::{accounts::Proposal, client::{accounts, args}},
     utils::{
         api_helpers::{self, get_vote_account_proof},
-        utils::{create_spinner, derive_vote_pda, setup_all},
+        utils::{
+            SnapshotAccounts, create_spinner, derive_event_authority_pda, derive_vote_pda,
+            is_epoch_stake, proposal_consensus_result, setup_all,
+        },
     },
 };
 
@@ -42,18 +44,27 @@ pub async fn modify_vote(
         .await
         .map_err(|e| anyhow!("Failed to fetch proposal: {}", e))?;
 
-    let snapshot_slot = proposal.snapshot_slot;
-    let consensus_result_pda = proposal
-        .consensus_result
-        .ok_or_else(|| anyhow!("Proposal consensus_result is not set"))?;
+    // Epoch stake proposals read the vote account's stake when the vote lands
+    let snapshot = if is_epoch_stake(&proposal) {
+        SnapshotAccounts::default()
+    } else {
+        let snapshot_slot = proposal.snapshot_slot;
+        let consensus_result_pda = proposal_consensus_result(&proposal)?;
 
-    let proof_response =
-        get_vote_account_proof(&vote_account.to_string(), snapshot_slot, &network).await?;
+        let proof_response =
+            get_vote_account_proof(&vote_account.to_string(), snapshot_slot, &network).await?;
 
-    // Generate meta_merkle_proof_pda using the consensus_result from proposal
-    let vote_account_pubkey = Pubkey::from_str(&proof_response.meta_merkle_leaf.vote_account)
-        .map_err(|e| anyhow!("Invalid vote_account pubkey in response: {}", e))?;
-    let meta_merkle_proof_pda = api_helpers::generate_meta_merkle_proof_pda(&consensus_result_pda, &vote_account_pubkey)?;
+        // Generate meta_merkle_proof_pda using the consensus_result from proposal
+        let vote_account_pubkey =
+            Pubkey::from_str(&proof_response.meta_merkle_leaf.vote_account)
+                .map_err(|e| anyhow!("Invalid vote_account pubkey in response: {}", e))?;
+        let meta_merkle_proof_pda = api_helpers::generate_meta_merkle_proof_pda(
+            &consensus_result_pda,
+            &vote_account_pubkey,
+        )?;
+
+        SnapshotAccounts::new(consensus_result_pda, meta_merkle_proof_pda)
+    };
 
     let vote_pda = derive_vote_pda(&proposal_pubkey, &vote_account, &program.id());
 
@@ -71,10 +82,12 @@ pub async fn modify_vote(
             spl_vote_account: vote_account,
             proposal: proposal_pubkey,
             vote: vote_pda,
-            consensus_result: consensus_result_pda,
-            meta_merkle_proof: meta_merkle_proof_pda,
-            snapshot_program: SNAPSHOT_PROGRAM_ID,
+            consensus_result: snapshot.consensus_result,
+            meta_merkle_proof: snapshot.meta_merkle_proof,
+            snapshot_program: snapshot.snapshot_program,
             system_program: system_program::ID,
+            event_authority: derive_event_authority_pda(&program.id()),
+            program: program.id(),
         })
         .send()
         .await?;
@@ -3,7 +3,6 @@ use std::str::FromStr;
 use anchor_client::solana_sdk::{pubkey::Pubkey, signer::Signer};
 use anchor_lang::system_program;
 use anyhow::{Result, anyhow};
-use gov_v1::ID as SNAPSHOT_PROGRAM_ID;
 
 use crate::{
     constants::*,
@@ -17,8 +16,9 @@ use crate::{
             get_stake_account_proof,
         },
         utils::{
-            create_spinner, derive_vote_override_cache_pda, derive_vote_override_pda,
-            derive_vote_pda, setup_all_with_staker,
+            SnapshotAccounts, create_spinner, derive_event_authority_pda, derive_stake_proxy_pda,
+            derive_vote_override_cache_pda, derive_vote_override_pda, derive_vote_pda,
+            is_epoch_stake, proposal_consensus_result, setup_all_with_staker,
         },
     },
 };
@@ -52,37 +52,47 @@ pub async fn modify_vote_override(
         .await
         .map_err(|e| anyhow!("Failed to fetch proposal: {}", e))?;
 
-    let snapshot_slot = proposal.snapshot_slot;
-    let consensus_result_pda = proposal
-        .consensus_result
-        .ok_or_else(|| anyhow!("Proposal consensus_result is not set"))?;
-
-    let stake_account_str = stake_account_override.clone();
+    let stake_account_pubkey = Pubkey::from_str(&stake_account_override)
+        .map_err(|_| anyhow!("Invalid stake account: {}", stake_account_override))?;
     let vote_account_pubkey = Pubkey::from_str(&vote_account)
         .map_err(|_| anyhow!("Invalid vote account: {}", vote_account))?;
 
-    let stake_merkle_proof =
-        get_stake_account_proof(&stake_account_str, snapshot_slot, &network).await?;
-
-    // Generate meta_merkle_proof_pda using the consensus_result from proposal
-    let meta_merkle_proof_pda =
-        api_helpers::generate_meta_merkle_proof_pda(&consensus_result_pda, &vote_account_pubkey)?;
-
     let validator_vote_pda = derive_vote_pda(&proposal_pubkey, &vote_account_pubkey, &program.id());
     let vote_override_pda = derive_vote_override_pda(
         &proposal_pubkey,
-        &Pubkey::from_str(&stake_account_str)?,
+        &stake_account_pubkey,
         &validator_vote_pda,
         &program.id(),
     );
     let vote_override_cache_pda =
         derive_vote_override_cache_pda(&proposal_pubkey, &validator_vote_pda, &program.id());
 
+    // Delegators cannot override on epoch stake proposals
+    if is_epoch_stake(&proposal) {
+        return Err(anyhow!(
+            "Proposal {} reads epoch stake; only validators vote on it",
+            proposal_pubkey
+        ));
+    }
+
+    let consensus_result_pda = proposal_consensus_result(&proposal)?;
+
+    let stake_merkle_proof =
+        get_stake_account_proof(&stake_account_override, proposal.snapshot_slot, &network).await?;
+
+    // Generate meta_merkle_proof_pda using the consensus_result from proposal
+    let meta_merkle_proof_pda =
+        api_helpers::generate_meta_merkle_proof_pda(&consensus_result_pda, &vote_account_pubkey)?;
+
     let stake_merkle_proof_vec =
         convert_merkle_proof_strings(&stake_merkle_proof.stake_merkle_proof)?;
-
     let stake_merkle_leaf =
         convert_stake_merkle_leaf_data_to_idl_type(&stake_merkle_proof.stake_merkle_leaf)?;
+    let snapshot = SnapshotAccounts::new(consensus_result_pda, meta_merkle_proof_pda);
+
+    // Signing for someone else's stake account goes through its registered proxy
+    let stake_proxy = (stake_merkle_leaf.voting_wallet != payer.pubkey())
+        .then(|| derive_stake_proxy_pda(&stake_account_pubkey, &program.id()));
 
     let spinner = create_spinner("Modifying vote override...");
 
@@ -98,15 +108,18 @@ pub async fn modify_vote_override(
         .accounts(accounts::ModifyVoteOverride {
             signer: payer.pubkey(),
             spl_vote_account: vote_account_pubkey,
-            spl_stake_account: Pubkey::from_str(&stake_account_str)?,
+            spl_stake_account: stake_account_pubkey,
+            stake_proxy,
             proposal: proposal_pubkey,
             validator_vote: validator_vote_pda,
             vote_override: vote_override_pda,
             vote_override_cache: vote_override_cache_pda,
-            consensus_result: consensus_result_pda,
-            meta_merkle_proof: meta_merkle_proof_pda,
-            snapshot_program: SNAPSHOT_PROGRAM_ID,
+            consensus_result: snapshot.consensus_result,
+            meta_merkle_proof: snapshot.meta_merkle_proof,
+            snapshot_program: snapshot.snapshot_program,
             system_program: system_program::ID,
+            event_authority: derive_event_authority_pda(&program.id()),
+            program: program.id(),
         })
         .send()
         .await?;
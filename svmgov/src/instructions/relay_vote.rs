@@ -0,0 +1,140 @@
+use std::str::FromStr;
+
+use anchor_client::solana_sdk::{
+    pubkey::Pubkey, signer::Signer, sysvar::instructions as instructions_sysvar,
+    transaction::Transaction,
+};
+use anchor_lang::system_program;
+use anyhow::{Result, anyhow};
+
+use crate::{
+    govcontract::{
+        accounts::Proposal,
+        client::{accounts, args},
+    },
+    instructions::cast_vote::init_meta_merkle_proof_if_missing,
+    utils::{
+        api_helpers::{self, get_vote_account_proof},
+        ballots::is_secret_ballot,
+        signed_votes::SignedVoteFile,
+        utils::{
+            SnapshotAccounts, create_spinner, derive_event_authority_pda,
+            derive_validator_profile_pda, derive_vote_override_cache_pda, derive_vote_pda,
+            is_epoch_stake, proposal_consensus_result, setup_all_with_payer,
+        },
+    },
+};
+
+/// Submits votes signed with sign-vote, paying for them with the identity keypair, which
+/// needn't belong to the voting validators
+pub async fn relay_vote(
+    files: Vec<String>,
+    identity_keypair: Option<String>,
+    rpc_url: Option<String>,
+    network: String,
+) -> Result<()> {
+    let (payer, program, merkle_proof_program) = setup_all_with_payer(identity_keypair, rpc_url)?;
+
+    for file in &files {
+        let signed = SignedVoteFile::load(file)?;
+        let vote = signed.vote()?;
+        let ed25519_ix = signed.ed25519_instruction()?;
+        let (proposal_pubkey, vote_account) = (vote.proposal, vote.vote_account);
+
+        if vote.expiry < chrono::Utc::now().timestamp() {
+            return Err(anyhow!("Signed vote {} has expired", file));
+        }
+
+        let proposal = program
+            .account::<Proposal>(proposal_pubkey)
+            .await
+            .map_err(|e| anyhow!("Failed to fetch proposal: {}", e))?;
+        if is_secret_ballot(&proposal) {
+            return Err(anyhow!(
+                "Proposal {} takes secret ballots, which cannot be relayed",
+                proposal_pubkey
+            ));
+        }
+
+        let vote_pda = derive_vote_pda(&proposal_pubkey, &vote_account, &program.id());
+        let vote_override_cache_pda =
+            derive_vote_override_cache_pda(&proposal_pubkey, &vote_pda, &program.id());
+
+        // Epoch stake proposals read the vote account's stake when the vote lands
+        let snapshot = if is_epoch_stake(&proposal) {
+            SnapshotAccounts::default()
+        } else {
+            let consensus_result_pda = proposal_consensus_result(&proposal)?;
+            let proof_response =
+                get_vote_account_proof(&vote_account.to_string(), proposal.snapshot_slot, &network)
+                    .await?;
+            let vote_account_pubkey =
+                Pubkey::from_str(&proof_response.meta_merkle_leaf.vote_account)
+                    .map_err(|e| anyhow!("Invalid vote_account pubkey in response: {}", e))?;
+            let meta_merkle_proof_pda = api_helpers::generate_meta_merkle_proof_pda(
+                &consensus_result_pda,
+                &vote_account_pubkey,
+            )?;
+
+            init_meta_merkle_proof_if_missing(
+                &merkle_proof_program,
+                &payer,
+                consensus_result_pda,
+                meta_merkle_proof_pda,
+                &proof_response,
+            )
+            .await?;
+
+            SnapshotAccounts::new(consensus_result_pda, meta_merkle_proof_pda)
+        };
+
+        let spinner = create_spinner(&format!("Relaying signed vote {}...", file));
+
+        let mut relay_ixs = vec![ed25519_ix];
+        relay_ixs.extend(
+            program
+                .request()
+                .args(args::CastVoteSigned { signed_vote: vote })
+                .accounts(accounts::CastVoteSigned {
+                    payer: payer.pubkey(),
+                    proposal: proposal_pubkey,
+                    vote: vote_pda,
+                    spl_vote_account: vote_account,
+                    validator_profile: derive_validator_profile_pda(&vote_account, &program.id()),
+                    vote_override_cache: vote_override_cache_pda,
+                    snapshot_program: snapshot.snapshot_program,
+                    consensus_result: snapshot.consensus_result,
+                    meta_merkle_proof: snapshot.meta_merkle_proof,
+                    instructions: instructions_sysvar::ID,
+                    system_program: system_program::ID,
+                    event_authority: derive_event_authority_pda(&program.id()),
+                    program: program.id(),
+                })
+                .instructions()?,
+        );
+
+        let blockhash = program.rpc().get_latest_blockhash().await?;
+        let transaction = Transaction::new_signed_with_payer(
+            &relay_ixs,
+            Some(&payer.pubkey()),
+            &[&payer],
+            blockhash,
+        );
+
+        let sig = program
+            .rpc()
+            .send_and_confirm_transaction(&transaction)
+            .await?;
+        log::debug!(
+            "Relay vote transaction sent successfully: signature={}",
+            sig
+        );
+
+        spinner.finish_with_message(format!(
+            "Vote of {} relayed. https://explorer.solana.com/tx/{}",
+            vote_account, sig
+        ));
+    }
+
+    Ok(())
+}
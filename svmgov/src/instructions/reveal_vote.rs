@@ -0,0 +1,113 @@
+use std::str::FromStr;
+
+use anchor_client::solana_sdk::{pubkey::Pubkey, signer::Signer};
+use anyhow::{Result, anyhow};
+
+use crate::{
+    govcontract::client::{accounts, args},
+    utils::{
+        ballots::SecretBallot,
+        utils::{
+            create_spinner, derive_ballot_pda, derive_event_authority_pda,
+            derive_vote_override_pda, derive_vote_pda, setup_all, setup_all_with_staker,
+        },
+    },
+};
+
+pub async fn reveal_vote(
+    proposal_id: String,
+    identity_keypair: Option<String>,
+    rpc_url: Option<String>,
+) -> Result<()> {
+    let proposal_pubkey = Pubkey::from_str(&proposal_id)
+        .map_err(|_| anyhow!("Invalid proposal ID: {}", proposal_id))?;
+
+    let (payer, vote_account, program, _merkle_proof_program) =
+        setup_all(identity_keypair, rpc_url).await?;
+
+    let vote_pda = derive_vote_pda(&proposal_pubkey, &vote_account, &program.id());
+    let ballot = SecretBallot::load(&vote_pda)?;
+
+    let spinner = create_spinner("Revealing secret ballot...");
+
+    let sig = program
+        .request()
+        .args(args::RevealVote {
+            for_votes_bp: ballot.for_votes_bp,
+            against_votes_bp: ballot.against_votes_bp,
+            abstain_votes_bp: ballot.abstain_votes_bp,
+            salt: ballot.salt()?,
+        })
+        .accounts(accounts::RevealVote {
+            signer: payer.pubkey(),
+            proposal: proposal_pubkey,
+            vote: vote_pda,
+            ballot: derive_ballot_pda(&vote_pda, &program.id()),
+            event_authority: derive_event_authority_pda(&program.id()),
+            program: program.id(),
+        })
+        .send()
+        .await?;
+
+    spinner.finish_with_message(format!(
+        "Secret ballot revealed. https://explorer.solana.com/tx/{}",
+        sig
+    ));
+
+    Ok(())
+}
+
+pub async fn reveal_vote_override(
+    proposal_id: String,
+    stake_account: String,
+    vote_account: String,
+    staker_keypair: String,
+    rpc_url: Option<String>,
+) -> Result<()> {
+    let proposal_pubkey = Pubkey::from_str(&proposal_id)
+        .map_err(|_| anyhow!("Invalid proposal ID: {}", proposal_id))?;
+    let stake_account_pubkey = Pubkey::from_str(&stake_account)
+        .map_err(|_| anyhow!("Invalid stake account: {}", stake_account))?;
+    let vote_account_pubkey = Pubkey::from_str(&vote_account)
+        .map_err(|_| anyhow!("Invalid vote account: {}", vote_account))?;
+
+    let (payer, program, _merkle_proof_program) = setup_all_with_staker(staker_keypair, rpc_url)?;
+
+    let validator_vote_pda = derive_vote_pda(&proposal_pubkey, &vote_account_pubkey, &program.id());
+    let vote_override_pda = derive_vote_override_pda(
+        &proposal_pubkey,
+        &stake_account_pubkey,
+        &validator_vote_pda,
+        &program.id(),
+    );
+    let ballot = SecretBallot::load(&vote_override_pda)?;
+
+    let spinner = create_spinner("Revealing secret ballot override...");
+
+    let sig = program
+        .request()
+        .args(args::RevealVoteOverride {
+            for_votes_bp: ballot.for_votes_bp,
+            against_votes_bp: ballot.against_votes_bp,
+            abstain_votes_bp: ballot.abstain_votes_bp,
+            salt: ballot.salt()?,
+        })
+        .accounts(accounts::RevealVoteOverride {
+            signer: payer.pubkey(),
+            proposal: proposal_pubkey,
+            vote_override: vote_override_pda,
+            validator_vote: validator_vote_pda,
+            ballot: derive_ballot_pda(&vote_override_pda, &program.id()),
+            event_authority: derive_event_authority_pda(&program.id()),
+            program: program.id(),
+        })
+        .send()
+        .await?;
+
+    spinner.finish_with_message(format!(
+        "Secret ballot override revealed. https://explorer.solana.com/tx/{}",
+        sig
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use std::str::FromStr;
+
+use anchor_client::solana_sdk::{pubkey::Pubkey, signer::Signer};
+use anyhow::{anyhow, Result};
+
+use crate::{
+    govcontract::{
+        accounts::{Proposal, ProposalBond},
+        client::{accounts, args},
+        types::BondStatus,
+    },
+    utils::utils::{
+        create_spinner, derive_event_authority_pda, derive_proposal_bond_pda, derive_treasury_pda,
+        derive_validator_profile_pda, setup_all,
+    },
+};
+
+pub async fn settle_proposal_bond(
+    proposal_id: String,
+    identity_keypair: Option<String>,
+    rpc_url: Option<String>,
+) -> Result<()> {
+    let proposal_pubkey = Pubkey::from_str(&proposal_id)
+        .map_err(|_| anyhow!("Invalid proposal ID: {}", proposal_id))?;
+
+    let (payer, _vote_account, program, _merkle_proof_program) = setup_all(identity_keypair, rpc_url).await?;
+
+    let proposal_bond_pda = derive_proposal_bond_pda(&proposal_pubkey, &program.id());
+    let bond = program.account::<ProposalBond>(proposal_bond_pda).await?;
+    let proposal = program.account::<Proposal>(proposal_pubkey).await?;
+
+    let spinner = create_spinner("Settling proposal bond...");
+
+    let sig = program
+        .request()
+        .args(args::SettleProposalBond {})
+        .accounts(accounts::SettleProposalBond {
+            signer: payer.pubkey(),
+            proposal: proposal_pubkey,
+            proposal_bond: proposal_bond_pda,
+            author: bond.author,
+            treasury: derive_treasury_pda(&program.id()),
+            author_profile: derive_validator_profile_pda(
+                &proposal.vote_account_pubkey,
+                &program.id(),
+            ),
+            event_authority: derive_event_authority_pda(&program.id()),
+            program: program.id(),
+        })
+        .send()
+        .await?;
+
+    let settled = program.account::<ProposalBond>(proposal_bond_pda).await?;
+    let outcome = match settled.status {
+        BondStatus::Returned => "returned to the author",
+        _ => "forfeited to the treasury",
+    };
+
+    spinner.finish_with_message(format!(
+        "Proposal bond {}. https://explorer.solana.com/tx/{}",
+        outcome, sig
+    ));
+
+    Ok(())
+}
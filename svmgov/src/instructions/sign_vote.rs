@@ -0,0 +1,62 @@
+use std::str::FromStr;
+
+use anchor_client::solana_sdk::pubkey::Pubkey;
+use anyhow::{Result, anyhow};
+
+use crate::{
+    constants::*,
+    govcontract::types::SignedVote,
+    utils::{signed_votes::SignedVoteFile, utils::load_identity_keypair},
+};
+
+/// Signs a vote with the identity keypair without touching the network, so it can be done on
+/// an offline machine and the file handed to a relayer
+pub fn sign_vote(
+    proposal_id: String,
+    votes_for: u64,
+    votes_against: u64,
+    abstain: u64,
+    vote_account: String,
+    identity_keypair: Option<String>,
+    nonce: Option<u64>,
+    expires_in_hours: i64,
+    output: String,
+) -> Result<()> {
+    if votes_for + votes_against + abstain != BASIS_POINTS_TOTAL {
+        return Err(anyhow!(
+            "Total vote basis points must sum to {}",
+            BASIS_POINTS_TOTAL
+        ));
+    }
+    if expires_in_hours <= 0 {
+        return Err(anyhow!("Signed votes must expire in at least one hour"));
+    }
+
+    let proposal = Pubkey::from_str(&proposal_id)
+        .map_err(|_| anyhow!("Invalid proposal ID: {}", proposal_id))?;
+    let vote_account = Pubkey::from_str(&vote_account)
+        .map_err(|_| anyhow!("Invalid vote account: {}", vote_account))?;
+    let voting_wallet = load_identity_keypair(identity_keypair)?;
+
+    let vote = SignedVote {
+        proposal,
+        vote_account,
+        for_votes_bp: votes_for,
+        against_votes_bp: votes_against,
+        abstain_votes_bp: abstain,
+        nonce: nonce.unwrap_or_else(rand::random),
+        expiry: chrono::Utc::now().timestamp() + expires_in_hours * 3600,
+    };
+    let signed = SignedVoteFile::sign(&voting_wallet, &vote);
+    signed.save(&output)?;
+
+    println!(
+        "Signed vote saved to {}; it can be relayed with relay-vote until {}",
+        output,
+        chrono::DateTime::from_timestamp(vote.expiry, 0)
+            .map(|expiry| expiry.to_rfc3339())
+            .unwrap_or_else(|| vote.expiry.to_string())
+    );
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use std::str::FromStr;
+
+use anchor_client::solana_sdk::{pubkey::Pubkey, signer::Signer};
+use anchor_lang::system_program;
+use anyhow::{Result, anyhow};
+
+use crate::{
+    govcontract::{
+        client::{accounts, args},
+        types::SignalLean,
+    },
+    utils::utils::{
+        create_spinner, derive_event_authority_pda, derive_signal_pda, derive_signal_tally_pda,
+        setup_all,
+    },
+};
+
+pub async fn signal_proposal(
+    proposal_id: String,
+    lean: String,
+    identity_keypair: Option<String>,
+    rpc_url: Option<String>,
+) -> Result<()> {
+    let proposal_pubkey = Pubkey::from_str(&proposal_id)
+        .map_err(|_| anyhow!("Invalid proposal ID: {}", proposal_id))?;
+    let lean = match lean.to_lowercase().as_str() {
+        "for" => SignalLean::For,
+        "against" => SignalLean::Against,
+        "undecided" => SignalLean::Undecided,
+        _ => {
+            return Err(anyhow!(
+                "Invalid lean: {} (expected for, against or undecided)",
+                lean
+            ));
+        }
+    };
+
+    let (payer, vote_account, program, _merkle_proof_program) =
+        setup_all(identity_keypair, rpc_url).await?;
+
+    let spinner = create_spinner("Sending signal...");
+
+    let sig = program
+        .request()
+        .args(args::Signal { lean })
+        .accounts(accounts::SignalProposal {
+            signer: payer.pubkey(),
+            proposal: proposal_pubkey,
+            signal: derive_signal_pda(&proposal_pubkey, &vote_account, &program.id()),
+            signal_tally: derive_signal_tally_pda(&proposal_pubkey, &program.id()),
+            spl_vote_account: vote_account,
+            system_program: system_program::ID,
+            event_authority: derive_event_authority_pda(&program.id()),
+            program: program.id(),
+        })
+        .send()
+        .await?;
+
+    spinner.finish_with_message(format!(
+        "Signal recorded. https://explorer.solana.com/tx/{}",
+        sig
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use std::str::FromStr;
+
+use anchor_client::solana_sdk::{pubkey::Pubkey, signer::Signer};
+use anchor_lang::system_program;
+use anyhow::{Result, anyhow};
+
+use crate::{
+    govcontract::client::{accounts, args},
+    utils::utils::{
+        create_spinner, derive_event_authority_pda, derive_stake_proxy_pda, setup_all_with_staker,
+    },
+};
+
+pub async fn register_stake_proxy(
+    stake_account: String,
+    proxy: String,
+    staker_keypair: String,
+    rpc_url: Option<String>,
+) -> Result<()> {
+    let stake_account_pubkey = Pubkey::from_str(&stake_account)
+        .map_err(|_| anyhow!("Invalid stake account: {}", stake_account))?;
+    let proxy_pubkey =
+        Pubkey::from_str(&proxy).map_err(|_| anyhow!("Invalid proxy wallet: {}", proxy))?;
+
+    let (payer, program, _merkle_proof_program) = setup_all_with_staker(staker_keypair, rpc_url)?;
+
+    let spinner = create_spinner("Registering stake proxy...");
+
+    let sig = program
+        .request()
+        .args(args::RegisterStakeProxy {
+            proxy: proxy_pubkey,
+        })
+        .accounts(accounts::RegisterStakeProxy {
+            signer: payer.pubkey(),
+            spl_stake_account: stake_account_pubkey,
+            stake_proxy: derive_stake_proxy_pda(&stake_account_pubkey, &program.id()),
+            system_program: system_program::ID,
+            event_authority: derive_event_authority_pda(&program.id()),
+            program: program.id(),
+        })
+        .send()
+        .await?;
+
+    spinner.finish_with_message(format!(
+        "{} can now override votes for {}. https://explorer.solana.com/tx/{}",
+        proxy_pubkey, stake_account_pubkey, sig
+    ));
+
+    Ok(())
+}
+
+pub async fn revoke_stake_proxy(
+    stake_account: String,
+    staker_keypair: String,
+    rpc_url: Option<String>,
+) -> Result<()> {
+    let stake_account_pubkey = Pubkey::from_str(&stake_account)
+        .map_err(|_| anyhow!("Invalid stake account: {}", stake_account))?;
+
+    let (payer, program, _merkle_proof_program) = setup_all_with_staker(staker_keypair, rpc_url)?;
+
+    let spinner = create_spinner("Revoking stake proxy...");
+
+    let sig = program
+        .request()
+        .args(args::RevokeStakeProxy {})
+        .accounts(accounts::RevokeStakeProxy {
+            signer: payer.pubkey(),
+            spl_stake_account: stake_account_pubkey,
+            stake_proxy: derive_stake_proxy_pda(&stake_account_pubkey, &program.id()),
+            event_authority: derive_event_authority_pda(&program.id()),
+            program: program.id(),
+        })
+        .send()
+        .await?;
+
+    spinner.finish_with_message(format!(
+        "Stake proxy revoked. https://explorer.solana.com/tx/{}",
+        sig
+    ));
+
+    Ok(())
+}
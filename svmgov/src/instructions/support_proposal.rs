@@ -1,16 +1,22 @@
-use std::str::FromStr;
+use std::{str::FromStr, sync::Arc};
 
-use anchor_client::solana_sdk::{pubkey::Pubkey, signer::Signer, transaction::Transaction};
+use anchor_client::{
+    Program,
+    solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer, transaction::Transaction},
+};
 use anchor_lang::system_program;
 use anyhow::{Result, anyhow};
 use gov_v1::ID as SNAPSHOT_PROGRAM_ID;
 
 use crate::{
-    constants::{DISCUSSION_EPOCHS, SNAPSHOT_EPOCH_EXTENSION},
-    govcontract::client::{accounts, args},
+    constants::SNAPSHOT_EPOCH_EXTENSION,
+    govcontract::{
+        accounts::Proposal,
+        client::{accounts, args},
+    },
     utils::utils::{
-        create_spinner, derive_program_config_pda, derive_support_pda, get_epoch_slot_range,
-        setup_all,
+        create_spinner, derive_event_authority_pda, derive_program_config_pda, derive_support_pda,
+        derive_validator_profile_pda, get_epoch_slot_range, is_epoch_stake, setup_all,
     },
 };
 
@@ -30,19 +36,26 @@ pub async fn support_proposal(
 
     let spinner = create_spinner("Supporting proposal...");
 
-    let clock = program.rpc().get_epoch_info().await?;
-    let target_epoch = clock.epoch + DISCUSSION_EPOCHS + SNAPSHOT_EPOCH_EXTENSION;
+    let proposal = program.account::<Proposal>(proposal_pubkey).await?;
 
-    let (start_slot, _) = get_epoch_slot_range(target_epoch);
-    let snapshot_slot = start_slot + 1000;
+    // A random snapshot slot is only drawn during the snapshot epoch, so its ballot box is
+    // created by draw-snapshot-slot. Epoch stake proposals have no ballot box.
+    let epoch_stake = is_epoch_stake(&proposal);
+    let ballot_box_pda = if epoch_stake {
+        None
+    } else if proposal.random_snapshot == 0 {
+        let clock = program.rpc().get_epoch_info().await?;
+        // Discussion length is fixed on the proposal at creation
+        let target_epoch = clock.epoch + proposal.discussion_epochs + SNAPSHOT_EPOCH_EXTENSION;
 
-    let ballot_box_pda = {
-        let seeds = &[b"BallotBox".as_ref(), &snapshot_slot.to_le_bytes()];
-        let (pda, _) = Pubkey::find_program_address(seeds, &SNAPSHOT_PROGRAM_ID);
-        pda
+        let (start_slot, _) = get_epoch_slot_range(target_epoch);
+        Some(derive_ballot_box_pda(start_slot + 1000))
+    } else {
+        None
     };
 
-    let program_config_pda = derive_program_config_pda(&SNAPSHOT_PROGRAM_ID);
+    let program_config_pda =
+        (!epoch_stake).then(|| derive_program_config_pda(&SNAPSHOT_PROGRAM_ID));
 
     let support_proposal_ixs = program
         .request()
@@ -52,10 +65,13 @@ pub async fn support_proposal(
             proposal: proposal_pubkey,
             support: support_pda,
             spl_vote_account: vote_account,
+            validator_profile: derive_validator_profile_pda(&vote_account, &program.id()),
             ballot_box: ballot_box_pda,
             program_config: program_config_pda,
             ballot_program: SNAPSHOT_PROGRAM_ID,
             system_program: system_program::ID,
+            event_authority: derive_event_authority_pda(&program.id()),
+            program: program.id(),
         })
         .instructions()?;
 
@@ -77,5 +93,64 @@ pub async fn support_proposal(
         sig
     ));
 
+    if proposal.random_snapshot != 0 {
+        let proposal = program.account::<Proposal>(proposal_pubkey).await?;
+        if proposal.voting != 0 {
+            println!(
+                "Voting activated. Run draw-snapshot-slot during epoch {} to draw the snapshot slot.",
+                proposal.start_epoch - 1
+            );
+        }
+    }
+
     Ok(())
 }
+
+/// Creates the ballot box for a drawn snapshot slot, unless someone already did
+pub(crate) async fn init_ballot_box(
+    program: &Program<Arc<Keypair>>,
+    payer: &Arc<Keypair>,
+    proposal_pubkey: Pubkey,
+    snapshot_slot: u64,
+) -> Result<()> {
+    let ballot_box_pda = derive_ballot_box_pda(snapshot_slot);
+    let existing = program
+        .rpc()
+        .get_multiple_accounts(&[ballot_box_pda])
+        .await?;
+    if existing[0].is_some() {
+        return Ok(());
+    }
+
+    let spinner = create_spinner(&format!(
+        "Creating ballot box for snapshot slot {}...",
+        snapshot_slot
+    ));
+
+    let sig = program
+        .request()
+        .args(args::InitBallotBox {})
+        .accounts(accounts::InitBallotBox {
+            signer: payer.pubkey(),
+            proposal: proposal_pubkey,
+            ballot_box: ballot_box_pda,
+            ballot_program: SNAPSHOT_PROGRAM_ID,
+            program_config: derive_program_config_pda(&SNAPSHOT_PROGRAM_ID),
+            system_program: system_program::ID,
+        })
+        .send()
+        .await?;
+
+    spinner.finish_with_message(format!(
+        "Ballot box created for snapshot slot {}. https://explorer.solana.com/tx/{}",
+        snapshot_slot, sig
+    ));
+
+    Ok(())
+}
+
+fn derive_ballot_box_pda(snapshot_slot: u64) -> Pubkey {
+    let seeds = &[b"BallotBox".as_ref(), &snapshot_slot.to_le_bytes()];
+    let (pda, _) = Pubkey::find_program_address(seeds, &SNAPSHOT_PROGRAM_ID);
+    pda
+}
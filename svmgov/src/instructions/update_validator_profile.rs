@@ -0,0 +1,55 @@
+use anchor_client::solana_sdk::{signer::Signer, system_program};
+use anyhow::{Result, anyhow};
+
+use crate::{
+    govcontract::client::{accounts, args},
+    utils::utils::{
+        create_spinner, derive_event_authority_pda, derive_validator_profile_pda, setup_all,
+    },
+};
+
+pub async fn update_validator_profile(
+    display_name: Option<String>,
+    website: Option<String>,
+    governance_policy_url: Option<String>,
+    identity_keypair: Option<String>,
+    rpc_url: Option<String>,
+) -> Result<()> {
+    if display_name.is_none() && website.is_none() && governance_policy_url.is_none() {
+        return Err(anyhow!(
+            "Nothing to update: pass --display-name, --website or --governance-policy-url"
+        ));
+    }
+
+    let (payer, vote_account, program, _merkle_proof_program) =
+        setup_all(identity_keypair, rpc_url).await?;
+
+    let validator_profile = derive_validator_profile_pda(&vote_account, &program.id());
+
+    let spinner = create_spinner("Updating validator profile...");
+
+    let sig = program
+        .request()
+        .args(args::UpdateValidatorProfile {
+            display_name,
+            website,
+            governance_policy_url,
+        })
+        .accounts(accounts::UpdateValidatorProfile {
+            signer: payer.pubkey(),
+            spl_vote_account: vote_account,
+            validator_profile,
+            system_program: system_program::ID,
+            event_authority: derive_event_authority_pda(&program.id()),
+            program: program.id(),
+        })
+        .send()
+        .await?;
+
+    spinner.finish_with_message(format!(
+        "Validator profile updated. https://explorer.solana.com/tx/{}",
+        sig
+    ));
+
+    Ok(())
+}
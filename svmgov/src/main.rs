@@ -9,6 +9,9 @@ use clap::{Parser, Subcommand};
 
 use config::Config;
 use constants::*;
+use govcontract::types::{
+    AntiSnipingConfig, ProposalClass, SecretBallotConfig, StakeSource, UnrevealedBallots,
+};
 use utils::{
     commands,
     config_command::{ConfigSubcommand, handle_config_command},
@@ -67,9 +70,23 @@ enum Commands {
         long_about = "This command creates a new governance proposal with the help of the Solana Validator Governance program. \
                       It requires a title and a GitHub link for the proposal description, and optionally a unique seed to derive the proposal's address (PDA). \
                       The identity keypair is required to sign the transaction, and an optional RPC URL can be provided to connect to the chain.\n\n\
+                      The anti-sniping flags, given together, extend voting when a late vote flips the result (another option takes the lead, or the proposal starts or stops passing) within the window before voting ends.\n\n\
+                      --reveal-epochs makes the proposal a secret ballot: votes are committed while voting is open and revealed during the given epochs after it ends. \
+                      --unrevealed sets whether ballots never revealed count as abstain (the default) or are excluded from the tally.\n\n\
+                      --random-snapshot draws the snapshot slot from the SlotHashes sysvar once the snapshot epoch has started (see draw-snapshot-slot), instead of fixing it 1000 slots into the snapshot epoch.\n\n\
+                      --epoch-stake counts votes with the stake delegated to each vote account in the epoch before voting, read from the runtime, \
+                      instead of a gov-v1 snapshot. Validators must vote in the first voting epoch and delegators cannot override. \
+                      It is meant for clusters where gov-v1 is not deployed and cannot be combined with --random-snapshot.\n\n\
+                      --emergency fast-tracks the proposal for urgent changes such as a feature-gate rollback: it needs 15% of cluster stake in support instead of 5%, \
+                      skips discussion so the snapshot is taken the epoch after support, and votes for a single epoch.\n\n\
+                      --supersedes and --depends-on link the proposal to earlier ones, such as the previous revision of a SIMD. \
+                      Only the author of a proposal can supersede it. If a superseding proposal passes, the proposal it supersedes is marked as superseded \
+                      and can no longer pass or be executed.\n\n\
                       Examples:\n\
                       $ svmgov --identity-keypair /path/to/key.json create-proposal --title \"New Governance Rule\" --description \"https://github.com/repo/proposal\"\n\
-                      $ svmgov --identity-keypair /path/to/key.json --rpc-url https://api.mainnet-beta.solana.com create-proposal --seed 42 --title \"New Governance Rule\" --description \"https://github.com/repo/proposal\""
+                      $ svmgov --identity-keypair /path/to/key.json --rpc-url https://api.mainnet-beta.solana.com create-proposal --seed 42 --title \"New Governance Rule\" --description \"https://github.com/repo/proposal\"\n\
+                      $ svmgov --identity-keypair /path/to/key.json create-proposal --title \"New Governance Rule\" --description \"https://github.com/repo/proposal\" --anti-snipe-window-slots 21600 --anti-snipe-extension-epochs 1 --anti-snipe-max-extensions 2\n\
+                      $ svmgov --identity-keypair /path/to/key.json create-proposal --title \"New Governance Rule\" --description \"https://github.com/repo/proposal\" --reveal-epochs 1 --unrevealed exclude"
     )]
     CreateProposal {
         /// Optional unique seed for the proposal (used to derive the PDA).
@@ -87,6 +104,77 @@ enum Commands {
         /// Network for fetching merkle proofs
         #[arg(long, help = "Network for fetching merkle proofs")]
         network: String,
+
+        /// Slots before the end of voting in which a result-flipping vote extends voting.
+        #[arg(
+            long,
+            requires_all = ["anti_snipe_extension_epochs", "anti_snipe_max_extensions"],
+            help = "Anti-sniping window in slots before the end of voting (optional)"
+        )]
+        anti_snipe_window_slots: Option<u64>,
+
+        /// Epochs added to the voting period per extension.
+        #[arg(
+            long,
+            requires = "anti_snipe_window_slots",
+            help = "Epochs added to voting per anti-sniping extension"
+        )]
+        anti_snipe_extension_epochs: Option<u64>,
+
+        /// Maximum number of anti-sniping extensions.
+        #[arg(
+            long,
+            requires = "anti_snipe_window_slots",
+            help = "Maximum number of anti-sniping extensions"
+        )]
+        anti_snipe_max_extensions: Option<u8>,
+
+        /// Epochs after voting ends in which secret ballots are revealed.
+        #[arg(
+            long,
+            conflicts_with = "anti_snipe_window_slots",
+            help = "Make the proposal a secret ballot revealed over this many epochs (optional)"
+        )]
+        reveal_epochs: Option<u64>,
+
+        /// What an unrevealed secret ballot counts as.
+        #[arg(
+            long,
+            requires = "reveal_epochs",
+            default_value = "abstain",
+            help = "Unrevealed secret ballots count as: abstain or exclude"
+        )]
+        unrevealed: String,
+
+        /// Draw the snapshot slot from SlotHashes when voting activates.
+        #[arg(
+            long,
+            help = "Draw the snapshot slot pseudo-randomly within the snapshot epoch"
+        )]
+        random_snapshot: bool,
+
+        /// Read stake from the runtime's epoch stakes in the first voting epoch.
+        #[arg(
+            long,
+            conflicts_with = "random_snapshot",
+            help = "Count validator votes with epoch stake instead of a gov-v1 snapshot"
+        )]
+        epoch_stake: bool,
+
+        /// Create the proposal on the emergency fast track.
+        #[arg(
+            long,
+            help = "Fast-track the proposal: 15% support, no discussion, one voting epoch"
+        )]
+        emergency: bool,
+
+        /// Earlier proposal this one replaces.
+        #[arg(long, help = "Proposal ID this proposal supersedes (optional)")]
+        supersedes: Option<String>,
+
+        /// Earlier proposal this one builds on.
+        #[arg(long, help = "Proposal ID this proposal depends on (optional)")]
+        depends_on: Option<String>,
     },
 
     #[command(
@@ -106,10 +194,44 @@ enum Commands {
         network: String,
     },
 
+    #[command(
+        about = "Draw the snapshot slot of a random snapshot proposal",
+        long_about = "This command draws the snapshot slot of a proposal created with --random-snapshot and creates its ballot box. \
+                      The slot is drawn from the SlotHashes sysvar among the slots left in the snapshot epoch, the epoch before voting starts, \
+                      so it can only be run during that epoch. Anyone can run it; running it again after the draw only creates a missing ballot box. \
+                      It requires the proposal ID and the identity keypair to interact with the chain. \
+                      An optional RPC URL can be provided to connect to the chain.\n\n\
+                      Example:\n\
+                      $ svmgov --identity-keypair /path/to/key.json --rpc-url https://api.mainnet-beta.solana.com draw-snapshot-slot --proposal-id \"123\""
+    )]
+    DrawSnapshotSlot {
+        #[arg(long, help = "Proposal ID")]
+        proposal_id: String,
+    },
+
+    #[command(
+        about = "Signal a non-binding lean on a proposal during discussion",
+        long_about = "This command records a stake-weighted temperature check on a proposal between voting activation and the snapshot. \
+                      Signals are tallied separately from votes and do not affect the outcome; signalling again replaces the previous signal. \
+                      It requires the proposal ID, the lean (for, against or undecided) and the validator's identity keypair.\n\n\
+                      Example:\n\
+                      $ svmgov --identity-keypair /path/to/key.json signal --proposal-id \"123\" --lean for"
+    )]
+    Signal {
+        /// Proposal ID to signal on (proposal Pubkey).
+        #[arg(long, help = "Proposal ID")]
+        proposal_id: String,
+
+        /// Lean to record: for, against or undecided
+        #[arg(long, help = "Lean: for, against or undecided")]
+        lean: String,
+    },
+
     #[command(
         about = "Cast a vote on a proposal",
         long_about = "This command casts a vote on a live governance proposal. \
                       Voters specify how to allocate their stake weight across 'For', 'Against', and 'Abstain' using basis points, which must sum to 10,000 (representing 100% of their stake). \
+                      On a secret ballot proposal the split is committed instead of cast, and saved under ~/.svmgov/ballots for reveal-vote. \
                       It requires the proposal ID and the identity keypair to sign the vote. An optional RPC URL can be provided to connect to the chain.\n\n\
                       Example:\n\
                       $ svmgov --identity-keypair /path/to/key.json --rpc-url https://api.mainnet-beta.solana.com cast-vote --proposal-id 123 --for-votes 6000 --against-votes 3000 --abstain-votes 1000"
@@ -145,7 +267,8 @@ enum Commands {
         about = "Modify an existing vote on a proposal",
         long_about = "This command modifies an existing vote on a live governance proposal. \
                       Voters can update how they allocate their stake weight across 'For', 'Against', and 'Abstain' using basis points, which must sum to 10,000 (representing 100% of their stake). \
-                      It requires the proposal ID and the identity keypair to sign the modification. An optional RPC URL can be provided to connect to the chain.\n\n\
+                      It requires the proposal ID and the identity keypair to sign the modification. An optional RPC URL can be provided to connect to the chain. \
+                      Validators that delegated their vote to you keep your previous split until cast-delegated-vote is run for each of them again.\n\n\
                       Example:\n\
                       $ svmgov --identity-keypair /path/to/key.json --rpc-url https://api.mainnet-beta.solana.com modify-vote --proposal-id 123 --for-votes 7000 --against-votes 2000 --abstain-votes 1000"
     )]
@@ -171,6 +294,145 @@ enum Commands {
         network: String,
     },
 
+    #[command(
+        about = "Reveal your secret ballot vote on a proposal",
+        long_about = "This command reveals the split committed by cast-vote on a secret ballot proposal, so it counts in the tally. \
+                      It must run after voting ends and before the reveal period closes, on the machine that cast the vote: the split and salt are read from ~/.svmgov/ballots. \
+                      It requires the proposal ID and the validator's identity keypair.\n\n\
+                      Example:\n\
+                      $ svmgov --identity-keypair /path/to/key.json reveal-vote --proposal-id \"123\""
+    )]
+    RevealVote {
+        /// Proposal ID the ballot was cast on
+        #[arg(long, help = "Proposal ID")]
+        proposal_id: String,
+    },
+
+    #[command(
+        about = "Sign a vote offline for someone else to relay",
+        long_about = "This command signs a vote with the identity keypair, which must be the vote account's voting wallet, and writes it to a file without connecting to the chain. \
+                      The file can be carried off an offline or cold machine and submitted with relay-vote by anyone, who pays the fees but cannot change the vote. \
+                      A signed vote can be relayed once, until it expires, and only on proposals without secret ballots.\n\n\
+                      Example:\n\
+                      $ svmgov --identity-keypair /path/to/key.json sign-vote --proposal-id \"123\" --vote-account <VOTE_PUBKEY> --for-votes 6000 --against-votes 3000 --abstain-votes 1000 --output vote.json"
+    )]
+    SignVote {
+        /// Proposal ID the vote is for
+        #[arg(long, help = "Proposal ID")]
+        proposal_id: String,
+
+        /// Vote account pubkey for the validator
+        #[arg(long, help = "Vote account pubkey (base58) for the validator")]
+        vote_account: String,
+
+        /// Basis points for 'For' vote.
+        #[arg(long, help = "Basis points for 'For'")]
+        for_votes: u64,
+
+        /// Basis points for 'Against' vote.
+        #[arg(long, help = "Basis points for 'Against'")]
+        against_votes: u64,
+
+        /// Basis points for 'Abstain' vote.
+        #[arg(long, help = "Basis points for 'Abstain'")]
+        abstain_votes: u64,
+
+        /// Nonce making the signed message unique
+        #[arg(long, help = "Nonce for the signed vote (random if omitted)")]
+        nonce: Option<u64>,
+
+        /// Hours until the signed vote can no longer be relayed
+        #[arg(
+            long,
+            default_value_t = DEFAULT_SIGNED_VOTE_EXPIRY_HOURS,
+            help = "Hours until the signed vote expires"
+        )]
+        expires_in_hours: i64,
+
+        /// File to write the signed vote to
+        #[arg(long, help = "Path of the signed vote file to write")]
+        output: String,
+    },
+
+    #[command(
+        about = "Submit votes signed with sign-vote",
+        long_about = "This command submits one or more signed vote files written by sign-vote. \
+                      The identity keypair only pays the fees and the vote account rent; it does not need to belong to the voting validators. \
+                      On snapshot proposals the vote account's meta merkle proof is initialized first if missing, as with cast-vote.\n\n\
+                      Example:\n\
+                      $ svmgov --identity-keypair /path/to/relayer.json relay-vote --files vote1.json,vote2.json --network mainnet"
+    )]
+    RelayVote {
+        /// Signed vote files to submit
+        #[arg(
+            long,
+            value_delimiter = ',',
+            required = true,
+            help = "Comma-separated signed vote files"
+        )]
+        files: Vec<String>,
+
+        /// Network for fetching merkle proofs
+        #[arg(long, help = "Network for fetching merkle proofs")]
+        network: String,
+    },
+
+    #[command(
+        about = "Delegate your governance vote to another validator",
+        long_about = "This command lets another validator's votes count for your snapshot stake. \
+                      Without --proposal-id the delegation stands for every proposal; with it, it applies to that proposal only and takes precedence over a standing delegation. \
+                      Voting directly with modify-vote, or your stakers overriding, still takes precedence. \
+                      Your vote follows the delegate's split only when cast-delegated-vote is run for it: if the delegate later modifies its vote, \
+                      your vote keeps the old split until cast-delegated-vote is run again before voting ends. \
+                      It requires the identity keypair of the delegating validator.\n\n\
+                      Example:\n\
+                      $ svmgov --identity-keypair /path/to/key.json delegate --to \"DelegateVoteAccount111...\""
+    )]
+    Delegate {
+        /// Vote account of the validator to follow
+        #[arg(long, help = "Vote account of the validator to follow")]
+        to: String,
+
+        /// Limit the delegation to one proposal
+        #[arg(long, help = "Proposal ID, omit for a standing delegation")]
+        proposal_id: Option<String>,
+    },
+
+    #[command(
+        about = "Remove a governance delegation",
+        long_about = "This command removes your standing delegation, or the delegation for one proposal when --proposal-id is given. \
+                      Delegated votes already cast stay with the delegate's split until you vote directly with modify-vote.\n\n\
+                      Example:\n\
+                      $ svmgov --identity-keypair /path/to/key.json undelegate"
+    )]
+    Undelegate {
+        /// Delegation for one proposal to remove
+        #[arg(long, help = "Proposal ID, omit for the standing delegation")]
+        proposal_id: Option<String>,
+    },
+
+    #[command(
+        about = "Cast a delegating validator's vote with its delegate's split",
+        long_about = "This command casts, or refreshes, the vote of a validator that delegated its governance vote, using the current split of its delegate. \
+                      Anyone can run it once the delegate has voted; run it again after the delegate modifies its vote. \
+                      It requires the proposal ID and the delegator's vote account.\n\n\
+                      Example:\n\
+                      $ svmgov --identity-keypair /path/to/key.json cast-delegated-vote --proposal-id \"123\" --delegator \"VoteAccount111...\" --network mainnet"
+    )]
+    CastDelegatedVote {
+        /// Proposal ID (proposal Pubkey).
+        #[arg(long, help = "Proposal ID")]
+        proposal_id: String,
+
+        /// Vote account of the delegating validator
+        #[arg(long, help = "Vote account of the delegating validator")]
+        delegator: String,
+
+        /// Network for fetching merkle proofs
+        #[arg(long, help = "Network for fetching merkle proofs")]
+        network: String,
+    },
+
     #[command(
         about = "Finalize a proposal after voting period has ended",
         long_about = "This command sends a transaction to finalize a governance proposal after its voting period has ended. \
@@ -186,6 +448,59 @@ enum Commands {
         proposal_id: String,
     },
 
+    #[command(
+        about = "Cancel a proposal before voting starts",
+        long_about = "This command withdraws a proposal its author no longer wants to put to a vote. \
+                      Only the author can cancel, and only until the proposal reaches voting. \
+                      The proposal's bond is returned if nobody has supported it yet and forfeited to the governance treasury otherwise, and its open proposal slot is freed. \
+                      It requires the proposal ID and the author's identity keypair to sign the transaction. \
+                      An optional RPC URL can be provided to connect to the chain.\n\n\
+                      Example:\n\
+                      $ svmgov --identity-keypair /path/to/key.json --rpc-url https://api.mainnet-beta.solana.com cancel-proposal --proposal-id \"123\""
+    )]
+    CancelProposal {
+        /// Proposal ID to cancel.
+        #[arg(long, help = "Proposal ID")]
+        proposal_id: String,
+    },
+
+    #[command(
+        about = "Settle a proposal's bond",
+        long_about = "This command settles the SOL bond locked when a proposal was created. \
+                      Anyone can settle it: the bond is returned to the author once the proposal reaches voting, \
+                      and forfeited to the governance treasury if the support period ends without enough support. \
+                      It requires the proposal ID and the identity keypair to interact with the chain. \
+                      An optional RPC URL can be provided to connect to the chain.\n\n\
+                      Example:\n\
+                      $ svmgov --identity-keypair /path/to/key.json --rpc-url https://api.mainnet-beta.solana.com settle-bond --proposal-id \"123\""
+    )]
+    SettleBond {
+        /// Proposal ID whose bond to settle.
+        #[arg(long, help = "Proposal ID")]
+        proposal_id: String,
+    },
+
+    #[command(
+        about = "Close the meta merkle proofs a wallet paid for",
+        long_about = "This command closes the snapshot program's meta merkle proof accounts created when voting, once their proposal is finalized, \
+                      and refunds their rent to the wallet that created them. \
+                      Proposals activated in the same epoch share a snapshot, so proofs are kept until 16 epochs after the snapshot epoch, \
+                      when none of them can still be voting or revealing. \
+                      It uses the identity keypair, or the staker keypair that paid for vote override proofs. \
+                      Finding the proofs requires an RPC URL that allows getProgramAccounts.\n\n\
+                      Examples:\n\
+                      $ svmgov --identity-keypair /path/to/key.json close-proofs\n\
+                      $ svmgov close-proofs --staker-keypair /path/to/staker.json"
+    )]
+    CloseProofs {
+        /// Staker keypair that paid for vote override proofs
+        #[arg(
+            long,
+            help = "Staker keypair that paid for the proofs. If omitted, the identity keypair is used."
+        )]
+        staker_keypair: Option<String>,
+    },
+
     #[command(
         about = "Display a proposal and its details",
         long_about = "This command retrieves and displays a governance proposal and its details from the Solana Validator Governance program. \
@@ -198,6 +513,72 @@ enum Commands {
         proposal_id: String,
     },
 
+    #[command(
+        about = "List all proposals from the on-chain registry",
+        long_about = "This command lists every proposal recorded in the program's proposal registry, with its index and status. \
+                      It only reads the proposal index and registry pages, so it works with RPC providers that restrict getProgramAccounts. \
+                      An optional RPC URL can be provided to connect to the chain; otherwise, a default URL is used.\n\n\
+                      Example:\n\
+                      $ svmgov --rpc-url https://api.mainnet-beta.solana.com list-proposals"
+    )]
+    ListProposals {},
+
+    #[command(
+        about = "Display a validator's governance profile",
+        long_about = "This command shows the governance profile of a vote account: the metadata its validator published \
+                      and how many proposals it authored, supported and voted on. \
+                      An optional RPC URL can be provided to connect to the chain; otherwise, a default URL is used.\n\n\
+                      Example:\n\
+                      $ svmgov --rpc-url https://api.mainnet-beta.solana.com validator \"VoteAccount111...\""
+    )]
+    Validator {
+        /// Vote account whose profile to display
+        vote_account: String,
+    },
+
+    #[command(
+        about = "Publish governance metadata for your validator",
+        long_about = "This command sets the display name, website and governance policy URL shown on your validator's governance profile. \
+                      Only the given fields are changed; pass an empty string to clear one. \
+                      It requires the identity keypair of the validator.\n\n\
+                      Example:\n\
+                      $ svmgov --identity-keypair /path/to/key.json update-profile --display-name \"My Validator\" --website \"https://example.com\""
+    )]
+    UpdateProfile {
+        /// Display name, up to 64 bytes
+        #[arg(long, help = "Display name")]
+        display_name: Option<String>,
+
+        /// Website URL, up to 128 bytes
+        #[arg(long, help = "Website URL")]
+        website: Option<String>,
+
+        /// Governance policy URL, up to 128 bytes
+        #[arg(long, help = "URL of the validator's governance policy")]
+        governance_policy_url: Option<String>,
+    },
+
+    #[command(
+        about = "Show the governance treasury",
+        long_about = "This command shows the SOL balance of the governance treasury PDA and the treasury transfers attached to proposals \
+                      that have not been executed yet. Funds only leave the treasury through such a transfer once its proposal has passed. \
+                      An optional RPC URL can be provided to connect to the chain; otherwise, a default URL is used.\n\n\
+                      Example:\n\
+                      $ svmgov --rpc-url https://api.mainnet-beta.solana.com treasury"
+    )]
+    Treasury {},
+
+    #[command(
+        about = "Show the governance rules",
+        long_about = "This command shows the rules new proposals are created with: support and pass thresholds, discussion and voting \
+                      epochs, proposer stake, bond and open proposal limit, along with parameter changes attached to proposals that \
+                      have not been enacted yet. A passed proposal's changes can be enacted by anyone; proposals already in flight keep their rules. \
+                      An optional RPC URL can be provided to connect to the chain; otherwise, a default URL is used.\n\n\
+                      Example:\n\
+                      $ svmgov --rpc-url https://api.mainnet-beta.solana.com governance-config"
+    )]
+    GovernanceConfig {},
+
     #[command(
         about = "Initialize the proposal index pda",
         long_about = "This command allows anyone to initialize the proposal index pda which will follow proposal creation \
@@ -207,13 +588,22 @@ enum Commands {
     )]
     InitIndex {},
 
+    #[command(
+        about = "Initialize the governance config pda",
+        long_about = "This command allows anyone to initialize the governance config pda with the default governance rules. \
+                      Proposals cannot be created until it exists. An optional RPC URL can be provided to connect to the chain.\n\n\
+                      Example:\n\
+                      $ svmgov --identity-keypair /path/to/key.json --rpc-url https://api.mainnet-beta.solana.com init-config"
+    )]
+    InitConfig {},
+
     #[command(
         about = "Override validator vote with delegator vote",
         long_about = "This command allows a delegator to override their validator's vote on a proposal. \
                       The CLI fetches snapshot data from the operator API and submits the override. \
                       Requires the proposal ID and a stake account delegated by the signer. You may explicitly pass a stake \
                       account using --stake-account <PUBKEY> (base58). If omitted, the CLI selects the first stake account \
-                      from the voter summary.\n\n\
+                      from the voter summary. On a secret ballot proposal the split is committed instead of cast, and saved under ~/.svmgov/ballots for reveal-vote-override.\n\n\
                       Examples:\n\
                       # Auto-select first stake account from summary\n\
                       $ svmgov --identity-keypair /path/to/key.json cast-vote-override --proposal-id \"123\" --for-votes 6000 --against-votes 3000 --abstain-votes 1000\n\
@@ -266,6 +656,62 @@ enum Commands {
         vote_account: String,
     },
 
+    #[command(
+        about = "Override validator vote for many stake accounts at once",
+        long_about = "This command casts the same vote override for several stake accounts delegated to one validator. \
+                      Stake accounts are packed into as few transactions as possible, each holding as many accounts as fit in a single packet. \
+                      Every stake account must be delegated to the given vote account and not have overridden on this proposal yet.\n\n\
+                      Example:\n\
+                      $ svmgov cast-vote-override-batch --proposal-id \"123\" --for-votes 6000 --against-votes 3000 --abstain-votes 1000 --stake-accounts <STAKE_1>,<STAKE_2>,<STAKE_3> --vote-account <VOTE_PUBKEY> --staker-keypair /path/to/staker.json --network mainnet"
+    )]
+    CastVoteOverrideBatch {
+        /// Proposal ID for which to override the vote
+        #[arg(long, help = "Proposal ID")]
+        proposal_id: String,
+
+        /// Basis points for 'For' vote
+        #[arg(
+            long,
+            help = "Basis points for 'For' (must sum to 10,000 with other votes)"
+        )]
+        for_votes: u64,
+
+        /// Basis points for 'Against' vote
+        #[arg(
+            long,
+            help = "Basis points for 'Against' (must sum to 10,000 with other votes)"
+        )]
+        against_votes: u64,
+
+        /// Basis points for 'Abstain' vote
+        #[arg(
+            long,
+            help = "Basis points for 'Abstain' (must sum to 10,000 with other votes)"
+        )]
+        abstain_votes: u64,
+
+        /// Stake accounts to override with
+        #[arg(
+            long,
+            value_delimiter = ',',
+            required = true,
+            help = "Comma-separated stake accounts (base58 pubkeys) delegated to the vote account"
+        )]
+        stake_accounts: Vec<String>,
+
+        /// Network for fetching merkle proofs
+        #[arg(long, help = "Network for fetching merkle proofs")]
+        network: String,
+
+        /// Staker keypair for signing the transactions
+        #[arg(long, help = "Staker keypair for signing the transactions")]
+        staker_keypair: String,
+
+        /// Vote account pubkey for the validator
+        #[arg(long, help = "Vote account pubkey (base58) for the validator")]
+        vote_account: String,
+    },
+
     #[command(
         about = "Modify an existing vote override on a proposal",
         long_about = "This command allows a delegator to modify their existing vote override on a proposal. \
@@ -325,6 +771,70 @@ enum Commands {
         vote_account: String,
     },
 
+    #[command(
+        about = "Reveal your secret ballot vote override on a proposal",
+        long_about = "This command reveals the split committed by cast-vote-override on a secret ballot proposal, so it counts in the tally. \
+                      It must run after voting ends and before the reveal period closes, on the machine that cast the override: the split and salt are read from ~/.svmgov/ballots.\n\n\
+                      Example:\n\
+                      $ svmgov reveal-vote-override --proposal-id \"123\" --stake-account \"StakeAccount111...\" --vote-account \"VoteAccount111...\" --staker-keypair /path/to/key.json"
+    )]
+    RevealVoteOverride {
+        /// Proposal ID the override was cast on
+        #[arg(long, help = "Proposal ID")]
+        proposal_id: String,
+
+        /// Stake account the override was cast for
+        #[arg(long, help = "Stake account pubkey (base58)")]
+        stake_account: String,
+
+        /// Vote account pubkey for the validator
+        #[arg(long, help = "Vote account pubkey (base58) for the validator")]
+        vote_account: String,
+
+        /// Staker keypair for signing the transaction
+        #[arg(long, help = "Staker keypair for signing the transaction")]
+        staker_keypair: String,
+    },
+
+    #[command(
+        about = "Let another wallet override votes for your stake account",
+        long_about = "This command registers a proxy wallet, such as a custodian or a hot wallet, that may cast and modify vote overrides for a stake account. \
+                      The stake account's withdraw authority must sign. Registering again replaces the proxy. \
+                      The proxy then runs cast-vote-override, modify-vote-override or cast-vote-override-batch with its own keypair as --staker-keypair.\n\n\
+                      Example:\n\
+                      $ svmgov register-proxy --stake-account \"StakeAccount111...\" --proxy \"ProxyWallet111...\" --staker-keypair /path/to/withdrawer.json"
+    )]
+    RegisterProxy {
+        /// Stake account the proxy may override for
+        #[arg(long, help = "Stake account pubkey (base58)")]
+        stake_account: String,
+
+        /// Wallet allowed to override for the stake account
+        #[arg(long, help = "Proxy wallet pubkey (base58)")]
+        proxy: String,
+
+        /// Withdraw authority of the stake account
+        #[arg(long, help = "Withdraw authority keypair of the stake account")]
+        staker_keypair: String,
+    },
+
+    #[command(
+        about = "Revoke the proxy of your stake account",
+        long_about = "This command removes the proxy registered for a stake account. \
+                      Overrides the proxy already cast stay in place until you modify them.\n\n\
+                      Example:\n\
+                      $ svmgov revoke-proxy --stake-account \"StakeAccount111...\" --staker-keypair /path/to/withdrawer.json"
+    )]
+    RevokeProxy {
+        /// Stake account whose proxy to revoke
+        #[arg(long, help = "Stake account pubkey (base58)")]
+        stake_account: String,
+
+        /// Withdraw authority of the stake account
+        #[arg(long, help = "Withdraw authority keypair of the stake account")]
+        staker_keypair: String,
+    },
+
     #[command(
         about = "Initialize the CLI configuration",
         long_about = "This command sets up the initial configuration for svmgov CLI. \
@@ -391,11 +901,69 @@ async fn handle_command(cli: Cli) -> Result<()> {
             title,
             description,
             network,
+            anti_snipe_window_slots,
+            anti_snipe_extension_epochs,
+            anti_snipe_max_extensions,
+            reveal_epochs,
+            unrevealed,
+            random_snapshot,
+            epoch_stake,
+            emergency,
+            supersedes,
+            depends_on,
         } => {
+            let anti_sniping = match (
+                anti_snipe_window_slots,
+                anti_snipe_extension_epochs,
+                anti_snipe_max_extensions,
+            ) {
+                (Some(window_slots), Some(extension_epochs), Some(max_extensions)) => {
+                    Some(AntiSnipingConfig {
+                        window_slots: *window_slots,
+                        extension_epochs: *extension_epochs,
+                        max_extensions: *max_extensions,
+                    })
+                }
+                _ => None,
+            };
+            let secret_ballot = match reveal_epochs {
+                Some(reveal_epochs) => {
+                    let unrevealed = match unrevealed.to_lowercase().as_str() {
+                        "abstain" => UnrevealedBallots::Abstain,
+                        "exclude" => UnrevealedBallots::Exclude,
+                        _ => {
+                            return Err(anyhow::anyhow!(
+                                "Invalid unrevealed policy: {} (expected abstain or exclude)",
+                                unrevealed
+                            ));
+                        }
+                    };
+                    Some(SecretBallotConfig {
+                        reveal_epochs: *reveal_epochs,
+                        unrevealed,
+                    })
+                }
+                None => None,
+            };
             instructions::create_proposal(
                 title.to_string(),
                 description.to_string(),
                 *seed,
+                anti_sniping,
+                secret_ballot,
+                *random_snapshot,
+                if *emergency {
+                    ProposalClass::Emergency
+                } else {
+                    ProposalClass::Standard
+                },
+                if *epoch_stake {
+                    StakeSource::EpochStake
+                } else {
+                    StakeSource::Snapshot
+                },
+                supersedes.clone(),
+                depends_on.clone(),
                 cli.identity_keypair,
                 cli.rpc_url,
                 network.clone(),
@@ -414,6 +982,23 @@ async fn handle_command(cli: Cli) -> Result<()> {
             )
             .await?;
         }
+        Commands::DrawSnapshotSlot { proposal_id } => {
+            instructions::draw_snapshot_slot(
+                proposal_id.to_string(),
+                cli.identity_keypair,
+                cli.rpc_url,
+            )
+            .await?;
+        }
+        Commands::Signal { proposal_id, lean } => {
+            instructions::signal_proposal(
+                proposal_id.to_string(),
+                lean.to_string(),
+                cli.identity_keypair,
+                cli.rpc_url,
+            )
+            .await?;
+        }
         Commands::CastVote {
             proposal_id,
             for_votes,
@@ -450,6 +1035,72 @@ async fn handle_command(cli: Cli) -> Result<()> {
             )
             .await?;
         }
+        Commands::RevealVote { proposal_id } => {
+            instructions::reveal_vote(proposal_id.to_string(), cli.identity_keypair, cli.rpc_url)
+                .await?;
+        }
+        Commands::SignVote {
+            proposal_id,
+            vote_account,
+            for_votes,
+            against_votes,
+            abstain_votes,
+            nonce,
+            expires_in_hours,
+            output,
+        } => {
+            instructions::sign_vote(
+                proposal_id.to_string(),
+                *for_votes,
+                *against_votes,
+                *abstain_votes,
+                vote_account.to_string(),
+                cli.identity_keypair,
+                *nonce,
+                *expires_in_hours,
+                output.to_string(),
+            )?;
+        }
+        Commands::RelayVote { files, network } => {
+            instructions::relay_vote(
+                files.clone(),
+                cli.identity_keypair,
+                cli.rpc_url,
+                network.clone(),
+            )
+            .await?;
+        }
+        Commands::Delegate { to, proposal_id } => {
+            instructions::set_governance_delegation(
+                to.to_string(),
+                proposal_id.clone(),
+                cli.identity_keypair,
+                cli.rpc_url,
+            )
+            .await?;
+        }
+        Commands::Undelegate { proposal_id } => {
+            instructions::remove_governance_delegation(
+                proposal_id.clone(),
+                cli.identity_keypair,
+                cli.rpc_url,
+            )
+            .await?;
+        }
+        Commands::CastDelegatedVote {
+            proposal_id,
+            delegator,
+            network,
+        } => {
+            instructions::cast_delegated_vote(
+                proposal_id.to_string(),
+                delegator.to_string(),
+                cli.identity_keypair,
+                cli.rpc_url,
+                network.clone(),
+            )
+            .await?;
+        }
         Commands::FinalizeProposal { proposal_id } => {
             instructions::finalize_proposal(
                 proposal_id.to_string(),
@@ -458,12 +1109,65 @@ async fn handle_command(cli: Cli) -> Result<()> {
             )
             .await?;
         }
+        Commands::CancelProposal { proposal_id } => {
+            instructions::cancel_proposal(
+                proposal_id.to_string(),
+                cli.identity_keypair,
+                cli.rpc_url,
+            )
+            .await?;
+        }
+        Commands::SettleBond { proposal_id } => {
+            instructions::settle_proposal_bond(
+                proposal_id.to_string(),
+                cli.identity_keypair,
+                cli.rpc_url,
+            )
+            .await?;
+        }
+        Commands::CloseProofs { staker_keypair } => {
+            instructions::close_meta_merkle_proofs(
+                staker_keypair.clone(),
+                cli.identity_keypair,
+                cli.rpc_url,
+            )
+            .await?;
+        }
         Commands::Proposal { proposal_id } => {
             commands::get_proposal(cli.rpc_url.clone(), proposal_id).await?;
         }
+        Commands::ListProposals {} => {
+            commands::list_proposals(cli.rpc_url.clone()).await?;
+        }
+        Commands::Validator { vote_account } => {
+            commands::get_validator_profile(cli.rpc_url.clone(), vote_account).await?;
+        }
+        Commands::UpdateProfile {
+            display_name,
+            website,
+            governance_policy_url,
+        } => {
+            instructions::update_validator_profile(
+                display_name.clone(),
+                website.clone(),
+                governance_policy_url.clone(),
+                cli.identity_keypair,
+                cli.rpc_url,
+            )
+            .await?;
+        }
+        Commands::Treasury {} => {
+            commands::show_treasury(cli.rpc_url.clone()).await?;
+        }
+        Commands::GovernanceConfig {} => {
+            commands::show_governance_config(cli.rpc_url.clone()).await?;
+        }
         Commands::InitIndex {} => {
             instructions::initialize_index(cli.identity_keypair, cli.rpc_url).await?;
         }
+        Commands::InitConfig {} => {
+            instructions::initialize_governance_config(cli.identity_keypair, cli.rpc_url).await?;
+        }
         Commands::CastVoteOverride {
             proposal_id,
             for_votes,
@@ -487,6 +1191,29 @@ async fn handle_command(cli: Cli) -> Result<()> {
             )
             .await?;
         }
+        Commands::CastVoteOverrideBatch {
+            proposal_id,
+            for_votes,
+            against_votes,
+            abstain_votes,
+            stake_accounts,
+            network,
+            staker_keypair,
+            vote_account,
+        } => {
+            instructions::cast_vote_override_batch(
+                proposal_id.to_string(),
+                *for_votes,
+                *against_votes,
+                *abstain_votes,
+                staker_keypair.clone(),
+                cli.rpc_url,
+                stake_accounts.clone(),
+                vote_account.clone(),
+                network.clone(),
+            )
+            .await?;
+        }
         Commands::ModifyVoteOverride {
             proposal_id,
             for_votes,
@@ -510,6 +1237,45 @@ async fn handle_command(cli: Cli) -> Result<()> {
             )
             .await?;
         }
+        Commands::RevealVoteOverride {
+            proposal_id,
+            stake_account,
+            vote_account,
+            staker_keypair,
+        } => {
+            instructions::reveal_vote_override(
+                proposal_id.to_string(),
+                stake_account.clone(),
+                vote_account.clone(),
+                staker_keypair.clone(),
+                cli.rpc_url,
+            )
+            .await?;
+        }
+        Commands::RegisterProxy {
+            stake_account,
+            proxy,
+            staker_keypair,
+        } => {
+            instructions::register_stake_proxy(
+                stake_account.clone(),
+                proxy.clone(),
+                staker_keypair.clone(),
+                cli.rpc_url,
+            )
+            .await?;
+        }
+        Commands::RevokeProxy {
+            stake_account,
+            staker_keypair,
+        } => {
+            instructions::revoke_stake_proxy(
+                stake_account.clone(),
+                staker_keypair.clone(),
+                cli.rpc_url,
+            )
+            .await?;
+        }
         Commands::Init => {
             init::run_init().await?;
         }
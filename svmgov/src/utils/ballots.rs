@@ -0,0 +1,114 @@
+use std::{fs, path::PathBuf};
+
+use anchor_client::solana_sdk::{hash::hashv, pubkey::Pubkey};
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+
+use crate::{config::Config, constants::BASIS_POINTS_TOTAL, govcontract::accounts::Proposal};
+
+/// Split and salt of a committed secret ballot, kept under ~/.svmgov/ballots until it is
+/// revealed. Losing this file means the ballot can no longer be revealed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SecretBallot {
+    pub proposal: String,
+    /// `Vote` or `VoteOverride` the ballot is committed against
+    pub vote_record: String,
+    pub for_votes_bp: u64,
+    pub against_votes_bp: u64,
+    pub abstain_votes_bp: u64,
+    /// Hex encoded 32 byte salt
+    pub salt: String,
+}
+
+impl SecretBallot {
+    /// Creates a ballot for the given split with a fresh random salt
+    pub fn new(
+        proposal: &Pubkey,
+        vote_record: &Pubkey,
+        for_votes_bp: u64,
+        against_votes_bp: u64,
+        abstain_votes_bp: u64,
+    ) -> Self {
+        Self {
+            proposal: proposal.to_string(),
+            vote_record: vote_record.to_string(),
+            for_votes_bp,
+            against_votes_bp,
+            abstain_votes_bp,
+            salt: hex::encode(rand::random::<[u8; 32]>()),
+        }
+    }
+
+    pub fn salt(&self) -> Result<[u8; 32]> {
+        let bytes = hex::decode(&self.salt).map_err(|e| anyhow!("Invalid ballot salt: {}", e))?;
+        bytes
+            .try_into()
+            .map_err(|_| anyhow!("Ballot salt must be 32 bytes"))
+    }
+
+    /// Commitment checked on chain at reveal, matching `Ballot::commitment` in the program
+    pub fn commitment(&self) -> Result<[u8; 32]> {
+        let vote_record: Pubkey = self
+            .vote_record
+            .parse()
+            .map_err(|_| anyhow!("Invalid vote record in ballot: {}", self.vote_record))?;
+        Ok(hashv(&[
+            &self.for_votes_bp.to_le_bytes(),
+            &self.against_votes_bp.to_le_bytes(),
+            &self.abstain_votes_bp.to_le_bytes(),
+            &self.salt()?,
+            vote_record.as_ref(),
+        ])
+        .to_bytes())
+    }
+
+    fn path(vote_record: &Pubkey) -> Result<PathBuf> {
+        Ok(Config::config_dir()?
+            .join("ballots")
+            .join(format!("{}.json", vote_record)))
+    }
+
+    pub fn save(&self) -> Result<PathBuf> {
+        let vote_record: Pubkey = self
+            .vote_record
+            .parse()
+            .map_err(|_| anyhow!("Invalid vote record in ballot: {}", self.vote_record))?;
+        let path = Self::path(&vote_record)?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| {
+                anyhow!("Failed to create ballot directory {}: {}", dir.display(), e)
+            })?;
+        }
+        fs::write(&path, serde_json::to_string_pretty(self)?)
+            .map_err(|e| anyhow!("Failed to write ballot {}: {}", path.display(), e))?;
+        Ok(path)
+    }
+
+    pub fn load(vote_record: &Pubkey) -> Result<Self> {
+        let path = Self::path(vote_record)?;
+        let contents = fs::read_to_string(&path).map_err(|e| {
+            anyhow!(
+                "No saved secret ballot at {} ({}); ballots can only be revealed from the machine that committed them",
+                path.display(),
+                e
+            )
+        })?;
+        serde_json::from_str(&contents)
+            .map_err(|e| anyhow!("Failed to parse ballot {}: {}", path.display(), e))
+    }
+}
+
+/// Whether the proposal takes secret ballots
+pub fn is_secret_ballot(proposal: &Proposal) -> bool {
+    proposal.reveal_epochs > 0
+}
+
+/// Split a secret ballot vote is cast with until it is revealed: all abstain, or nothing
+/// when unrevealed ballots are excluded
+pub fn placeholder_split(proposal: &Proposal) -> (u64, u64, u64) {
+    if proposal.unrevealed_ballots == 0 {
+        (0, 0, BASIS_POINTS_TOTAL)
+    } else {
+        (0, 0, 0)
+    }
+}
@@ -1,14 +1,31 @@
 use std::{str::FromStr, sync::Arc};
 
-use anchor_client::solana_sdk::signature::Keypair;
+use anchor_client::{Program, solana_sdk::signature::Keypair};
 
-use anchor_lang::prelude::Pubkey;
+use anchor_lang::{AccountDeserialize, prelude::Pubkey};
 use anyhow::{Result, anyhow};
 use comfy_table::modifiers::UTF8_ROUND_CORNERS;
 use comfy_table::{Cell, Table, presets::UTF8_FULL};
 use log::info;
 
-use crate::{anchor_client_setup, govcontract::accounts::Proposal};
+use crate::{
+    anchor_client_setup,
+    constants::{PROPOSAL_OUTCOME_CANCELLED, PROPOSAL_OUTCOME_PASSED},
+    govcontract::{
+        accounts::{
+            GovernanceConfig, GovernanceDelegation, ParameterChange, Proposal, ProposalBond,
+            ProposalContent, ProposalIndex, ProposalRegistryPage, SignalTally, TreasuryTransfer,
+            ValidatorGovernanceProfile,
+        },
+        types::{BondStatus, GovernanceParameter, ProposalOutcome, RegistryEntry},
+    },
+    utils::utils::{
+        derive_delegation_pda, derive_governance_config_pda, derive_parameter_change_pda,
+        derive_proposal_bond_pda, derive_proposal_content_pda, derive_proposal_index_pda,
+        derive_registry_page_pda, derive_signal_tally_pda, derive_treasury_pda,
+        derive_treasury_transfer_pda, derive_validator_profile_pda, registry_page_for,
+    },
+};
 
 /// Detect terminal width using various methods
 fn detect_terminal_width() -> Option<u16> {
@@ -62,13 +79,428 @@ pub async fn get_proposal(rpc_url: Option<String>, proposal_id: &String) -> Resu
     let program = anchor_client_setup(rpc_url, mock_payer)?;
 
     let proposal_acc = program.account::<Proposal>(proposal_pubkey).await?;
+    let content_acc = program
+        .account::<ProposalContent>(derive_proposal_content_pda(&proposal_pubkey, &program.id()))
+        .await?;
+    // Proposals created before bonds were introduced have no bond account
+    let bond_acc = program
+        .account::<ProposalBond>(derive_proposal_bond_pda(&proposal_pubkey, &program.id()))
+        .await
+        .ok();
+    // The signal tally only exists once a validator has signalled
+    let signal_tally_acc = program
+        .account::<SignalTally>(derive_signal_tally_pda(&proposal_pubkey, &program.id()))
+        .await
+        .ok();
 
-    print_proposal_detail(proposal_id, &proposal_acc);
+    print_proposal_detail(
+        proposal_id,
+        &proposal_acc,
+        &content_acc,
+        bond_acc.as_ref(),
+        signal_tally_acc.as_ref(),
+    );
 
     Ok(())
 }
 
-fn print_proposal_detail(proposal_id: &str, proposal: &Proposal) {
+/// Reads every registry entry, in index order. Proposals created before the registry was
+/// deployed have no page and are skipped.
+pub(crate) async fn fetch_registry_entries(program: &Program<Arc<Keypair>>) -> Result<Vec<RegistryEntry>> {
+    let proposal_index = program
+        .account::<ProposalIndex>(derive_proposal_index_pda(&program.id()))
+        .await?;
+    if proposal_index.current_index == 0 {
+        return Ok(Vec::new());
+    }
+
+    let mut entries = Vec::new();
+    for page in 0..=registry_page_for(proposal_index.current_index) {
+        let page_pda = derive_registry_page_pda(page, &program.id());
+        if let Ok(registry_page) = program.account::<ProposalRegistryPage>(page_pda).await {
+            entries.extend(registry_page.entries);
+        }
+    }
+    Ok(entries)
+}
+
+pub async fn list_proposals(rpc_url: Option<String>) -> Result<()> {
+    let mock_payer = Arc::new(Keypair::new());
+    let program = anchor_client_setup(rpc_url, mock_payer)?;
+
+    let entries = fetch_registry_entries(&program).await?;
+    if entries.is_empty() {
+        println!("No proposals have been created yet.");
+        return Ok(());
+    }
+
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_ROUND_CORNERS)
+        .set_header(vec!["Index", "Proposal ID", "Status"]);
+
+    for entry in entries {
+        let status = match entry.outcome {
+            ProposalOutcome::Undecided => "Open",
+            ProposalOutcome::Passed => "Passed",
+            ProposalOutcome::Rejected => "Rejected",
+            ProposalOutcome::Cancelled => "Cancelled",
+        };
+        table.add_row(vec![
+            Cell::new(entry.index.to_string()),
+            Cell::new(entry.proposal.to_string()),
+            Cell::new(status),
+        ]);
+    }
+
+    println!("\n{}", table);
+    println!("Use `svmgov proposal <PROPOSAL_ID>` for details.");
+
+    Ok(())
+}
+
+pub async fn show_treasury(rpc_url: Option<String>) -> Result<()> {
+    let mock_payer = Arc::new(Keypair::new());
+    let program = anchor_client_setup(rpc_url, mock_payer)?;
+
+    let treasury = derive_treasury_pda(&program.id());
+    let balance = program.rpc().get_balance(&treasury).await?;
+    println!("Treasury: {}", treasury);
+    println!("Balance:  {:.9} SOL", balance as f64 / 1_000_000_000.0);
+
+    // Transfers of proposals that were rejected, cancelled or already executed can no longer
+    // pay out
+    let entries = fetch_registry_entries(&program)
+        .await?
+        .into_iter()
+        .filter(|entry| {
+            !matches!(
+                entry.outcome,
+                ProposalOutcome::Rejected | ProposalOutcome::Cancelled
+            )
+        })
+        .collect::<Vec<_>>();
+    let transfer_pdas = entries
+        .iter()
+        .map(|entry| derive_treasury_transfer_pda(&entry.proposal, &program.id()))
+        .collect::<Vec<_>>();
+
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_ROUND_CORNERS)
+        .set_header(vec![
+            "Proposal ID",
+            "Asset",
+            "Amount",
+            "Destination",
+            "Status",
+        ]);
+    let mut pending = 0;
+
+    for (chunk_entries, chunk_pdas) in entries.chunks(100).zip(transfer_pdas.chunks(100)) {
+        let accounts = program.rpc().get_multiple_accounts(chunk_pdas).await?;
+        for (entry, account) in chunk_entries.iter().zip(accounts) {
+            let Some(account) = account else {
+                continue;
+            };
+            let transfer = TreasuryTransfer::try_deserialize(&mut account.data.as_slice())?;
+            if transfer.executed_timestamp != 0 {
+                continue;
+            }
+            let (asset, amount) = if transfer.mint == Pubkey::default() {
+                (
+                    "SOL".to_string(),
+                    format!("{:.9}", transfer.amount as f64 / 1_000_000_000.0),
+                )
+            } else {
+                (transfer.mint.to_string(), transfer.amount.to_string())
+            };
+            let status = match entry.outcome {
+                ProposalOutcome::Passed => format!(
+                    "Passed, executable {}s after finalization",
+                    transfer.timelock_seconds
+                ),
+                _ => "Awaiting vote".to_string(),
+            };
+            table.add_row(vec![
+                Cell::new(entry.proposal.to_string()),
+                Cell::new(asset),
+                Cell::new(amount),
+                Cell::new(transfer.destination.to_string()),
+                Cell::new(status),
+            ]);
+            pending += 1;
+        }
+    }
+
+    if pending == 0 {
+        println!("\nNo pending treasury transfers.");
+    } else {
+        println!("\nPending transfers:\n{}", table);
+    }
+
+    Ok(())
+}
+
+pub async fn show_governance_config(rpc_url: Option<String>) -> Result<()> {
+    let mock_payer = Arc::new(Keypair::new());
+    let program = anchor_client_setup(rpc_url, mock_payer)?;
+
+    let config_pda = derive_governance_config_pda(&program.id());
+    let config = program
+        .account::<GovernanceConfig>(config_pda)
+        .await
+        .map_err(|e| anyhow!("Failed to fetch governance config {}: {}", config_pda, e))?;
+
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_ROUND_CORNERS)
+        .set_header(vec!["Rule", "Standard", "Emergency"]);
+    table.add_row(vec![
+        Cell::new("Support Threshold"),
+        Cell::new(format_bp(config.support_threshold_bp)),
+        Cell::new(format_bp(config.emergency_support_threshold_bp)),
+    ]);
+    table.add_row(vec![
+        Cell::new("Support Epochs"),
+        Cell::new(config.support_epochs),
+        Cell::new(config.emergency_support_epochs),
+    ]);
+    table.add_row(vec![
+        Cell::new("Discussion Epochs"),
+        Cell::new(config.discussion_epochs),
+        Cell::new(config.emergency_discussion_epochs),
+    ]);
+    table.add_row(vec![
+        Cell::new("Voting Epochs"),
+        Cell::new(config.voting_epochs),
+        Cell::new(config.emergency_voting_epochs),
+    ]);
+    table.add_row(vec![
+        Cell::new("Pass Threshold"),
+        Cell::new(format_bp(config.pass_threshold_bp)),
+        Cell::new(format_bp(config.pass_threshold_bp)),
+    ]);
+    table.add_row(vec![
+        Cell::new("Quorum"),
+        Cell::new(format_bp(config.quorum_bp)),
+        Cell::new(format_bp(config.quorum_bp)),
+    ]);
+    println!("Governance config: {}", config_pda);
+    println!("{}", table);
+    println!(
+        "Min Proposal Stake:     {:.9} SOL",
+        config.min_proposal_stake_lamports as f64 / 1_000_000_000.0
+    );
+    println!(
+        "Proposal Bond:          {:.9} SOL",
+        config.proposal_bond_lamports as f64 / 1_000_000_000.0
+    );
+    println!(
+        "Max Open Proposals:     {} per vote account",
+        config.max_open_proposals_per_author
+    );
+
+    // Changes of rejected or cancelled proposals can never be enacted
+    let entries = fetch_registry_entries(&program)
+        .await?
+        .into_iter()
+        .filter(|entry| {
+            !matches!(
+                entry.outcome,
+                ProposalOutcome::Rejected | ProposalOutcome::Cancelled
+            )
+        })
+        .collect::<Vec<_>>();
+    let change_pdas = entries
+        .iter()
+        .map(|entry| derive_parameter_change_pda(&entry.proposal, &program.id()))
+        .collect::<Vec<_>>();
+
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_ROUND_CORNERS)
+        .set_header(vec!["Proposal ID", "Changes", "Status"]);
+    let mut pending = 0;
+
+    for (chunk_entries, chunk_pdas) in entries.chunks(100).zip(change_pdas.chunks(100)) {
+        let accounts = program.rpc().get_multiple_accounts(chunk_pdas).await?;
+        for (entry, account) in chunk_entries.iter().zip(accounts) {
+            let Some(account) = account else {
+                continue;
+            };
+            let change = ParameterChange::try_deserialize(&mut account.data.as_slice())?;
+            if change.enacted_timestamp != 0 {
+                continue;
+            }
+            let changes = change
+                .changes
+                .iter()
+                .map(format_parameter)
+                .collect::<Vec<_>>()
+                .join("\n");
+            let status = match entry.outcome {
+                ProposalOutcome::Passed => "Passed, ready to enact",
+                _ => "Awaiting vote",
+            };
+            table.add_row(vec![
+                Cell::new(entry.proposal.to_string()),
+                Cell::new(changes),
+                Cell::new(status),
+            ]);
+            pending += 1;
+        }
+    }
+
+    if pending == 0 {
+        println!("\nNo pending parameter changes.");
+    } else {
+        println!("\nPending parameter changes:\n{}", table);
+    }
+
+    Ok(())
+}
+
+fn format_bp(bp: u64) -> String {
+    format!("{:.2}%", bp as f64 / 100.0)
+}
+
+fn format_parameter(parameter: &GovernanceParameter) -> String {
+    match parameter {
+        GovernanceParameter::SupportThresholdBp(bp) => {
+            format!("Support threshold = {}", format_bp(*bp))
+        }
+        GovernanceParameter::EmergencySupportThresholdBp(bp) => {
+            format!("Emergency support threshold = {}", format_bp(*bp))
+        }
+        GovernanceParameter::DiscussionEpochs(epochs) => {
+            format!("Discussion epochs = {}", epochs)
+        }
+        GovernanceParameter::EmergencyDiscussionEpochs(epochs) => {
+            format!("Emergency discussion epochs = {}", epochs)
+        }
+        GovernanceParameter::VotingEpochs(epochs) => format!("Voting epochs = {}", epochs),
+        GovernanceParameter::EmergencyVotingEpochs(epochs) => {
+            format!("Emergency voting epochs = {}", epochs)
+        }
+        GovernanceParameter::PassThresholdBp(bp) => {
+            format!("Pass threshold = {}", format_bp(*bp))
+        }
+        GovernanceParameter::MinProposalStakeLamports(lamports) => format!(
+            "Min proposal stake = {:.9} SOL",
+            *lamports as f64 / 1_000_000_000.0
+        ),
+        GovernanceParameter::ProposalBondLamports(lamports) => format!(
+            "Proposal bond = {:.9} SOL",
+            *lamports as f64 / 1_000_000_000.0
+        ),
+        GovernanceParameter::MaxOpenProposalsPerAuthor(count) => {
+            format!("Max open proposals = {}", count)
+        }
+        GovernanceParameter::SupportEpochs(epochs) => format!("Support epochs = {}", epochs),
+        GovernanceParameter::EmergencySupportEpochs(epochs) => {
+            format!("Emergency support epochs = {}", epochs)
+        }
+        GovernanceParameter::QuorumBp(bp) => format!("Quorum = {}", format_bp(*bp)),
+    }
+}
+
+pub async fn get_validator_profile(rpc_url: Option<String>, vote_account: &String) -> Result<()> {
+    let vote_account_pubkey = Pubkey::from_str(vote_account)
+        .map_err(|_| anyhow!("Invalid vote account: {}", vote_account))?;
+    let mock_payer = Arc::new(Keypair::new());
+    let program = anchor_client_setup(rpc_url, mock_payer)?;
+
+    let profile_pda = derive_validator_profile_pda(&vote_account_pubkey, &program.id());
+    let Ok(profile) = program.account::<ValidatorGovernanceProfile>(profile_pda).await else {
+        println!(
+            "Vote account {} has not created, supported or voted on any proposal yet.",
+            vote_account
+        );
+        return Ok(());
+    };
+
+    let or_unset = |value: &str| {
+        if value.is_empty() {
+            "-".to_string()
+        } else {
+            value.to_string()
+        }
+    };
+
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_ROUND_CORNERS)
+        .set_header(vec!["Field", "Value"]);
+    table.add_row(vec![Cell::new("Vote Account"), Cell::new(vote_account)]);
+    table.add_row(vec![
+        Cell::new("Display Name"),
+        Cell::new(or_unset(&profile.display_name)),
+    ]);
+    table.add_row(vec![
+        Cell::new("Website"),
+        Cell::new(or_unset(&profile.website)),
+    ]);
+    table.add_row(vec![
+        Cell::new("Governance Policy"),
+        Cell::new(or_unset(&profile.governance_policy_url)),
+    ]);
+    table.add_row(vec![
+        Cell::new("Proposals Authored"),
+        Cell::new(profile.proposals_authored.to_string()),
+    ]);
+    table.add_row(vec![
+        Cell::new("Open Proposals"),
+        Cell::new(profile.open_proposals.to_string()),
+    ]);
+    table.add_row(vec![
+        Cell::new("Proposals Supported"),
+        Cell::new(profile.proposals_supported.to_string()),
+    ]);
+    table.add_row(vec![
+        Cell::new("Proposals Voted"),
+        Cell::new(profile.proposals_voted.to_string()),
+    ]);
+    let last_vote_epoch = if profile.proposals_voted == 0 {
+        "-".to_string()
+    } else {
+        profile.last_vote_epoch.to_string()
+    };
+    table.add_row(vec![Cell::new("Last Vote Epoch"), Cell::new(last_vote_epoch)]);
+    let standing_delegation = program
+        .account::<GovernanceDelegation>(derive_delegation_pda(
+            &vote_account_pubkey,
+            None,
+            &program.id(),
+        ))
+        .await
+        .ok();
+    table.add_row(vec![
+        Cell::new("Delegates To"),
+        Cell::new(
+            standing_delegation
+                .map(|delegation| delegation.delegate.to_string())
+                .unwrap_or_else(|| "-".to_string()),
+        ),
+    ]);
+
+    println!("\n{}", table);
+
+    Ok(())
+}
+
+fn print_proposal_detail(
+    proposal_id: &str,
+    proposal: &Proposal,
+    content: &ProposalContent,
+    bond: Option<&ProposalBond>,
+    signal_tally: Option<&SignalTally>,
+) {
     let mut table = Table::new();
     table
         .load_preset(UTF8_FULL)
@@ -91,29 +523,85 @@ fn print_proposal_detail(proposal_id: &str, proposal: &Proposal) {
     let cluster_support_sol = proposal.cluster_support_lamports as f64 / 1_000_000_000.0;
     let proposer_stake_bp = proposal.proposer_stake_weight_bp as f64 / 100.0;
 
-    let status = if proposal.finalized {
-        "Finalized"
-    } else if proposal.voting {
+    let status = if proposal.finalized != 0 {
+        match proposal.outcome {
+            PROPOSAL_OUTCOME_PASSED => "Finalized (Passed)",
+            PROPOSAL_OUTCOME_CANCELLED => "Cancelled",
+            _ => "Finalized (Rejected)",
+        }
+    } else if proposal.voting != 0 {
         "Voting"
     } else {
         "Support Period"
     };
 
     table.add_row(vec![Cell::new("Proposal ID"), Cell::new(proposal_id)]);
-    table.add_row(vec![Cell::new("Title"), Cell::new(&proposal.title)]);
+    table.add_row(vec![Cell::new("Title"), Cell::new(&content.title)]);
     table.add_row(vec![
         Cell::new("Description"),
-        Cell::new(&proposal.description),
+        Cell::new(&content.description),
     ]);
     table.add_row(vec![
         Cell::new("Author"),
         Cell::new(proposal.author.to_string()),
     ]);
     table.add_row(vec![Cell::new("Status"), Cell::new(status)]);
+    if let Some(bond) = bond {
+        let bond_status = match bond.status {
+            BondStatus::Locked => "Locked",
+            BondStatus::Returned => "Returned",
+            BondStatus::Forfeited => "Forfeited",
+        };
+        table.add_row(vec![
+            Cell::new("Bond"),
+            Cell::new(format!(
+                "{:.2} SOL ({})",
+                bond.amount as f64 / 1_000_000_000.0,
+                bond_status
+            )),
+        ]);
+    }
     table.add_row(vec![
         Cell::new("Index"),
         Cell::new(proposal.index.to_string()),
     ]);
+    table.add_row(vec![
+        Cell::new("Class"),
+        Cell::new(if proposal.class == 0 {
+            "Standard"
+        } else {
+            "Emergency"
+        }),
+    ]);
+    // Rules are fixed when the proposal is created, later parameter changes don't affect it
+    table.add_row(vec![
+        Cell::new("Support Threshold"),
+        Cell::new(format_bp(proposal.support_threshold_bp)),
+    ]);
+    table.add_row(vec![
+        Cell::new("Pass Threshold"),
+        Cell::new(format_bp(proposal.pass_threshold_bp)),
+    ]);
+    table.add_row(vec![
+        Cell::new("Quorum"),
+        Cell::new(format_bp(proposal.quorum_bp)),
+    ]);
+    table.add_row(vec![
+        Cell::new("Support / Discussion / Voting Epochs"),
+        Cell::new(format!(
+            "{} / {} / {}",
+            proposal.support_epochs, proposal.discussion_epochs, proposal.voting_epochs
+        )),
+    ]);
+    for (field, linked) in [
+        ("Supersedes", proposal.supersedes),
+        ("Depends On", proposal.depends_on),
+        ("Superseded By", proposal.superseded_by),
+    ] {
+        if linked != Pubkey::default() {
+            table.add_row(vec![Cell::new(field), Cell::new(linked.to_string())]);
+        }
+    }
     table.add_row(vec![
         Cell::new("Creation Epoch"),
         Cell::new(proposal.creation_epoch.to_string()),
@@ -126,10 +614,63 @@ fn print_proposal_detail(proposal_id: &str, proposal: &Proposal) {
         Cell::new("End Epoch"),
         Cell::new(proposal.end_epoch.to_string()),
     ]);
+    if proposal.flush_count > 0 {
+        let prior_slots = proposal.prior_snapshot_slots[..proposal.flush_count as usize]
+            .iter()
+            .map(|slot| slot.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        table.add_row(vec![
+            Cell::new("Merkle Root Flushes"),
+            Cell::new(format!("{} (prior snapshot slots: {})", proposal.flush_count, prior_slots)),
+        ]);
+    }
+    if proposal.anti_sniping_max_extensions > 0 {
+        table.add_row(vec![
+            Cell::new("Anti-Sniping"),
+            Cell::new(format!(
+                "{} slot window, +{} epoch(s) per extension",
+                proposal.anti_sniping_window_slots, proposal.anti_sniping_extension_epochs
+            )),
+        ]);
+        table.add_row(vec![
+            Cell::new("Voting Extensions"),
+            Cell::new(format!(
+                "{} / {}",
+                proposal.voting_extensions, proposal.anti_sniping_max_extensions
+            )),
+        ]);
+    }
+    if proposal.reveal_epochs > 0 {
+        let unrevealed = if proposal.unrevealed_ballots == 0 {
+            "abstain"
+        } else {
+            "excluded"
+        };
+        table.add_row(vec![
+            Cell::new("Secret Ballot"),
+            Cell::new(format!(
+                "revealed over {} epoch(s) after voting ends, unrevealed ballots {}",
+                proposal.reveal_epochs, unrevealed
+            )),
+        ]);
+    }
     table.add_row(vec![
         Cell::new("Snapshot Slot"),
         Cell::new(proposal.snapshot_slot.to_string()),
     ]);
+    if proposal.random_snapshot != 0 {
+        let derivation = if proposal.snapshot_seed_slot == 0 {
+            "drawn once the snapshot epoch starts".to_string()
+        } else {
+            format!(
+                "drawn from the hash of slot {} ({})",
+                proposal.snapshot_seed_slot,
+                hex::encode(proposal.snapshot_seed_hash)
+            )
+        };
+        table.add_row(vec![Cell::new("Random Snapshot"), Cell::new(derivation)]);
+    }
     table.add_row(vec![
         Cell::new("Proposer Stake Weight"),
         Cell::new(format!("{:.2}%", proposer_stake_bp)),
@@ -163,10 +704,22 @@ fn print_proposal_detail(proposal_id: &str, proposal: &Proposal) {
             proposal.abstain_votes_lamports, abstain_sol
         )),
     ]);
-    if let Some(consensus_result) = proposal.consensus_result {
+    if let Some(signal_tally) = signal_tally {
+        table.add_row(vec![
+            Cell::new("Discussion Signals"),
+            Cell::new(format!(
+                "{} signal(s): {:.2} SOL for, {:.2} SOL against, {:.2} SOL undecided",
+                signal_tally.signal_count,
+                signal_tally.for_lamports as f64 / 1_000_000_000.0,
+                signal_tally.against_lamports as f64 / 1_000_000_000.0,
+                signal_tally.undecided_lamports as f64 / 1_000_000_000.0
+            )),
+        ]);
+    }
+    if proposal.consensus_result != Pubkey::default() {
         table.add_row(vec![
             Cell::new("Consensus Result"),
-            Cell::new(consensus_result.to_string()),
+            Cell::new(proposal.consensus_result.to_string()),
         ]);
     }
     table.add_row(vec![
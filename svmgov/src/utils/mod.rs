@@ -1,5 +1,7 @@
 pub mod api_helpers;
+pub mod ballots;
 pub mod commands;
 pub mod config_command;
 pub mod init;
+pub mod signed_votes;
 pub mod utils;
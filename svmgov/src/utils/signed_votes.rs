@@ -0,0 +1,147 @@
+use std::{fs, str::FromStr};
+
+use anchor_client::solana_sdk::{
+    ed25519_program,
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{Keypair, Signature},
+    signer::Signer,
+};
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    constants::{BASIS_POINTS_TOTAL, SIGNED_VOTE_DOMAIN},
+    govcontract::{self, types::SignedVote},
+};
+
+/// Vote signed off-chain by a vote account's voting wallet, written by sign-vote and submitted
+/// by relay-vote. Whoever holds the file can relay it, but only with the split it was signed for.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignedVoteFile {
+    pub proposal: String,
+    pub vote_account: String,
+    pub voting_wallet: String,
+    pub for_votes_bp: u64,
+    pub against_votes_bp: u64,
+    pub abstain_votes_bp: u64,
+    pub nonce: u64,
+    /// Unix timestamp after which the vote can no longer be relayed
+    pub expiry: i64,
+    /// Base58 encoded Ed25519 signature of the vote message by the voting wallet
+    pub signature: String,
+}
+
+impl SignedVoteFile {
+    /// Signs the vote with the voting wallet
+    pub fn sign(voting_wallet: &Keypair, vote: &SignedVote) -> Self {
+        Self {
+            proposal: vote.proposal.to_string(),
+            vote_account: vote.vote_account.to_string(),
+            voting_wallet: voting_wallet.pubkey().to_string(),
+            for_votes_bp: vote.for_votes_bp,
+            against_votes_bp: vote.against_votes_bp,
+            abstain_votes_bp: vote.abstain_votes_bp,
+            nonce: vote.nonce,
+            expiry: vote.expiry,
+            signature: voting_wallet.sign_message(&message(vote)).to_string(),
+        }
+    }
+
+    /// Vote as passed to `cast_vote_signed`
+    pub fn vote(&self) -> Result<SignedVote> {
+        if self.for_votes_bp + self.against_votes_bp + self.abstain_votes_bp != BASIS_POINTS_TOTAL {
+            return Err(anyhow!(
+                "Signed vote basis points must sum to {}",
+                BASIS_POINTS_TOTAL
+            ));
+        }
+        Ok(SignedVote {
+            proposal: parse_pubkey("proposal", &self.proposal)?,
+            vote_account: parse_pubkey("vote account", &self.vote_account)?,
+            for_votes_bp: self.for_votes_bp,
+            against_votes_bp: self.against_votes_bp,
+            abstain_votes_bp: self.abstain_votes_bp,
+            nonce: self.nonce,
+            expiry: self.expiry,
+        })
+    }
+
+    pub fn voting_wallet(&self) -> Result<Pubkey> {
+        parse_pubkey("voting wallet", &self.voting_wallet)
+    }
+
+    /// Ed25519 program instruction verifying the signature, which `cast_vote_signed` must
+    /// directly follow. The public key, signature and message are all stored in its own data.
+    pub fn ed25519_instruction(&self) -> Result<Instruction> {
+        const HEADER_LEN: u16 = 16;
+
+        let message = message(&self.vote()?);
+        let signature = Signature::from_str(&self.signature)
+            .map_err(|e| anyhow!("Invalid signature in signed vote: {}", e))?;
+        let voting_wallet = self.voting_wallet()?;
+        if !signature.verify(voting_wallet.as_ref(), &message) {
+            return Err(anyhow!(
+                "Signed vote signature does not match voting wallet {}",
+                voting_wallet
+            ));
+        }
+
+        let public_key_offset = HEADER_LEN;
+        let signature_offset = public_key_offset + 32;
+        let message_offset = signature_offset + 64;
+        let mut data = vec![1, 0];
+        for field in [
+            signature_offset,
+            u16::MAX,
+            public_key_offset,
+            u16::MAX,
+            message_offset,
+            message.len() as u16,
+            u16::MAX,
+        ] {
+            data.extend_from_slice(&field.to_le_bytes());
+        }
+        data.extend_from_slice(voting_wallet.as_ref());
+        data.extend_from_slice(signature.as_ref());
+        data.extend_from_slice(&message);
+
+        Ok(Instruction {
+            program_id: ed25519_program::ID,
+            accounts: vec![],
+            data,
+        })
+    }
+
+    pub fn save(&self, path: &str) -> Result<()> {
+        fs::write(path, serde_json::to_string_pretty(self)?)
+            .map_err(|e| anyhow!("Failed to write signed vote {}: {}", path, e))
+    }
+
+    pub fn load(path: &str) -> Result<Self> {
+        let contents = fs::read_to_string(path)
+            .map_err(|e| anyhow!("Failed to read signed vote {}: {}", path, e))?;
+        serde_json::from_str(&contents)
+            .map_err(|e| anyhow!("Failed to parse signed vote {}: {}", path, e))
+    }
+}
+
+/// Bytes the voting wallet signs, matching `SignedVote::message` in the program
+fn message(vote: &SignedVote) -> Vec<u8> {
+    [
+        SIGNED_VOTE_DOMAIN,
+        govcontract::ID.as_ref(),
+        vote.proposal.as_ref(),
+        vote.vote_account.as_ref(),
+        &vote.for_votes_bp.to_le_bytes(),
+        &vote.against_votes_bp.to_le_bytes(),
+        &vote.abstain_votes_bp.to_le_bytes(),
+        &vote.nonce.to_le_bytes(),
+        &vote.expiry.to_le_bytes(),
+    ]
+    .concat()
+}
+
+fn parse_pubkey(name: &str, value: &str) -> Result<Pubkey> {
+    Pubkey::from_str(value).map_err(|_| anyhow!("Invalid {} in signed vote: {}", name, value))
+}
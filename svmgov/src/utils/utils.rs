@@ -14,8 +14,9 @@ use textwrap::wrap;
 use crate::{
     constants::*,
     govcontract::{
-        accounts::{Proposal, Vote},
+        accounts::{Proposal, ProposalContent, Vote},
         program::Govcontract,
+        types::StakeSource,
     },
 };
 
@@ -102,6 +103,18 @@ pub fn setup_all_with_staker(
     Ok((staker_keypair_arc, program, merkle_proof_program))
 }
 
+/// Same as `setup_all`, for a payer that needn't be a validator, such as a vote relayer
+pub fn setup_all_with_payer(
+    keypair_path: Option<String>,
+    rpc_url: Option<String>,
+) -> Result<(Arc<Keypair>, Program<Arc<Keypair>>, Program<Arc<Keypair>>)> {
+    let payer = Arc::new(load_identity_keypair(keypair_path)?);
+    let client = Client::new(set_cluster(rpc_url), payer.clone());
+    let program = client.program(Govcontract::id())?;
+    let merkle_proof_program = client.program(gov_v1::id())?;
+    Ok((payer, program, merkle_proof_program))
+}
+
 fn load_staker_keypair(keypair_path: String) -> Result<Keypair> {
     let file_content = fs::read_to_string(&keypair_path).map_err(|e| match e.kind() {
         std::io::ErrorKind::NotFound => {
@@ -132,7 +145,7 @@ fn load_staker_keypair(keypair_path: String) -> Result<Keypair> {
     Ok(staker_keypair)
 }
 
-fn load_identity_keypair(keypair_path: Option<String>) -> Result<Keypair> {
+pub fn load_identity_keypair(keypair_path: Option<String>) -> Result<Keypair> {
     // Check if the keypair path is provided
     let identity_keypair_path = if let Some(path) = keypair_path {
         path
@@ -285,9 +298,7 @@ impl fmt::Display for Proposal {
             &author_str[..4],
             &author_str[author_str.len() - 4..]
         );
-        let wrapped_desc = wrap(&self.description, 80);
 
-        writeln!(f, "{:<25} {}", "Proposal:", self.title)?;
         writeln!(f, "{:<25} {}", "Author:", short_author)?;
         writeln!(f, "{:<25} epoch {}", "Created:", self.creation_epoch)?;
         writeln!(f, "{:<25} epoch {}", "Starts:", self.start_epoch)?;
@@ -331,15 +342,23 @@ impl fmt::Display for Proposal {
             f,
             "{:<25} {}",
             "Voting:",
-            if self.voting { "Yes" } else { "No" }
+            if self.voting != 0 { "Yes" } else { "No" }
         )?;
         writeln!(
             f,
             "{:<25} {}",
             "Finalized:",
-            if self.finalized { "Yes" } else { "No" }
+            if self.finalized != 0 { "Yes" } else { "No" }
         )?;
+        Ok(())
+    }
+}
 
+impl fmt::Display for ProposalContent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let wrapped_desc = wrap(&self.description, 80);
+
+        writeln!(f, "{:<25} {}", "Proposal:", self.title)?;
         writeln!(f, "{:<25}", "Description:")?;
         for line in wrapped_desc {
             writeln!(f, "  {}", line)?;
@@ -412,12 +431,158 @@ pub fn derive_proposal_pda(seed: u64, vote_account: &Pubkey, program_id: &Pubkey
     pda
 }
 
+/// Derives the ProposalContent PDA holding a proposal's title and description
+pub fn derive_proposal_content_pda(proposal_pubkey: &Pubkey, program_id: &Pubkey) -> Pubkey {
+    let seeds = &[b"proposal_content", proposal_pubkey.as_ref()];
+    let (pda, _) = Pubkey::find_program_address(seeds, program_id);
+    pda
+}
+
+/// Returns the consensus result PDA recorded on a proposal, unset until voting is activated
+pub fn proposal_consensus_result(proposal: &Proposal) -> Result<Pubkey> {
+    if proposal.consensus_result == Pubkey::default() {
+        return Err(anyhow!("Proposal consensus_result is not set"));
+    }
+    Ok(proposal.consensus_result)
+}
+
+/// Whether the proposal reads stake at vote time instead of from a gov-v1 snapshot
+pub fn is_epoch_stake(proposal: &Proposal) -> bool {
+    proposal.stake_source == StakeSource::EpochStake as u8
+}
+
+/// Snapshot program accounts of the vote instructions, left out on epoch stake proposals
+#[derive(Clone, Copy, Default)]
+pub struct SnapshotAccounts {
+    pub snapshot_program: Option<Pubkey>,
+    pub consensus_result: Option<Pubkey>,
+    pub meta_merkle_proof: Option<Pubkey>,
+}
+
+impl SnapshotAccounts {
+    pub fn new(consensus_result: Pubkey, meta_merkle_proof: Pubkey) -> Self {
+        Self {
+            snapshot_program: Some(gov_v1::ID),
+            consensus_result: Some(consensus_result),
+            meta_merkle_proof: Some(meta_merkle_proof),
+        }
+    }
+}
+
 pub fn derive_proposal_index_pda(program_id: &Pubkey) -> Pubkey {
     let seeds = &[&b"index"[..]];
     let (pda, _) = Pubkey::find_program_address(seeds, program_id);
     pda
 }
 
+/// Derives the proposal bond PDA using the seeds [b"bond", proposal]
+pub fn derive_proposal_bond_pda(proposal_pubkey: &Pubkey, program_id: &Pubkey) -> Pubkey {
+    let seeds = &[b"bond", proposal_pubkey.as_ref()];
+    let (pda, _) = Pubkey::find_program_address(seeds, program_id);
+    pda
+}
+
+/// Derives the governance treasury PDA using the seeds [b"treasury"]
+pub fn derive_treasury_pda(program_id: &Pubkey) -> Pubkey {
+    let seeds = &[&b"treasury"[..]];
+    let (pda, _) = Pubkey::find_program_address(seeds, program_id);
+    pda
+}
+
+/// Derives the validator governance profile PDA using the seeds [b"validator_profile", vote_account]
+pub fn derive_validator_profile_pda(vote_account: &Pubkey, program_id: &Pubkey) -> Pubkey {
+    let seeds = &[b"validator_profile", vote_account.as_ref()];
+    let (pda, _) = Pubkey::find_program_address(seeds, program_id);
+    pda
+}
+
+/// Derives the treasury transfer PDA using the seeds [b"treasury_transfer", proposal]
+pub fn derive_treasury_transfer_pda(proposal_pubkey: &Pubkey, program_id: &Pubkey) -> Pubkey {
+    let seeds = &[b"treasury_transfer", proposal_pubkey.as_ref()];
+    let (pda, _) = Pubkey::find_program_address(seeds, program_id);
+    pda
+}
+
+/// Derives the event authority PDA using the seeds [b"__event_authority"], which signs the
+/// program's self-CPI events
+pub fn derive_event_authority_pda(program_id: &Pubkey) -> Pubkey {
+    let seeds = &[&b"__event_authority"[..]];
+    let (pda, _) = Pubkey::find_program_address(seeds, program_id);
+    pda
+}
+
+/// Derives the governance config PDA using the seeds [b"governance_config"]
+pub fn derive_governance_config_pda(program_id: &Pubkey) -> Pubkey {
+    let seeds = &[&b"governance_config"[..]];
+    let (pda, _) = Pubkey::find_program_address(seeds, program_id);
+    pda
+}
+
+/// Derives the parameter change PDA using the seeds [b"parameter_change", proposal]
+pub fn derive_parameter_change_pda(proposal_pubkey: &Pubkey, program_id: &Pubkey) -> Pubkey {
+    let seeds = &[b"parameter_change", proposal_pubkey.as_ref()];
+    let (pda, _) = Pubkey::find_program_address(seeds, program_id);
+    pda
+}
+
+/// Registry page that holds the proposal with the given (1-based) index
+pub fn registry_page_for(index: u32) -> u32 {
+    index.saturating_sub(1) / REGISTRY_PAGE_SIZE
+}
+
+/// Derives the registry page PDA using the seeds [b"registry", page]
+pub fn derive_registry_page_pda(page: u32, program_id: &Pubkey) -> Pubkey {
+    let seeds = &[b"registry".as_ref(), &page.to_le_bytes()];
+    let (pda, _) = Pubkey::find_program_address(seeds, program_id);
+    pda
+}
+
+/// Derives the delegation PDA using the seeds [b"delegation", vote_account, proposal], where a
+/// standing delegation (`proposal` is `None`) uses the default pubkey
+pub fn derive_delegation_pda(
+    vote_account: &Pubkey,
+    proposal: Option<&Pubkey>,
+    program_id: &Pubkey,
+) -> Pubkey {
+    let proposal = proposal.copied().unwrap_or_default();
+    let seeds = &[b"delegation", vote_account.as_ref(), proposal.as_ref()];
+    let (pda, _) = Pubkey::find_program_address(seeds, program_id);
+    pda
+}
+
+/// Derives the stake proxy PDA using the seeds [b"stake_proxy", stake_account]
+pub fn derive_stake_proxy_pda(stake_account: &Pubkey, program_id: &Pubkey) -> Pubkey {
+    let seeds = &[b"stake_proxy", stake_account.as_ref()];
+    let (pda, _) = Pubkey::find_program_address(seeds, program_id);
+    pda
+}
+
+/// Derives the secret ballot PDA using the seeds [b"ballot", vote_record], where the vote
+/// record is the `Vote` or `VoteOverride` the ballot is committed against
+pub fn derive_ballot_pda(vote_record: &Pubkey, program_id: &Pubkey) -> Pubkey {
+    let seeds = &[b"ballot", vote_record.as_ref()];
+    let (pda, _) = Pubkey::find_program_address(seeds, program_id);
+    pda
+}
+
+/// Derives the signal PDA using the seeds [b"signal", proposal, vote_account]
+pub fn derive_signal_pda(
+    proposal_pubkey: &Pubkey,
+    vote_account: &Pubkey,
+    program_id: &Pubkey,
+) -> Pubkey {
+    let seeds = &[b"signal", proposal_pubkey.as_ref(), vote_account.as_ref()];
+    let (pda, _) = Pubkey::find_program_address(seeds, program_id);
+    pda
+}
+
+/// Derives the signal tally PDA using the seeds [b"signal_tally", proposal]
+pub fn derive_signal_tally_pda(proposal_pubkey: &Pubkey, program_id: &Pubkey) -> Pubkey {
+    let seeds = &[b"signal_tally", proposal_pubkey.as_ref()];
+    let (pda, _) = Pubkey::find_program_address(seeds, program_id);
+    pda
+}
+
 /// Derives the Support PDA using the seeds [b"support", proposal, spl_vote_account]
 /// This matches the on-chain derivation in the support_proposal instruction.
 pub fn derive_support_pda(
@@ -475,3 +640,11 @@ pub fn get_epoch_slot_range(epoch: u64) -> (u64, u64) {
 
     (start_slot, end_slot)
 }
+
+/// First epoch in which the program lets the proofs of a snapshot slot be closed, once no
+/// proposal can still vote with them
+pub fn get_snapshot_release_epoch(snapshot_slot: u64) -> u64 {
+    const SLOTS_PER_EPOCH: u64 = 432_000;
+
+    snapshot_slot / SLOTS_PER_EPOCH + SNAPSHOT_RETENTION_EPOCHS
+}